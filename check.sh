@@ -17,7 +17,6 @@ cd "$(dirname "$0")"
 (cd simulator; cargo fmt --check --quiet)
 (cd transaction; cargo fmt --check --quiet)
 
-(cd assets/blueprints/account; scrypto fmt --check --quiet)
 (cd assets/blueprints/faucet; scrypto fmt --check --quiet)
 (cd examples; find . -maxdepth 1 -type d \( ! -name . \) -print0 | xargs -0 -n1 -I '{}' scrypto fmt --path {} --check --quiet)
 (cd radix-engine/tests/blueprints; find . -maxdepth 1 -type d \( ! -name . \) -print0 | xargs -0 -n1 -I '{}' scrypto fmt --path {} --check --quiet)
@@ -5,6 +5,9 @@
 /// The default cost unit limit.
 pub const DEFAULT_COST_UNIT_LIMIT: u32 = 100_000_000;
 
+/// The margin added to the cost units consumed in preview when recommending a cost unit limit, in percent.
+pub const PREVIEW_COST_UNIT_LIMIT_MARGIN_PERCENTAGE: u32 = 20;
+
 //==========================
 // Transaction validation
 //==========================
@@ -37,5 +40,8 @@ pub const DEFAULT_SYSTEM_LOAN: u32 = 1_000_000;
 /// The default max call depth, used by transaction executor.
 pub const DEFAULT_MAX_CALL_DEPTH: usize = 12;
 
+/// The max number of key value store entries which can be scanned by a single system call.
+pub const MAX_KV_STORE_SCAN_LIMIT: u32 = 1000;
+
 /// The default cost unit price.
 pub const DEFAULT_COST_UNIT_PRICE: u128 = 100_000_000_000u128;
@@ -92,7 +92,8 @@ impl Bech32Encoder {
             ComponentAddress::Normal(data)
             | ComponentAddress::Account(data)
             | ComponentAddress::EcdsaSecp256k1VirtualAccount(data)
-            | ComponentAddress::EddsaEd25519VirtualAccount(data) => {
+            | ComponentAddress::EddsaEd25519VirtualAccount(data)
+            | ComponentAddress::AccessController(data) => {
                 self.encode_to_fmt(fmt, EntityType::component(component_address), data)
             }
         }
@@ -24,6 +24,9 @@ pub const ECDSA_SECP_256K1_VIRTUAL_ACCOUNT_COMPONENT_ADDRESS_ENTITY_ID: u8 = 0x0
 /// A unique identifier used in the addressing of a virtual Account Component Addresses.
 pub const EDDSA_ED_25519_VIRTUAL_ACCOUNT_COMPONENT_ADDRESS_ENTITY_ID: u8 = 0x07;
 
+/// A unique identifier used in the addressing of Access Controller Component Addresses.
+pub const ACCESS_CONTROLLER_COMPONENT_ADDRESS_ENTITY_ID: u8 = 0x08;
+
 /// An enum which represents the different addressable entities.
 #[derive(PartialEq, Eq)]
 pub enum EntityType {
@@ -33,6 +36,7 @@ pub enum EntityType {
     AccountComponent,
     EcdsaSecp256k1VirtualAccountComponent,
     EddsaEd25519VirtualAccountComponent,
+    AccessControllerComponent,
     EpochManager,
     Clock,
 }
@@ -54,6 +58,7 @@ impl EntityType {
             ComponentAddress::EddsaEd25519VirtualAccount(_) => {
                 Self::EddsaEd25519VirtualAccountComponent
             }
+            ComponentAddress::AccessController(_) => Self::AccessControllerComponent,
         }
     }
 
@@ -76,6 +81,7 @@ impl EntityType {
             Self::EddsaEd25519VirtualAccountComponent => {
                 EDDSA_ED_25519_VIRTUAL_ACCOUNT_COMPONENT_ADDRESS_ENTITY_ID
             }
+            Self::AccessControllerComponent => ACCESS_CONTROLLER_COMPONENT_ADDRESS_ENTITY_ID,
             Self::EpochManager => EPOCH_MANAGER_SYSTEM_ADDRESS_ENTITY_ID,
             Self::Clock => CLOCK_SYSTEM_ADDRESS_ENTITY_ID,
         }
@@ -97,6 +103,7 @@ impl TryFrom<u8> for EntityType {
             EDDSA_ED_25519_VIRTUAL_ACCOUNT_COMPONENT_ADDRESS_ENTITY_ID => {
                 Ok(Self::EddsaEd25519VirtualAccountComponent)
             }
+            ACCESS_CONTROLLER_COMPONENT_ADDRESS_ENTITY_ID => Ok(Self::AccessControllerComponent),
             EPOCH_MANAGER_SYSTEM_ADDRESS_ENTITY_ID => Ok(Self::EpochManager),
             CLOCK_SYSTEM_ADDRESS_ENTITY_ID => Ok(Self::Clock),
             _ => Err(EntityTypeError::InvalidEntityTypeId(value)),
@@ -28,9 +28,16 @@ pub trait EngineApi<E: Debug> {
     fn sys_read(&mut self, lock_handle: LockHandle) -> Result<Vec<u8>, E>;
     fn sys_write(&mut self, lock_handle: LockHandle, buffer: Vec<u8>) -> Result<(), E>;
     fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), E>;
+    fn sys_list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<KeyValueStoreKeysPage, E>;
     fn sys_get_actor(&mut self) -> Result<ScryptoActor, E>;
     fn sys_generate_uuid(&mut self) -> Result<u128, E>;
     fn sys_get_transaction_hash(&mut self) -> Result<Hash, E>;
+    fn sys_emit_event(&mut self, type_name: String, event: Vec<u8>) -> Result<(), E>;
 }
 
 pub trait LoggerApi<E: Debug> {
@@ -43,10 +50,52 @@ pub trait InvokableModel<E>:
     + Invokable<EpochManagerCreateInvocation, E>
     + Invokable<EpochManagerSetEpochInvocation, E>
     + Invokable<EpochManagerGetCurrentEpochInvocation, E>
+    + Invokable<EpochManagerNextEpochInvocation, E>
+    + Invokable<EpochManagerGetValidatorSetInvocation, E>
+    + Invokable<EpochManagerRegisterValidatorInvocation, E>
+    + Invokable<EpochManagerUnregisterValidatorInvocation, E>
+    + Invokable<EpochManagerGetValidatorInvocation, E>
+    + Invokable<EpochManagerStakeInvocation, E>
+    + Invokable<EpochManagerUnstakeInvocation, E>
+    + Invokable<EpochManagerClaimXrdInvocation, E>
+    + Invokable<EpochManagerUpdateFeeConfigInvocation, E>
     + Invokable<ClockCreateInvocation, E>
     + Invokable<ClockSetCurrentTimeInvocation, E>
     + Invokable<ClockGetCurrentTimeInvocation, E>
     + Invokable<ClockCompareCurrentTimeInvocation, E>
+    + Invokable<AccountNewInvocation, E>
+    + Invokable<AccountNewWithResourceInvocation, E>
+    + Invokable<AccountBalanceInvocation, E>
+    + Invokable<AccountLockFeeInvocation, E>
+    + Invokable<AccountLockContingentFeeInvocation, E>
+    + Invokable<AccountLockSponsoredFeeInvocation, E>
+    + Invokable<AccountDepositInvocation, E>
+    + Invokable<AccountDepositBatchInvocation, E>
+    + Invokable<AccountTryDepositOrRefundInvocation, E>
+    + Invokable<AccountTryDepositBatchOrRefundInvocation, E>
+    + Invokable<AccountWithdrawInvocation, E>
+    + Invokable<AccountWithdrawByAmountInvocation, E>
+    + Invokable<AccountWithdrawByIdsInvocation, E>
+    + Invokable<AccountLockFeeAndWithdrawInvocation, E>
+    + Invokable<AccountLockFeeAndWithdrawByAmountInvocation, E>
+    + Invokable<AccountLockFeeAndWithdrawByIdsInvocation, E>
+    + Invokable<AccountCreateProofInvocation, E>
+    + Invokable<AccountCreateProofByAmountInvocation, E>
+    + Invokable<AccountCreateProofByIdsInvocation, E>
+    + Invokable<AccountSetDepositPolicyInvocation, E>
+    + Invokable<AccountGetDepositPolicyInvocation, E>
+    + Invokable<AccessControllerCreateGlobalInvocation, E>
+    + Invokable<AccessControllerCreateProofInvocation, E>
+    + Invokable<AccessControllerInitiateRecoveryAsPrimaryInvocation, E>
+    + Invokable<AccessControllerInitiateRecoveryAsRecoveryInvocation, E>
+    + Invokable<AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation, E>
+    + Invokable<AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation, E>
+    + Invokable<AccessControllerTimedConfirmRecoveryInvocation, E>
+    + Invokable<AccessControllerCancelPrimaryRoleRecoveryProposalInvocation, E>
+    + Invokable<AccessControllerCancelRecoveryRoleRecoveryProposalInvocation, E>
+    + Invokable<AccessControllerLockPrimaryRoleInvocation, E>
+    + Invokable<AccessControllerUnlockPrimaryRoleInvocation, E>
+    + Invokable<AccessControllerStopTimedRecoveryInvocation, E>
     + Invokable<MetadataSetInvocation, E>
     + Invokable<MetadataGetInvocation, E>
     + Invokable<AccessRulesAddAccessCheckInvocation, E>
@@ -95,12 +144,15 @@ pub trait InvokableModel<E>:
     + Invokable<ResourceManagerMintInvocation, E>
     + Invokable<ResourceManagerGetResourceTypeInvocation, E>
     + Invokable<ResourceManagerGetTotalSupplyInvocation, E>
+    + Invokable<ResourceManagerGetMaxSupplyInvocation, E>
     + Invokable<ResourceManagerUpdateNonFungibleDataInvocation, E>
+    + Invokable<ResourceManagerUpdateNonFungibleDataFieldInvocation, E>
     + Invokable<ResourceManagerNonFungibleExistsInvocation, E>
     + Invokable<ResourceManagerGetNonFungibleInvocation, E>
     + Invokable<VaultTakeInvocation, E>
     + Invokable<VaultPutInvocation, E>
     + Invokable<VaultLockFeeInvocation, E>
+    + Invokable<VaultLockSponsoredFeeInvocation, E>
     + Invokable<VaultTakeNonFungiblesInvocation, E>
     + Invokable<VaultGetAmountInvocation, E>
     + Invokable<VaultGetResourceAddressInvocation, E>
@@ -110,6 +162,9 @@ pub trait InvokableModel<E>:
     + Invokable<VaultCreateProofByIdsInvocation, E>
     + Invokable<VaultRecallInvocation, E>
     + Invokable<VaultRecallNonFungiblesInvocation, E>
+    + Invokable<VaultBurnNonFungiblesInvocation, E>
+    + Invokable<VaultFreezeInvocation, E>
+    + Invokable<VaultUnfreezeInvocation, E>
     + Invokable<WorktopPutInvocation, E>
     + Invokable<WorktopTakeAmountInvocation, E>
     + Invokable<WorktopTakeAllInvocation, E>
@@ -12,3 +12,5 @@ pub type ResourceManagerId = [u8; 36];
 pub type PackageId = [u8; 36];
 pub type EpochManagerId = [u8; 36];
 pub type ClockId = [u8; 36];
+pub type AccountId = [u8; 36];
+pub type AccessControllerId = [u8; 36];
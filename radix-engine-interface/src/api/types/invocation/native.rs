@@ -41,6 +41,8 @@ pub enum NativeMethod {
     Proof(ProofMethod),
     Worktop(WorktopMethod),
     Clock(ClockMethod),
+    Account(AccountMethod),
+    AccessController(AccessControllerMethod),
 }
 
 // Native method enum used by Kernel SystemAPI and WASM
@@ -53,6 +55,8 @@ pub enum NativeFunction {
     Package(PackageFunction),
     TransactionProcessor(TransactionProcessorFunction),
     Clock(ClockFunction),
+    Account(AccountFunction),
+    AccessController(AccessControllerFunction),
 }
 
 #[derive(
@@ -146,6 +150,119 @@ pub enum EpochManagerFunction {
     Create,
 }
 
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountFunction {
+    New,
+    NewWithResource,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountMethod {
+    Balance,
+    LockFee,
+    LockContingentFee,
+    LockSponsoredFee,
+    Deposit,
+    DepositBatch,
+    TryDepositOrRefund,
+    TryDepositBatchOrRefund,
+    Withdraw,
+    WithdrawByAmount,
+    WithdrawByIds,
+    LockFeeAndWithdraw,
+    LockFeeAndWithdrawByAmount,
+    LockFeeAndWithdrawByIds,
+    CreateProof,
+    CreateProofByAmount,
+    CreateProofByIds,
+    SetDepositPolicy,
+    GetDepositPolicy,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum AccessControllerFunction {
+    CreateGlobal,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum AccessControllerMethod {
+    CreateProof,
+    InitiateRecoveryAsPrimary,
+    InitiateRecoveryAsRecovery,
+    QuickConfirmPrimaryRoleRecoveryProposal,
+    QuickConfirmRecoveryRoleRecoveryProposal,
+    TimedConfirmRecovery,
+    CancelPrimaryRoleRecoveryProposal,
+    CancelRecoveryRoleRecoveryProposal,
+    LockPrimaryRole,
+    UnlockPrimaryRole,
+    StopTimedRecovery,
+}
+
 #[derive(
     Debug,
     Clone,
@@ -210,6 +327,15 @@ pub enum PackageMethod {
 pub enum EpochManagerMethod {
     GetCurrentEpoch,
     SetEpoch,
+    NextEpoch,
+    GetValidatorSet,
+    RegisterValidator,
+    UnregisterValidator,
+    GetValidator,
+    Stake,
+    Unstake,
+    ClaimXrd,
+    UpdateFeeConfig,
 }
 
 #[derive(
@@ -286,9 +412,11 @@ pub enum ResourceManagerMethod {
     UpdateVaultAuth,
     LockAuth,
     UpdateNonFungibleData,
+    UpdateNonFungibleDataField,
     GetNonFungible,
     GetResourceType,
     GetTotalSupply,
+    GetMaxSupply,
     NonFungibleExists,
     CreateBucket,
     CreateVault,
@@ -341,6 +469,7 @@ pub enum BucketMethod {
 pub enum VaultMethod {
     Take,
     LockFee,
+    LockSponsoredFee,
     Put,
     TakeNonFungibles,
     GetAmount,
@@ -351,6 +480,9 @@ pub enum VaultMethod {
     CreateProofByIds,
     Recall,
     RecallNonFungibles,
+    BurnNonFungibles,
+    Freeze,
+    Unfreeze,
 }
 
 #[derive(
@@ -19,6 +19,7 @@ pub enum RENodeType {
     AuthZoneStack,
     Worktop,
     GlobalAccount,
+    GlobalAccessController,
     GlobalComponent,
     GlobalResourceManager,
     GlobalPackage,
@@ -32,6 +33,8 @@ pub enum RENodeType {
     Package,
     EpochManager,
     Clock,
+    Account,
+    AccessController,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -51,6 +54,8 @@ pub enum RENodeId {
     Package(PackageId),
     EpochManager(EpochManagerId),
     Clock(ClockId),
+    Account(AccountId),
+    AccessController(AccessControllerId),
 }
 
 impl Into<[u8; 36]> for RENodeId {
@@ -64,6 +69,8 @@ impl Into<[u8; 36]> for RENodeId {
             RENodeId::Package(id) => id,
             RENodeId::EpochManager(id) => id,
             RENodeId::Clock(id) => id,
+            RENodeId::Account(id) => id,
+            RENodeId::AccessController(id) => id,
             _ => panic!("Not a stored id"),
         }
     }
@@ -198,6 +205,15 @@ pub enum KeyValueStoreOffset {
     Entry(Vec<u8>),
 }
 
+/// A page of keys, as returned by a scan of the entries of a key value store.
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
+pub struct KeyValueStoreKeysPage {
+    /// The keys of the scanned entries which hold a value.
+    pub keys: Vec<Vec<u8>>,
+    /// The key to resume the scan from, or `None` if all entries have been scanned.
+    pub next_start_after: Option<Vec<u8>>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum NonFungibleStoreOffset {
@@ -212,6 +228,8 @@ pub enum VaultOffset {
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum EpochManagerOffset {
     EpochManager,
+    ValidatorSet,
+    FeeConfig,
 }
 
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -239,6 +257,16 @@ pub enum ClockOffset {
     CurrentTimeRoundedToMinutes,
 }
 
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountOffset {
+    Account,
+}
+
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccessControllerOffset {
+    AccessController,
+}
+
 /// Specifies a specific Substate into a given RENode
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[scrypto(TypeId, Encode, Decode)]
@@ -260,6 +288,8 @@ pub enum SubstateOffset {
     Proof(ProofOffset),
     Worktop(WorktopOffset),
     Clock(ClockOffset),
+    Account(AccountOffset),
+    AccessController(AccessControllerOffset),
 }
 
 /// TODO: separate space addresses?
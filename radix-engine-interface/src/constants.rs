@@ -41,6 +41,9 @@ pub const FAUCET_PACKAGE: PackageAddress = construct_address!(
 pub const FAUCET_BLUEPRINT: &str = "Faucet";
 
 /// The address of the account package.
+///
+/// The account blueprint is native, so no package is created at this address. It is reserved so
+/// that manifests and blueprints can keep calling account functions through it.
 pub const ACCOUNT_PACKAGE: PackageAddress = construct_address!(
     EntityType::Package,
     43,
@@ -75,99 +78,129 @@ pub const ACCOUNT_BLUEPRINT: &str = "Account";
 /// The ECDSA virtual resource address.
 pub const ECDSA_SECP256K1_TOKEN: ResourceAddress = construct_address!(
     EntityType::Resource,
-    185,
-    23,
+    117,
+    149,
+    161,
+    192,
+    155,
+    192,
+    68,
+    56,
+    79,
+    186,
+    128,
+    155,
+    199,
+    188,
+    92,
+    59,
+    83,
+    241,
+    146,
+    178,
+    126,
+    213,
     55,
-    238,
-    138,
-    77,
-    229,
-    157,
-    73,
-    218,
-    212,
-    13,
-    229,
-    86,
-    14,
-    87,
-    84,
-    70,
-    106,
-    200,
-    76,
-    245,
-    67,
-    46,
-    169,
-    93
+    167,
+    164,
+    201
 );
 
 /// The system token which allows access to system resources (e.g. setting epoch)
 pub const SYSTEM_TOKEN: ResourceAddress = construct_address!(
     EntityType::Resource,
-    146,
-    35,
-    6,
-    166,
-    209,
-    58,
-    246,
-    56,
-    102,
-    182,
-    136,
-    201,
-    16,
-    55,
+    143,
+    46,
+    234,
+    87,
     25,
-    208,
-    75,
-    20,
-    192,
-    96,
-    188,
-    72,
-    153,
-    166,
+    53,
+    120,
+    228,
+    5,
+    237,
+    56,
+    58,
     19,
-    181
+    153,
+    205,
+    168,
+    37,
+    196,
+    182,
+    161,
+    162,
+    189,
+    144,
+    106,
+    252,
+    99
 );
 
 /// The XRD resource address.
 pub const RADIX_TOKEN: ResourceAddress = construct_address!(
     EntityType::Resource,
-    173,
-    130,
-    50,
-    141,
-    112,
-    34,
-    61,
-    91,
-    174,
-    38,
-    130,
-    96,
-    179,
-    4,
-    93,
-    204,
-    113,
-    220,
-    243,
-    95,
-    55,
-    167,
-    67,
-    74,
-    9,
-    105
+    159,
+    148,
+    169,
+    154,
+    227,
+    78,
+    75,
+    52,
+    72,
+    3,
+    114,
+    131,
+    232,
+    41,
+    172,
+    176,
+    75,
+    148,
+    70,
+    164,
+    177,
+    26,
+    121,
+    68,
+    254,
+    162
 );
 
 /// The address of the faucet component, test network only.
 pub const FAUCET_COMPONENT: ComponentAddress = construct_address!(
     EntityType::NormalComponent,
+    35,
+    78,
+    150,
+    173,
+    221,
+    245,
+    198,
+    37,
+    78,
+    106,
+    20,
+    17,
+    169,
+    73,
+    152,
+    133,
+    204,
+    145,
+    37,
+    125,
+    141,
+    154,
+    21,
+    174,
+    199,
+    75
+);
+
+pub const EPOCH_MANAGER: SystemAddress = construct_address!(
+    EntityType::EpochManager,
     87,
     220,
     4,
@@ -196,8 +229,8 @@ pub const FAUCET_COMPONENT: ComponentAddress = construct_address!(
     60
 );
 
-pub const EPOCH_MANAGER: SystemAddress = construct_address!(
-    EntityType::EpochManager,
+pub const CLOCK: SystemAddress = construct_address!(
+    EntityType::Clock,
     242,
     112,
     114,
@@ -226,69 +259,40 @@ pub const EPOCH_MANAGER: SystemAddress = construct_address!(
     190
 );
 
-pub const CLOCK: SystemAddress = construct_address!(
-    EntityType::Clock,
-    198,
-    192,
-    61,
-    210,
-    4,
-    230,
-    44,
-    57,
-    219,
-    60,
-    174,
-    35,
-    57,
-    88,
-    91,
-    98,
-    186,
-    244,
-    0,
-    251,
-    251,
-    77,
-    116,
-    187,
-    229,
-    39
-);
-
 /// The ED25519 virtual resource address.
 pub const EDDSA_ED25519_TOKEN: ResourceAddress = construct_address!(
     EntityType::Resource,
-    15,
-    142,
-    146,
-    10,
-    167,
-    159,
-    83,
-    52,
-    157,
-    10,
-    153,
-    116,
+    172,
     110,
-    23,
-    181,
-    146,
-    65,
-    189,
-    81,
-    225,
-    154,
+    120,
+    193,
+    250,
+    70,
     187,
-    80,
-    173,
-    107,
-    106
+    76,
+    68,
+    171,
+    211,
+    30,
+    43,
+    73,
+    30,
+    13,
+    198,
+    37,
+    110,
+    194,
+    242,
+    109,
+    76,
+    165,
+    200,
+    50
 );
 
 pub const EPOCH_MANAGER_BLUEPRINT: &str = "EpochManager";
 pub const CLOCK_BLUEPRINT: &str = "Clock";
+pub const ACCESS_CONTROLLER_BLUEPRINT: &str = "AccessController";
 pub const RESOURCE_MANAGER_BLUEPRINT: &str = "ResourceManager";
 pub const PACKAGE_BLUEPRINT: &str = "Package";
 pub const TRANSACTION_PROCESSOR_BLUEPRINT: &str = "TransactionProcessor";
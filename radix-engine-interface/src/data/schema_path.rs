@@ -1,4 +1,5 @@
 use sbor::path::SborPath;
+use sbor::rust::fmt;
 use sbor::rust::str::FromStr;
 use sbor::rust::string::String;
 use sbor::rust::string::ToString;
@@ -54,6 +55,15 @@ impl SchemaPath {
     }
 
     pub fn to_sbor_path(&self, schema: &Type) -> Option<SborPath> {
+        self.resolve(schema).map(|(sbor_path, _)| sbor_path)
+    }
+
+    /// The type of the value this path points to within some sbor of the given schema
+    pub fn to_type<'a>(&self, schema: &'a Type) -> Option<&'a Type> {
+        self.resolve(schema).map(|(_, ty)| ty)
+    }
+
+    fn resolve<'a>(&self, schema: &'a Type) -> Option<(SborPath, &'a Type)> {
         let mut cur_type = schema;
         let mut sbor_path: Vec<usize> = vec![];
 
@@ -97,7 +107,22 @@ impl SchemaPath {
             }
         }
 
-        Option::Some(SborPath::new(sbor_path))
+        Option::Some((SborPath::new(sbor_path), cur_type))
+    }
+}
+
+impl fmt::Display for SchemaPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, sub_path) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            match sub_path {
+                SchemaSubPath::Index(index) => write!(f, "{}", index)?,
+                SchemaSubPath::Field(field) => write!(f, "{}", field)?,
+            }
+        }
+        Ok(())
     }
 }
 
@@ -0,0 +1,35 @@
+use sbor::*;
+
+use crate::model::*;
+use crate::scrypto;
+use crate::Describe;
+
+/// The access rules of the three roles of an access controller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct RuleSet {
+    /// Used for day-to-day access to the controlled asset
+    pub primary_role: AccessRule,
+
+    /// Used to recover from a lost or compromised primary role
+    pub recovery_role: AccessRule,
+
+    /// Used to confirm recovery proposals made by either of the other roles
+    pub confirmation_role: AccessRule,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub enum Role {
+    Primary,
+    Recovery,
+    Confirmation,
+}
+
+/// A proposal to replace the rule set and timed recovery delay of an access controller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct RecoveryProposal {
+    pub rule_set: RuleSet,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
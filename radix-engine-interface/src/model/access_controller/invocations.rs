@@ -0,0 +1,294 @@
+use sbor::rust::fmt::Debug;
+use sbor::*;
+
+use crate::api::api::*;
+use crate::model::*;
+use crate::scrypto;
+use crate::wasm::*;
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerCreateGlobalInvocation {
+    pub controlled_asset: Bucket,
+    pub rule_set: RuleSet,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+impl Invocation for AccessControllerCreateGlobalInvocation {
+    type Output = ComponentAddress;
+}
+
+impl SerializableInvocation for AccessControllerCreateGlobalInvocation {
+    type ScryptoOutput = ComponentAddress;
+}
+
+impl Into<SerializedInvocation> for AccessControllerCreateGlobalInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Function(NativeFunctionInvocation::AccessController(
+            AccessControllerFunctionInvocation::CreateGlobal(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerCreateProofInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerCreateProofInvocation {
+    type Output = Proof;
+}
+
+impl SerializableInvocation for AccessControllerCreateProofInvocation {
+    type ScryptoOutput = Proof;
+}
+
+impl Into<SerializedInvocation> for AccessControllerCreateProofInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::CreateProof(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerInitiateRecoveryAsPrimaryInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerInitiateRecoveryAsPrimaryInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerInitiateRecoveryAsPrimaryInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerInitiateRecoveryAsPrimaryInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::InitiateRecoveryAsPrimary(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerInitiateRecoveryAsRecoveryInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerInitiateRecoveryAsRecoveryInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerInitiateRecoveryAsRecoveryInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerInitiateRecoveryAsRecoveryInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::InitiateRecoveryAsRecovery(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation>
+    for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation
+{
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::QuickConfirmPrimaryRoleRecoveryProposal(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation>
+    for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation
+{
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::QuickConfirmRecoveryRoleRecoveryProposal(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerTimedConfirmRecoveryInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerTimedConfirmRecoveryInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerTimedConfirmRecoveryInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerTimedConfirmRecoveryInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::TimedConfirmRecovery(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerCancelPrimaryRoleRecoveryProposalInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerCancelPrimaryRoleRecoveryProposalInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerCancelPrimaryRoleRecoveryProposalInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerCancelPrimaryRoleRecoveryProposalInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::CancelPrimaryRoleRecoveryProposal(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerCancelRecoveryRoleRecoveryProposalInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerCancelRecoveryRoleRecoveryProposalInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerCancelRecoveryRoleRecoveryProposalInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerCancelRecoveryRoleRecoveryProposalInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::CancelRecoveryRoleRecoveryProposal(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerLockPrimaryRoleInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerLockPrimaryRoleInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerLockPrimaryRoleInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerLockPrimaryRoleInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::LockPrimaryRole(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerUnlockPrimaryRoleInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerUnlockPrimaryRoleInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerUnlockPrimaryRoleInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerUnlockPrimaryRoleInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::UnlockPrimaryRole(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerStopTimedRecoveryInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerStopTimedRecoveryInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerStopTimedRecoveryInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccessControllerStopTimedRecoveryInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::AccessController(
+            AccessControllerMethodInvocation::StopTimedRecovery(self),
+        ))
+        .into()
+    }
+}
@@ -0,0 +1,31 @@
+use sbor::rust::collections::BTreeSet;
+use sbor::*;
+
+use crate::model::*;
+use crate::scrypto;
+use crate::Describe;
+
+/// Which resources an account accepts deposits of.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub enum AccountDepositPolicy {
+    /// Accepts deposits of any resource
+    #[default]
+    AcceptAll,
+
+    /// Only accepts deposits of the listed resources
+    AllowList(BTreeSet<ResourceAddress>),
+
+    /// Accepts deposits of any resource but the listed ones
+    DenyList(BTreeSet<ResourceAddress>),
+}
+
+impl AccountDepositPolicy {
+    pub fn accepts(&self, resource_address: &ResourceAddress) -> bool {
+        match self {
+            AccountDepositPolicy::AcceptAll => true,
+            AccountDepositPolicy::AllowList(allowed) => allowed.contains(resource_address),
+            AccountDepositPolicy::DenyList(denied) => !denied.contains(resource_address),
+        }
+    }
+}
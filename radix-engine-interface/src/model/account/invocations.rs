@@ -0,0 +1,522 @@
+use sbor::rust::collections::BTreeSet;
+use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+use crate::api::api::*;
+use crate::math::*;
+use crate::model::*;
+use crate::scrypto;
+use crate::wasm::*;
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountNewInvocation {
+    pub withdraw_rule: AccessRule,
+}
+
+impl Invocation for AccountNewInvocation {
+    type Output = ComponentAddress;
+}
+
+impl SerializableInvocation for AccountNewInvocation {
+    type ScryptoOutput = ComponentAddress;
+}
+
+impl Into<SerializedInvocation> for AccountNewInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Function(NativeFunctionInvocation::Account(
+            AccountFunctionInvocation::New(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountNewWithResourceInvocation {
+    pub withdraw_rule: AccessRule,
+    pub bucket: Bucket,
+}
+
+impl Invocation for AccountNewWithResourceInvocation {
+    type Output = ComponentAddress;
+}
+
+impl SerializableInvocation for AccountNewWithResourceInvocation {
+    type ScryptoOutput = ComponentAddress;
+}
+
+impl Into<SerializedInvocation> for AccountNewWithResourceInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Function(NativeFunctionInvocation::Account(
+            AccountFunctionInvocation::NewWithResource(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountBalanceInvocation {
+    pub receiver: ComponentAddress,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountBalanceInvocation {
+    type Output = Decimal;
+}
+
+impl SerializableInvocation for AccountBalanceInvocation {
+    type ScryptoOutput = Decimal;
+}
+
+impl Into<SerializedInvocation> for AccountBalanceInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::Balance(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountLockFeeInvocation {
+    pub receiver: ComponentAddress,
+    pub amount: Decimal,
+}
+
+impl Invocation for AccountLockFeeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccountLockFeeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccountLockFeeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::LockFee(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountLockContingentFeeInvocation {
+    pub receiver: ComponentAddress,
+    pub amount: Decimal,
+}
+
+impl Invocation for AccountLockContingentFeeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccountLockContingentFeeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccountLockContingentFeeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::LockContingentFee(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountLockSponsoredFeeInvocation {
+    pub receiver: ComponentAddress,
+    pub amount: Decimal,
+    pub sponsorship: FeeSponsorship,
+}
+
+impl Invocation for AccountLockSponsoredFeeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccountLockSponsoredFeeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccountLockSponsoredFeeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::LockSponsoredFee(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountDepositInvocation {
+    pub receiver: ComponentAddress,
+    pub bucket: Bucket,
+}
+
+impl Invocation for AccountDepositInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccountDepositInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccountDepositInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::Deposit(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountDepositBatchInvocation {
+    pub receiver: ComponentAddress,
+    pub buckets: Vec<Bucket>,
+}
+
+impl Invocation for AccountDepositBatchInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccountDepositBatchInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccountDepositBatchInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::DepositBatch(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountTryDepositOrRefundInvocation {
+    pub receiver: ComponentAddress,
+    pub bucket: Bucket,
+}
+
+impl Invocation for AccountTryDepositOrRefundInvocation {
+    type Output = Option<Bucket>;
+}
+
+impl SerializableInvocation for AccountTryDepositOrRefundInvocation {
+    type ScryptoOutput = Option<Bucket>;
+}
+
+impl Into<SerializedInvocation> for AccountTryDepositOrRefundInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::TryDepositOrRefund(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountTryDepositBatchOrRefundInvocation {
+    pub receiver: ComponentAddress,
+    pub buckets: Vec<Bucket>,
+}
+
+impl Invocation for AccountTryDepositBatchOrRefundInvocation {
+    type Output = Vec<Bucket>;
+}
+
+impl SerializableInvocation for AccountTryDepositBatchOrRefundInvocation {
+    type ScryptoOutput = Vec<Bucket>;
+}
+
+impl Into<SerializedInvocation> for AccountTryDepositBatchOrRefundInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::TryDepositBatchOrRefund(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountWithdrawInvocation {
+    pub receiver: ComponentAddress,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountWithdrawInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for AccountWithdrawInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for AccountWithdrawInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::Withdraw(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountWithdrawByAmountInvocation {
+    pub receiver: ComponentAddress,
+    pub amount: Decimal,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountWithdrawByAmountInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for AccountWithdrawByAmountInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for AccountWithdrawByAmountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::WithdrawByAmount(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountWithdrawByIdsInvocation {
+    pub receiver: ComponentAddress,
+    pub ids: BTreeSet<NonFungibleId>,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountWithdrawByIdsInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for AccountWithdrawByIdsInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for AccountWithdrawByIdsInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::WithdrawByIds(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountLockFeeAndWithdrawInvocation {
+    pub receiver: ComponentAddress,
+    pub amount_to_lock: Decimal,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountLockFeeAndWithdrawInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for AccountLockFeeAndWithdrawInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for AccountLockFeeAndWithdrawInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::LockFeeAndWithdraw(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountLockFeeAndWithdrawByAmountInvocation {
+    pub receiver: ComponentAddress,
+    pub amount_to_lock: Decimal,
+    pub amount: Decimal,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountLockFeeAndWithdrawByAmountInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for AccountLockFeeAndWithdrawByAmountInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for AccountLockFeeAndWithdrawByAmountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::LockFeeAndWithdrawByAmount(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountLockFeeAndWithdrawByIdsInvocation {
+    pub receiver: ComponentAddress,
+    pub amount_to_lock: Decimal,
+    pub ids: BTreeSet<NonFungibleId>,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountLockFeeAndWithdrawByIdsInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for AccountLockFeeAndWithdrawByIdsInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for AccountLockFeeAndWithdrawByIdsInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::LockFeeAndWithdrawByIds(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountCreateProofInvocation {
+    pub receiver: ComponentAddress,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountCreateProofInvocation {
+    type Output = Proof;
+}
+
+impl SerializableInvocation for AccountCreateProofInvocation {
+    type ScryptoOutput = Proof;
+}
+
+impl Into<SerializedInvocation> for AccountCreateProofInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::CreateProof(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountCreateProofByAmountInvocation {
+    pub receiver: ComponentAddress,
+    pub amount: Decimal,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountCreateProofByAmountInvocation {
+    type Output = Proof;
+}
+
+impl SerializableInvocation for AccountCreateProofByAmountInvocation {
+    type ScryptoOutput = Proof;
+}
+
+impl Into<SerializedInvocation> for AccountCreateProofByAmountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::CreateProofByAmount(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountCreateProofByIdsInvocation {
+    pub receiver: ComponentAddress,
+    pub ids: BTreeSet<NonFungibleId>,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for AccountCreateProofByIdsInvocation {
+    type Output = Proof;
+}
+
+impl SerializableInvocation for AccountCreateProofByIdsInvocation {
+    type ScryptoOutput = Proof;
+}
+
+impl Into<SerializedInvocation> for AccountCreateProofByIdsInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::CreateProofByIds(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountSetDepositPolicyInvocation {
+    pub receiver: ComponentAddress,
+    pub deposit_policy: AccountDepositPolicy,
+}
+
+impl Invocation for AccountSetDepositPolicyInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccountSetDepositPolicyInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for AccountSetDepositPolicyInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::SetDepositPolicy(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountGetDepositPolicyInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccountGetDepositPolicyInvocation {
+    type Output = AccountDepositPolicy;
+}
+
+impl SerializableInvocation for AccountGetDepositPolicyInvocation {
+    type ScryptoOutput = AccountDepositPolicy;
+}
+
+impl Into<SerializedInvocation> for AccountGetDepositPolicyInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Account(
+            AccountMethodInvocation::GetDepositPolicy(self),
+        ))
+        .into()
+    }
+}
@@ -17,6 +17,7 @@ pub enum ComponentAddress {
     Account([u8; 26]),
     EcdsaSecp256k1VirtualAccount([u8; 26]),
     EddsaEd25519VirtualAccount([u8; 26]),
+    AccessController([u8; 26]),
 }
 
 //========
@@ -39,6 +40,9 @@ impl TryFrom<&[u8]> for ComponentAddress {
                 EntityType::EddsaEd25519VirtualAccountComponent => {
                     Ok(Self::EddsaEd25519VirtualAccount(copy_u8_array(&slice[1..])))
                 }
+                EntityType::AccessControllerComponent => {
+                    Ok(Self::AccessController(copy_u8_array(&slice[1..])))
+                }
                 _ => Err(AddressError::InvalidEntityTypeId(slice[0])),
             },
             _ => Err(AddressError::InvalidLength(slice.len())),
@@ -54,7 +58,8 @@ impl ComponentAddress {
             Self::Normal(v)
             | Self::Account(v)
             | Self::EddsaEd25519VirtualAccount(v)
-            | Self::EcdsaSecp256k1VirtualAccount(v) => buf.extend(v),
+            | Self::EcdsaSecp256k1VirtualAccount(v)
+            | Self::AccessController(v) => buf.extend(v),
         }
         buf
     }
@@ -132,6 +137,9 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for ComponentAddress {
             ComponentAddress::EddsaEd25519VirtualAccount(_) => {
                 write!(f, "EddsaEd25519VirtualAccountComponent[{}]", self.to_hex())
             }
+            ComponentAddress::AccessController(_) => {
+                write!(f, "AccessControllerComponent[{}]", self.to_hex())
+            }
         }
         .map_err(|err| AddressError::FormatError(err))
     }
@@ -2,13 +2,18 @@ use sbor::rust::fmt::Debug;
 use sbor::*;
 
 use crate::api::api::*;
+use crate::crypto::EcdsaSecp256k1PublicKey;
 use crate::model::*;
 use crate::scrypto;
 use crate::wasm::*;
 
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
-pub struct EpochManagerCreateInvocation {}
+pub struct EpochManagerCreateInvocation {
+    pub max_validators: u32,
+    pub unbonding_delay_in_epochs: u64,
+    pub fee_config: FeeConfig,
+}
 
 impl Invocation for EpochManagerCreateInvocation {
     type Output = SystemAddress;
@@ -73,3 +78,222 @@ impl Into<SerializedInvocation> for EpochManagerSetEpochInvocation {
         .into()
     }
 }
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerUpdateFeeConfigInvocation {
+    pub receiver: SystemAddress,
+    /// The version of the new configuration, which must follow the current one
+    pub version: u64,
+    pub fee_config: FeeConfig,
+}
+
+impl Invocation for EpochManagerUpdateFeeConfigInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for EpochManagerUpdateFeeConfigInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for EpochManagerUpdateFeeConfigInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::UpdateFeeConfig(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerNextEpochInvocation {
+    pub receiver: SystemAddress,
+}
+
+impl Invocation for EpochManagerNextEpochInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for EpochManagerNextEpochInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for EpochManagerNextEpochInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::NextEpoch(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerGetValidatorSetInvocation {
+    pub receiver: SystemAddress,
+}
+
+impl Invocation for EpochManagerGetValidatorSetInvocation {
+    type Output = ValidatorSet;
+}
+
+impl SerializableInvocation for EpochManagerGetValidatorSetInvocation {
+    type ScryptoOutput = ValidatorSet;
+}
+
+impl Into<SerializedInvocation> for EpochManagerGetValidatorSetInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::GetValidatorSet(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerRegisterValidatorInvocation {
+    pub receiver: SystemAddress,
+    pub key: EcdsaSecp256k1PublicKey,
+}
+
+impl Invocation for EpochManagerRegisterValidatorInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for EpochManagerRegisterValidatorInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for EpochManagerRegisterValidatorInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::RegisterValidator(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerUnregisterValidatorInvocation {
+    pub receiver: SystemAddress,
+    pub key: EcdsaSecp256k1PublicKey,
+}
+
+impl Invocation for EpochManagerUnregisterValidatorInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for EpochManagerUnregisterValidatorInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for EpochManagerUnregisterValidatorInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::UnregisterValidator(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerGetValidatorInvocation {
+    pub receiver: SystemAddress,
+    pub key: EcdsaSecp256k1PublicKey,
+}
+
+impl Invocation for EpochManagerGetValidatorInvocation {
+    type Output = Option<ValidatorInfo>;
+}
+
+impl SerializableInvocation for EpochManagerGetValidatorInvocation {
+    type ScryptoOutput = Option<ValidatorInfo>;
+}
+
+impl Into<SerializedInvocation> for EpochManagerGetValidatorInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::GetValidator(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerStakeInvocation {
+    pub receiver: SystemAddress,
+    pub key: EcdsaSecp256k1PublicKey,
+    pub stake: Bucket,
+}
+
+impl Invocation for EpochManagerStakeInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for EpochManagerStakeInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for EpochManagerStakeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::Stake(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerUnstakeInvocation {
+    pub receiver: SystemAddress,
+    pub key: EcdsaSecp256k1PublicKey,
+    pub stake_units: Bucket,
+}
+
+impl Invocation for EpochManagerUnstakeInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for EpochManagerUnstakeInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for EpochManagerUnstakeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::Unstake(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EpochManagerClaimXrdInvocation {
+    pub receiver: SystemAddress,
+    pub key: EcdsaSecp256k1PublicKey,
+    pub unstake_claims: Bucket,
+}
+
+impl Invocation for EpochManagerClaimXrdInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for EpochManagerClaimXrdInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for EpochManagerClaimXrdInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
+            EpochManagerMethodInvocation::ClaimXrd(self),
+        ))
+        .into()
+    }
+}
@@ -1,3 +1,5 @@
 mod invocations;
+mod validator;
 
 pub use invocations::*;
+pub use validator::*;
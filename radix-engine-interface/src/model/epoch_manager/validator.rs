@@ -0,0 +1,30 @@
+use sbor::rust::collections::BTreeMap;
+use sbor::*;
+
+use crate::crypto::EcdsaSecp256k1PublicKey;
+use crate::math::*;
+use crate::model::*;
+use crate::scrypto;
+
+/// The validators of an epoch, keyed by their consensus key, along with the stake each had
+/// when the epoch started.
+pub type ValidatorSet = BTreeMap<EcdsaSecp256k1PublicKey, Decimal>;
+
+/// A snapshot of a validator in the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ValidatorInfo {
+    pub is_registered: bool,
+    pub stake: Decimal,
+    pub stake_unit_resource: ResourceAddress,
+    pub unstake_claim_resource: ResourceAddress,
+}
+
+/// The immutable data of an unstake claim non-fungible, which can be exchanged for `amount` XRD
+/// once the epoch manager reaches `claimable_epoch`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct UnstakeClaim {
+    pub amount: Decimal,
+    pub claimable_epoch: u64,
+}
@@ -0,0 +1,29 @@
+use sbor::*;
+
+use crate::math::Decimal;
+use crate::scrypto;
+use crate::Describe;
+
+/// The pricing of transaction execution, held on ledger by the epoch manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct FeeConfig {
+    /// The price of a cost unit in XRD
+    pub cost_unit_price: Decimal,
+    pub fee_table: FeeTableConfig,
+}
+
+/// The costs of the fee table, in cost units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct FeeTableConfig {
+    pub tx_base_fee: u32,
+    pub tx_manifest_decoding_per_byte: u32,
+    pub tx_manifest_verification_per_byte: u32,
+    pub tx_signature_verification_per_sig: u32,
+    pub tx_blob_price_per_byte: u32,
+    pub fixed_low: u32,
+    pub fixed_medium: u32,
+    pub fixed_high: u32,
+    pub wasm_instantiation_per_byte: u32,
+}
@@ -0,0 +1,91 @@
+use sbor::rust::string::String;
+use sbor::rust::string::ToString;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+use crate::math::Decimal;
+use crate::model::{ComponentAddress, PackageAddress};
+use crate::scrypto;
+use crate::Describe;
+
+/// The terms under which a sponsor pays the fees of a transaction.
+///
+/// A sponsorship only covers transactions which invoke nothing but the allowed component
+/// methods and blueprint functions, so a sponsor has to list the method which locks the fee
+/// too. Methods of native components, such as accounts, are listed by name just the same.
+/// The transaction's own worktop, auth zone, buckets and proofs are exempt, while native
+/// functions, such as publishing a package or creating a resource, are never allowed.
+///
+/// A notary pays the fees of the signers of a transaction by notarizing as a signatory and
+/// locking a sponsored fee from its own account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct FeeSponsorship {
+    /// The most XRD the sponsor locks in a single transaction
+    pub max_contribution: Decimal,
+    /// The component methods a sponsored transaction may call
+    pub allowed_methods: Vec<(ComponentAddress, String)>,
+    /// The blueprint functions a sponsored transaction may call
+    pub allowed_functions: Vec<(PackageAddress, String, String)>,
+    /// The epoch from which the sponsorship no longer applies
+    pub expires_at_epoch: Option<u64>,
+}
+
+impl FeeSponsorship {
+    pub fn new(max_contribution: Decimal) -> Self {
+        Self {
+            max_contribution,
+            allowed_methods: Vec::new(),
+            allowed_functions: Vec::new(),
+            expires_at_epoch: None,
+        }
+    }
+
+    pub fn allow_method(mut self, component_address: ComponentAddress, method: &str) -> Self {
+        self.allowed_methods
+            .push((component_address, method.to_string()));
+        self
+    }
+
+    pub fn allow_function(
+        mut self,
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        function: &str,
+    ) -> Self {
+        self.allowed_functions.push((
+            package_address,
+            blueprint_name.to_string(),
+            function.to_string(),
+        ));
+        self
+    }
+
+    pub fn expires_at_epoch(mut self, epoch: u64) -> Self {
+        self.expires_at_epoch = Some(epoch);
+        self
+    }
+
+    pub fn allows_method(&self, component_address: &ComponentAddress, method: &str) -> bool {
+        self.allowed_methods
+            .iter()
+            .any(|(address, name)| address == component_address && name == method)
+    }
+
+    pub fn allows_function(
+        &self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+        function: &str,
+    ) -> bool {
+        self.allowed_functions
+            .iter()
+            .any(|(address, blueprint, name)| {
+                address == package_address && blueprint == blueprint_name && name == function
+            })
+    }
+
+    pub fn is_expired(&self, current_epoch: u64) -> bool {
+        matches!(self.expires_at_epoch, Some(expires_at_epoch) if current_epoch >= expires_at_epoch)
+    }
+}
@@ -1,3 +1,7 @@
+mod fee_config;
+mod fee_sponsorship;
 mod royalty_config;
 
+pub use fee_config::*;
+pub use fee_sponsorship::*;
 pub use royalty_config::*;
@@ -1,6 +1,7 @@
 use sbor::rust::fmt::Debug;
 
 use crate::api::{api::*, types::*};
+use crate::model::MetadataValue;
 use crate::scrypto;
 use crate::wasm::*;
 
@@ -9,7 +10,7 @@ use crate::wasm::*;
 pub struct MetadataSetInvocation {
     pub receiver: RENodeId,
     pub key: String,
-    pub value: String,
+    pub value: MetadataValue,
 }
 
 impl Invocation for MetadataSetInvocation {
@@ -37,11 +38,11 @@ pub struct MetadataGetInvocation {
 }
 
 impl Invocation for MetadataGetInvocation {
-    type Output = Option<String>;
+    type Output = Option<MetadataValue>;
 }
 
 impl SerializableInvocation for MetadataGetInvocation {
-    type ScryptoOutput = Option<String>;
+    type ScryptoOutput = Option<MetadataValue>;
 }
 
 impl Into<SerializedInvocation> for MetadataGetInvocation {
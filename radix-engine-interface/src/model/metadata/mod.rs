@@ -1,3 +1,5 @@
 mod invocations;
+mod value;
 
 pub use invocations::*;
+pub use value::*;
@@ -0,0 +1,199 @@
+use sbor::rust::boxed::Box;
+use sbor::rust::fmt;
+use sbor::rust::string::String;
+use sbor::rust::string::ToString;
+use sbor::rust::vec::Vec;
+use sbor::*;
+use utils::ContextualDisplay;
+
+use crate::address::{AddressDisplayContext, AddressError};
+use crate::api::types::GlobalAddress;
+use crate::math::Decimal;
+use crate::scrypto;
+
+/// A typed metadata value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum MetadataValue {
+    String(String),
+    Url(String),
+    Decimal(Decimal),
+    Address(GlobalAddress),
+    List(Vec<MetadataValue>),
+}
+
+/// The type of a metadata value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum MetadataValueType {
+    String,
+    Url,
+    Decimal,
+    Address,
+    List(Box<MetadataValueType>),
+}
+
+/// The keys which wallets and explorers rely on, with the type their values must have.
+pub const WELL_KNOWN_METADATA_KEYS: [(&str, MetadataValueType); 5] = [
+    ("name", MetadataValueType::String),
+    ("symbol", MetadataValueType::String),
+    ("description", MetadataValueType::String),
+    ("icon_url", MetadataValueType::Url),
+    ("url", MetadataValueType::Url),
+];
+
+/// Returns the type values of a well-known metadata key must have, if the key is well-known.
+pub fn well_known_metadata_type(key: &str) -> Option<MetadataValueType> {
+    WELL_KNOWN_METADATA_KEYS
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, value_type)| value_type.clone())
+}
+
+impl MetadataValue {
+    /// Whether the value is of the given type. Every element of a list must be of the element
+    /// type, so an empty list matches any list type.
+    pub fn is_of_type(&self, value_type: &MetadataValueType) -> bool {
+        match (self, value_type) {
+            (MetadataValue::String(..), MetadataValueType::String)
+            | (MetadataValue::Url(..), MetadataValueType::Url)
+            | (MetadataValue::Decimal(..), MetadataValueType::Decimal)
+            | (MetadataValue::Address(..), MetadataValueType::Address) => true,
+            (MetadataValue::List(values), MetadataValueType::List(element_type)) => {
+                values.iter().all(|value| value.is_of_type(element_type))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the string content of a `String` or `Url` value.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MetadataValue::String(value) | MetadataValue::Url(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Checks a URL is an absolute `http` or `https` URL without whitespace.
+pub fn is_valid_metadata_url(url: &str) -> bool {
+    let rest = match url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    !host.is_empty() && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+impl From<String> for MetadataValue {
+    fn from(value: String) -> Self {
+        MetadataValue::String(value)
+    }
+}
+
+impl From<&str> for MetadataValue {
+    fn from(value: &str) -> Self {
+        MetadataValue::String(value.to_string())
+    }
+}
+
+impl From<Decimal> for MetadataValue {
+    fn from(value: Decimal) -> Self {
+        MetadataValue::Decimal(value)
+    }
+}
+
+impl From<GlobalAddress> for MetadataValue {
+    fn from(value: GlobalAddress) -> Self {
+        MetadataValue::Address(value)
+    }
+}
+
+impl<T: Into<MetadataValue>> From<Vec<T>> for MetadataValue {
+    fn from(values: Vec<T>) -> Self {
+        MetadataValue::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+//======
+// text
+//======
+
+impl fmt::Display for MetadataValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetadataValueType::String => write!(f, "String"),
+            MetadataValueType::Url => write!(f, "Url"),
+            MetadataValueType::Decimal => write!(f, "Decimal"),
+            MetadataValueType::Address => write!(f, "Address"),
+            MetadataValueType::List(element_type) => write!(f, "List<{}>", element_type),
+        }
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for MetadataValue {
+    type Error = AddressError;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            MetadataValue::String(value) | MetadataValue::Url(value) => {
+                write!(f, "{}", value).map_err(AddressError::FormatError)
+            }
+            MetadataValue::Decimal(value) => {
+                write!(f, "{}", value).map_err(AddressError::FormatError)
+            }
+            MetadataValue::Address(address) => match address {
+                GlobalAddress::Component(address) => address.contextual_format(f, context),
+                GlobalAddress::Package(address) => address.contextual_format(f, context),
+                GlobalAddress::Resource(address) => address.contextual_format(f, context),
+                GlobalAddress::System(address) => address.contextual_format(f, context),
+            },
+            MetadataValue::List(values) => {
+                f.write_str("[").map_err(AddressError::FormatError)?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ").map_err(AddressError::FormatError)?;
+                    }
+                    value.contextual_format(f, context)?;
+                }
+                f.write_str("]").map_err(AddressError::FormatError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::vec;
+
+    #[test]
+    fn urls_must_be_absolute_http_urls() {
+        assert!(is_valid_metadata_url("https://example.com/icon.png"));
+        assert!(is_valid_metadata_url("http://example.com?size=64"));
+        assert!(!is_valid_metadata_url("ftp://example.com/icon.png"));
+        assert!(!is_valid_metadata_url("https:///icon.png"));
+        assert!(!is_valid_metadata_url("https://example.com/my icon.png"));
+        assert!(!is_valid_metadata_url("example.com"));
+    }
+
+    #[test]
+    fn list_values_match_their_element_type() {
+        let list_of_strings = MetadataValueType::List(Box::new(MetadataValueType::String));
+        assert!(MetadataValue::from(vec!["a", "b"]).is_of_type(&list_of_strings));
+        assert!(MetadataValue::List(vec![]).is_of_type(&list_of_strings));
+        assert!(!MetadataValue::List(vec![
+            MetadataValue::from("a"),
+            MetadataValue::Url("https://example.com".to_string()),
+        ])
+        .is_of_type(&list_of_strings));
+        assert!(!MetadataValue::from("a").is_of_type(&list_of_strings));
+    }
+}
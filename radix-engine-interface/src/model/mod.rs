@@ -1,3 +1,5 @@
+mod access_controller;
+mod account;
 mod auth;
 mod clock;
 mod component;
@@ -9,6 +11,8 @@ mod resource;
 mod scrypto;
 mod system;
 
+pub use access_controller::*;
+pub use account::*;
 pub use auth::*;
 pub use clock::*;
 pub use component::*;
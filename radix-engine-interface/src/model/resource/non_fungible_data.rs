@@ -1,7 +1,9 @@
 use sbor::rust::vec::Vec;
-use sbor::{DecodeError, EncodeError};
+use sbor::*;
 use scrypto_abi::Type;
 
+use crate::scrypto;
+
 /// Represents the data structure of a non-fungible.
 pub trait NonFungibleData {
     /// Decodes `Self` from the serialized immutable and mutable parts.
@@ -20,4 +22,26 @@ pub trait NonFungibleData {
 
     /// Returns the schema of the mutable data.
     fn mutable_data_schema() -> Type;
+
+    /// Returns the schema of both data parts, which a resource manager can be created with to
+    /// have minted and updated data validated.
+    fn data_schema() -> NonFungibleDataSchema
+    where
+        Self: Sized,
+    {
+        NonFungibleDataSchema {
+            immutable: Self::immutable_data_schema(),
+            mutable: Self::mutable_data_schema(),
+        }
+    }
+}
+
+/// The schema of the immutable and mutable data parts of the non-fungibles of a resource.
+///
+/// Both parts are expected to be structs with named fields, as derived by `NonFungibleData`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct NonFungibleDataSchema {
+    pub immutable: Type,
+    pub mutable: Type,
 }
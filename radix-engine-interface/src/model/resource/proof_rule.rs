@@ -3,6 +3,7 @@ use crate::math::Decimal;
 use crate::model::AccessRuleNode::{AllOf, AnyOf};
 use crate::model::*;
 use crate::scrypto;
+use crate::time::Instant;
 use crate::Describe;
 use sbor::rust::borrow::ToOwned;
 use sbor::rust::boxed::Box;
@@ -161,6 +162,12 @@ pub enum ProofRule {
     CountOf(SoftCount, SoftResourceOrNonFungibleList),
     AllOf(SoftResourceOrNonFungibleList),
     AnyOf(SoftResourceOrNonFungibleList),
+    /// Satisfied once the ledger time, which is rounded down to the minute, has reached the instant
+    AllowedAfter(Instant),
+    /// Satisfied while the ledger time, which is rounded down to the minute, is before the instant
+    AllowedBefore(Instant),
+    /// Satisfied while the current epoch is within `[start, end)`
+    WithinEpochRange(u64, u64),
 }
 
 impl From<ResourceAddress> for ProofRule {
@@ -269,6 +276,18 @@ where
     ProofRule::AmountOf(amount.into(), resource.into())
 }
 
+pub fn allowed_after(instant: Instant) -> ProofRule {
+    ProofRule::AllowedAfter(instant)
+}
+
+pub fn allowed_before(instant: Instant) -> ProofRule {
+    ProofRule::AllowedBefore(instant)
+}
+
+pub fn within_epoch_range(start: u64, end: u64) -> ProofRule {
+    ProofRule::WithinEpochRange(start, end)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 #[scrypto(TypeId, Encode, Decode, Describe)]
 pub enum AccessRule {
@@ -23,6 +23,7 @@ pub enum VaultMethodAuthKey {
     Withdraw,
     Deposit,
     Recall,
+    Freeze,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -35,6 +36,7 @@ pub enum ResourceMethodAuthKey {
     Withdraw,
     Deposit,
     Recall,
+    Freeze,
 }
 
 #[derive(Debug)]
@@ -44,6 +46,8 @@ pub struct ResourceManagerCreateInvocation {
     pub metadata: HashMap<String, String>,
     pub access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
     pub mint_params: Option<MintParams>,
+    pub max_supply: Option<Decimal>,
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
 }
 
 impl Invocation for ResourceManagerCreateInvocation {
@@ -70,6 +74,8 @@ pub struct ResourceManagerCreateWithOwnerInvocation {
     pub metadata: HashMap<String, String>,
     pub owner_badge: NonFungibleAddress,
     pub mint_params: Option<MintParams>,
+    pub max_supply: Option<Decimal>,
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
 }
 
 impl Invocation for ResourceManagerCreateWithOwnerInvocation {
@@ -302,6 +308,29 @@ impl Into<SerializedInvocation> for ResourceManagerGetTotalSupplyInvocation {
     }
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerGetMaxSupplyInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerGetMaxSupplyInvocation {
+    type Output = Option<Decimal>;
+}
+
+impl SerializableInvocation for ResourceManagerGetMaxSupplyInvocation {
+    type ScryptoOutput = Option<Decimal>;
+}
+
+impl Into<SerializedInvocation> for ResourceManagerGetMaxSupplyInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::GetMaxSupply(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct ResourceManagerUpdateNonFungibleDataInvocation {
@@ -327,6 +356,32 @@ impl Into<SerializedInvocation> for ResourceManagerUpdateNonFungibleDataInvocati
     }
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    pub receiver: ResourceAddress,
+    pub id: NonFungibleId,
+    pub field: String,
+    pub data: Vec<u8>,
+}
+
+impl Invocation for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::UpdateNonFungibleDataField(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct ResourceManagerNonFungibleExistsInvocation {
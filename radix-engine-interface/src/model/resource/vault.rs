@@ -12,6 +12,7 @@ use crate::abi::*;
 use crate::api::{api::*, types::*};
 use crate::data::ScryptoCustomTypeId;
 use crate::math::*;
+use crate::model::FeeSponsorship;
 use crate::scrypto;
 use crate::scrypto_type;
 use crate::wasm::*;
@@ -159,6 +160,76 @@ impl Into<SerializedInvocation> for VaultRecallNonFungiblesInvocation {
     }
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct VaultBurnNonFungiblesInvocation {
+    pub receiver: VaultId,
+    pub non_fungible_ids: BTreeSet<NonFungibleId>,
+}
+
+impl Invocation for VaultBurnNonFungiblesInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for VaultBurnNonFungiblesInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for VaultBurnNonFungiblesInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Vault(
+            VaultMethodInvocation::BurnNonFungibles(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct VaultFreezeInvocation {
+    pub receiver: VaultId,
+}
+
+impl Invocation for VaultFreezeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for VaultFreezeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for VaultFreezeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Vault(
+            VaultMethodInvocation::Freeze(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct VaultUnfreezeInvocation {
+    pub receiver: VaultId,
+}
+
+impl Invocation for VaultUnfreezeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for VaultUnfreezeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for VaultUnfreezeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Vault(
+            VaultMethodInvocation::Unfreeze(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct VaultGetResourceAddressInvocation {
@@ -301,6 +372,31 @@ impl Into<SerializedInvocation> for VaultLockFeeInvocation {
     }
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct VaultLockSponsoredFeeInvocation {
+    pub receiver: VaultId,
+    pub amount: Decimal,
+    pub sponsorship: FeeSponsorship,
+}
+
+impl Invocation for VaultLockSponsoredFeeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for VaultLockSponsoredFeeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for VaultLockSponsoredFeeInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Vault(
+            VaultMethodInvocation::LockSponsoredFee(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(PartialEq, Eq, Hash)]
 pub struct Vault(pub VaultId);
 
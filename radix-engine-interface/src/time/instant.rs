@@ -1,10 +1,13 @@
 use crate::time::constants::*;
+use crate::Describe;
 use sbor::*;
 
 /// Represents a Unix timestamp, capturing the seconds since the unix epoch.
 ///
 /// See also the [`UtcDateTime`](super::UtcDateTime) type which supports conversion to/from `Instant`.
-#[derive(Encode, Decode, TypeId, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(
+    Encode, Decode, TypeId, Describe, Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord,
+)]
 pub struct Instant {
     pub seconds_since_unix_epoch: i64,
 }
@@ -19,9 +19,11 @@ pub enum RadixEngineInput {
     DropLock(LockHandle),
     Read(LockHandle),
     Write(LockHandle, Vec<u8>),
+    ListKeyValueStoreKeys(RENodeId, Option<Vec<u8>>, u32),
 
     GetActor(),
     EmitLog(Level, String),
+    EmitEvent(String, Vec<u8>),
     GenerateUuid(),
     GetTransactionHash(),
 }
@@ -55,6 +57,8 @@ pub enum NativeMethodInvocation {
     Component(ComponentMethodInvocation),
     EpochManager(EpochManagerMethodInvocation),
     Clock(ClockMethodInvocation),
+    Account(AccountMethodInvocation),
+    AccessController(AccessControllerMethodInvocation),
     AuthZoneStack(AuthZoneStackMethodInvocation),
     ResourceManager(ResourceManagerMethodInvocation),
     Bucket(BucketMethodInvocation),
@@ -69,6 +73,8 @@ pub enum NativeFunctionInvocation {
     Component(ComponentFunctionInvocation),
     EpochManager(EpochManagerFunctionInvocation),
     Clock(ClockFunctionInvocation),
+    Account(AccountFunctionInvocation),
+    AccessController(AccessControllerFunctionInvocation),
     ResourceManager(ResourceManagerFunctionInvocation),
     Package(PackageFunctionInvocation),
 }
@@ -117,6 +123,65 @@ pub enum ClockMethodInvocation {
     SetCurrentTime(ClockSetCurrentTimeInvocation),
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AccountFunctionInvocation {
+    New(AccountNewInvocation),
+    NewWithResource(AccountNewWithResourceInvocation),
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AccountMethodInvocation {
+    Balance(AccountBalanceInvocation),
+    LockFee(AccountLockFeeInvocation),
+    LockContingentFee(AccountLockContingentFeeInvocation),
+    LockSponsoredFee(AccountLockSponsoredFeeInvocation),
+    Deposit(AccountDepositInvocation),
+    DepositBatch(AccountDepositBatchInvocation),
+    TryDepositOrRefund(AccountTryDepositOrRefundInvocation),
+    TryDepositBatchOrRefund(AccountTryDepositBatchOrRefundInvocation),
+    Withdraw(AccountWithdrawInvocation),
+    WithdrawByAmount(AccountWithdrawByAmountInvocation),
+    WithdrawByIds(AccountWithdrawByIdsInvocation),
+    LockFeeAndWithdraw(AccountLockFeeAndWithdrawInvocation),
+    LockFeeAndWithdrawByAmount(AccountLockFeeAndWithdrawByAmountInvocation),
+    LockFeeAndWithdrawByIds(AccountLockFeeAndWithdrawByIdsInvocation),
+    CreateProof(AccountCreateProofInvocation),
+    CreateProofByAmount(AccountCreateProofByAmountInvocation),
+    CreateProofByIds(AccountCreateProofByIdsInvocation),
+    SetDepositPolicy(AccountSetDepositPolicyInvocation),
+    GetDepositPolicy(AccountGetDepositPolicyInvocation),
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AccessControllerFunctionInvocation {
+    CreateGlobal(AccessControllerCreateGlobalInvocation),
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AccessControllerMethodInvocation {
+    CreateProof(AccessControllerCreateProofInvocation),
+    InitiateRecoveryAsPrimary(AccessControllerInitiateRecoveryAsPrimaryInvocation),
+    InitiateRecoveryAsRecovery(AccessControllerInitiateRecoveryAsRecoveryInvocation),
+    QuickConfirmPrimaryRoleRecoveryProposal(
+        AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation,
+    ),
+    QuickConfirmRecoveryRoleRecoveryProposal(
+        AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation,
+    ),
+    TimedConfirmRecovery(AccessControllerTimedConfirmRecoveryInvocation),
+    CancelPrimaryRoleRecoveryProposal(AccessControllerCancelPrimaryRoleRecoveryProposalInvocation),
+    CancelRecoveryRoleRecoveryProposal(
+        AccessControllerCancelRecoveryRoleRecoveryProposalInvocation,
+    ),
+    LockPrimaryRole(AccessControllerLockPrimaryRoleInvocation),
+    UnlockPrimaryRole(AccessControllerUnlockPrimaryRoleInvocation),
+    StopTimedRecovery(AccessControllerStopTimedRecoveryInvocation),
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum ComponentMethodInvocation {
@@ -136,6 +201,15 @@ pub enum PackageMethodInvocation {
 pub enum EpochManagerMethodInvocation {
     GetCurrentEpoch(EpochManagerGetCurrentEpochInvocation),
     SetEpoch(EpochManagerSetEpochInvocation),
+    NextEpoch(EpochManagerNextEpochInvocation),
+    GetValidatorSet(EpochManagerGetValidatorSetInvocation),
+    RegisterValidator(EpochManagerRegisterValidatorInvocation),
+    UnregisterValidator(EpochManagerUnregisterValidatorInvocation),
+    GetValidator(EpochManagerGetValidatorInvocation),
+    Stake(EpochManagerStakeInvocation),
+    Unstake(EpochManagerUnstakeInvocation),
+    ClaimXrd(EpochManagerClaimXrdInvocation),
+    UpdateFeeConfig(EpochManagerUpdateFeeConfigInvocation),
 }
 
 #[derive(Debug)]
@@ -170,7 +244,9 @@ pub enum ResourceManagerMethodInvocation {
     UpdateVaultAuth(ResourceManagerUpdateVaultAuthInvocation),
     LockVaultAuth(ResourceManagerSetVaultAuthMutabilityInvocation),
     GetTotalSupply(ResourceManagerGetTotalSupplyInvocation),
+    GetMaxSupply(ResourceManagerGetMaxSupplyInvocation),
     UpdateNonFungibleData(ResourceManagerUpdateNonFungibleDataInvocation),
+    UpdateNonFungibleDataField(ResourceManagerUpdateNonFungibleDataFieldInvocation),
     GetNonFungible(ResourceManagerGetNonFungibleInvocation),
     NonFungibleExists(ResourceManagerNonFungibleExistsInvocation),
 }
@@ -192,6 +268,7 @@ pub enum BucketMethodInvocation {
 pub enum VaultMethodInvocation {
     Take(VaultTakeInvocation),
     LockFee(VaultLockFeeInvocation),
+    LockSponsoredFee(VaultLockSponsoredFeeInvocation),
     Put(VaultPutInvocation),
     TakeNonFungibles(VaultTakeNonFungiblesInvocation),
     GetAmount(VaultGetAmountInvocation),
@@ -202,6 +279,9 @@ pub enum VaultMethodInvocation {
     CreateProofByIds(VaultCreateProofByIdsInvocation),
     Recall(VaultRecallInvocation),
     RecallNonFungibles(VaultRecallNonFungiblesInvocation),
+    BurnNonFungibles(VaultBurnNonFungiblesInvocation),
+    Freeze(VaultFreezeInvocation),
+    Unfreeze(VaultUnfreezeInvocation),
 }
 
 #[derive(Debug)]
@@ -250,6 +330,19 @@ impl NativeFnInvocation {
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
+                NativeFunctionInvocation::Account(invocation) => match invocation {
+                    AccountFunctionInvocation::New(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountFunctionInvocation::NewWithResource(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                },
+                NativeFunctionInvocation::AccessController(invocation) => match invocation {
+                    AccessControllerFunctionInvocation::CreateGlobal(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                },
                 NativeFunctionInvocation::ResourceManager(invocation) => match invocation {
                     ResourceManagerFunctionInvocation::Create(invocation) => api
                         .invoke(invocation)
@@ -368,6 +461,9 @@ impl NativeFnInvocation {
                     VaultMethodInvocation::LockFee(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    VaultMethodInvocation::LockSponsoredFee(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                     VaultMethodInvocation::TakeNonFungibles(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
@@ -395,6 +491,15 @@ impl NativeFnInvocation {
                     VaultMethodInvocation::RecallNonFungibles(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    VaultMethodInvocation::BurnNonFungibles(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    VaultMethodInvocation::Freeze(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    VaultMethodInvocation::Unfreeze(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
                 NativeMethodInvocation::AccessRulesChain(access_rules_method) => {
                     match access_rules_method {
@@ -451,9 +556,15 @@ impl NativeFnInvocation {
                     ResourceManagerMethodInvocation::GetTotalSupply(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::GetMaxSupply(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                     ResourceManagerMethodInvocation::UpdateNonFungibleData(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::UpdateNonFungibleDataField(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                     ResourceManagerMethodInvocation::NonFungibleExists(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
@@ -469,6 +580,33 @@ impl NativeFnInvocation {
                         EpochManagerMethodInvocation::SetEpoch(invocation) => api
                             .invoke(invocation)
                             .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::NextEpoch(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::GetValidatorSet(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::RegisterValidator(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::UnregisterValidator(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::GetValidator(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::Stake(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::Unstake(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::ClaimXrd(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        EpochManagerMethodInvocation::UpdateFeeConfig(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
                     }
                 }
                 NativeMethodInvocation::Clock(clock_method) => match clock_method {
@@ -482,6 +620,102 @@ impl NativeFnInvocation {
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
+                NativeMethodInvocation::Account(account_method) => match account_method {
+                    AccountMethodInvocation::Balance(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::LockFee(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::LockContingentFee(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::LockSponsoredFee(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::Deposit(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::DepositBatch(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::TryDepositOrRefund(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::TryDepositBatchOrRefund(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::Withdraw(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::WithdrawByAmount(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::WithdrawByIds(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::LockFeeAndWithdraw(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::LockFeeAndWithdrawByAmount(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::LockFeeAndWithdrawByIds(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::CreateProof(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::CreateProofByAmount(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::CreateProofByIds(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::SetDepositPolicy(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    AccountMethodInvocation::GetDepositPolicy(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                },
+                NativeMethodInvocation::AccessController(access_controller_method) => {
+                    match access_controller_method {
+                        AccessControllerMethodInvocation::CreateProof(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::InitiateRecoveryAsPrimary(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::InitiateRecoveryAsRecovery(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::QuickConfirmPrimaryRoleRecoveryProposal(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::QuickConfirmRecoveryRoleRecoveryProposal(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::TimedConfirmRecovery(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::CancelPrimaryRoleRecoveryProposal(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::CancelRecoveryRoleRecoveryProposal(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::LockPrimaryRole(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::UnlockPrimaryRole(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        AccessControllerMethodInvocation::StopTimedRecovery(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    }
+                }
                 NativeMethodInvocation::Worktop(worktop_method) => match worktop_method {
                     WorktopMethodInvocation::TakeNonFungibles(invocation) => api
                         .invoke(invocation)
@@ -0,0 +1,381 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::mem;
+
+use radix_engine::ledger::{
+    IterableSubstateStore, OutputValue, SubstateRange, WriteableSubstateStore,
+};
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::crypto::hash;
+use transaction::validation::IntentHashStore;
+
+/// The version of the export format produced by `export_ledger`.
+pub const LEDGER_EXPORT_FORMAT_VERSION: u32 = 2;
+
+/// The number of substates committed to the store at once by `import_ledger`.
+const IMPORT_BATCH_SIZE: usize = 1000;
+
+/// The first frame of a ledger export, describing the frames which follow it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct LedgerExportHeader {
+    pub format_version: u32,
+    pub network_id: u8,
+    pub substate_count: u64,
+    pub intent_hash_count: u64,
+    /// The hash chained over all substate and intent hash frames, in the order they were written.
+    pub checksum: Hash,
+}
+
+/// A record of a ledger export, read from one of the frames following the header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerExportRecord {
+    Substate(SubstateId, Box<OutputValue>),
+    /// The hash of a committed intent, with its `end_epoch_exclusive`.
+    IntentHash(Hash, u64),
+}
+
+#[derive(Debug)]
+pub enum LedgerExportError {
+    IOError(io::Error),
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+    UnsupportedFormatVersion(u32),
+    NetworkMismatch { expected: u8, actual: u8 },
+    ChecksumMismatch,
+}
+
+/// Writes every substate and committed intent hash in the store to the writer.
+///
+/// The export consists of a `LedgerExportHeader` frame followed by one frame per substate and
+/// then one frame per intent hash, each frame being an SBOR payload prefixed with its length as
+/// a little-endian `u32`. The store is read twice, first to compute the header, so it must not be
+/// written to during the export.
+pub fn export_ledger<S: IterableSubstateStore + IntentHashStore, W: Write>(
+    store: &S,
+    network: &NetworkDefinition,
+    writer: &mut W,
+) -> Result<LedgerExportHeader, LedgerExportError> {
+    let mut substate_count = 0u64;
+    let mut checksum = Hash([0u8; Hash::LENGTH]);
+    for_each_substate(store, |substate_id, output_value| {
+        let frame =
+            scrypto_encode(&(substate_id, output_value)).map_err(LedgerExportError::EncodeError)?;
+        checksum = chain_checksum(&checksum, &frame);
+        substate_count += 1;
+        Ok(())
+    })?;
+    let intent_hash_frames = store
+        .list_intent_hashes()
+        .iter()
+        .map(|intent_hash| scrypto_encode(intent_hash).map_err(LedgerExportError::EncodeError))
+        .collect::<Result<Vec<_>, _>>()?;
+    for frame in &intent_hash_frames {
+        checksum = chain_checksum(&checksum, frame);
+    }
+
+    let header = LedgerExportHeader {
+        format_version: LEDGER_EXPORT_FORMAT_VERSION,
+        network_id: network.id,
+        substate_count,
+        intent_hash_count: intent_hash_frames.len() as u64,
+        checksum,
+    };
+    write_frame(
+        writer,
+        &scrypto_encode(&header).map_err(LedgerExportError::EncodeError)?,
+    )?;
+    for_each_substate(store, |substate_id, output_value| {
+        let frame =
+            scrypto_encode(&(substate_id, output_value)).map_err(LedgerExportError::EncodeError)?;
+        write_frame(writer, &frame)
+    })?;
+    for frame in &intent_hash_frames {
+        write_frame(writer, frame)?;
+    }
+    writer.flush().map_err(LedgerExportError::IOError)?;
+
+    Ok(header)
+}
+
+/// Reads a ledger export and commits all of its substates and intent hashes to the store.
+///
+/// The export is streamed twice: it's verified in full before anything is committed, so a
+/// corrupted export leaves the store untouched, and its substates are then committed in batches
+/// of `IMPORT_BATCH_SIZE`. The export must not be modified during the import.
+pub fn import_ledger<S, R>(
+    mut reader: R,
+    network: &NetworkDefinition,
+    store: &mut S,
+) -> Result<LedgerExportHeader, LedgerExportError>
+where
+    S: WriteableSubstateStore + IntentHashStore,
+    R: Read + Seek,
+{
+    verify_ledger_export(&mut reader, network)?;
+    reader
+        .seek(SeekFrom::Start(0))
+        .map_err(LedgerExportError::IOError)?;
+
+    let reader = LedgerExportReader::new(reader)?;
+    let header = reader.header().clone();
+    let mut substates = Vec::with_capacity(IMPORT_BATCH_SIZE);
+    for record in reader {
+        match record? {
+            LedgerExportRecord::Substate(substate_id, output_value) => {
+                substates.push((substate_id, *output_value));
+                if substates.len() == IMPORT_BATCH_SIZE {
+                    store.commit_batch(mem::take(&mut substates));
+                }
+            }
+            LedgerExportRecord::IntentHash(intent_hash, end_epoch_exclusive) => {
+                store.put_intent_hash(intent_hash, end_epoch_exclusive);
+            }
+        }
+    }
+    if !substates.is_empty() {
+        store.commit_batch(substates);
+    }
+
+    Ok(header)
+}
+
+/// Streams through a ledger export without keeping its records, verifying its network and
+/// checksum.
+pub fn verify_ledger_export<R: Read>(
+    reader: R,
+    network: &NetworkDefinition,
+) -> Result<LedgerExportHeader, LedgerExportError> {
+    let reader = LedgerExportReader::new(reader)?;
+    let header = reader.header().clone();
+    if header.network_id != network.id {
+        return Err(LedgerExportError::NetworkMismatch {
+            expected: network.id,
+            actual: header.network_id,
+        });
+    }
+
+    for record in reader {
+        record?;
+    }
+
+    Ok(header)
+}
+
+/// Streams the records of a ledger export.
+///
+/// The checksum is verified once the last record has been read; if it does not match, the
+/// iterator ends with a `ChecksumMismatch` error.
+pub struct LedgerExportReader<R: Read> {
+    reader: R,
+    header: LedgerExportHeader,
+    records_read: u64,
+    checksum: Hash,
+    finished: bool,
+}
+
+impl<R: Read> LedgerExportReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, LedgerExportError> {
+        let header: LedgerExportHeader =
+            scrypto_decode(&read_frame(&mut reader)?).map_err(LedgerExportError::DecodeError)?;
+        if header.format_version != LEDGER_EXPORT_FORMAT_VERSION {
+            return Err(LedgerExportError::UnsupportedFormatVersion(
+                header.format_version,
+            ));
+        }
+
+        Ok(Self {
+            reader,
+            header,
+            records_read: 0,
+            checksum: Hash([0u8; Hash::LENGTH]),
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> &LedgerExportHeader {
+        &self.header
+    }
+
+    fn read_record(&mut self) -> Result<Option<LedgerExportRecord>, LedgerExportError> {
+        if self.records_read == self.header.substate_count + self.header.intent_hash_count {
+            self.finished = true;
+            if self.checksum != self.header.checksum {
+                return Err(LedgerExportError::ChecksumMismatch);
+            }
+            return Ok(None);
+        }
+
+        let frame = read_frame(&mut self.reader)?;
+        self.checksum = chain_checksum(&self.checksum, &frame);
+        let record = if self.records_read < self.header.substate_count {
+            scrypto_decode(&frame).map(|(substate_id, output_value)| {
+                LedgerExportRecord::Substate(substate_id, Box::new(output_value))
+            })
+        } else {
+            scrypto_decode(&frame).map(|(intent_hash, end_epoch_exclusive)| {
+                LedgerExportRecord::IntentHash(intent_hash, end_epoch_exclusive)
+            })
+        };
+        self.records_read += 1;
+        record.map(Some).map_err(LedgerExportError::DecodeError)
+    }
+}
+
+impl<R: Read> Iterator for LedgerExportReader<R> {
+    type Item = Result<LedgerExportRecord, LedgerExportError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.read_record();
+        if result.is_err() {
+            self.finished = true;
+        }
+        result.transpose()
+    }
+}
+
+fn for_each_substate<S, F>(store: &S, mut f: F) -> Result<(), LedgerExportError>
+where
+    S: IterableSubstateStore,
+    F: FnMut(&SubstateId, &OutputValue) -> Result<(), LedgerExportError>,
+{
+    let mut cursor = None;
+    loop {
+        let page = store.list_substates(&SubstateRange::All, cursor.as_ref(), 1000);
+        for (substate_id, output_value) in &page.substates {
+            f(substate_id, output_value)?;
+        }
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => return Ok(()),
+        }
+    }
+}
+
+fn chain_checksum(checksum: &Hash, frame: &[u8]) -> Hash {
+    hash([checksum.as_ref(), frame].concat())
+}
+
+fn write_frame<W: Write>(writer: &mut W, frame: &[u8]) -> Result<(), LedgerExportError> {
+    let length = u32::try_from(frame.len()).map_err(|_| {
+        LedgerExportError::IOError(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Frame is too large",
+        ))
+    })?;
+    writer
+        .write_all(&length.to_le_bytes())
+        .and_then(|_| writer.write_all(frame))
+        .map_err(LedgerExportError::IOError)
+}
+
+fn read_frame<R: Read>(reader: &mut R) -> Result<Vec<u8>, LedgerExportError> {
+    let mut length = [0u8; 4];
+    reader
+        .read_exact(&mut length)
+        .map_err(LedgerExportError::IOError)?;
+    let length = u32::from_le_bytes(length) as u64;
+
+    // Read through `take` so that a corrupted length can't trigger a huge allocation upfront
+    let mut frame = Vec::new();
+    reader
+        .take(length)
+        .read_to_end(&mut frame)
+        .map_err(LedgerExportError::IOError)?;
+    if frame.len() as u64 != length {
+        return Err(LedgerExportError::IOError(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "Ledger export ended in the middle of a frame",
+        )));
+    }
+    Ok(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine::ledger::TypedInMemorySubstateStore;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_exported_ledger_can_be_imported_into_another_store() {
+        // Arrange
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let intent_hash = hash("intent");
+        store.put_intent_hash(intent_hash, 10);
+        let network = NetworkDefinition::simulator();
+        let mut export = Vec::new();
+        let mut imported_store = TypedInMemorySubstateStore::new();
+
+        // Act
+        let exported_header = export_ledger(&store, &network, &mut export).unwrap();
+        let imported_header =
+            import_ledger(Cursor::new(&export), &network, &mut imported_store).unwrap();
+
+        // Assert
+        assert_eq!(exported_header, imported_header);
+        assert_eq!(
+            imported_header.substate_count as usize,
+            store.list_all_substates(&SubstateRange::All).len()
+        );
+        assert_eq!(
+            imported_store.list_all_substates(&SubstateRange::All),
+            store.list_all_substates(&SubstateRange::All)
+        );
+        assert_eq!(imported_header.intent_hash_count, 1);
+        assert_eq!(
+            imported_store.get_intent_hash_end_epoch(&intent_hash),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_corrupted_export_is_rejected_without_touching_the_store() {
+        // Arrange
+        let store = TypedInMemorySubstateStore::with_bootstrap();
+        let network = NetworkDefinition::simulator();
+        let mut export = Vec::new();
+        export_ledger(&store, &network, &mut export).unwrap();
+        // Flip a bit in the last substate frame, which leaves the framing intact
+        let last = export.len() - 1;
+        export[last] ^= 0x01;
+        let mut imported_store = TypedInMemorySubstateStore::new();
+
+        // Act
+        let result = import_ledger(Cursor::new(&export), &network, &mut imported_store);
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(LedgerExportError::ChecksumMismatch) | Err(LedgerExportError::DecodeError(_))
+        ));
+        assert!(imported_store
+            .list_all_substates(&SubstateRange::All)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_export_from_another_network_is_rejected() {
+        // Arrange
+        let store = TypedInMemorySubstateStore::with_bootstrap();
+        let mut export = Vec::new();
+        export_ledger(&store, &NetworkDefinition::simulator(), &mut export).unwrap();
+        let mut imported_store = TypedInMemorySubstateStore::new();
+
+        // Act
+        let result = import_ledger(
+            Cursor::new(&export),
+            &NetworkDefinition::mainnet(),
+            &mut imported_store,
+        );
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(LedgerExportError::NetworkMismatch { .. })
+        ));
+    }
+}
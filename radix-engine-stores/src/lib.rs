@@ -1,3 +1,5 @@
+#[cfg(feature = "std")]
+pub mod ledger_export;
 pub mod memory_db;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
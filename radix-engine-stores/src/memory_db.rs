@@ -1,20 +1,46 @@
 use radix_engine::ledger::{
-    bootstrap, OutputValue, QueryableSubstateStore, ReadableSubstateStore, WriteableSubstateStore,
+    bootstrap, encode_substate_key, HistoricalSubstateStore, IterableSubstateStore, OutputValue,
+    ReadableSubstateStore, SubstatePage, SubstateRange, WriteableSubstateStore,
 };
-use radix_engine::model::PersistedSubstate;
 use radix_engine::types::*;
-use radix_engine_interface::api::types::RENodeId;
+
+/// Encoded substate values, keyed by the state version they were committed at.
+type SubstateHistory = BTreeMap<u64, Vec<u8>>;
 
 /// A substate store that stores all typed substates in host memory.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct SerializedInMemorySubstateStore {
-    substates: HashMap<Vec<u8>, Vec<u8>>,
+    substates: BTreeMap<Vec<u8>, Vec<u8>>,
+    state_version: u64,
+    /// The encoded values of each substate, keyed by the state version they were committed at.
+    /// Only kept if the store was created in history mode.
+    history: Option<BTreeMap<Vec<u8>, SubstateHistory>>,
+}
+
+// Only the current substates are compared, as in `TypedInMemorySubstateStore`.
+impl PartialEq for SerializedInMemorySubstateStore {
+    fn eq(&self, other: &Self) -> bool {
+        self.substates == other.substates
+    }
 }
 
+impl Eq for SerializedInMemorySubstateStore {}
+
 impl SerializedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
-            substates: HashMap::new(),
+            substates: BTreeMap::new(),
+            state_version: 0,
+            history: None,
+        }
+    }
+
+    /// Creates a store which keeps the full history of substate values.
+    pub fn with_history() -> Self {
+        Self {
+            substates: BTreeMap::new(),
+            state_version: 0,
+            history: Some(BTreeMap::new()),
         }
     }
 
@@ -23,6 +49,22 @@ impl SerializedInMemorySubstateStore {
         bootstrap(&mut substate_store);
         substate_store
     }
+
+    pub fn with_bootstrap_and_history() -> Self {
+        let mut substate_store = Self::with_history();
+        bootstrap(&mut substate_store);
+        substate_store
+    }
+
+    fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        if let Some(history) = &mut self.history {
+            history
+                .entry(key.clone())
+                .or_default()
+                .insert(self.state_version, value.clone());
+        }
+        self.substates.insert(key, value);
+    }
 }
 
 impl Default for SerializedInMemorySubstateStore {
@@ -37,41 +79,355 @@ impl ReadableSubstateStore for SerializedInMemorySubstateStore {
             .get(&scrypto_encode(substate_id).expect("Could not encode substate id"))
             .map(|b| scrypto_decode(&b).unwrap())
     }
+
+    fn as_iterable(&self) -> Option<&dyn IterableSubstateStore> {
+        Some(self)
+    }
 }
 
 impl WriteableSubstateStore for SerializedInMemorySubstateStore {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
-        self.substates.insert(
-            scrypto_encode(&substate_id).expect("Could not encode substate id"),
-            scrypto_encode(&substate).expect("Could not encode substate"),
-        );
+        self.commit_batch(vec![(substate_id, substate)]);
+    }
+
+    fn commit_batch(&mut self, substates: Vec<(SubstateId, OutputValue)>) {
+        // Encode everything upfront, so that a failure leaves the store untouched
+        let encoded: Vec<(Vec<u8>, Vec<u8>)> = substates
+            .into_iter()
+            .map(|(substate_id, substate)| {
+                (
+                    scrypto_encode(&substate_id).expect("Could not encode substate id"),
+                    scrypto_encode(&substate).expect("Could not encode substate"),
+                )
+            })
+            .collect();
+        self.state_version += 1;
+        for (key, value) in encoded {
+            self.insert(key, value);
+        }
     }
 }
 
-impl QueryableSubstateStore for SerializedInMemorySubstateStore {
-    fn get_kv_store_entries(
+impl HistoricalSubstateStore for SerializedInMemorySubstateStore {
+    fn get_state_version(&self) -> u64 {
+        self.state_version
+    }
+
+    fn keeps_history(&self) -> bool {
+        self.history.is_some()
+    }
+
+    fn get_substate_at(&self, substate_id: &SubstateId, state_version: u64) -> Option<OutputValue> {
+        self.history
+            .as_ref()?
+            .get(&scrypto_encode(substate_id).expect("Could not encode substate id"))
+            .and_then(|versions| versions.range(..=state_version).next_back())
+            .map(|(_, b)| scrypto_decode(b).unwrap())
+    }
+
+    fn list_substates_at(
         &self,
-        kv_store_id: &KeyValueStoreId,
-    ) -> HashMap<Vec<u8>, PersistedSubstate> {
-        self.substates
-            .iter()
-            .filter_map(|(key, value)| {
-                let substate_id: SubstateId = scrypto_decode(key).unwrap();
-                if let SubstateId(
-                    RENodeId::KeyValueStore(id),
-                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
-                ) = substate_id
-                {
-                    let output_value: OutputValue = scrypto_decode(value).unwrap();
-                    if id == *kv_store_id {
-                        Some((key.clone(), output_value.substate))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+        state_version: u64,
+    ) -> SubstatePage {
+        let history = match &self.history {
+            Some(history) => history,
+            None => return SubstatePage::empty(),
+        };
+        let prefix = range.key_prefix();
+        let start = match cursor {
+            Some(cursor) => encode_substate_key(cursor).max(prefix.clone()),
+            None => prefix.clone(),
+        };
+        let substates = history
+            .range(start..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .filter_map(|(key, versions)| {
+                let (_, value) = versions.range(..=state_version).next_back()?;
+                Some((
+                    scrypto_decode(key).expect("Could not decode substate id"),
+                    scrypto_decode(value).expect("Could not decode substate"),
+                ))
+            });
+
+        SubstatePage::from_sorted(substates, range, cursor, limit)
+    }
+}
+
+impl IterableSubstateStore for SerializedInMemorySubstateStore {
+    fn list_substates(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> SubstatePage {
+        let prefix = range.key_prefix();
+        let start = match cursor {
+            Some(cursor) => encode_substate_key(cursor).max(prefix.clone()),
+            None => prefix.clone(),
+        };
+        let substates = self
+            .substates
+            .range(start..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                (
+                    scrypto_decode(key).expect("Could not decode substate id"),
+                    scrypto_decode(value).expect("Could not decode substate"),
+                )
+            });
+
+        SubstatePage::from_sorted(substates, range, cursor, limit)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use radix_engine::ledger::SubstateStoreSnapshot;
+    use radix_engine::ledger::TypedInMemorySubstateStore;
+    use radix_engine::model::{AccessRulesChainSubstate, MetadataSubstate, PersistedSubstate};
+    use radix_engine_interface::data::MAX_SCRYPTO_SBOR_DEPTH;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// Creates a batch which updates every substate in the store.
+    pub(crate) fn create_update_batch<S: IterableSubstateStore>(
+        store: &S,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        store
+            .list_all_substates(&SubstateRange::All)
+            .into_iter()
+            .map(|(substate_id, output_value)| {
+                let version = output_value.version + 1;
+                (
+                    substate_id,
+                    OutputValue {
+                        substate: output_value.substate,
+                        version,
+                    },
+                )
             })
             .collect()
     }
+
+    /// Creates a batch which updates every substate in the store, except for one in the middle,
+    /// which is replaced with a substate too deep to be encoded.
+    fn create_unencodable_batch<S: IterableSubstateStore>(
+        store: &S,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        let mut batch = create_update_batch(store);
+        let mut rule_node = AccessRuleNode::AllOf(vec![]);
+        for _ in 0..MAX_SCRYPTO_SBOR_DEPTH {
+            rule_node = AccessRuleNode::AnyOf(vec![rule_node]);
+        }
+        let middle = batch.len() / 2;
+        batch[middle].1.substate = PersistedSubstate::AccessRulesChain(AccessRulesChainSubstate {
+            access_rules_chain: vec![
+                AccessRules::new().default(AccessRule::Protected(rule_node), AccessRule::DenyAll)
+            ],
+        });
+
+        batch
+    }
+
+    fn find_resource_manager_node<S: IterableSubstateStore>(store: &S) -> RENodeId {
+        store
+            .list_all_substates(&SubstateRange::All)
+            .into_iter()
+            .find_map(|(substate_id, _)| match substate_id {
+                SubstateId(
+                    node_id @ RENodeId::ResourceManager(..),
+                    SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
+                ) => Some(node_id),
+                _ => None,
+            })
+            .expect("Bootstrap should create a resource manager")
+    }
+
+    #[test]
+    fn test_list_substates_is_consistent_across_backends() {
+        // Arrange
+        let typed_store = TypedInMemorySubstateStore::with_bootstrap();
+        let serialized_store = SerializedInMemorySubstateStore::with_bootstrap();
+
+        // Act
+        let typed_substates = typed_store.list_all_substates(&SubstateRange::All);
+        let serialized_substates = serialized_store.list_all_substates(&SubstateRange::All);
+
+        // Assert
+        assert!(!typed_substates.is_empty());
+        assert_eq!(typed_substates, serialized_substates);
+    }
+
+    #[test]
+    fn test_list_substates_can_be_paginated() {
+        // Arrange
+        let store = SerializedInMemorySubstateStore::with_bootstrap();
+        let range = SubstateRange::Node(find_resource_manager_node(&store));
+        let all_substates = store.list_all_substates(&range);
+
+        // Act
+        let mut paginated_substates = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = store.list_substates(&range, cursor.as_ref(), 1);
+            assert!(page.substates.len() <= 1);
+            paginated_substates.extend(page.substates);
+            match page.next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+
+        // Assert
+        assert!(all_substates.len() > 1);
+        assert_eq!(paginated_substates, all_substates);
+    }
+
+    #[test]
+    fn test_list_substates_by_offset_kind() {
+        // Arrange
+        let store = TypedInMemorySubstateStore::with_bootstrap();
+        let node_id = find_resource_manager_node(&store);
+
+        // Act
+        let substates = store.list_all_substates(&SubstateRange::NodeOffsets(
+            node_id,
+            SubstateOffset::Metadata(MetadataOffset::Metadata),
+        ));
+
+        // Assert
+        assert_eq!(substates.len(), 1);
+        assert_eq!(
+            substates[0].0,
+            SubstateId(node_id, SubstateOffset::Metadata(MetadataOffset::Metadata))
+        );
+    }
+
+    #[test]
+    fn test_failed_commit_batch_leaves_store_untouched() {
+        // Arrange
+        let mut store = SerializedInMemorySubstateStore::with_bootstrap_and_history();
+        let state_version = store.get_state_version();
+        let substates_before = store.list_all_substates(&SubstateRange::All);
+        let batch = create_unencodable_batch(&store);
+
+        // Act
+        let result = catch_unwind(AssertUnwindSafe(|| store.commit_batch(batch)));
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(store.get_state_version(), state_version);
+        assert_eq!(
+            store.list_all_substates(&SubstateRange::All),
+            substates_before
+        );
+        for (substate_id, output_value) in substates_before {
+            assert_eq!(
+                store.get_substate_at(&substate_id, state_version + 1),
+                Some(output_value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_substates_can_be_read_at_past_state_versions() {
+        // Arrange
+        let mut store = SerializedInMemorySubstateStore::with_bootstrap_and_history();
+        let substate_id = SubstateId(
+            find_resource_manager_node(&store),
+            SubstateOffset::Metadata(MetadataOffset::Metadata),
+        );
+        let state_version = store.get_state_version();
+        let old_substate = store.get_substate(&substate_id).unwrap();
+        let new_substate = OutputValue {
+            substate: PersistedSubstate::Metadata(MetadataSubstate {
+                metadata: HashMap::new(),
+            }),
+            version: old_substate.version + 1,
+        };
+
+        // Act
+        store.commit_batch(vec![(substate_id.clone(), new_substate.clone())]);
+
+        // Assert
+        assert_eq!(store.get_state_version(), state_version + 1);
+        assert_eq!(store.get_substate(&substate_id), Some(new_substate.clone()));
+        assert_eq!(
+            store.get_substate_at(&substate_id, state_version),
+            Some(old_substate.clone())
+        );
+        assert_eq!(
+            store.get_substate_at(&substate_id, state_version + 1),
+            Some(new_substate)
+        );
+        assert_eq!(store.get_substate_at(&substate_id, 0), None);
+        let snapshot = SubstateStoreSnapshot::new(&store, state_version).unwrap();
+        assert_eq!(snapshot.get_substate(&substate_id), Some(old_substate));
+        assert!(SubstateStoreSnapshot::new(&store, state_version + 2).is_none());
+    }
+
+    #[test]
+    fn test_past_substates_cannot_be_read_without_history() {
+        // Arrange
+        let store = SerializedInMemorySubstateStore::with_bootstrap();
+        let substate_id = SubstateId(
+            find_resource_manager_node(&store),
+            SubstateOffset::Metadata(MetadataOffset::Metadata),
+        );
+        let state_version = store.get_state_version();
+
+        // Act
+        let substate = store.get_substate_at(&substate_id, state_version);
+
+        // Assert
+        assert!(!store.keeps_history());
+        assert_eq!(substate, None);
+        assert!(SubstateStoreSnapshot::new(&store, state_version).is_none());
+    }
+
+    #[test]
+    fn test_snapshot_lists_substates_at_its_state_version() {
+        // Arrange
+        let mut typed_store = TypedInMemorySubstateStore::with_bootstrap_and_history();
+        let mut serialized_store = SerializedInMemorySubstateStore::with_bootstrap_and_history();
+        let state_version = serialized_store.get_state_version();
+        let substates_before = serialized_store.list_all_substates(&SubstateRange::All);
+        let batch = create_update_batch(&serialized_store);
+        typed_store.commit_batch(batch.clone());
+        serialized_store.commit_batch(batch);
+
+        // Act
+        let typed_snapshot = SubstateStoreSnapshot::new(&typed_store, state_version).unwrap();
+        let typed_substates = typed_snapshot
+            .as_iterable()
+            .expect("Should be iterable")
+            .list_all_substates(&SubstateRange::All);
+        let serialized_snapshot =
+            SubstateStoreSnapshot::new(&serialized_store, state_version).unwrap();
+        let serialized_substates = serialized_snapshot
+            .as_iterable()
+            .expect("Should be iterable")
+            .list_all_substates(&SubstateRange::All);
+
+        // Assert
+        assert_eq!(typed_substates, substates_before);
+        assert_eq!(serialized_substates, substates_before);
+    }
+
+    #[test]
+    fn test_put_substate_commits_a_single_state_version() {
+        // Arrange
+        let mut store = SerializedInMemorySubstateStore::with_bootstrap();
+        let state_version = store.get_state_version();
+        let (substate_id, output_value) = create_update_batch(&store).remove(0);
+
+        // Act
+        store.put_substate(substate_id, output_value);
+
+        // Assert
+        assert_eq!(store.get_state_version(), state_version + 1);
+    }
 }
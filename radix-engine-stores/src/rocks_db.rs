@@ -1,20 +1,61 @@
-use std::collections::HashMap;
 use std::path::PathBuf;
 
 use radix_engine::ledger::*;
-use radix_engine::model::PersistedSubstate;
+use radix_engine::state_manager::{ReadableTreeStore, StateHashTree, TreeNode, TreeNodeKey};
+use radix_engine::transaction::IntentHashTrackingSubstateStore;
 use radix_engine::types::*;
 use radix_engine_interface::{api::types::RENodeId, data::ScryptoDecode};
-use rocksdb::{DBWithThreadMode, Direction, IteratorMode, SingleThreaded, DB};
+use rocksdb::{
+    DBWithThreadMode, Direction, IteratorMode, SingleThreaded, WriteBatch, WriteOptions, DB,
+};
+use transaction::validation::IntentHashStore;
+
+// Substate keys all start with the SBOR payload prefix, so keys starting with any other byte are
+// free to be used for the bookkeeping of the store itself.
+const STATE_VERSION_KEY: [u8; 1] = [0x00];
+const HISTORY_KEY_PREFIX: u8 = 0x01;
+const INTENT_HASH_KEY_PREFIX: u8 = 0x02;
+const INTENT_HASH_BY_END_EPOCH_KEY_PREFIX: u8 = 0x03;
+const TREE_NODE_KEY_PREFIX: u8 = 0x04;
+const KEEP_HISTORY_KEY: [u8; 1] = [0x05];
 
 pub struct RadixEngineDB {
     db: DBWithThreadMode<SingleThreaded>,
+    keep_history: bool,
 }
 
 impl RadixEngineDB {
+    /// Opens a store in the mode it was created in. New stores don't keep history.
     pub fn new(root: PathBuf) -> Self {
+        Self::open(root, false)
+    }
+
+    /// Opens a store which keeps the history of all substate values committed through it.
+    ///
+    /// Panics if the store was created without history, as its past substates are lost.
+    pub fn with_history(root: PathBuf) -> Self {
+        let substate_store = Self::open(root, true);
+        if !substate_store.keep_history {
+            panic!("Substate history is not kept by this store");
+        }
+        substate_store
+    }
+
+    /// The mode of a store is persisted when it's created, so that reopening it can't silently
+    /// start or stop keeping history.
+    fn open(root: PathBuf, keep_history: bool) -> Self {
         let db = DB::open_default(root.as_path()).unwrap();
-        Self { db }
+        let keep_history = match db.get(KEEP_HISTORY_KEY).unwrap() {
+            Some(flag) => flag == [1],
+            None => {
+                // Stores which predate the persisted mode never kept history
+                let is_new = db.get(STATE_VERSION_KEY).unwrap().is_none();
+                let keep_history = keep_history && is_new;
+                db.put(KEEP_HISTORY_KEY, [keep_history as u8]).unwrap();
+                keep_history
+            }
+        };
+        Self { db, keep_history }
     }
 
     pub fn with_bootstrap(root: PathBuf) -> Self {
@@ -23,6 +64,12 @@ impl RadixEngineDB {
         substate_store
     }
 
+    pub fn with_bootstrap_and_history(root: PathBuf) -> Self {
+        let mut substate_store = Self::with_history(root);
+        bootstrap(&mut substate_store);
+        substate_store
+    }
+
     pub fn list_packages(&self) -> Vec<PackageAddress> {
         let start = &scrypto_encode(&SubstateId(
             RENodeId::Global(GlobalAddress::Package(PackageAddress::Normal([0; 26]))),
@@ -148,53 +195,119 @@ impl RadixEngineDB {
             .unwrap()
     }
 
-    fn write(&self, substate_id: SubstateId, value: Vec<u8>) {
-        self.db
-            .put(
-                scrypto_encode(&substate_id).expect("Could not encode substate id"),
-                value,
-            )
-            .unwrap();
+    fn write_batch(&self, batch: WriteBatch) {
+        // The batch is logged to the write-ahead log (synced to disk) before being applied,
+        // so after a crash either all or none of its writes are recovered.
+        let mut write_options = WriteOptions::default();
+        write_options.set_sync(true);
+        self.db.write_opt(batch, &write_options).unwrap();
     }
-}
 
-impl QueryableSubstateStore for RadixEngineDB {
-    fn get_kv_store_entries(
+    /// Adds the writes of a new state version to the batch, including the updated state hash
+    /// tree nodes.
+    fn add_substates_to_batch(
         &self,
-        kv_store_id: &KeyValueStoreId,
-    ) -> HashMap<Vec<u8>, PersistedSubstate> {
-        let unit = scrypto_encode(&()).unwrap();
-        let id = scrypto_encode(&SubstateId(
-            RENodeId::KeyValueStore(kv_store_id.clone()),
-            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
-                scrypto_encode(&unit).unwrap(),
-            )),
-        ))
-        .unwrap();
+        batch: &mut WriteBatch,
+        substates: Vec<(SubstateId, OutputValue)>,
+    ) {
+        let mut tree = StateHashTree::new(self);
+        tree.update_substates(&substates);
+        for (key, node) in tree.into_changes() {
+            match node {
+                Some(node) => batch.put(
+                    Self::tree_node_key(&key),
+                    scrypto_encode(&node).expect("Could not encode tree node"),
+                ),
+                None => batch.delete(Self::tree_node_key(&key)),
+            }
+        }
 
-        let mut iter = self
-            .db
-            .iterator(IteratorMode::From(&id, Direction::Forward));
-        let mut items = HashMap::new();
-        while let Some(kv) = iter.next() {
-            let (key, value) = kv.unwrap();
-            let substate: OutputValue = scrypto_decode(&value.to_vec()).unwrap();
-            let substate_id: SubstateId = scrypto_decode(&key).unwrap();
-            if let SubstateId(
-                RENodeId::KeyValueStore(id),
-                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
-            ) = substate_id
-            {
-                if id == *kv_store_id {
-                    items.insert(key, substate.substate)
-                } else {
-                    break;
-                }
-            } else {
-                break;
-            };
+        let state_version = self.get_state_version() + 1;
+        for (substate_id, substate) in substates {
+            let key = scrypto_encode(&substate_id).expect("Could not encode substate id");
+            let value =
+                scrypto_encode(&substate).expect("Could not encode substate for persistence");
+            if self.keep_history {
+                batch.put(Self::history_key(&key, state_version), &value);
+            }
+            batch.put(key, value);
         }
-        items
+        batch.put(STATE_VERSION_KEY, state_version.to_be_bytes());
+    }
+
+    fn add_intent_hash_to_batch(
+        batch: &mut WriteBatch,
+        intent_hash: &Hash,
+        end_epoch_exclusive: u64,
+    ) {
+        batch.put(
+            Self::intent_hash_key(intent_hash),
+            end_epoch_exclusive.to_be_bytes(),
+        );
+        batch.put(
+            Self::intent_hash_by_end_epoch_key(end_epoch_exclusive, intent_hash),
+            b"",
+        );
+    }
+
+    fn history_key(substate_key: &[u8], state_version: u64) -> Vec<u8> {
+        let mut key = Vec::with_capacity(1 + substate_key.len() + 8);
+        key.push(HISTORY_KEY_PREFIX);
+        key.extend_from_slice(substate_key);
+        key.extend_from_slice(&state_version.to_be_bytes());
+        key
+    }
+
+    fn intent_hash_key(intent_hash: &Hash) -> Vec<u8> {
+        let mut key = Vec::with_capacity(1 + Hash::LENGTH);
+        key.push(INTENT_HASH_KEY_PREFIX);
+        key.extend_from_slice(&intent_hash.0);
+        key
+    }
+
+    fn tree_node_key(key: &TreeNodeKey) -> Vec<u8> {
+        let mut db_key = Vec::with_capacity(1 + 2 + Hash::LENGTH);
+        db_key.push(TREE_NODE_KEY_PREFIX);
+        db_key.extend_from_slice(&key.depth.to_be_bytes());
+        db_key.extend_from_slice(&key.path.0);
+        db_key
+    }
+
+    /// Big-endian epochs keep the keys sorted by `end_epoch_exclusive`, for pruning.
+    fn intent_hash_by_end_epoch_key(end_epoch_exclusive: u64, intent_hash: &Hash) -> Vec<u8> {
+        let mut key = Vec::with_capacity(1 + 8 + Hash::LENGTH);
+        key.push(INTENT_HASH_BY_END_EPOCH_KEY_PREFIX);
+        key.extend_from_slice(&end_epoch_exclusive.to_be_bytes());
+        key.extend_from_slice(&intent_hash.0);
+        key
+    }
+}
+
+impl IterableSubstateStore for RadixEngineDB {
+    fn list_substates(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> SubstatePage {
+        let prefix = range.key_prefix();
+        let start = match cursor {
+            Some(cursor) => encode_substate_key(cursor).max(prefix.clone()),
+            None => prefix.clone(),
+        };
+        let substates = self
+            .db
+            .iterator(IteratorMode::From(&start, Direction::Forward))
+            .map(|kv| kv.unwrap())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                (
+                    scrypto_decode(&key).expect("Could not decode substate id"),
+                    scrypto_decode(&value).expect("Could not decode persisted substate"),
+                )
+            });
+
+        SubstatePage::from_sorted(substates, range, cursor, limit)
     }
 }
 
@@ -203,13 +316,304 @@ impl ReadableSubstateStore for RadixEngineDB {
         self.read(substate_id)
             .map(|b| scrypto_decode(&b).expect("Could not decode persisted substate"))
     }
+
+    fn as_iterable(&self) -> Option<&dyn IterableSubstateStore> {
+        Some(self)
+    }
 }
 
 impl WriteableSubstateStore for RadixEngineDB {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
-        self.write(
-            substate_id,
-            scrypto_encode(&substate).expect("Could not encode substate for persistence"),
+        self.commit_batch(vec![(substate_id, substate)]);
+    }
+
+    fn commit_batch(&mut self, substates: Vec<(SubstateId, OutputValue)>) {
+        let mut batch = WriteBatch::default();
+        self.add_substates_to_batch(&mut batch, substates);
+        self.write_batch(batch);
+    }
+
+    fn state_root(&self) -> Option<Hash> {
+        Some(StateHashTree::new(self).root())
+    }
+}
+
+impl ReadableTreeStore for RadixEngineDB {
+    fn get_tree_node(&self, key: &TreeNodeKey) -> Option<TreeNode> {
+        self.db
+            .get(Self::tree_node_key(key))
+            .unwrap()
+            .map(|b| scrypto_decode(&b).expect("Could not decode tree node"))
+    }
+}
+
+impl IntentHashTrackingSubstateStore for RadixEngineDB {
+    fn commit_batch_with_intent_hashes(
+        &mut self,
+        substates: Vec<(SubstateId, OutputValue)>,
+        intent_hashes: Vec<(Hash, u64)>,
+    ) {
+        let mut batch = WriteBatch::default();
+        self.add_substates_to_batch(&mut batch, substates);
+        for (intent_hash, end_epoch_exclusive) in intent_hashes {
+            Self::add_intent_hash_to_batch(&mut batch, &intent_hash, end_epoch_exclusive);
+        }
+        self.write_batch(batch);
+    }
+}
+
+impl HistoricalSubstateStore for RadixEngineDB {
+    fn get_state_version(&self) -> u64 {
+        self.db
+            .get(STATE_VERSION_KEY)
+            .unwrap()
+            .map(|b| u64::from_be_bytes(b.try_into().expect("Invalid state version")))
+            .unwrap_or(0)
+    }
+
+    fn keeps_history(&self) -> bool {
+        self.keep_history
+    }
+
+    fn get_substate_at(&self, substate_id: &SubstateId, state_version: u64) -> Option<OutputValue> {
+        if !self.keep_history {
+            return None;
+        }
+
+        // Seek backwards to the last history entry at or before the requested version
+        let key = Self::history_key(
+            &scrypto_encode(substate_id).expect("Could not encode substate id"),
+            state_version,
         );
+        let substate_prefix = &key[..key.len() - 8];
+        self.db
+            .iterator(IteratorMode::From(&key, Direction::Reverse))
+            .next()
+            .map(|kv| kv.unwrap())
+            .filter(|(k, _)| k.len() == key.len() && k.starts_with(substate_prefix))
+            .map(|(_, v)| scrypto_decode(&v).expect("Could not decode persisted substate"))
+    }
+
+    fn list_substates_at(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+        state_version: u64,
+    ) -> SubstatePage {
+        if !self.keep_history {
+            return SubstatePage::empty();
+        }
+
+        let prefix = range.key_prefix();
+        let start = match cursor {
+            Some(cursor) => encode_substate_key(cursor).max(prefix.clone()),
+            None => prefix.clone(),
+        };
+        let mut seek_key = vec![HISTORY_KEY_PREFIX];
+        seek_key.extend_from_slice(&start);
+        let mut entries = self
+            .db
+            .iterator(IteratorMode::From(&seek_key, Direction::Forward))
+            .map(|kv| kv.unwrap())
+            .take_while(|(key, _)| key[0] == HISTORY_KEY_PREFIX && key[1..].starts_with(&prefix))
+            .map(|(key, value)| {
+                let (substate_key, version) = key[1..].split_at(key.len() - 9);
+                let version = u64::from_be_bytes(version.try_into().unwrap());
+                (substate_key.to_vec(), version, value)
+            })
+            .peekable();
+
+        // History entries are ordered by substate key and then by state version, so the value
+        // of a substate at the state version is the last of its entries not past it
+        let substates = std::iter::from_fn(move || loop {
+            let (substate_key, version, value) = entries.next()?;
+            let mut latest = (version <= state_version).then_some(value);
+            while let Some((_, version, value)) =
+                entries.next_if(|(next_key, ..)| next_key.eq(&substate_key))
+            {
+                if version <= state_version {
+                    latest = Some(value);
+                }
+            }
+            if let Some(value) = latest {
+                return Some((
+                    scrypto_decode(&substate_key).expect("Could not decode substate id"),
+                    scrypto_decode(&value).expect("Could not decode persisted substate"),
+                ));
+            }
+        });
+
+        SubstatePage::from_sorted(substates, range, cursor, limit)
+    }
+}
+
+impl IntentHashStore for RadixEngineDB {
+    fn get_intent_hash_end_epoch(&self, intent_hash: &Hash) -> Option<u64> {
+        self.db
+            .get(Self::intent_hash_key(intent_hash))
+            .unwrap()
+            .map(|b| u64::from_be_bytes(b.try_into().expect("Invalid intent hash end epoch")))
+    }
+
+    fn put_intent_hash(&mut self, intent_hash: Hash, end_epoch_exclusive: u64) {
+        let mut batch = WriteBatch::default();
+        Self::add_intent_hash_to_batch(&mut batch, &intent_hash, end_epoch_exclusive);
+        self.write_batch(batch);
+    }
+
+    fn remove_expired_intent_hashes(&mut self, current_epoch: u64) {
+        let mut batch = WriteBatch::default();
+        let start = [INTENT_HASH_BY_END_EPOCH_KEY_PREFIX];
+        for kv in self
+            .db
+            .iterator(IteratorMode::From(&start, Direction::Forward))
+        {
+            let (key, _) = kv.unwrap();
+            if key[0] != INTENT_HASH_BY_END_EPOCH_KEY_PREFIX {
+                break;
+            }
+            let end_epoch_exclusive = u64::from_be_bytes(key[1..9].try_into().unwrap());
+            if end_epoch_exclusive > current_epoch {
+                break;
+            }
+            let intent_hash = Hash(key[9..].try_into().expect("Invalid intent hash key"));
+            batch.delete(Self::intent_hash_key(&intent_hash));
+            batch.delete(key);
+        }
+        self.write_batch(batch);
+    }
+
+    fn list_intent_hashes(&self) -> Vec<(Hash, u64)> {
+        let start = [INTENT_HASH_BY_END_EPOCH_KEY_PREFIX];
+        self.db
+            .iterator(IteratorMode::From(&start, Direction::Forward))
+            .map(|kv| kv.unwrap())
+            .take_while(|(key, _)| key[0] == INTENT_HASH_BY_END_EPOCH_KEY_PREFIX)
+            .map(|(key, _)| {
+                let end_epoch_exclusive = u64::from_be_bytes(key[1..9].try_into().unwrap());
+                let intent_hash = Hash(key[9..].try_into().expect("Invalid intent hash key"));
+                (intent_hash, end_epoch_exclusive)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::tests::create_update_batch;
+    use radix_engine::state_manager::compute_state_root;
+    use rocksdb::Options;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use transaction::validation::IntentHashManager;
+
+    #[test]
+    fn test_store_is_consistent_after_interrupted_commit_batch() {
+        // Arrange
+        let root = std::env::temp_dir().join(format!(
+            "radix-engine-db-recovery-test-{}",
+            std::process::id()
+        ));
+        let store = RadixEngineDB::with_bootstrap(root.clone());
+        let state_version = store.get_state_version();
+        let state_root = store.state_root();
+        let substates_before = store.list_all_substates(&SubstateRange::All);
+        let batch = create_update_batch(&store);
+        drop(store);
+        // A read-only store stages the whole batch, but fails to write it
+        let mut store = RadixEngineDB {
+            db: DB::open_for_read_only(&Options::default(), root.as_path(), false).unwrap(),
+            keep_history: false,
+        };
+
+        // Act
+        let result = catch_unwind(AssertUnwindSafe(|| store.commit_batch(batch)));
+        drop(store);
+        let store = RadixEngineDB::new(root.clone());
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(store.get_state_version(), state_version);
+        assert_eq!(store.state_root(), state_root);
+        assert_eq!(
+            store.list_all_substates(&SubstateRange::All),
+            substates_before
+        );
+        drop(store);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_intent_hashes_survive_restart_until_expired() {
+        // Arrange
+        let root = std::env::temp_dir().join(format!(
+            "radix-engine-db-intent-hash-test-{}",
+            std::process::id()
+        ));
+        let mut store = RadixEngineDB::new(root.clone());
+        store.put_intent_hash(Hash([1u8; 32]), 10);
+        store.put_intent_hash(Hash([2u8; 32]), 20);
+        drop(store);
+
+        // Act
+        let mut store = RadixEngineDB::new(root.clone());
+        store.remove_expired_intent_hashes(10);
+
+        // Assert
+        assert_eq!(store.get_intent_hash_end_epoch(&Hash([1u8; 32])), None);
+        assert_eq!(store.get_intent_hash_end_epoch(&Hash([2u8; 32])), Some(20));
+        assert!(!store.allows(&Hash([2u8; 32])));
+        drop(store);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_history_mode_survives_restart() {
+        // Arrange
+        let with_history_root = std::env::temp_dir().join(format!(
+            "radix-engine-db-with-history-test-{}",
+            std::process::id()
+        ));
+        let without_history_root = std::env::temp_dir().join(format!(
+            "radix-engine-db-without-history-test-{}",
+            std::process::id()
+        ));
+        drop(RadixEngineDB::with_bootstrap_and_history(
+            with_history_root.clone(),
+        ));
+        drop(RadixEngineDB::with_bootstrap(without_history_root.clone()));
+
+        // Act
+        let store = RadixEngineDB::new(with_history_root.clone());
+        let result = catch_unwind(|| RadixEngineDB::with_history(without_history_root.clone()));
+
+        // Assert
+        assert!(store.keeps_history());
+        assert!(result.is_err());
+        drop(store);
+        std::fs::remove_dir_all(with_history_root).unwrap();
+        std::fs::remove_dir_all(without_history_root).unwrap();
+    }
+
+    #[test]
+    fn test_state_hash_tree_survives_restart() {
+        // Arrange
+        let root = std::env::temp_dir().join(format!(
+            "radix-engine-db-state-hash-tree-test-{}",
+            std::process::id()
+        ));
+        let store = RadixEngineDB::with_bootstrap(root.clone());
+        let state_root = store.state_root();
+        drop(store);
+
+        // Act
+        let store = RadixEngineDB::new(root.clone());
+
+        // Assert
+        assert_eq!(store.state_root(), state_root);
+        assert_eq!(state_root, Some(compute_state_root(&store)));
+        drop(store);
+        std::fs::remove_dir_all(root).unwrap();
     }
 }
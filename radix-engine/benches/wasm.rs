@@ -8,7 +8,7 @@ use radix_engine_interface::crypto::hash;
 use sbor::rust::sync::Arc;
 
 fn bench_wasm_validation(c: &mut Criterion) {
-    let code = include_bytes!("../../assets/account.wasm");
+    let code = include_bytes!("../../assets/faucet.wasm");
     let abi = extract_abi(code).unwrap();
 
     c.bench_function("WASM validation", |b| {
@@ -17,7 +17,7 @@ fn bench_wasm_validation(c: &mut Criterion) {
 }
 
 fn bench_wasm_instantiation(c: &mut Criterion) {
-    let code = include_bytes!("../../assets/account.wasm").to_vec();
+    let code = include_bytes!("../../assets/faucet.wasm").to_vec();
     let code_hash = hash(&code);
     let pretend_instrumented_code = InstrumentedCode {
         code: Arc::new(code),
@@ -32,7 +32,7 @@ fn bench_wasm_instantiation(c: &mut Criterion) {
 }
 
 fn bench_wasm_instantiation_pre_loaded(c: &mut Criterion) {
-    let code = include_bytes!("../../assets/account.wasm").to_vec();
+    let code = include_bytes!("../../assets/faucet.wasm").to_vec();
     let code_hash = hash(&code);
     let pretend_instrumented_code = InstrumentedCode {
         code: Arc::new(code),
@@ -8,6 +8,7 @@ use radix_engine_interface::data::ScryptoValueDecodeError;
 use sbor::*;
 use transaction::errors::*;
 
+use crate::fee::SponsoredInvocation;
 use crate::model::*;
 use crate::types::*;
 use crate::wasm::WasmError;
@@ -31,6 +32,18 @@ pub enum RejectionError {
         valid_until: u64,
         current_epoch: u64,
     },
+    FeeSponsorshipExpired {
+        expires_at_epoch: u64,
+        current_epoch: u64,
+    },
+    FeeSponsorshipLimitExceeded {
+        vault_id: VaultId,
+        max_contribution: Decimal,
+    },
+    InvocationNotAllowedByFeeSponsorship {
+        vault_id: VaultId,
+        invocation: SponsoredInvocation,
+    },
 }
 
 impl fmt::Display for RejectionError {
@@ -82,6 +95,8 @@ pub enum KernelError {
 
     MaxCallDepthLimitReached,
     InvalidScryptoFnOutput,
+    UndeclaredScryptoEvent(String),
+    InvalidScryptoEvent(String),
     MethodReceiverNotMatch(NativeMethod, RENodeId),
 
     // ID allocation
@@ -106,6 +121,7 @@ pub enum KernelError {
     InvalidOwnership(SubstateOffset, RENodeId),
     InvalidOverwrite,
     InvalidId(RENodeId),
+    KeyValueStoreScanLimitExceeded(u32),
 
     // Actor Constraints
     InvalidDropNodeVisibility {
@@ -206,6 +222,10 @@ pub enum ApplicationError {
 
     EpochManagerError(EpochManagerError),
 
+    AccountError(AccountError),
+
+    AccessControllerError(AccessControllerError),
+
     ResourceManagerError(ResourceManagerError),
 
     AccessRulesChainError(AccessRulesChainError),
@@ -219,6 +239,8 @@ pub enum ApplicationError {
     WorktopError(WorktopError),
 
     AuthZoneError(AuthZoneError),
+
+    MetadataError(MetadataError),
 }
 
 impl fmt::Display for RuntimeError {
@@ -226,3 +248,23 @@ impl fmt::Display for RuntimeError {
         write!(f, "{:?}", self)
     }
 }
+
+impl RuntimeError {
+    /// How the failed authorization was evaluated, if this is an authorization failure of a
+    /// transaction executed with tracing
+    pub fn auth_trace(&self) -> Option<&AuthTrace> {
+        match self {
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                trace,
+                ..
+            }))
+            | RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
+                AccessRulesChainError::Unauthorized(_, _, trace),
+            ))
+            | RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(_, _, trace),
+            )) => trace.as_deref(),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::engine::SysCallTrace;
+use crate::model::Resource;
 use crate::types::*;
+use radix_engine_interface::api::types::{BucketId, VaultId};
 use transaction::model::Instruction;
 
 #[derive(Debug)]
@@ -26,11 +28,82 @@ pub enum RuntimeEvent<'a> {
 #[scrypto(TypeId, Encode, Decode)]
 pub enum TrackedEvent {
     Native(NativeEvent),
-    Scrypto(Vec<u8>),
+    Scrypto(ScryptoEvent),
+}
+
+/// An event emitted by a blueprint through `Runtime::emit_event`.
+#[derive(Debug, Clone)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ScryptoEvent {
+    /// The global component which emitted the event, or the package of the emitting blueprint if
+    /// the event was emitted by a function or by a component which isn't globalized.
+    pub emitter: GlobalAddress,
+    /// The name of the event type, as declared in the blueprint ABI.
+    pub type_name: String,
+    /// The SBOR-encoded event.
+    pub data: Vec<u8>,
+}
+
+impl ScryptoEvent {
+    pub fn decode<T: ScryptoDecode>(&self) -> Result<T, DecodeError> {
+        scrypto_decode(&self.data)
+    }
 }
 
 #[derive(Debug, Clone)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum NativeEvent {
     SysCallTrace(SysCallTrace),
+    Resource(ResourceEvent),
+}
+
+/// A change to the resources held by a vault or bucket, or to the supply of a resource.
+///
+/// Resource events are only kept in the receipt of a successfully committed transaction, with the
+/// exception of fee payments which are also recorded for failed transactions. Together they
+/// account for every change to vault balances made by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceEvent {
+    pub kind: ResourceEventKind,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    /// The ids of the non-fungibles involved, or `None` for a fungible resource.
+    pub non_fungible_ids: Option<BTreeSet<NonFungibleId>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum ResourceEventKind {
+    /// Resource was put into a vault.
+    VaultDeposit(VaultId),
+    /// Resource was taken from a vault.
+    VaultWithdraw(VaultId),
+    /// Resource was recalled from a vault.
+    VaultRecall(VaultId),
+    /// XRD was taken from a vault to pay the transaction fee.
+    VaultFeePayment(VaultId),
+    /// Resource was put into a bucket.
+    BucketPut(BucketId),
+    /// Resource was taken from a bucket into a new bucket.
+    BucketTake(BucketId),
+    /// New resource was minted.
+    Mint,
+    /// Resource was burnt.
+    Burn,
+}
+
+impl ResourceEvent {
+    pub fn new(kind: ResourceEventKind, resource: &Resource) -> Self {
+        let non_fungible_ids = match resource {
+            Resource::Fungible { .. } => None,
+            Resource::NonFungible { ids, .. } => Some(ids.clone()),
+        };
+        Self {
+            kind,
+            resource_address: resource.resource_address(),
+            amount: resource.amount(),
+            non_fungible_ids,
+        }
+    }
 }
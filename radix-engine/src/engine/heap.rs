@@ -1,12 +1,14 @@
-use crate::engine::{CallFrameError, Track};
+use crate::engine::{collect_kv_store_keys, CallFrameError, Track};
 use crate::fee::FeeReserve;
+use crate::ledger::encode_substate_key;
 use crate::model::{
     BucketSubstate, KeyValueStoreEntrySubstate, NonFungibleSubstate, ProofSubstate,
     RuntimeSubstate, SubstateRef, SubstateRefMut,
 };
-use crate::types::{HashMap, HashSet};
+use crate::types::{BTreeMap, HashMap, HashSet};
 use radix_engine_interface::api::types::{
-    BucketOffset, ProofOffset, RENodeId, SubstateId, SubstateOffset,
+    BucketOffset, KeyValueStoreKeysPage, KeyValueStoreOffset, ProofOffset, RENodeId, SubstateId,
+    SubstateOffset,
 };
 
 pub struct Heap {
@@ -86,6 +88,43 @@ impl Heap {
         }
     }
 
+    /// Scans up to `limit` entries of a key value store on the heap, in the same order and with
+    /// the same results as `Track::list_kv_store_keys` does for stored ones.
+    pub fn list_kv_store_keys(
+        &self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: usize,
+    ) -> Result<KeyValueStoreKeysPage, CallFrameError> {
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or(CallFrameError::RENodeNotOwned(node_id))?;
+        let cursor_key = start_after.map(|key| {
+            encode_substate_key(&SubstateId(
+                node_id,
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+            ))
+        });
+
+        let mut entries: BTreeMap<Vec<u8>, (Vec<u8>, bool)> = BTreeMap::new();
+        for (offset, substate) in &node.substates {
+            if let SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)) = offset {
+                let substate_key = encode_substate_key(&SubstateId(node_id, offset.clone()));
+                let after_cursor = match &cursor_key {
+                    Some(cursor_key) => substate_key.gt(cursor_key),
+                    None => true,
+                };
+                if after_cursor {
+                    let exists = substate.kv_store_entry().0.is_some();
+                    entries.insert(substate_key, (key.clone(), exists));
+                }
+            }
+        }
+
+        Ok(collect_kv_store_keys(entries, limit))
+    }
+
     pub fn create_node(&mut self, node_id: RENodeId, node: HeapRENode) {
         self.nodes.insert(node_id, node);
     }
@@ -1,6 +1,6 @@
 use crate::engine::{
-    Kernel, KernelError, LockFlags, REActor, RENode, ResolvedFunction, ResolvedMethod,
-    ResolvedReceiver, RuntimeError, SystemApi,
+    Event, ExecutionMode, Kernel, KernelError, LockFlags, REActor, RENode, ResolvedFunction,
+    ResolvedMethod, ResolvedReceiver, RuntimeError, ScryptoEvent, SystemApi, TrackedEvent,
 };
 use crate::fee::FeeReserve;
 use crate::model::{
@@ -12,15 +12,16 @@ use crate::types::HashMap;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::api::EngineApi;
 use radix_engine_interface::api::types::{
-    ComponentMethod, LockHandle, NativeFn, NativeMethod, RENodeId, RENodeType, ScryptoActor,
-    ScryptoRENode, SubstateOffset,
+    ComponentMethod, GlobalAddress, KeyValueStoreKeysPage, LockHandle, NativeFn, NativeMethod,
+    PackageOffset, RENodeId, RENodeType, ScryptoActor, ScryptoRENode, SubstateOffset,
 };
 use radix_engine_interface::constants::RADIX_TOKEN;
 use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::data::{match_schema_with_value, IndexedScryptoValue};
 use radix_engine_interface::model::{
     AccessRule, AccessRuleKey, AccessRules, ResourceType, RoyaltyConfig,
 };
-use sbor::rust::string::ToString;
+use sbor::rust::string::{String, ToString};
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 
@@ -149,6 +150,15 @@ where
         self.drop_lock(lock_handle)
     }
 
+    fn sys_list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<KeyValueStoreKeysPage, RuntimeError> {
+        self.list_kv_store_keys(node_id, start_after, limit)
+    }
+
     fn sys_get_actor(&mut self) -> Result<ScryptoActor, RuntimeError> {
         let actor = match self.get_actor() {
             REActor::Method(
@@ -185,4 +195,70 @@ where
     fn sys_get_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
         self.read_transaction_hash()
     }
+
+    fn sys_emit_event(&mut self, type_name: String, event: Vec<u8>) -> Result<(), RuntimeError> {
+        let (package_address, blueprint_name, emitter) = match self.get_actor() {
+            REActor::Method(
+                ResolvedMethod::Scrypto {
+                    package_address,
+                    blueprint_name,
+                    ..
+                },
+                ResolvedReceiver { derefed_from, .. },
+            ) => {
+                let emitter = match derefed_from {
+                    Some((RENodeId::Global(global_address), _)) => *global_address,
+                    _ => GlobalAddress::Package(*package_address),
+                };
+                (*package_address, blueprint_name.clone(), emitter)
+            }
+            REActor::Function(ResolvedFunction::Scrypto {
+                package_address,
+                blueprint_name,
+                ..
+            }) => (
+                *package_address,
+                blueprint_name.clone(),
+                GlobalAddress::Package(*package_address),
+            ),
+            _ => panic!("Should not get here."),
+        };
+
+        // Look up the event schema in the blueprint ABI
+        let event_abi =
+            self.execute_in_mode_from_kernel(ExecutionMode::ScryptoInterpreter, |system_api| {
+                let handle = system_api.lock_substate(
+                    RENodeId::Global(GlobalAddress::Package(package_address)),
+                    SubstateOffset::Package(PackageOffset::Info),
+                    LockFlags::read_only(),
+                )?;
+                let substate_ref = system_api.get_ref(handle)?;
+                let event_abi = substate_ref
+                    .package_info()
+                    .blueprint_abi(&blueprint_name)
+                    .and_then(|abi| abi.get_event_abi(&type_name))
+                    .cloned();
+                system_api.drop_lock(handle)?;
+
+                Ok(event_abi)
+            })?;
+
+        // Check the event against the schema
+        let event_abi = event_abi.ok_or(RuntimeError::KernelError(
+            KernelError::UndeclaredScryptoEvent(type_name.clone()),
+        ))?;
+        let value = IndexedScryptoValue::from_slice(&event)
+            .map_err(|e| RuntimeError::KernelError(KernelError::InvalidScryptoValue(e)))?;
+        if !match_schema_with_value(&event_abi, &value.dom) {
+            return Err(RuntimeError::KernelError(KernelError::InvalidScryptoEvent(
+                type_name,
+            )));
+        }
+
+        self.emit_event(Event::Tracked(TrackedEvent::Scrypto(ScryptoEvent {
+            emitter,
+            type_name,
+            data: event,
+        })))
+    }
 }
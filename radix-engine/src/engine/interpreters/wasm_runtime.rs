@@ -50,7 +50,19 @@ where
         let rtn = match input {
             RadixEngineInput::Invoke(invocation) => match invocation {
                 SerializedInvocation::Scrypto(invocation) => {
-                    encode(self.api.invoke(invocation)?)? // TODO: Figure out to remove encode
+                    let is_account_invocation = match &invocation {
+                        ScryptoInvocation::Function(function_ident, _) => {
+                            is_account_function(function_ident)
+                        }
+                        ScryptoInvocation::Method(method_ident, _) => {
+                            is_account_method(method_ident)
+                        }
+                    };
+                    if is_account_invocation {
+                        encode(parse_and_invoke_account_fn(invocation, self.api)?.raw)?
+                    } else {
+                        encode(self.api.invoke(invocation)?)? // TODO: Figure out to remove encode
+                    }
                 }
                 SerializedInvocation::Native(invocation) => {
                     invocation.invoke(self.api).map(|v| v.raw)?
@@ -69,12 +81,19 @@ where
             RadixEngineInput::DropLock(lock_handle) => {
                 encode(self.api.sys_drop_lock(lock_handle)?)?
             }
+            RadixEngineInput::ListKeyValueStoreKeys(node_id, start_after, limit) => encode(
+                self.api
+                    .sys_list_kv_store_keys(node_id, start_after, limit)?,
+            )?,
             RadixEngineInput::GetActor() => encode(self.api.sys_get_actor()?)?,
             RadixEngineInput::GetTransactionHash() => encode(self.api.sys_get_transaction_hash()?)?,
             RadixEngineInput::GenerateUuid() => encode(self.api.sys_generate_uuid()?)?,
             RadixEngineInput::EmitLog(level, message) => {
                 encode(self.api.emit_log(level, message)?)?
             }
+            RadixEngineInput::EmitEvent(type_name, event) => {
+                encode(self.api.sys_emit_event(type_name, event)?)?
+            }
         };
 
         Ok(rtn)
@@ -2,13 +2,13 @@ use radix_engine_interface::api::api::{
     EngineApi, Invocation, Invokable, InvokableModel, LoggerApi,
 };
 use radix_engine_interface::api::types::{
-    AuthZoneStackOffset, ComponentOffset, GlobalAddress, GlobalOffset, Level, LockHandle,
-    ProofOffset, RENodeId, ScryptoFunctionIdent, ScryptoPackage, SubstateId, SubstateOffset,
+    AuthZoneStackOffset, ComponentOffset, GlobalAddress, GlobalOffset, KeyValueStoreKeysPage,
+    KeyValueStoreOffset, Level, LockHandle, ProofOffset, RENodeId, SubstateId, SubstateOffset,
     VaultId, WorktopOffset,
 };
 use radix_engine_interface::crypto::Hash;
-use radix_engine_interface::data::*;
 
+use radix_engine_constants::MAX_KV_STORE_SCAN_LIMIT;
 use radix_engine_interface::rule;
 use sbor::rust::fmt::Debug;
 use sbor::rust::mem;
@@ -21,6 +21,7 @@ use crate::engine::system_api::LockInfo;
 use crate::engine::*;
 use crate::fee::FeeReserve;
 use crate::model::*;
+use crate::transaction::ExecutionConfig;
 use crate::types::*;
 use crate::wasm::*;
 
@@ -82,7 +83,7 @@ where
         transaction_hash: Hash,
         auth_zone_params: AuthZoneParams,
         blobs: &'g HashMap<Hash, &'g [u8]>,
-        max_depth: usize,
+        execution_config: &ExecutionConfig,
         track: Track<'s, R>,
         scrypto_interpreter: &'g ScryptoInterpreter<W>,
         modules: Vec<Box<dyn Module<R>>>,
@@ -91,7 +92,7 @@ where
             execution_mode: ExecutionMode::Kernel,
             transaction_hash,
             blobs,
-            max_depth,
+            max_depth: execution_config.max_call_depth,
             heap: Heap::new(),
             track,
             scrypto_interpreter,
@@ -109,6 +110,7 @@ where
                     vec![],
                     auth_zone_params.virtualizable_proofs_resource_addresses,
                     auth_zone_params.initial_proofs.into_iter().collect(),
+                    execution_config.trace,
                 );
 
                 let node_id = system_api.allocate_node_id(RENodeType::AuthZoneStack)?;
@@ -142,6 +144,8 @@ where
             RENodeId::Global(GlobalAddress::System(CLOCK)),
             RENodeVisibilityOrigin::Normal,
         );
+        // The account package is reserved rather than created, and calls to it are routed to
+        // the native account
         kernel.current_frame.add_stored_ref(
             RENodeId::Global(GlobalAddress::Package(ACCOUNT_PACKAGE)),
             RENodeVisibilityOrigin::Normal,
@@ -188,21 +192,32 @@ where
                     _ => return Ok(false),
                 };
 
-                // TODO: Replace with trusted IndexedScryptoValue
+                let account_id = self
+                    .id_allocator
+                    .new_component_id(self.transaction_hash)
+                    .map(RENodeId::Account)
+                    .map_err(|e| RuntimeError::KernelError(KernelError::IdAllocationError(e)))?;
                 let access_rule = rule!(require(non_fungible_address));
-                let result = self.invoke(ParsedScryptoInvocation::Function(
-                    ScryptoFunctionIdent {
-                        package: ScryptoPackage::Global(ACCOUNT_PACKAGE),
-                        blueprint_name: "Account".to_string(),
-                        function_name: "create".to_string(),
-                    },
-                    IndexedScryptoValue::from_slice(&args!(access_rule)).unwrap(),
-                ))?;
-                let component_id = result.component_ids.into_iter().next().unwrap();
+                self.current_frame.create_node(
+                    account_id,
+                    RENode::Account(
+                        AccountSubstate {
+                            vaults: BTreeMap::new(),
+                            deposit_policy: AccountDepositPolicy::default(),
+                        },
+                        AccessRulesChainSubstate {
+                            access_rules_chain: vec![Account::access_rules(access_rule)],
+                        },
+                    ),
+                    &mut self.heap,
+                    &mut self.track,
+                    false,
+                    true,
+                )?;
 
-                // TODO: Use system_api to globalize component when create_node is refactored
+                // TODO: Use system_api to globalize account when create_node is refactored
                 // TODO: to allow for address selection
-                let global_substate = GlobalAddressSubstate::Component(component_id);
+                let global_substate = GlobalAddressSubstate::Account(account_id.into());
 
                 self.current_frame.create_node(
                     node_id,
@@ -476,6 +491,23 @@ where
         }
     }
 
+    /// Executes in the given mode on behalf of a system call, regardless of the current mode.
+    pub(crate) fn execute_in_mode_from_kernel<X, RTN>(
+        &mut self,
+        execution_mode: ExecutionMode,
+        execute: X,
+    ) -> Result<RTN, RuntimeError>
+    where
+        X: FnOnce(&mut Self) -> Result<RTN, RuntimeError>,
+    {
+        let saved_mode = self.execution_mode;
+        self.execution_mode = ExecutionMode::Kernel;
+        let rtn = self.execute_in_mode(execution_mode, execute);
+        // Restored on errors too, since the caller may handle them and carry on
+        self.execution_mode = saved_mode;
+        rtn
+    }
+
     fn invoke_internal<X: Executor>(
         &mut self,
         executor: X,
@@ -731,6 +763,28 @@ where
         Ok(fee)
     }
 
+    fn lock_sponsored_fee(
+        &mut self,
+        vault_id: VaultId,
+        mut fee: Resource,
+        sponsorship: FeeSponsorship,
+    ) -> Result<Resource, RuntimeError> {
+        for m in &mut self.modules {
+            fee = m
+                .on_lock_sponsored_fee(
+                    &self.current_frame,
+                    &mut self.heap,
+                    &mut self.track,
+                    vault_id,
+                    fee,
+                    &sponsorship,
+                )
+                .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(fee)
+    }
+
     fn get_actor(&self) -> &REActor {
         &self.current_frame.actor
     }
@@ -867,6 +921,14 @@ where
                 .id_allocator
                 .new_component_id(self.transaction_hash)
                 .map(|id| RENodeId::Clock(id)),
+            RENodeType::Account => self
+                .id_allocator
+                .new_component_id(self.transaction_hash)
+                .map(|id| RENodeId::Account(id)),
+            RENodeType::AccessController => self
+                .id_allocator
+                .new_component_id(self.transaction_hash)
+                .map(|id| RENodeId::AccessController(id)),
             RENodeType::GlobalPackage => self
                 .id_allocator
                 .new_package_address(self.transaction_hash)
@@ -887,6 +949,10 @@ where
                 .id_allocator
                 .new_account_address(self.transaction_hash)
                 .map(|address| RENodeId::Global(GlobalAddress::Component(address))),
+            RENodeType::GlobalAccessController => self
+                .id_allocator
+                .new_access_controller_address(self.transaction_hash)
+                .map(|address| RENodeId::Global(GlobalAddress::Component(address))),
             RENodeType::GlobalComponent => self
                 .id_allocator
                 .new_component_address(self.transaction_hash)
@@ -944,6 +1010,14 @@ where
                 RENodeId::Global(GlobalAddress::System(..)),
                 RENode::Global(GlobalAddressSubstate::Clock(..)),
             ) => {}
+            (
+                RENodeId::Global(GlobalAddress::Component(ComponentAddress::Account(..))),
+                RENode::Global(GlobalAddressSubstate::Account(..)),
+            ) => {}
+            (
+                RENodeId::Global(GlobalAddress::Component(ComponentAddress::AccessController(..))),
+                RENode::Global(GlobalAddressSubstate::AccessController(..)),
+            ) => {}
             (
                 RENodeId::Global(address),
                 RENode::Global(GlobalAddressSubstate::Component(component)),
@@ -968,13 +1042,6 @@ where
                     )?;
 
                 match address {
-                    GlobalAddress::Component(ComponentAddress::Account(..)) => {
-                        if !(package_address.eq(&ACCOUNT_PACKAGE)
-                            && blueprint_name.eq(&ACCOUNT_BLUEPRINT))
-                        {
-                            return Err(RuntimeError::KernelError(KernelError::InvalidId(node_id)));
-                        }
-                    }
                     GlobalAddress::Component(ComponentAddress::Normal(..)) => {
                         if package_address.eq(&ACCOUNT_PACKAGE)
                             && blueprint_name.eq(&ACCOUNT_BLUEPRINT)
@@ -999,6 +1066,8 @@ where
             (RENodeId::ResourceManager(..), RENode::ResourceManager(..)) => {}
             (RENodeId::EpochManager(..), RENode::EpochManager(..)) => {}
             (RENodeId::Clock(..), RENode::Clock(..)) => {}
+            (RENodeId::Account(..), RENode::Account(..)) => {}
+            (RENodeId::AccessController(..), RENode::AccessController(..)) => {}
             _ => return Err(RuntimeError::KernelError(KernelError::InvalidId(node_id))),
         }
 
@@ -1268,6 +1337,82 @@ where
         Ok(substate_ref_mut)
     }
 
+    fn list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<KeyValueStoreKeysPage, RuntimeError> {
+        // Checked before the costing module charges for the limit
+        if limit > MAX_KV_STORE_SCAN_LIMIT {
+            return Err(RuntimeError::KernelError(
+                KernelError::KeyValueStoreScanLimitExceeded(limit),
+            ));
+        }
+
+        for m in &mut self.modules {
+            m.pre_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallInput::ListKeyValueStoreKeys {
+                    node_id: &node_id,
+                    limit,
+                },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        if !matches!(node_id, RENodeId::KeyValueStore(..)) {
+            return Err(RuntimeError::KernelError(KernelError::InvalidId(node_id)));
+        }
+
+        // Authorization, scanning requires the same access as reading any of the entries
+        let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(Vec::new()));
+        let flags = LockFlags::read_only();
+        let actor = &self.current_frame.actor;
+        if !VisibilityProperties::check_substate_visibility(
+            self.execution_mode,
+            actor,
+            node_id,
+            offset.clone(),
+            flags,
+        ) {
+            return Err(RuntimeError::KernelError(
+                KernelError::InvalidSubstateVisibility {
+                    mode: self.execution_mode,
+                    actor: actor.clone(),
+                    node_id,
+                    offset,
+                    flags,
+                },
+            ));
+        }
+
+        let page = match self.current_frame.get_node_location(node_id)? {
+            RENodeLocation::Heap => {
+                self.heap
+                    .list_kv_store_keys(node_id, start_after, limit as usize)?
+            }
+            RENodeLocation::Store => self
+                .track
+                .list_kv_store_keys(node_id, start_after, limit as usize)
+                .map_err(KernelError::TrackError)?,
+        };
+
+        for m in &mut self.modules {
+            m.post_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallOutput::ListKeyValueStoreKeys { page: &page },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(page)
+    }
+
     fn read_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
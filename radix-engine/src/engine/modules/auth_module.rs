@@ -2,8 +2,8 @@ use crate::engine::*;
 use crate::model::*;
 use crate::types::*;
 use radix_engine_interface::api::types::{
-    AuthZoneStackOffset, ComponentOffset, GlobalAddress, NativeFunction, NativeMethod,
-    PackageOffset, RENodeId, SubstateOffset, VaultOffset,
+    AuthZoneStackOffset, ClockOffset, ComponentOffset, EpochManagerOffset, GlobalAddress,
+    NativeFunction, NativeMethod, PackageOffset, RENodeId, SubstateOffset, VaultOffset,
 };
 use radix_engine_interface::data::IndexedScryptoValue;
 
@@ -15,6 +15,8 @@ pub enum AuthError {
         actor: REActor,
         authorization: MethodAuthorization,
         error: MethodAuthorizationError,
+        /// Only recorded if the transaction is executed with tracing
+        trace: Option<Box<AuthTrace>>,
     },
 }
 
@@ -29,6 +31,12 @@ impl AuthModule {
         NonFungibleId::U32(1)
     }
 
+    /// Held virtually by every epoch manager frame, so that it can mint and burn the stake units
+    /// and unstake claims of its validators.
+    pub fn epoch_manager_id() -> NonFungibleId {
+        NonFungibleId::U32(2)
+    }
+
     pub fn on_call_frame_enter<Y: SystemApi>(
         call_frame_update: &mut CallFrameUpdate,
         actor: &REActor,
@@ -40,6 +48,13 @@ impl AuthModule {
             .find(|e| matches!(e, RENodeId::AuthZoneStack(..)))
             .unwrap();
         call_frame_update.node_refs_to_copy.insert(auth_zone_id);
+        // Time-based rules may be checked in any frame
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Global(GlobalAddress::System(CLOCK)));
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)));
 
         if !matches!(
             actor,
@@ -55,13 +70,66 @@ impl AuthModule {
 
             // New auth zone frame managed by the AuthModule
             let is_barrier = Self::is_barrier(actor);
-            auth_zone_stack.new_frame(is_barrier);
+            if matches!(
+                actor,
+                REActor::Method(ResolvedMethod::Native(NativeMethod::EpochManager(..)), ..)
+            ) {
+                auth_zone_stack.new_frame_with_virtual_non_fungibles(
+                    is_barrier,
+                    BTreeSet::from([NonFungibleAddress::new(
+                        SYSTEM_TOKEN,
+                        Self::epoch_manager_id(),
+                    )]),
+                );
+            } else {
+                auth_zone_stack.new_frame(is_barrier);
+            }
             system_api.drop_lock(handle)?;
         }
 
         Ok(())
     }
 
+    /// Loads the parts of the ledger state which the time-based rules of the authorizations
+    /// are verified against.
+    pub fn ledger_state<Y>(
+        method_auths: &[MethodAuthorization],
+        system_api: &mut Y,
+    ) -> Result<AuthLedgerState, RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let mut ledger_state = AuthLedgerState::default();
+
+        if method_auths.iter().any(|auth| auth.requires_current_time()) {
+            let handle = system_api.lock_substate(
+                RENodeId::Global(GlobalAddress::System(CLOCK)),
+                SubstateOffset::Clock(ClockOffset::CurrentTimeRoundedToMinutes),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = system_api.get_ref(handle)?;
+            ledger_state.current_time =
+                Some(substate_ref.current_time_rounded_to_minutes().instant());
+            system_api.drop_lock(handle)?;
+        }
+
+        if method_auths
+            .iter()
+            .any(|auth| auth.requires_current_epoch())
+        {
+            let handle = system_api.lock_substate(
+                RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = system_api.get_ref(handle)?;
+            ledger_state.current_epoch = Some(substate_ref.epoch_manager().epoch);
+            system_api.drop_lock(handle)?;
+        }
+
+        Ok(ledger_state)
+    }
+
     fn is_barrier(actor: &REActor) -> bool {
         matches!(
             actor,
@@ -113,6 +181,8 @@ impl AuthModule {
                             || matches!(method, NativeMethod::ResourceManager(..))
                             || matches!(method, NativeMethod::Package(..))
                             || matches!(method, NativeMethod::Clock(..))
+                            || matches!(method, NativeMethod::Account(..))
+                            || matches!(method, NativeMethod::AccessController(..))
                             || matches!(method, NativeMethod::Component(..)) =>
                     {
                         let offset = SubstateOffset::AccessRulesChain(
@@ -236,6 +306,16 @@ impl AuthModule {
                                     );
                                     vec![authorization]
                                 }
+                                VaultMethod::Freeze | VaultMethod::Unfreeze => {
+                                    let access_rule =
+                                        substate.access_rules_chain[0].get_group("freeze");
+                                    let authorization = convert(
+                                        &Type::Any,
+                                        &IndexedScryptoValue::unit(),
+                                        access_rule,
+                                    );
+                                    vec![authorization]
+                                }
                                 _ => {
                                     return Err(RuntimeError::ModuleError(ModuleError::AuthError(
                                         AuthError::VisibilityError(vault_node_id),
@@ -252,6 +332,8 @@ impl AuthModule {
             }
         };
 
+        let ledger_state = Self::ledger_state(&method_auths, system_api)?;
+
         let refed = system_api.get_visible_node_ids()?;
         let auth_zone_id = refed
             .into_iter()
@@ -269,12 +351,13 @@ impl AuthModule {
 
         // Authorization check
         auth_zone_stack
-            .check_auth(is_barrier, method_auths)
-            .map_err(|(authorization, error)| {
+            .check_auth(is_barrier, method_auths, &ledger_state)
+            .map_err(|(authorization, error, trace)| {
                 RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
                     actor: actor.clone(),
                     authorization,
                     error,
+                    trace,
                 }))
             })?;
 
@@ -1,10 +1,15 @@
 use crate::engine::*;
-use crate::fee::{FeeReserve, FeeReserveError, SystemApiCostingEntry};
+use crate::fee::{
+    CostedInvocation, FeeReserve, FeeReserveError, SponsoredInvocation, SystemApiCostingEntry,
+};
 use crate::model::Resource;
 use crate::types::*;
-use radix_engine_interface::api::types::{RENodeId, VaultId};
+use radix_engine_interface::api::types::{
+    NativeFunction, RENodeId, TransactionProcessorFunction, VaultId,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeId)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
 pub enum CostingError {
     FeeReserveError(FeeReserveError),
 }
@@ -135,6 +140,18 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                                     loaded: false,
                                     size: 0,
                                 },
+                                RENodeId::Account(..) => SystemApiCostingEntry::BorrowNode {
+                                    // TODO: figure out loaded state and size
+                                    loaded: false,
+                                    size: 0,
+                                },
+                                RENodeId::AccessController(..) => {
+                                    SystemApiCostingEntry::BorrowNode {
+                                        // TODO: figure out loaded state and size
+                                        loaded: false,
+                                        size: 0,
+                                    }
+                                }
                             }
                         }),
                         1,
@@ -250,6 +267,20 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                     )
                     .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
             }
+            SysCallInput::ListKeyValueStoreKeys { limit, .. } => {
+                // Costing, charged upfront for the whole scan
+                track
+                    .fee_reserve
+                    .consume_execution(
+                        track.fee_table.system_api_cost(
+                            SystemApiCostingEntry::ListKeyValueStoreKeys { limit },
+                        ),
+                        1,
+                        "list_kv_store_keys",
+                        false,
+                    )
+                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+            }
             SysCallInput::ReadTransactionHash => {
                 track
                     .fee_reserve
@@ -308,8 +339,8 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                 let (native, tracked, size) = match event {
                     Event::Runtime(_) => (true, false, 0),
                     Event::Tracked(TrackedEvent::Native(..)) => (true, true, 0),
-                    Event::Tracked(TrackedEvent::Scrypto(value)) => {
-                        (false, true, value.len() as u32)
+                    Event::Tracked(TrackedEvent::Scrypto(event)) => {
+                        (false, true, event.data.len() as u32)
                     }
                 };
                 track
@@ -327,6 +358,19 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                         false,
                     )
                     .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+
+                match event {
+                    Event::Runtime(RuntimeEvent::PreExecuteInstruction {
+                        instruction_index,
+                        ..
+                    }) => track
+                        .fee_reserve
+                        .set_instruction_index(Some(*instruction_index)),
+                    Event::Runtime(RuntimeEvent::PostExecuteInstruction { .. }) => {
+                        track.fee_reserve.set_instruction_index(None)
+                    }
+                    _ => {}
+                }
             }
         }
 
@@ -389,14 +433,49 @@ impl<R: FeeReserve> Module<R> for CostingModule {
             .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))
     }
 
+    fn on_lock_sponsored_fee(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+        vault_id: VaultId,
+        fee: Resource,
+        sponsorship: &FeeSponsorship,
+    ) -> Result<Resource, ModuleError> {
+        track
+            .fee_reserve
+            .lock_sponsored_fee(vault_id, fee, sponsorship.clone())
+            .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))
+    }
+
     fn pre_execute_invocation(
         &mut self,
         actor: &REActor,
         _call_frame_update: &CallFrameUpdate,
-        _call_frame: &CallFrame,
+        call_frame: &CallFrame,
         _heap: &mut Heap,
         track: &mut Track<R>,
     ) -> Result<(), ModuleError> {
+        // Invocations made by the transaction itself are subject to fee sponsorships
+        if let REActor::Function(ResolvedFunction::Native(NativeFunction::TransactionProcessor(
+            TransactionProcessorFunction::Run,
+        ))) = &call_frame.actor
+        {
+            if let Some(invocation) = SponsoredInvocation::from_actor(actor) {
+                track
+                    .fee_reserve
+                    .record_invocation(invocation)
+                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+            }
+        }
+
+        // The transaction processor itself is not attributed any costs
+        if call_frame.depth > 0 {
+            track
+                .fee_reserve
+                .enter_invocation(CostedInvocation::from(actor));
+        }
+
         match actor {
             REActor::Function(ResolvedFunction::Native(native_function)) => track
                 .fee_reserve
@@ -419,4 +498,18 @@ impl<R: FeeReserve> Module<R> for CostingModule {
             _ => Ok(()),
         }
     }
+
+    fn post_execute_invocation(
+        &mut self,
+        _caller: &REActor,
+        _update: &CallFrameUpdate,
+        call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+    ) -> Result<(), ModuleError> {
+        if call_frame.depth > 1 {
+            track.fee_reserve.exit_invocation();
+        }
+        Ok(())
+    }
 }
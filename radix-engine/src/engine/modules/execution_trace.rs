@@ -18,6 +18,10 @@ pub struct ResourceChange {
     pub component_id: ComponentId, // TODO: support non component actor
     pub vault_id: VaultId,
     pub amount: Decimal,
+    /// The non-fungibles added to the vault, empty for a fungible resource.
+    pub added_ids: BTreeSet<NonFungibleId>,
+    /// The non-fungibles removed from the vault, empty for a fungible resource.
+    pub removed_ids: BTreeSet<NonFungibleId>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -28,11 +32,43 @@ pub struct ExecutionTraceReceipt {
 #[derive(Debug)]
 pub enum VaultOp {
     Create(Decimal), // TODO: add trace of vault creation
-    Put(Decimal),    // TODO: add non-fungible support
-    Take(Decimal),
+    Put(Resource),
+    Take(Resource),
     LockFee,
 }
 
+/// The net change to a single vault.
+#[derive(Debug, Default)]
+struct VaultChange {
+    amount: Decimal,
+    added_ids: BTreeSet<NonFungibleId>,
+    removed_ids: BTreeSet<NonFungibleId>,
+}
+
+impl VaultChange {
+    fn put(&mut self, resource: &Resource) {
+        self.amount += resource.amount();
+        if let Resource::NonFungible { ids, .. } = resource {
+            for id in ids {
+                if !self.removed_ids.remove(id) {
+                    self.added_ids.insert(id.clone());
+                }
+            }
+        }
+    }
+
+    fn take(&mut self, resource: &Resource) {
+        self.amount -= resource.amount();
+        if let Resource::NonFungible { ids, .. } = resource {
+            for id in ids {
+                if !self.added_ids.remove(id) {
+                    self.removed_ids.insert(id.clone());
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum ExecutionTraceError {
@@ -195,9 +231,10 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
                 (NativeMethod::Vault(VaultMethod::Put), RENodeId::Vault(vault_id)) => {
                     Self::handle_vault_put(update, heap, track, caller, &vault_id)
                 }
-                (NativeMethod::Vault(VaultMethod::LockFee), RENodeId::Vault(vault_id)) => {
-                    Self::handle_vault_lock_fee(track, caller, &vault_id)
-                }
+                (
+                    NativeMethod::Vault(VaultMethod::LockFee | VaultMethod::LockSponsoredFee),
+                    RENodeId::Vault(vault_id),
+                ) => Self::handle_vault_lock_fee(track, caller, &vault_id),
                 _ => {}
             }
         }
@@ -217,9 +254,10 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
             &call_frame.actor
         {
             match (native_method, resolved_receiver.receiver) {
-                (NativeMethod::Vault(VaultMethod::Take), RENodeId::Vault(vault_id)) => {
-                    Self::handle_vault_take(update, heap, track, caller, &vault_id)
-                }
+                (
+                    NativeMethod::Vault(VaultMethod::Take | VaultMethod::TakeNonFungibles),
+                    RENodeId::Vault(vault_id),
+                ) => Self::handle_vault_take(update, heap, track, caller, &vault_id),
                 _ => {}
             }
         }
@@ -532,7 +570,7 @@ impl ExecutionTraceModule {
                         track.vault_ops.push((
                             actor.clone(),
                             vault_id.clone(),
-                            VaultOp::Put(bucket_substate.bucket().total_amount()),
+                            VaultOp::Put(bucket_substate.bucket().peek_resource()),
                         ));
                     }
                 }
@@ -558,7 +596,7 @@ impl ExecutionTraceModule {
                         track.vault_ops.push((
                             actor.clone(),
                             vault_id.clone(),
-                            VaultOp::Take(bucket_substate.bucket().total_amount()),
+                            VaultOp::Take(bucket_substate.bucket().peek_resource()),
                         ));
                     }
                 }
@@ -588,34 +626,23 @@ impl ExecutionTraceReceipt {
         to_persist: &mut HashMap<SubstateId, (PersistedSubstate, Option<u32>)>,
         is_commit_success: bool,
     ) -> Self {
-        let mut vault_changes = HashMap::<ComponentId, HashMap<VaultId, Decimal>>::new();
+        let mut vault_changes = HashMap::<ComponentId, HashMap<VaultId, VaultChange>>::new();
         let mut vault_locked_by = HashMap::<VaultId, ComponentId>::new();
         for (actor, vault_id, vault_op) in ops {
             if let REActor::Method(_, resolved_receiver) = actor {
-                if let RENodeId::Component(component_id) = resolved_receiver.receiver {
+                if let RENodeId::Component(component_id) | RENodeId::Account(component_id) =
+                    resolved_receiver.receiver
+                {
+                    let vault_change = vault_changes
+                        .entry(component_id)
+                        .or_default()
+                        .entry(vault_id)
+                        .or_default();
                     match vault_op {
                         VaultOp::Create(_) => todo!("Not supported yet!"),
-                        VaultOp::Put(amount) => {
-                            *vault_changes
-                                .entry(component_id)
-                                .or_default()
-                                .entry(vault_id)
-                                .or_default() += amount;
-                        }
-                        VaultOp::Take(amount) => {
-                            *vault_changes
-                                .entry(component_id)
-                                .or_default()
-                                .entry(vault_id)
-                                .or_default() -= amount;
-                        }
+                        VaultOp::Put(resource) => vault_change.put(&resource),
+                        VaultOp::Take(resource) => vault_change.take(&resource),
                         VaultOp::LockFee => {
-                            *vault_changes
-                                .entry(component_id)
-                                .or_default()
-                                .entry(vault_id)
-                                .or_default() -= 0;
-
                             // Hack: Additional check to avoid second `lock_fee` attempts (runtime failure) from
                             // polluting the `vault_locked_by` index.
                             if !vault_locked_by.contains_key(&vault_id) {
@@ -629,26 +656,29 @@ impl ExecutionTraceReceipt {
 
         let mut resource_changes = Vec::<ResourceChange>::new();
         for (component_id, map) in vault_changes {
-            for (vault_id, delta) in map {
+            for (vault_id, change) in map {
                 // Amount = put/take amount - fee_amount
                 let fee_amount = actual_fee_payments
                     .get(&vault_id)
                     .cloned()
                     .unwrap_or_default();
-                let amount = if is_commit_success {
-                    delta
+                let (amount, added_ids, removed_ids) = if is_commit_success {
+                    (change.amount, change.added_ids, change.removed_ids)
                 } else {
-                    Decimal::zero()
-                } - fee_amount;
+                    (Decimal::zero(), BTreeSet::new(), BTreeSet::new())
+                };
+                let amount = amount - fee_amount;
 
-                // Add a resource change log if non-zero
-                if !amount.is_zero() {
+                // Add a resource change log if non-zero, or if non-fungibles were swapped
+                if !amount.is_zero() || !added_ids.is_empty() || !removed_ids.is_empty() {
                     let resource_address = Self::get_vault_resource_address(vault_id, to_persist);
                     resource_changes.push(ResourceChange {
                         resource_address,
                         component_id,
                         vault_id,
                         amount,
+                        added_ids,
+                        removed_ids,
                     });
                 }
             }
@@ -75,6 +75,14 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
                     substate_id
                 );
             }
+            SysCallInput::ListKeyValueStoreKeys { node_id, limit } => {
+                log!(
+                    call_frame,
+                    "Listing key value store keys: node_id = {:?} limit = {}",
+                    node_id,
+                    limit
+                );
+            }
             SysCallInput::ReadTransactionHash => {
                 log!(call_frame, "Reading transaction hash");
             }
@@ -120,6 +128,7 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             SysCallOutput::GetRef { .. } => {}
             SysCallOutput::GetRefMut { .. } => {}
             SysCallOutput::DropLock { .. } => {}
+            SysCallOutput::ListKeyValueStoreKeys { .. } => {}
             SysCallOutput::ReadTransactionHash { .. } => {}
             SysCallOutput::ReadBlob { .. } => {}
             SysCallOutput::GenerateUuid { .. } => {}
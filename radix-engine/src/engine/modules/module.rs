@@ -4,7 +4,7 @@ use crate::fee::FeeReserve;
 use crate::model::Resource;
 use crate::types::*;
 use radix_engine_interface::api::types::{
-    Level, LockHandle, RENodeId, SubstateId, SubstateOffset, VaultId,
+    KeyValueStoreKeysPage, Level, LockHandle, RENodeId, SubstateId, SubstateOffset, VaultId,
 };
 use sbor::rust::fmt::Debug;
 
@@ -42,6 +42,10 @@ pub enum SysCallInput<'a> {
     TakeSubstate {
         substate_id: &'a SubstateId,
     },
+    ListKeyValueStoreKeys {
+        node_id: &'a RENodeId,
+        limit: u32,
+    },
     ReadTransactionHash,
     ReadBlob {
         blob_hash: &'a Hash,
@@ -67,6 +71,7 @@ pub enum SysCallOutput<'a> {
     GetRef { lock_handle: LockHandle },
     GetRefMut,
     DropLock,
+    ListKeyValueStoreKeys { page: &'a KeyValueStoreKeysPage },
     ReadTransactionHash { hash: &'a Hash },
     ReadBlob { blob: &'a [u8] },
     GenerateUuid { uuid: u128 },
@@ -149,6 +154,18 @@ pub trait Module<R: FeeReserve> {
         Ok(fee)
     }
 
+    fn on_lock_sponsored_fee(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        _track: &mut Track<R>,
+        _vault_id: VaultId,
+        fee: Resource,
+        _sponsorship: &FeeSponsorship,
+    ) -> Result<Resource, ModuleError> {
+        Ok(fee)
+    }
+
     fn on_finished_processing(
         &mut self,
         _heap: &mut Heap,
@@ -75,6 +75,8 @@ impl NodeMoveModule {
             | RENodeId::Worktop
             | RENodeId::EpochManager(..)
             | RENodeId::Clock(..)
+            | RENodeId::Account(..)
+            | RENodeId::AccessController(..)
             | RENodeId::Global(..) => Err(RuntimeError::ModuleError(ModuleError::NodeMoveError(
                 NodeMoveError::CantMoveDownstream(node_id),
             ))),
@@ -115,6 +117,8 @@ impl NodeMoveModule {
             | RENodeId::Worktop
             | RENodeId::EpochManager(..)
             | RENodeId::Clock(..)
+            | RENodeId::Account(..)
+            | RENodeId::AccessController(..)
             | RENodeId::Global(..) => Err(RuntimeError::ModuleError(ModuleError::NodeMoveError(
                 NodeMoveError::CantMoveUpstream(node_id),
             ))),
@@ -3,12 +3,12 @@ use crate::engine::*;
 use crate::types::*;
 use radix_engine_interface::api::api::InvokableModel;
 use radix_engine_interface::api::types::{
-    AccessRulesChainMethod, AuthZoneStackMethod, BucketMethod, EpochManagerFunction,
-    EpochManagerMethod, NativeFn, NativeFunction, NativeMethod, PackageFunction, ProofMethod,
-    ResourceManagerFunction, ResourceManagerMethod, TransactionProcessorFunction, VaultMethod,
-    WorktopMethod,
+    AccessControllerFunction, AccessControllerMethod, AccessRulesChainMethod, AccountFunction,
+    AccountMethod, AuthZoneStackMethod, BucketMethod, EpochManagerFunction, EpochManagerMethod,
+    NativeFn, NativeFunction, NativeMethod, PackageFunction, ProofMethod, ResourceManagerFunction,
+    ResourceManagerMethod, TransactionProcessorFunction, VaultMethod, WorktopMethod,
 };
-use radix_engine_interface::data::IndexedScryptoValue;
+use radix_engine_interface::data::{IndexedScryptoValue, ScryptoValue};
 use radix_engine_interface::model::*;
 
 // TODO: Cleanup
@@ -72,6 +72,35 @@ where
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
             },
+            NativeFunction::Account(account_function) => match account_function {
+                AccountFunction::New => {
+                    let invocation: AccountNewInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountFunction::NewWithResource => {
+                    let invocation: AccountNewWithResourceInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+            },
+            NativeFunction::AccessController(access_controller_function) => {
+                match access_controller_function {
+                    AccessControllerFunction::CreateGlobal => {
+                        let invocation: AccessControllerCreateGlobalInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                }
+            }
             NativeFunction::Clock(ClockFunction::Create) => {
                 let invocation: ClockCreateInvocation = scrypto_decode(&args)
                     .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
@@ -265,6 +294,13 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                VaultMethod::LockSponsoredFee => {
+                    let invocation: VaultLockSponsoredFeeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
                 VaultMethod::TakeNonFungibles => {
                     let invocation: VaultTakeNonFungiblesInvocation = scrypto_decode(&args)
                         .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
@@ -328,6 +364,27 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                VaultMethod::BurnNonFungibles => {
+                    let invocation: VaultBurnNonFungiblesInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                VaultMethod::Freeze => {
+                    let invocation: VaultFreezeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                VaultMethod::Unfreeze => {
+                    let invocation: VaultUnfreezeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
             },
             NativeMethod::AccessRulesChain(component_method) => match component_method {
                 AccessRulesChainMethod::AddAccessCheck => {
@@ -458,6 +515,15 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                ResourceManagerMethod::GetMaxSupply => {
+                    let invocation: ResourceManagerGetMaxSupplyInvocation = scrypto_decode(&args)
+                        .map_err(|e| {
+                        RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                    })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
                 ResourceManagerMethod::UpdateNonFungibleData => {
                     let invocation: ResourceManagerUpdateNonFungibleDataInvocation =
                         scrypto_decode(&args).map_err(|e| {
@@ -467,6 +533,15 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                ResourceManagerMethod::UpdateNonFungibleDataField => {
+                    let invocation: ResourceManagerUpdateNonFungibleDataFieldInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
                 ResourceManagerMethod::NonFungibleExists => {
                     let invocation: ResourceManagerNonFungibleExistsInvocation =
                         scrypto_decode(&args).map_err(|e| {
@@ -501,6 +576,75 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                EpochManagerMethod::NextEpoch => {
+                    let invocation: EpochManagerNextEpochInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::GetValidatorSet => {
+                    let invocation: EpochManagerGetValidatorSetInvocation = scrypto_decode(&args)
+                        .map_err(|e| {
+                        RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                    })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::RegisterValidator => {
+                    let invocation: EpochManagerRegisterValidatorInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::UnregisterValidator => {
+                    let invocation: EpochManagerUnregisterValidatorInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::GetValidator => {
+                    let invocation: EpochManagerGetValidatorInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::Stake => {
+                    let invocation: EpochManagerStakeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::Unstake => {
+                    let invocation: EpochManagerUnstakeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::ClaimXrd => {
+                    let invocation: EpochManagerClaimXrdInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                EpochManagerMethod::UpdateFeeConfig => {
+                    let invocation: EpochManagerUpdateFeeConfigInvocation = scrypto_decode(&args)
+                        .map_err(|e| {
+                        RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                    })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
             },
             NativeMethod::Clock(clock_method) => match clock_method {
                 ClockMethod::SetCurrentTime => {
@@ -525,6 +669,242 @@ where
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
             },
+            NativeMethod::Account(account_method) => match account_method {
+                AccountMethod::Balance => {
+                    let invocation: AccountBalanceInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFee => {
+                    let invocation: AccountLockFeeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockContingentFee => {
+                    let invocation: AccountLockContingentFeeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockSponsoredFee => {
+                    let invocation: AccountLockSponsoredFeeInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::Deposit => {
+                    let invocation: AccountDepositInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::DepositBatch => {
+                    let invocation: AccountDepositBatchInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::TryDepositOrRefund => {
+                    let invocation: AccountTryDepositOrRefundInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::TryDepositBatchOrRefund => {
+                    let invocation: AccountTryDepositBatchOrRefundInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::Withdraw => {
+                    let invocation: AccountWithdrawInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::WithdrawByAmount => {
+                    let invocation: AccountWithdrawByAmountInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::WithdrawByIds => {
+                    let invocation: AccountWithdrawByIdsInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFeeAndWithdraw => {
+                    let invocation: AccountLockFeeAndWithdrawInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFeeAndWithdrawByAmount => {
+                    let invocation: AccountLockFeeAndWithdrawByAmountInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFeeAndWithdrawByIds => {
+                    let invocation: AccountLockFeeAndWithdrawByIdsInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::CreateProof => {
+                    let invocation: AccountCreateProofInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::CreateProofByAmount => {
+                    let invocation: AccountCreateProofByAmountInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::CreateProofByIds => {
+                    let invocation: AccountCreateProofByIdsInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::SetDepositPolicy => {
+                    let invocation: AccountSetDepositPolicyInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::GetDepositPolicy => {
+                    let invocation: AccountGetDepositPolicyInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+            },
+            NativeMethod::AccessController(access_controller_method) => {
+                match access_controller_method {
+                    AccessControllerMethod::CreateProof => {
+                        let invocation: AccessControllerCreateProofInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::InitiateRecoveryAsPrimary => {
+                        let invocation: AccessControllerInitiateRecoveryAsPrimaryInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::InitiateRecoveryAsRecovery => {
+                        let invocation: AccessControllerInitiateRecoveryAsRecoveryInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::QuickConfirmPrimaryRoleRecoveryProposal => {
+                        let invocation: AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::QuickConfirmRecoveryRoleRecoveryProposal => {
+                        let invocation: AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::TimedConfirmRecovery => {
+                        let invocation: AccessControllerTimedConfirmRecoveryInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::CancelPrimaryRoleRecoveryProposal => {
+                        let invocation: AccessControllerCancelPrimaryRoleRecoveryProposalInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::CancelRecoveryRoleRecoveryProposal => {
+                        let invocation: AccessControllerCancelRecoveryRoleRecoveryProposalInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::LockPrimaryRole => {
+                        let invocation: AccessControllerLockPrimaryRoleInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::UnlockPrimaryRole => {
+                        let invocation: AccessControllerUnlockPrimaryRoleInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    AccessControllerMethod::StopTimedRecovery => {
+                        let invocation: AccessControllerStopTimedRecoveryInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                }
+            }
             NativeMethod::Worktop(worktop_method) => match worktop_method {
                 WorktopMethod::TakeNonFungibles => {
                     let invocation: WorktopTakeNonFungiblesInvocation = scrypto_decode(&args)
@@ -622,3 +1002,267 @@ where
         },
     }
 }
+
+/// Whether a Scrypto function targets the native account blueprint, whose functions and
+/// methods remain callable by name for compatibility with existing manifests and blueprints.
+pub fn is_account_function(function_ident: &ScryptoFunctionIdent) -> bool {
+    match function_ident.package {
+        ScryptoPackage::Global(package_address) => {
+            package_address.eq(&ACCOUNT_PACKAGE)
+                && function_ident.blueprint_name.eq(ACCOUNT_BLUEPRINT)
+        }
+    }
+}
+
+/// Whether a Scrypto method targets a native account, see [`is_account_function`].
+pub fn is_account_method(method_ident: &ScryptoMethodIdent) -> bool {
+    matches!(
+        method_ident.receiver,
+        ScryptoReceiver::Global(ComponentAddress::Account(..))
+            | ScryptoReceiver::Global(ComponentAddress::EcdsaSecp256k1VirtualAccount(..))
+            | ScryptoReceiver::Global(ComponentAddress::EddsaEd25519VirtualAccount(..))
+    )
+}
+
+pub fn parse_and_invoke_account_fn<Y>(
+    invocation: ScryptoInvocation,
+    system_api: &mut Y,
+) -> Result<IndexedScryptoValue, RuntimeError>
+where
+    Y: InvokableModel<RuntimeError>,
+{
+    match invocation {
+        ScryptoInvocation::Function(function_ident, args) => {
+            let account_function = AccountFunction::from_str(&function_ident.function_name)
+                .map_err(|_| {
+                    RuntimeError::InterpreterError(
+                        InterpreterError::InvalidScryptoFunctionInvocation(
+                            function_ident.clone(),
+                            ScryptoFnResolvingError::FunctionNotFound,
+                        ),
+                    )
+                })?;
+            let invalid_input = || {
+                RuntimeError::InterpreterError(InterpreterError::InvalidScryptoFunctionInvocation(
+                    function_ident.clone(),
+                    ScryptoFnResolvingError::InvalidInput,
+                ))
+            };
+
+            match account_function {
+                AccountFunction::New => {
+                    let (withdraw_rule,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountNewInvocation { withdraw_rule })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountFunction::NewWithResource => {
+                    let (withdraw_rule, bucket) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountNewWithResourceInvocation {
+                            withdraw_rule,
+                            bucket,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+            }
+        }
+        ScryptoInvocation::Method(method_ident, args) => {
+            let method_not_found = || {
+                RuntimeError::InterpreterError(InterpreterError::InvalidScryptoMethodInvocation(
+                    method_ident.clone(),
+                    ScryptoFnResolvingError::MethodNotFound,
+                ))
+            };
+            let receiver = match method_ident.receiver {
+                ScryptoReceiver::Global(address) => address,
+                ScryptoReceiver::Component(..) => return Err(method_not_found()),
+            };
+            let account_method = AccountMethod::from_str(&method_ident.method_name)
+                .map_err(|_| method_not_found())?;
+            let invalid_input = || {
+                RuntimeError::InterpreterError(InterpreterError::InvalidScryptoMethodInvocation(
+                    method_ident.clone(),
+                    ScryptoFnResolvingError::InvalidInput,
+                ))
+            };
+
+            match account_method {
+                AccountMethod::Balance => {
+                    let (resource_address,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountBalanceInvocation {
+                            receiver,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFee => {
+                    let (amount,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountLockFeeInvocation { receiver, amount })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockContingentFee => {
+                    let (amount,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountLockContingentFeeInvocation { receiver, amount })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockSponsoredFee => {
+                    let (amount, sponsorship) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountLockSponsoredFeeInvocation {
+                            receiver,
+                            amount,
+                            sponsorship,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::Deposit => {
+                    let (bucket,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountDepositInvocation { receiver, bucket })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::DepositBatch => {
+                    let (buckets,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountDepositBatchInvocation { receiver, buckets })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::TryDepositOrRefund => {
+                    let (bucket,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountTryDepositOrRefundInvocation { receiver, bucket })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::TryDepositBatchOrRefund => {
+                    let (buckets,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountTryDepositBatchOrRefundInvocation { receiver, buckets })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::Withdraw => {
+                    let (resource_address,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountWithdrawInvocation {
+                            receiver,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::WithdrawByAmount => {
+                    let (amount, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountWithdrawByAmountInvocation {
+                            receiver,
+                            amount,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::WithdrawByIds => {
+                    let (ids, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountWithdrawByIdsInvocation {
+                            receiver,
+                            ids,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFeeAndWithdraw => {
+                    let (amount_to_lock, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountLockFeeAndWithdrawInvocation {
+                            receiver,
+                            amount_to_lock,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFeeAndWithdrawByAmount => {
+                    let (amount_to_lock, amount, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountLockFeeAndWithdrawByAmountInvocation {
+                            receiver,
+                            amount_to_lock,
+                            amount,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::LockFeeAndWithdrawByIds => {
+                    let (amount_to_lock, ids, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountLockFeeAndWithdrawByIdsInvocation {
+                            receiver,
+                            amount_to_lock,
+                            ids,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::CreateProof => {
+                    let (resource_address,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountCreateProofInvocation {
+                            receiver,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::CreateProofByAmount => {
+                    let (amount, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountCreateProofByAmountInvocation {
+                            receiver,
+                            amount,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::CreateProofByIds => {
+                    let (ids, resource_address) =
+                        scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountCreateProofByIdsInvocation {
+                            receiver,
+                            ids,
+                            resource_address,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::SetDepositPolicy => {
+                    let (deposit_policy,) = scrypto_decode(&args).map_err(|_| invalid_input())?;
+                    system_api
+                        .invoke(AccountSetDepositPolicyInvocation {
+                            receiver,
+                            deposit_policy,
+                        })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                AccountMethod::GetDepositPolicy => {
+                    let value =
+                        IndexedScryptoValue::from_slice(&args).map_err(|_| invalid_input())?;
+                    if !matches!(value.dom, ScryptoValue::Tuple { ref fields } if fields.is_empty())
+                    {
+                        return Err(invalid_input());
+                    }
+                    system_api
+                        .invoke(AccountGetDepositPolicyInvocation { receiver })
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+            }
+        }
+    }
+}
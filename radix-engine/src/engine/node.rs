@@ -38,11 +38,18 @@ pub enum RENode {
         AccessRulesChainSubstate,
         AccessRulesChainSubstate,
     ),
-    EpochManager(EpochManagerSubstate, AccessRulesChainSubstate),
+    EpochManager(
+        EpochManagerSubstate,
+        ValidatorSetSubstate,
+        FeeConfigSubstate,
+        AccessRulesChainSubstate,
+    ),
     Clock(
         CurrentTimeRoundedToMinutesSubstate,
         AccessRulesChainSubstate,
     ),
+    Account(AccountSubstate, AccessRulesChainSubstate),
+    AccessController(AccessControllerSubstate, AccessRulesChainSubstate),
 }
 
 impl RENode {
@@ -183,11 +190,19 @@ impl RENode {
                     );
                 }
             }
-            RENode::EpochManager(epoch_manager, access_rules) => {
+            RENode::EpochManager(epoch_manager, validator_set, fee_config, access_rules) => {
                 substates.insert(
                     SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
                     epoch_manager.into(),
                 );
+                substates.insert(
+                    SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet),
+                    validator_set.into(),
+                );
+                substates.insert(
+                    SubstateOffset::EpochManager(EpochManagerOffset::FeeConfig),
+                    fee_config.into(),
+                );
                 substates.insert(
                     SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
                     access_rules.into(),
@@ -203,6 +218,26 @@ impl RENode {
                     access_rules_substate.into(),
                 );
             }
+            RENode::Account(account, access_rules) => {
+                substates.insert(
+                    SubstateOffset::Account(AccountOffset::Account),
+                    account.into(),
+                );
+                substates.insert(
+                    SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+                    access_rules.into(),
+                );
+            }
+            RENode::AccessController(access_controller, access_rules) => {
+                substates.insert(
+                    SubstateOffset::AccessController(AccessControllerOffset::AccessController),
+                    access_controller.into(),
+                );
+                substates.insert(
+                    SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+                    access_rules.into(),
+                );
+            }
             RENode::FeeReserve(fee_reserve) => {
                 substates.insert(
                     SubstateOffset::FeeReserve(FeeReserveOffset::FeeReserve),
@@ -4,7 +4,8 @@ use crate::engine::{
 };
 use crate::model::GlobalAddressSubstate;
 use radix_engine_interface::api::types::{
-    AccessRulesChainOffset, AuthZoneStackOffset, BucketOffset, ComponentOffset, GlobalOffset,
+    AccessControllerOffset, AccessRulesChainOffset, AccountOffset, AuthZoneStackOffset,
+    BucketOffset, ClockOffset, ComponentOffset, EpochManagerOffset, GlobalOffset,
     KeyValueStoreOffset, NativeFunction, NativeMethod, PackageOffset, ProofOffset, RENodeId,
     ResourceManagerOffset, SubstateOffset, TransactionProcessorFunction, VaultOffset,
     WorktopOffset,
@@ -153,6 +154,13 @@ impl VisibilityProperties {
                 SubstateOffset::VaultAccessRulesChain(AccessRulesChainOffset::AccessRulesChain) => {
                     flags == LockFlags::read_only()
                 }
+                // Time-based rules
+                SubstateOffset::Clock(ClockOffset::CurrentTimeRoundedToMinutes) => {
+                    flags == LockFlags::read_only()
+                }
+                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager) => {
+                    flags == LockFlags::read_only()
+                }
                 _ => false,
             },
             (ExecutionMode::ScryptoInterpreter, offset) => match offset {
@@ -261,6 +269,17 @@ impl SubstateProperties {
                     node_id,
                 ))),
             },
+            SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet)
+            | SubstateOffset::Account(AccountOffset::Account)
+            | SubstateOffset::AccessController(AccessControllerOffset::AccessController) => {
+                match node_id {
+                    RENodeId::Vault(..) => Ok(()),
+                    _ => Err(RuntimeError::KernelError(KernelError::InvalidOwnership(
+                        offset.clone(),
+                        node_id,
+                    ))),
+                }
+            }
             SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager) => {
                 match node_id {
                     RENodeId::NonFungibleStore(..) => Ok(()),
@@ -275,7 +294,9 @@ impl SubstateProperties {
                 | RENodeId::Package(..)
                 | RENodeId::ResourceManager(..)
                 | RENodeId::EpochManager(..)
-                | RENodeId::Clock(..) => Ok(()),
+                | RENodeId::Clock(..)
+                | RENodeId::Account(..)
+                | RENodeId::AccessController(..) => Ok(()),
                 _ => Err(RuntimeError::KernelError(KernelError::InvalidOwnership(
                     offset.clone(),
                     node_id,
@@ -4,7 +4,9 @@ use crate::model::{Resource, SubstateRef, SubstateRefMut};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use bitflags::bitflags;
-use radix_engine_interface::api::types::{LockHandle, RENodeId, SubstateOffset, VaultId};
+use radix_engine_interface::api::types::{
+    KeyValueStoreKeysPage, LockHandle, RENodeId, SubstateOffset, VaultId,
+};
 
 bitflags! {
     #[derive(Encode, Decode, TypeId)]
@@ -50,6 +52,13 @@ pub trait SystemApi {
         contingent: bool,
     ) -> Result<Resource, RuntimeError>;
 
+    fn lock_sponsored_fee(
+        &mut self,
+        vault_id: VaultId,
+        fee: Resource,
+        sponsorship: FeeSponsorship,
+    ) -> Result<Resource, RuntimeError>;
+
     /// Retrieve the running actor for the current frame
     fn get_actor(&self) -> &REActor;
 
@@ -90,6 +99,15 @@ pub trait SystemApi {
     /// Get a mutable reference to a locked substate
     fn get_ref_mut(&mut self, lock_handle: LockHandle) -> Result<SubstateRefMut, RuntimeError>;
 
+    /// Scans up to `limit` entries of a visible key value store, starting after the entry with
+    /// key `start_after`
+    fn list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<KeyValueStoreKeysPage, RuntimeError>;
+
     fn read_transaction_hash(&mut self) -> Result<Hash, RuntimeError>;
 
     fn read_blob(&mut self, blob_hash: &Hash) -> Result<&[u8], RuntimeError>;
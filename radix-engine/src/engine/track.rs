@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use radix_engine_interface::api::types::{
-    GlobalAddress, GlobalOffset, KeyValueStoreOffset, Level, NonFungibleStoreOffset, RENodeId,
-    SubstateId, SubstateOffset, VaultId, VaultOffset,
+    GlobalAddress, GlobalOffset, KeyValueStoreKeysPage, KeyValueStoreOffset, Level,
+    NonFungibleStoreOffset, RENodeId, SubstateId, SubstateOffset, VaultId, VaultOffset,
 };
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::model::*;
@@ -9,16 +9,16 @@ use transaction::model::Executable;
 
 use crate::engine::*;
 use crate::fee::FeeReserveError;
-use crate::fee::FeeSummary;
 use crate::fee::FeeTable;
+use crate::fee::{FeePayerContribution, FeeSummary};
 use crate::fee::{FeeReserve, RoyaltyReceiver};
 use crate::ledger::*;
 use crate::model::Resource;
 use crate::model::RuntimeSubstate;
 use crate::model::SubstateRef;
-use crate::model::TransactionProcessorError;
 use crate::model::{KeyValueStoreEntrySubstate, PersistedSubstate};
 use crate::model::{NonFungibleSubstate, SubstateRefMut};
+use crate::model::{TransactionProcessorError, VaultError};
 use crate::state_manager::StateDiff;
 use crate::transaction::CommitResult;
 use crate::transaction::EntityChanges;
@@ -80,6 +80,7 @@ pub enum TrackError {
     SubstateLocked(SubstateId, LockState),
     LockUnmodifiedBaseOnNewSubstate(SubstateId),
     LockUnmodifiedBaseOnOnUpdatedSubstate(SubstateId),
+    RangeScanNotSupported,
 }
 
 pub type InvokeResult = Result<Vec<Vec<u8>>, RuntimeError>;
@@ -301,6 +302,67 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         runtime_substate.to_ref_mut()
     }
 
+    /// Scans up to `limit` entries of a stored key value store, following the order of their
+    /// encoded substate keys and starting after the entry with key `start_after`.
+    ///
+    /// Returns the keys of the scanned entries which hold a value, along with the key to resume
+    /// the scan from if there may be more entries.
+    pub fn list_kv_store_keys(
+        &self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: usize,
+    ) -> Result<KeyValueStoreKeysPage, TrackError> {
+        let substate_store = self
+            .substate_store
+            .as_iterable()
+            .ok_or(TrackError::RangeScanNotSupported)?;
+        let range = SubstateRange::NodeOffsets(
+            node_id,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(Vec::new())),
+        );
+        let cursor = start_after.map(|key| {
+            SubstateId(
+                node_id,
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+            )
+        });
+        let cursor_key = cursor.as_ref().map(encode_substate_key);
+
+        let mut entries: BTreeMap<Vec<u8>, (Vec<u8>, bool)> = BTreeMap::new();
+        for (substate_id, output) in substate_store
+            .list_substates(&range, cursor.as_ref(), limit)
+            .substates
+        {
+            if let SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)) = &substate_id.1 {
+                let exists = output.substate.to_runtime().kv_store_entry().0.is_some();
+                entries.insert(encode_substate_key(&substate_id), (key.clone(), exists));
+            }
+        }
+
+        // Entries loaded by this transaction may have been updated since
+        for (substate_id, loaded_substate) in &self.loaded_substates {
+            if let SubstateId(id, SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key))) =
+                substate_id
+            {
+                if !node_id.eq(id) {
+                    continue;
+                }
+                let substate_key = encode_substate_key(substate_id);
+                let after_cursor = match &cursor_key {
+                    Some(cursor_key) => substate_key.gt(cursor_key),
+                    None => true,
+                };
+                if after_cursor {
+                    let exists = loaded_substate.substate.kv_store_entry().0.is_some();
+                    entries.insert(substate_key, (key.clone(), exists));
+                }
+            }
+        }
+
+        Ok(collect_kv_store_keys(entries, limit))
+    }
+
     pub fn insert_substate(&mut self, substate_id: SubstateId, substate: RuntimeSubstate) {
         assert!(!self.loaded_substates.contains_key(&substate_id));
 
@@ -478,9 +540,16 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         &mut self,
         transaction: &Executable,
     ) -> Result<(), FeeReserveError> {
-        let encoded_instructions_byte_length = scrypto_encode(transaction.instructions())
+        let mut encoded_instructions_byte_length = scrypto_encode(transaction.instructions())
             .expect("Valid transaction had instructions which couldn't be encoded")
             .len();
+        let mut signature_count = transaction.auth_zone_params().initial_proofs.len();
+        for subintent in transaction.subintents() {
+            encoded_instructions_byte_length += scrypto_encode(&subintent.instructions)
+                .expect("Valid transaction had instructions which couldn't be encoded")
+                .len();
+            signature_count += subintent.auth_zone_params.initial_proofs.len();
+        }
         let blobs_size = {
             let mut total_size: usize = 0;
             for blob in transaction.blobs() {
@@ -515,7 +584,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
             .and_then(|()| {
                 self.fee_reserve.consume_execution(
                     self.fee_table.tx_signature_verification_per_sig(),
-                    transaction.auth_zone_params().initial_proofs.len(),
+                    signature_count,
                     "verify_signatures",
                     true,
                 )
@@ -549,11 +618,38 @@ impl<'s, R: FeeReserve> Track<'s, R> {
             }),
         };
 
+        // Only resource events of a successful commit reflect the final state, apart from fees
+        let mut events = self.events;
+        let is_success = matches!(
+            &result,
+            TransactionResult::Commit(CommitResult {
+                outcome: TransactionOutcome::Success(..),
+                ..
+            })
+        );
+        if !is_success {
+            events
+                .retain(|event| !matches!(event, TrackedEvent::Native(NativeEvent::Resource(..))));
+        }
+        if let Some(vault_payments) = &fee_summary.vault_payments_xrd {
+            for (vault_id, amount) in vault_payments {
+                if amount.is_zero() {
+                    continue;
+                }
+                events.push(TrackedEvent::Native(NativeEvent::Resource(ResourceEvent {
+                    kind: ResourceEventKind::VaultFeePayment(*vault_id),
+                    resource_address: RADIX_TOKEN,
+                    amount: *amount,
+                    non_fungible_ids: None,
+                })));
+            }
+        }
+
         TrackReceipt {
             fee_summary,
             application_logs: self.application_logs,
             result,
-            events: self.events,
+            events,
         }
     }
 }
@@ -590,17 +686,64 @@ fn check_for_rejection(
         _ => {}
     }
 
-    // Check for errors before loan is repaid - in which case, we also reject
+    // Check for errors before loan is repaid - in which case, we also reject.
+    // Sponsorship violations are only rejections here too, as otherwise a transaction
+    // could void the fees it has locked by breaking a sponsorship as its last step.
     if !fee_summary.loan_fully_repaid() {
         return Err(match invoke_result {
             Ok(..) => RejectionError::SuccessButFeeLoanNotRepaid,
-            Err(error) => RejectionError::ErrorBeforeFeeLoanRepaid(error),
+            Err(error) => sponsorship_rejection(&error)
+                .unwrap_or(RejectionError::ErrorBeforeFeeLoanRepaid(error)),
         });
     }
 
+    // A sponsor never pays for a transaction breaking its sponsorship - the failure is billed
+    // to the other fee payers, and rejected if they can't cover the costs (royalties included
+    // in the costs are reverted on failure).
+    if let Err(error) = &invoke_result {
+        if let Some(rejection) = sponsorship_rejection(error) {
+            if fee_summary.unsponsored_fees_xrd() < fee_summary.total_execution_cost_xrd {
+                return Err(rejection);
+            }
+        }
+    }
+
     return Ok(invoke_result);
 }
 
+fn sponsorship_rejection(error: &RuntimeError) -> Option<RejectionError> {
+    match error {
+        RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::FeeSponsorshipExpired {
+                expires_at_epoch,
+                current_epoch,
+            },
+        )) => Some(RejectionError::FeeSponsorshipExpired {
+            expires_at_epoch: *expires_at_epoch,
+            current_epoch: *current_epoch,
+        }),
+        RuntimeError::ModuleError(ModuleError::CostingError(CostingError::FeeReserveError(
+            FeeReserveError::SponsorshipLimitExceeded {
+                vault_id,
+                max_contribution,
+            },
+        ))) => Some(RejectionError::FeeSponsorshipLimitExceeded {
+            vault_id: *vault_id,
+            max_contribution: *max_contribution,
+        }),
+        RuntimeError::ModuleError(ModuleError::CostingError(CostingError::FeeReserveError(
+            FeeReserveError::InvocationNotAllowedBySponsorship {
+                vault_id,
+                invocation,
+            },
+        ))) => Some(RejectionError::InvocationNotAllowedByFeeSponsorship {
+            vault_id: *vault_id,
+            invocation: invocation.clone(),
+        }),
+        _ => None,
+    }
+}
+
 /// This is just used when finalizing track into a commit
 struct FinalizingTrack<'s> {
     substate_store: &'s dyn ReadableSubstateStore,
@@ -616,6 +759,10 @@ impl<'s> FinalizingTrack<'s> {
         fee_summary: &mut FeeSummary,
     ) -> TransactionResult {
         let is_success = invoke_result.is_ok();
+        let is_sponsorship_broken = match &invoke_result {
+            Err(error) => sponsorship_rejection(error).is_some(),
+            Ok(..) => false,
+        };
 
         // Commit/rollback application state changes
         let mut to_persist = HashMap::new();
@@ -653,13 +800,18 @@ impl<'s> FinalizingTrack<'s> {
 
         // Finalize payments
         let mut actual_fee_payments: IndexMap<VaultId, Decimal> = IndexMap::new();
+        let mut payer_contributions: IndexMap<VaultId, FeePayerContribution> = IndexMap::new();
         let mut required = fee_summary.total_execution_cost_xrd
             + fee_summary.total_royalty_cost_xrd
             - fee_summary.bad_debt_xrd;
         let mut fees: Resource =
             Resource::new_empty(RADIX_TOKEN, ResourceType::Fungible { divisibility: 18 });
         for (vault_id, mut locked, contingent) in fee_summary.vault_locks.iter().cloned().rev() {
-            let amount = if contingent {
+            let sponsored = fee_summary.is_sponsored(&vault_id);
+            let amount = if sponsored && is_sponsorship_broken {
+                // Sponsors are refunded in full, see `check_for_rejection`
+                Decimal::zero()
+            } else if contingent {
                 if is_success {
                     Decimal::min(locked.amount(), required)
                 } else {
@@ -672,6 +824,20 @@ impl<'s> FinalizingTrack<'s> {
             // Deduct fee required
             required = required - amount;
 
+            // Record the contribution of the payer
+            let contribution =
+                payer_contributions
+                    .entry(vault_id)
+                    .or_insert_with(|| FeePayerContribution {
+                        locked: Decimal::zero(),
+                        paid: Decimal::zero(),
+                        refunded: Decimal::zero(),
+                        sponsored,
+                    });
+            contribution.locked += locked.amount();
+            contribution.paid += amount;
+            contribution.refunded += locked.amount() - amount;
+
             // Collect fees into collector
             fees.put(locked.take_by_amount(amount).unwrap()).unwrap();
 
@@ -695,6 +861,7 @@ impl<'s> FinalizingTrack<'s> {
             *actual_fee_payments.entry(vault_id).or_default() += amount;
         }
         fee_summary.vault_payments_xrd = Some(actual_fee_payments);
+        fee_summary.payer_contributions = Some(payer_contributions);
 
         // TODO: update XRD supply or disable it
         // TODO: pay tips to the lead validator
@@ -799,3 +966,27 @@ impl<'s> FinalizingTrack<'s> {
         })
     }
 }
+
+/// Takes the first `limit` scanned key value store entries, keyed by their encoded substate keys
+/// and paired with whether they hold a value, and returns the keys of the non-empty ones along
+/// with the key to resume the scan from.
+pub(crate) fn collect_kv_store_keys(
+    entries: BTreeMap<Vec<u8>, (Vec<u8>, bool)>,
+    limit: usize,
+) -> KeyValueStoreKeysPage {
+    let scanned: Vec<(Vec<u8>, bool)> = entries.into_values().take(limit).collect();
+    let next_start_after = if scanned.len() == limit {
+        scanned.last().map(|(key, _)| key.clone())
+    } else {
+        None
+    };
+    let keys = scanned
+        .into_iter()
+        .filter_map(|(key, exists)| if exists { Some(key) } else { None })
+        .collect();
+
+    KeyValueStoreKeysPage {
+        keys,
+        next_start_after,
+    }
+}
@@ -0,0 +1,132 @@
+use crate::engine::{REActor, ResolvedFunction, ResolvedMethod};
+use crate::types::*;
+
+/// The function or method run by an invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum CostedInvocation {
+    Scrypto {
+        package_address: PackageAddress,
+        blueprint_name: String,
+        ident: String,
+    },
+    Native(NativeFn),
+}
+
+impl From<&REActor> for CostedInvocation {
+    fn from(actor: &REActor) -> Self {
+        match actor {
+            REActor::Function(ResolvedFunction::Scrypto {
+                package_address,
+                blueprint_name,
+                ident,
+                ..
+            })
+            | REActor::Method(
+                ResolvedMethod::Scrypto {
+                    package_address,
+                    blueprint_name,
+                    ident,
+                    ..
+                },
+                ..,
+            ) => CostedInvocation::Scrypto {
+                package_address: *package_address,
+                blueprint_name: blueprint_name.clone(),
+                ident: ident.clone(),
+            },
+            REActor::Function(ResolvedFunction::Native(native_function)) => {
+                CostedInvocation::Native(NativeFn::Function(*native_function))
+            }
+            REActor::Method(ResolvedMethod::Native(native_method), ..) => {
+                CostedInvocation::Native(NativeFn::Method(*native_method))
+            }
+        }
+    }
+}
+
+/// The cost units consumed by a single invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct InvocationCost {
+    pub invocation: CostedInvocation,
+    /// The manifest instruction the invocation was made for
+    pub instruction_index: Option<usize>,
+    /// The nesting depth, 0 for invocations made by the transaction processor
+    pub depth: usize,
+    /// The cost units consumed by the invocation itself
+    pub cost_units: u32,
+    /// The cost units consumed by the invocation and the invocations nested in it
+    pub total_cost_units: u32,
+}
+
+/// The cost units of a transaction, attributed to its instructions and invocations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct CostBreakdown {
+    /// The cost units not attributable to any instruction, such as the base fee
+    pub transaction_cost_units: u32,
+    /// The cost units consumed by each instruction, including its invocations
+    pub instruction_cost_units: BTreeMap<usize, u32>,
+    /// All invocations, in the order they were made
+    pub invocations: Vec<InvocationCost>,
+}
+
+/// Builds a `CostBreakdown` while a transaction executes.
+#[derive(Debug, Default)]
+pub struct CostBreakdownTracker {
+    instruction_index: Option<usize>,
+    /// Indices of the invocations in progress, innermost last
+    invocation_stack: Vec<usize>,
+    breakdown: CostBreakdown,
+}
+
+impl CostBreakdownTracker {
+    pub fn set_instruction_index(&mut self, instruction_index: Option<usize>) {
+        self.instruction_index = instruction_index;
+    }
+
+    pub fn enter_invocation(&mut self, invocation: CostedInvocation) {
+        self.invocation_stack.push(self.breakdown.invocations.len());
+        self.breakdown.invocations.push(InvocationCost {
+            invocation,
+            instruction_index: self.instruction_index,
+            depth: self.invocation_stack.len() - 1,
+            cost_units: 0,
+            total_cost_units: 0,
+        });
+    }
+
+    pub fn exit_invocation(&mut self) {
+        self.invocation_stack.pop();
+    }
+
+    /// Attributes cost units to the current instruction and invocations.
+    pub fn record(&mut self, n: u32) {
+        match self.instruction_index {
+            Some(index) => self
+                .breakdown
+                .instruction_cost_units
+                .entry(index)
+                .or_default()
+                .add_assign(n),
+            None => self.breakdown.transaction_cost_units += n,
+        }
+
+        if let Some(innermost) = self.invocation_stack.last() {
+            self.breakdown.invocations[*innermost].cost_units += n;
+        }
+        for index in &self.invocation_stack {
+            self.breakdown.invocations[*index].total_cost_units += n;
+        }
+    }
+
+    /// Attributes cost units to the transaction as a whole.
+    pub fn record_for_transaction(&mut self, n: u32) {
+        self.breakdown.transaction_cost_units += n;
+    }
+
+    pub fn finalize(self) -> CostBreakdown {
+        self.breakdown
+    }
+}
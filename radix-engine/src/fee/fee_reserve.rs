@@ -1,4 +1,5 @@
-use crate::fee::FeeSummary;
+use crate::engine::{REActor, ResolvedFunction, ResolvedMethod, ResolvedReceiver};
+use crate::fee::{CostBreakdownTracker, CostedInvocation, FeeSummary, FeeTable};
 use crate::model::Resource;
 use crate::types::*;
 use radix_engine_constants::{
@@ -9,13 +10,114 @@ use sbor::rust::cmp::min;
 
 // Note: for performance reason, `u128` is used to represent decimal in this file.
 
-#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeId)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
 pub enum FeeReserveError {
     InsufficientBalance,
     Overflow,
     LimitExceeded,
     LoanRepaymentFailed,
     NotXrd,
+    SponsorshipLimitExceeded {
+        vault_id: VaultId,
+        max_contribution: Decimal,
+    },
+    InvocationNotAllowedBySponsorship {
+        vault_id: VaultId,
+        invocation: SponsoredInvocation,
+    },
+}
+
+/// An invocation made by the transaction itself, which every fee sponsorship has to allow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum SponsoredInvocation {
+    Method {
+        component_address: ComponentAddress,
+        method: String,
+    },
+    Function {
+        package_address: PackageAddress,
+        blueprint_name: String,
+        function: String,
+    },
+    /// Native functions and methods of nodes other than components, which are never sponsored
+    Other(CostedInvocation),
+}
+
+impl SponsoredInvocation {
+    /// Returns `None` for the invocations which are part of running any transaction.
+    pub fn from_actor(actor: &REActor) -> Option<Self> {
+        match actor {
+            REActor::Method(
+                method,
+                ResolvedReceiver {
+                    derefed_from: Some((RENodeId::Global(GlobalAddress::Component(address)), _)),
+                    ..
+                },
+            ) => Some(SponsoredInvocation::Method {
+                component_address: *address,
+                method: match method {
+                    ResolvedMethod::Scrypto { ident, .. } => ident.clone(),
+                    ResolvedMethod::Native(native_method) => {
+                        native_method_ident(native_method).to_string()
+                    }
+                },
+            }),
+            REActor::Function(ResolvedFunction::Scrypto {
+                package_address,
+                blueprint_name,
+                ident,
+                ..
+            }) => Some(SponsoredInvocation::Function {
+                package_address: *package_address,
+                blueprint_name: blueprint_name.clone(),
+                function: ident.clone(),
+            }),
+            // The transaction processor, the transaction's own worktop, auth zone, buckets and
+            // proofs, and the epoch read for its runtime validations
+            REActor::Function(ResolvedFunction::Native(NativeFunction::TransactionProcessor(
+                ..,
+            )))
+            | REActor::Method(
+                _,
+                ResolvedReceiver {
+                    receiver:
+                        RENodeId::Worktop
+                        | RENodeId::AuthZoneStack(..)
+                        | RENodeId::Bucket(..)
+                        | RENodeId::Proof(..),
+                    ..
+                },
+            )
+            | REActor::Method(
+                ResolvedMethod::Native(NativeMethod::EpochManager(
+                    EpochManagerMethod::GetCurrentEpoch,
+                )),
+                ..,
+            ) => None,
+            _ => Some(SponsoredInvocation::Other(CostedInvocation::from(actor))),
+        }
+    }
+}
+
+fn native_method_ident(native_method: &NativeMethod) -> &'static str {
+    match *native_method {
+        NativeMethod::AccessRulesChain(method) => method.into(),
+        NativeMethod::Component(method) => method.into(),
+        NativeMethod::Package(method) => method.into(),
+        NativeMethod::Metadata(method) => method.into(),
+        NativeMethod::EpochManager(method) => method.into(),
+        NativeMethod::AuthZoneStack(method) => method.into(),
+        NativeMethod::ResourceManager(method) => method.into(),
+        NativeMethod::Bucket(method) => method.into(),
+        NativeMethod::Vault(method) => method.into(),
+        NativeMethod::Proof(method) => method.into(),
+        NativeMethod::Worktop(method) => method.into(),
+        NativeMethod::Clock(method) => method.into(),
+        NativeMethod::Account(method) => method.into(),
+        NativeMethod::AccessController(method) => method.into(),
+    }
 }
 
 pub trait FeeReserve {
@@ -40,6 +142,27 @@ pub trait FeeReserve {
         contingent: bool,
     ) -> Result<Resource, FeeReserveError>;
 
+    /// Locks a fee under a sponsorship. A transaction breaking the sponsorship is billed to its
+    /// other fee payers, or rejected if they can't cover the costs.
+    fn lock_sponsored_fee(
+        &mut self,
+        vault_id: VaultId,
+        fee: Resource,
+        sponsorship: FeeSponsorship,
+    ) -> Result<Resource, FeeReserveError>;
+
+    /// Records an invocation made by the transaction itself, which every sponsorship must allow.
+    fn record_invocation(&mut self, invocation: SponsoredInvocation)
+        -> Result<(), FeeReserveError>;
+
+    /// Attributes the cost units consumed from now on to a manifest instruction.
+    fn set_instruction_index(&mut self, instruction_index: Option<usize>);
+
+    /// Attributes the cost units consumed from now on to a new nested invocation.
+    fn enter_invocation(&mut self, invocation: CostedInvocation);
+
+    fn exit_invocation(&mut self);
+
     fn finalize(self) -> FeeSummary;
 }
 
@@ -59,6 +182,10 @@ pub struct SystemLoanFeeReserve {
 
     /// Payments made during the execution of a transaction.
     payments: Vec<(VaultId, Resource, bool)>,
+    /// The sponsorships of the vaults which locked a sponsored fee
+    sponsorships: Vec<(VaultId, FeeSponsorship)>,
+    /// The invocations made by the transaction itself
+    invocations: Vec<SponsoredInvocation>,
 
     /// The cost unit balance (from system loan)
     loan_balance: u32,
@@ -80,6 +207,8 @@ pub struct SystemLoanFeeReserve {
     execution: HashMap<&'static str, u32>,
     /// Royalty cost breakdown
     royalty: HashMap<RoyaltyReceiver, u32>,
+    /// Cost breakdown by instruction and invocation, if requested
+    cost_breakdown: Option<CostBreakdownTracker>,
 
     /// Cache: effective execution price
     effective_execution_price: u128,
@@ -105,6 +234,15 @@ pub fn decimal_to_u128(a: Decimal) -> u128 {
     i256.try_into().expect("Overflow")
 }
 
+/// The fee configuration written to the ledger at genesis, which transactions are priced by
+/// until the epoch manager updates it.
+pub fn default_fee_config() -> FeeConfig {
+    FeeConfig {
+        cost_unit_price: u128_to_decimal(DEFAULT_COST_UNIT_PRICE),
+        fee_table: FeeTable::new().config(),
+    }
+}
+
 impl SystemLoanFeeReserve {
     pub fn no_fee() -> Self {
         Self::new(0, 0, DEFAULT_COST_UNIT_LIMIT, DEFAULT_SYSTEM_LOAN)
@@ -120,6 +258,8 @@ impl SystemLoanFeeReserve {
             cost_unit_price,
             tip_percentage,
             payments: Vec::new(),
+            sponsorships: Vec::new(),
+            invocations: Vec::new(),
             loan_balance: system_loan.into(),
             xrd_balance: 0,
             xrd_owed: 0,
@@ -129,12 +269,19 @@ impl SystemLoanFeeReserve {
             execution_deferred: HashMap::new(),
             execution: HashMap::new(),
             royalty: HashMap::new(),
+            cost_breakdown: None,
             effective_execution_price: cost_unit_price
                 + cost_unit_price * tip_percentage as u128 / 100,
             effective_royalty_price: cost_unit_price,
         }
     }
 
+    /// Attributes the consumed cost units to instructions and invocations.
+    pub fn with_cost_breakdown(mut self) -> Self {
+        self.cost_breakdown = Some(CostBreakdownTracker::default());
+        self
+    }
+
     fn consume(&mut self, n: u32, price: u128) -> Result<(), FeeReserveError> {
         // Check limit
         if checked_add(self.cost_unit_consumed, n)? > self.cost_unit_limit {
@@ -166,6 +313,9 @@ impl SystemLoanFeeReserve {
             checked_assign_add(&mut sum, *v)?;
         }
         self.consume(sum, self.execution_price())?;
+        if let Some(cost_breakdown) = &mut self.cost_breakdown {
+            cost_breakdown.record_for_transaction(sum);
+        }
         for (k, v) in self.execution_deferred.drain() {
             self.execution.entry(k).or_default().add_assign(v);
         }
@@ -196,6 +346,33 @@ impl SystemLoanFeeReserve {
     fn fully_repaid(&self) -> bool {
         self.xrd_owed <= 0 && self.execution_deferred.is_empty()
     }
+
+    fn check_invocation_allowed(
+        vault_id: VaultId,
+        sponsorship: &FeeSponsorship,
+        invocation: &SponsoredInvocation,
+    ) -> Result<(), FeeReserveError> {
+        let allowed = match invocation {
+            SponsoredInvocation::Method {
+                component_address,
+                method,
+            } => sponsorship.allows_method(component_address, method),
+            SponsoredInvocation::Function {
+                package_address,
+                blueprint_name,
+                function,
+            } => sponsorship.allows_function(package_address, blueprint_name, function),
+            SponsoredInvocation::Other(..) => false,
+        };
+        if allowed {
+            Ok(())
+        } else {
+            Err(FeeReserveError::InvocationNotAllowedBySponsorship {
+                vault_id,
+                invocation: invocation.clone(),
+            })
+        }
+    }
 }
 
 impl FeeReserve for SystemLoanFeeReserve {
@@ -210,6 +387,9 @@ impl FeeReserve for SystemLoanFeeReserve {
 
         self.consume(amount.into(), self.execution_price())?;
         checked_assign_add(self.royalty.entry(receiver).or_default(), amount)?;
+        if let Some(cost_breakdown) = &mut self.cost_breakdown {
+            cost_breakdown.record(amount);
+        }
 
         if self.cost_unit_consumed >= self.check_point && !self.fully_repaid() {
             self.repay_all()?;
@@ -237,6 +417,9 @@ impl FeeReserve for SystemLoanFeeReserve {
         } else {
             self.consume(n, self.execution_price())?;
             checked_assign_add(self.execution.entry(reason).or_default(), n)?;
+            if let Some(cost_breakdown) = &mut self.cost_breakdown {
+                cost_breakdown.record(n);
+            }
         }
 
         if self.cost_unit_consumed >= self.check_point && !self.fully_repaid() {
@@ -267,6 +450,73 @@ impl FeeReserve for SystemLoanFeeReserve {
         Ok(fee)
     }
 
+    fn lock_sponsored_fee(
+        &mut self,
+        vault_id: VaultId,
+        fee: Resource,
+        sponsorship: FeeSponsorship,
+    ) -> Result<Resource, FeeReserveError> {
+        if fee.resource_address() != RADIX_TOKEN {
+            return Err(FeeReserveError::NotXrd);
+        }
+
+        // Check the contribution of the vault across all its locks
+        let locked: Decimal = self
+            .payments
+            .iter()
+            .filter(|(id, _, contingent)| *id == vault_id && !contingent)
+            .map(|(_, locked, _)| locked.amount())
+            .sum();
+        if locked + fee.amount() > sponsorship.max_contribution {
+            return Err(FeeReserveError::SponsorshipLimitExceeded {
+                vault_id,
+                max_contribution: sponsorship.max_contribution,
+            });
+        }
+
+        // Check the invocations made so far
+        for invocation in &self.invocations {
+            Self::check_invocation_allowed(vault_id, &sponsorship, invocation)?;
+        }
+
+        match self.sponsorships.iter_mut().find(|(id, _)| *id == vault_id) {
+            Some((_, existing)) => *existing = sponsorship,
+            None => self.sponsorships.push((vault_id, sponsorship)),
+        }
+
+        self.lock_fee(vault_id, fee, false)
+    }
+
+    fn record_invocation(
+        &mut self,
+        invocation: SponsoredInvocation,
+    ) -> Result<(), FeeReserveError> {
+        for (vault_id, sponsorship) in &self.sponsorships {
+            Self::check_invocation_allowed(*vault_id, sponsorship, &invocation)?;
+        }
+
+        self.invocations.push(invocation);
+        Ok(())
+    }
+
+    fn set_instruction_index(&mut self, instruction_index: Option<usize>) {
+        if let Some(cost_breakdown) = &mut self.cost_breakdown {
+            cost_breakdown.set_instruction_index(instruction_index);
+        }
+    }
+
+    fn enter_invocation(&mut self, invocation: CostedInvocation) {
+        if let Some(cost_breakdown) = &mut self.cost_breakdown {
+            cost_breakdown.enter_invocation(invocation);
+        }
+    }
+
+    fn exit_invocation(&mut self) {
+        if let Some(cost_breakdown) = &mut self.cost_breakdown {
+            cost_breakdown.exit_invocation();
+        }
+    }
+
     fn finalize(mut self) -> FeeSummary {
         // In case the transaction finishes before check point.
         self.attempt_to_repay_all();
@@ -284,13 +534,16 @@ impl FeeReserve for SystemLoanFeeReserve {
             ),
             bad_debt_xrd: u128_to_decimal(self.xrd_owed),
             vault_locks: self.payments,
-            vault_payments_xrd: None, // Resolved later
+            fee_sponsorships: self.sponsorships,
+            vault_payments_xrd: None,  // Resolved later
+            payer_contributions: None, // Resolved later
             execution_cost_unit_breakdown: self
                 .execution
                 .into_iter()
                 .map(|(k, v)| (k.to_string(), v))
                 .collect(),
             royalty_cost_unit_breakdown: self.royalty,
+            cost_breakdown: self.cost_breakdown.map(CostBreakdownTracker::finalize),
         }
     }
 }
@@ -387,6 +640,114 @@ mod tests {
         assert_eq!(summary.vault_locks, vec![],);
     }
 
+    #[test]
+    fn test_sponsored_lock_limit() {
+        let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 100, 500);
+        let sponsorship = FeeSponsorship::new(dec!(5));
+        fee_reserve
+            .lock_sponsored_fee(TEST_VAULT_ID, xrd(3), sponsorship.clone())
+            .unwrap();
+        assert_eq!(
+            fee_reserve.lock_sponsored_fee(TEST_VAULT_ID, xrd(3), sponsorship),
+            Err(FeeReserveError::SponsorshipLimitExceeded {
+                vault_id: TEST_VAULT_ID,
+                max_contribution: dec!(5),
+            })
+        );
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.vault_locks, vec![(TEST_VAULT_ID, xrd(3), false)]);
+        assert_eq!(summary.fee_sponsorships.len(), 1);
+    }
+
+    #[test]
+    fn test_sponsored_invocations() {
+        let free = SponsoredInvocation::Method {
+            component_address: FAUCET_COMPONENT,
+            method: "free".to_string(),
+        };
+        let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 100, 500);
+        fee_reserve.record_invocation(free.clone()).unwrap();
+        assert_eq!(
+            fee_reserve.lock_sponsored_fee(TEST_VAULT_ID, xrd(3), FeeSponsorship::new(dec!(5))),
+            Err(FeeReserveError::InvocationNotAllowedBySponsorship {
+                vault_id: TEST_VAULT_ID,
+                invocation: free,
+            })
+        );
+        fee_reserve
+            .lock_sponsored_fee(
+                TEST_VAULT_ID,
+                xrd(3),
+                FeeSponsorship::new(dec!(5))
+                    .allow_method(FAUCET_COMPONENT, "free")
+                    .allow_function(FAUCET_PACKAGE, "Faucet", "new"),
+            )
+            .unwrap();
+        fee_reserve
+            .record_invocation(SponsoredInvocation::Function {
+                package_address: FAUCET_PACKAGE,
+                blueprint_name: "Faucet".to_string(),
+                function: "new".to_string(),
+            })
+            .unwrap();
+
+        let lock_fee = SponsoredInvocation::Method {
+            component_address: FAUCET_COMPONENT,
+            method: "lock_fee".to_string(),
+        };
+        assert_eq!(
+            fee_reserve.record_invocation(lock_fee.clone()),
+            Err(FeeReserveError::InvocationNotAllowedBySponsorship {
+                vault_id: TEST_VAULT_ID,
+                invocation: lock_fee,
+            })
+        );
+        let publish = SponsoredInvocation::Other(CostedInvocation::Native(NativeFn::Function(
+            NativeFunction::Package(PackageFunction::Publish),
+        )));
+        assert_eq!(
+            fee_reserve.record_invocation(publish.clone()),
+            Err(FeeReserveError::InvocationNotAllowedBySponsorship {
+                vault_id: TEST_VAULT_ID,
+                invocation: publish,
+            })
+        );
+    }
+
+    #[test]
+    fn test_cost_breakdown() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 100, 500).with_cost_breakdown();
+        fee_reserve.consume_execution(3, 1, "base", true).unwrap();
+        fee_reserve.set_instruction_index(Some(0));
+        fee_reserve.consume_execution(1, 1, "test", false).unwrap();
+        fee_reserve.enter_invocation(CostedInvocation::Native(NativeFn::Method(
+            NativeMethod::Vault(VaultMethod::LockFee),
+        )));
+        fee_reserve.consume_execution(2, 1, "test", false).unwrap();
+        fee_reserve.enter_invocation(CostedInvocation::Native(NativeFn::Method(
+            NativeMethod::Vault(VaultMethod::Take),
+        )));
+        fee_reserve.consume_execution(4, 1, "test", false).unwrap();
+        fee_reserve.exit_invocation();
+        fee_reserve.exit_invocation();
+        fee_reserve.set_instruction_index(None);
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .unwrap();
+        let summary = fee_reserve.finalize();
+        let cost_breakdown = summary.cost_breakdown.unwrap();
+        assert_eq!(summary.cost_unit_consumed, 10);
+        assert_eq!(cost_breakdown.transaction_cost_units, 3);
+        assert_eq!(cost_breakdown.instruction_cost_units.get(&0), Some(&7));
+        let costs: Vec<(usize, u32, u32)> = cost_breakdown
+            .invocations
+            .iter()
+            .map(|cost| (cost.depth, cost.cost_units, cost.total_cost_units))
+            .collect();
+        assert_eq!(costs, vec![(0, 2, 6), (1, 4, 4)]);
+    }
+
     #[test]
     fn test_royalty_execution_mix() {
         let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(5)), 1, 100, 50);
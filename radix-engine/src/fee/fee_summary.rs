@@ -1,4 +1,4 @@
-use super::RoyaltyReceiver;
+use super::{CostBreakdown, RoyaltyReceiver};
 use crate::model::Resource;
 use crate::types::*;
 use indexmap::IndexMap;
@@ -23,16 +23,46 @@ pub struct FeeSummary {
     pub bad_debt_xrd: Decimal,
     /// The vaults locked for XRD payment
     pub vault_locks: Vec<(VaultId, Resource, bool)>,
+    /// The sponsorships under which vaults locked fees
+    pub fee_sponsorships: Vec<(VaultId, FeeSponsorship)>,
     /// The resultant vault charges in XRD (only present on commit)
     pub vault_payments_xrd: Option<IndexMap<VaultId, Decimal>>,
+    /// The resultant contribution of each fee payer (only present on commit)
+    pub payer_contributions: Option<IndexMap<VaultId, FeePayerContribution>>,
     /// The execution cost breakdown
     pub execution_cost_unit_breakdown: HashMap<String, u32>,
     /// The royalty cost breakdown.
     pub royalty_cost_unit_breakdown: HashMap<RoyaltyReceiver, u32>,
+    /// The cost breakdown by instruction and invocation (only present if requested)
+    pub cost_breakdown: Option<CostBreakdown>,
+}
+
+/// What a fee payer locked, paid and got refunded, in XRD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct FeePayerContribution {
+    pub locked: Decimal,
+    pub paid: Decimal,
+    pub refunded: Decimal,
+    /// Whether the payer sponsored the transaction
+    pub sponsored: bool,
 }
 
 impl FeeSummary {
     pub fn loan_fully_repaid(&self) -> bool {
         self.bad_debt_xrd == 0.into()
     }
+
+    pub fn is_sponsored(&self, vault_id: &VaultId) -> bool {
+        self.fee_sponsorships.iter().any(|(id, _)| id == vault_id)
+    }
+
+    /// The XRD locked by vaults outside of any sponsorship, which isn't contingent on success.
+    pub fn unsponsored_fees_xrd(&self) -> Decimal {
+        self.vault_locks
+            .iter()
+            .filter(|(vault_id, _, contingent)| !contingent && !self.is_sponsored(vault_id))
+            .map(|(_, locked, _)| locked.amount())
+            .sum()
+    }
 }
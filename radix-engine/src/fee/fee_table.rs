@@ -1,10 +1,12 @@
 use radix_engine_interface::api::types::{
-    AccessRulesChainMethod, AuthZoneStackMethod, BucketMethod, ClockFunction, ClockMethod,
+    AccessControllerFunction, AccessControllerMethod, AccessRulesChainMethod, AccountFunction,
+    AccountMethod, AuthZoneStackMethod, BucketMethod, ClockFunction, ClockMethod,
     ComponentFunction, ComponentMethod, EpochManagerFunction, EpochManagerMethod, MetadataMethod,
     NativeFunction, NativeMethod, PackageFunction, PackageMethod, ProofMethod,
     ResourceManagerFunction, ResourceManagerMethod, TransactionProcessorFunction, VaultMethod,
     WorktopMethod,
 };
+use radix_engine_interface::model::FeeTableConfig;
 
 pub enum SystemApiCostingEntry {
     /*
@@ -66,6 +68,10 @@ pub enum SystemApiCostingEntry {
     },
 
     DropLock,
+    /// Scans the entries of a key value store
+    ListKeyValueStoreKeys {
+        limit: u32,
+    },
 
     /*
      * Misc
@@ -119,6 +125,34 @@ impl FeeTable {
         }
     }
 
+    pub fn from_config(config: &FeeTableConfig) -> Self {
+        Self {
+            tx_base_fee: config.tx_base_fee,
+            tx_manifest_decoding_per_byte: config.tx_manifest_decoding_per_byte,
+            tx_manifest_verification_per_byte: config.tx_manifest_verification_per_byte,
+            tx_signature_verification_per_sig: config.tx_signature_verification_per_sig,
+            tx_blob_price_per_byte: config.tx_blob_price_per_byte,
+            wasm_instantiation_per_byte: config.wasm_instantiation_per_byte,
+            fixed_low: config.fixed_low,
+            fixed_medium: config.fixed_medium,
+            fixed_high: config.fixed_high,
+        }
+    }
+
+    pub fn config(&self) -> FeeTableConfig {
+        FeeTableConfig {
+            tx_base_fee: self.tx_base_fee,
+            tx_manifest_decoding_per_byte: self.tx_manifest_decoding_per_byte,
+            tx_manifest_verification_per_byte: self.tx_manifest_verification_per_byte,
+            tx_signature_verification_per_sig: self.tx_signature_verification_per_sig,
+            tx_blob_price_per_byte: self.tx_blob_price_per_byte,
+            wasm_instantiation_per_byte: self.wasm_instantiation_per_byte,
+            fixed_low: self.fixed_low,
+            fixed_medium: self.fixed_medium,
+            fixed_high: self.fixed_high,
+        }
+    }
+
     pub fn tx_base_fee(&self) -> u32 {
         self.tx_base_fee
     }
@@ -164,6 +198,13 @@ impl FeeTable {
             NativeFunction::Clock(clock_fn) => match clock_fn {
                 ClockFunction::Create => self.fixed_low,
             },
+            NativeFunction::Account(account_fn) => match account_fn {
+                AccountFunction::New => self.fixed_medium,
+                AccountFunction::NewWithResource => self.fixed_medium,
+            },
+            NativeFunction::AccessController(access_controller_fn) => match access_controller_fn {
+                AccessControllerFunction::CreateGlobal => self.fixed_medium,
+            },
             NativeFunction::ResourceManager(resource_manager_ident) => {
                 match resource_manager_ident {
                     ResourceManagerFunction::Create => self.fixed_high, // TODO: more investigation about fungibility
@@ -191,12 +232,61 @@ impl FeeTable {
             NativeMethod::EpochManager(epoch_manager_method) => match epoch_manager_method {
                 EpochManagerMethod::GetCurrentEpoch => self.fixed_low,
                 EpochManagerMethod::SetEpoch => self.fixed_low,
+                EpochManagerMethod::NextEpoch => self.fixed_high,
+                EpochManagerMethod::GetValidatorSet => self.fixed_low,
+                EpochManagerMethod::RegisterValidator => self.fixed_high,
+                EpochManagerMethod::UnregisterValidator => self.fixed_low,
+                EpochManagerMethod::GetValidator => self.fixed_low,
+                EpochManagerMethod::Stake => self.fixed_medium,
+                EpochManagerMethod::Unstake => self.fixed_medium,
+                EpochManagerMethod::ClaimXrd => self.fixed_medium,
+                EpochManagerMethod::UpdateFeeConfig => self.fixed_low,
             },
             NativeMethod::Clock(clock_method) => match clock_method {
                 ClockMethod::SetCurrentTime => self.fixed_low,
                 ClockMethod::GetCurrentTime => self.fixed_high,
                 ClockMethod::CompareCurrentTime => self.fixed_high,
             },
+            NativeMethod::Account(account_method) => match account_method {
+                AccountMethod::Balance => self.fixed_low,
+                AccountMethod::LockFee => self.fixed_medium,
+                AccountMethod::LockContingentFee => self.fixed_medium,
+                AccountMethod::LockSponsoredFee => self.fixed_medium,
+                AccountMethod::Deposit => self.fixed_medium,
+                AccountMethod::DepositBatch => self.fixed_medium,
+                AccountMethod::TryDepositOrRefund => self.fixed_medium,
+                AccountMethod::TryDepositBatchOrRefund => self.fixed_medium,
+                AccountMethod::Withdraw => self.fixed_medium,
+                AccountMethod::WithdrawByAmount => self.fixed_medium,
+                AccountMethod::WithdrawByIds => self.fixed_medium,
+                AccountMethod::LockFeeAndWithdraw => self.fixed_medium,
+                AccountMethod::LockFeeAndWithdrawByAmount => self.fixed_medium,
+                AccountMethod::LockFeeAndWithdrawByIds => self.fixed_medium,
+                AccountMethod::CreateProof => self.fixed_medium,
+                AccountMethod::CreateProofByAmount => self.fixed_medium,
+                AccountMethod::CreateProofByIds => self.fixed_medium,
+                AccountMethod::SetDepositPolicy => self.fixed_low,
+                AccountMethod::GetDepositPolicy => self.fixed_low,
+            },
+            NativeMethod::AccessController(access_controller_method) => {
+                match access_controller_method {
+                    AccessControllerMethod::CreateProof => self.fixed_medium,
+                    AccessControllerMethod::InitiateRecoveryAsPrimary => self.fixed_medium,
+                    AccessControllerMethod::InitiateRecoveryAsRecovery => self.fixed_medium,
+                    AccessControllerMethod::QuickConfirmPrimaryRoleRecoveryProposal => {
+                        self.fixed_medium
+                    }
+                    AccessControllerMethod::QuickConfirmRecoveryRoleRecoveryProposal => {
+                        self.fixed_medium
+                    }
+                    AccessControllerMethod::TimedConfirmRecovery => self.fixed_medium,
+                    AccessControllerMethod::CancelPrimaryRoleRecoveryProposal => self.fixed_low,
+                    AccessControllerMethod::CancelRecoveryRoleRecoveryProposal => self.fixed_low,
+                    AccessControllerMethod::LockPrimaryRole => self.fixed_low,
+                    AccessControllerMethod::UnlockPrimaryRole => self.fixed_low,
+                    AccessControllerMethod::StopTimedRecovery => self.fixed_low,
+                }
+            }
             NativeMethod::Bucket(bucket_ident) => match bucket_ident {
                 BucketMethod::Take => self.fixed_medium,
                 BucketMethod::TakeNonFungibles => self.fixed_medium,
@@ -220,7 +310,9 @@ impl FeeTable {
                 ResourceManagerMethod::Mint => self.fixed_high,
                 ResourceManagerMethod::GetResourceType => self.fixed_low,
                 ResourceManagerMethod::GetTotalSupply => self.fixed_low,
+                ResourceManagerMethod::GetMaxSupply => self.fixed_low,
                 ResourceManagerMethod::UpdateNonFungibleData => self.fixed_medium,
+                ResourceManagerMethod::UpdateNonFungibleDataField => self.fixed_medium,
                 ResourceManagerMethod::NonFungibleExists => self.fixed_low,
                 ResourceManagerMethod::GetNonFungible => self.fixed_medium,
                 ResourceManagerMethod::Burn => self.fixed_medium,
@@ -267,8 +359,12 @@ impl FeeTable {
                     VaultMethod::CreateProofByAmount => self.fixed_high,
                     VaultMethod::CreateProofByIds => self.fixed_high,
                     VaultMethod::LockFee => self.fixed_medium,
+                    VaultMethod::LockSponsoredFee => self.fixed_medium,
                     VaultMethod::Recall => self.fixed_low,
                     VaultMethod::RecallNonFungibles => self.fixed_low,
+                    VaultMethod::BurnNonFungibles => self.fixed_medium,
+                    VaultMethod::Freeze => self.fixed_low,
+                    VaultMethod::Unfreeze => self.fixed_low,
                 }
             }
         }
@@ -308,6 +404,9 @@ impl FeeTable {
             SystemApiCostingEntry::ReadSubstate { .. } => self.fixed_medium,
             SystemApiCostingEntry::WriteSubstate { .. } => self.fixed_medium,
             SystemApiCostingEntry::DropLock => self.fixed_low,
+            SystemApiCostingEntry::ListKeyValueStoreKeys { limit } => {
+                self.fixed_low + self.fixed_low * limit
+            }
 
             SystemApiCostingEntry::ReadEpoch => self.fixed_low,
             SystemApiCostingEntry::ReadTransactionHash => self.fixed_low,
@@ -1,7 +1,9 @@
+mod cost_breakdown;
 mod fee_reserve;
 mod fee_summary;
 mod fee_table;
 
+pub use cost_breakdown::*;
 pub use fee_reserve::*;
 pub use fee_summary::*;
 pub use fee_table::*;
@@ -1,14 +1,19 @@
-use crate::engine::ScryptoInterpreter;
+use crate::engine::{RejectionError, RuntimeError, ScryptoInterpreter};
+use crate::fee::default_fee_config;
 use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
 use crate::transaction::{
-    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt,
+    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionOutcome, TransactionReceipt,
+    TransactionResult,
 };
 use crate::types::*;
 use crate::wasm::{DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig};
 use radix_engine_interface::api::types::{
-    EpochManagerFunction, GlobalAddress, NativeFunctionIdent, RENodeId, ResourceManagerFunction,
-    ResourceManagerOffset, ScryptoFunctionIdent, ScryptoPackage, SubstateId, SubstateOffset,
+    ClockMethod, EpochManagerFunction, EpochManagerMethod, GlobalAddress, NativeFunctionIdent,
+    NativeMethodIdent, RENodeId, ResourceManagerFunction, ResourceManagerOffset,
+    ScryptoFunctionIdent, ScryptoMethodIdent, ScryptoPackage, ScryptoReceiver, SubstateId,
+    SubstateOffset,
 };
+use radix_engine_interface::core::Expression;
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::data::*;
 use radix_engine_interface::model::*;
@@ -21,10 +26,11 @@ const XRD_NAME: &str = "Radix";
 const XRD_DESCRIPTION: &str = "The Radix Public Network's native token, used to pay the network's required transaction fees and to secure the network through staking to its validator nodes.";
 const XRD_URL: &str = "https://tokens.radixdlt.com";
 const XRD_MAX_SUPPLY: i128 = 1_000_000_000_000i128;
+const MAX_VALIDATORS: u32 = 100;
+const UNBONDING_DELAY_IN_EPOCHS: u64 = 2;
 
 pub struct GenesisReceipt {
     pub faucet_package: PackageAddress,
-    pub account_package: PackageAddress,
     pub ecdsa_secp256k1_token: ResourceAddress,
     pub system_token: ResourceAddress,
     pub xrd_token: ResourceAddress,
@@ -32,211 +38,297 @@ pub struct GenesisReceipt {
     pub epoch_manager: SystemAddress,
     pub clock: SystemAddress,
     pub eddsa_ed25519_token: ResourceAddress,
+    pub extra_packages: Vec<PackageAddress>,
 }
 
-pub fn create_genesis() -> SystemTransaction {
-    let mut blobs = Vec::new();
-    let mut id_allocator = IdAllocator::new(IdSpace::Transaction);
-    let create_faucet_package = {
-        let faucet_code = include_bytes!("../../../assets/faucet.wasm").to_vec();
-        let faucet_abi = include_bytes!("../../../assets/faucet.abi").to_vec();
-        let inst = Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: PACKAGE_BLUEPRINT.to_string(),
-                function_name: PackageFunction::Publish.to_string(),
-            },
-            args: scrypto_encode(&PackagePublishInvocation {
-                code: Blob(hash(&faucet_code)),
-                abi: Blob(hash(&faucet_abi)),
-                royalty_config: HashMap::new(),
-                metadata: HashMap::new(),
-                access_rules: AccessRules::new().default(AccessRule::DenyAll, AccessRule::DenyAll),
-            })
-            .unwrap(),
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenesisError {
+    /// An XRD allocation or validator stake below zero
+    NegativeXrdAmount(Decimal),
+    /// The XRD allocations and validator stakes add up to more than the XRD max supply
+    XrdMaxSupplyExceeded,
+    /// The genesis transaction was rejected, e.g. because it ran out of its system loan
+    TransactionRejected(Box<RejectionError>),
+    /// The genesis transaction failed, e.g. because the code of a package is not valid WASM
+    TransactionFailed(Box<RuntimeError>),
+}
+
+/// Builds the genesis transaction of a ledger.
+///
+/// The well-known system entities are always created first and in the same order, so their
+/// addresses match the constants regardless of the configuration.
+#[derive(Debug, Clone, Default)]
+pub struct GenesisBuilder {
+    xrd_metadata: HashMap<String, String>,
+    xrd_allocations: Vec<(ComponentAddress, Decimal)>,
+    packages: Vec<(Vec<u8>, Vec<u8>)>,
+    initial_epoch: Option<u64>,
+    initial_time_ms: Option<i64>,
+    validators: Vec<(EcdsaSecp256k1PublicKey, Decimal)>,
+    fee_config: Option<FeeConfig>,
+}
+
+impl GenesisBuilder {
+    pub fn new() -> Self {
+        let mut xrd_metadata = HashMap::new();
+        xrd_metadata.insert("symbol".to_owned(), XRD_SYMBOL.to_owned());
+        xrd_metadata.insert("name".to_owned(), XRD_NAME.to_owned());
+        xrd_metadata.insert("description".to_owned(), XRD_DESCRIPTION.to_owned());
+        xrd_metadata.insert("url".to_owned(), XRD_URL.to_owned());
+
+        Self {
+            xrd_metadata,
+            ..Default::default()
+        }
+    }
+
+    /// Sets an XRD metadata entry, overriding the default one if any.
+    pub fn xrd_metadata(&mut self, name: &str, value: &str) -> &mut Self {
+        self.xrd_metadata.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Deposits XRD into an account. The faucet receives whatever is not allocated.
+    pub fn allocate_xrd(&mut self, account: ComponentAddress, amount: Decimal) -> &mut Self {
+        self.xrd_allocations.push((account, amount));
+        self
+    }
+
+    /// Deposits XRD into the virtual account of a public key.
+    pub fn allocate_xrd_to_public_key<P: Into<PublicKey> + Clone>(
+        &mut self,
+        public_key: &P,
+        amount: Decimal,
+    ) -> &mut Self {
+        self.allocate_xrd(
+            ComponentAddress::virtual_account_from_public_key(public_key),
+            amount,
+        )
+    }
+
+    /// Publishes a package, given its code and encoded ABI.
+    pub fn package(&mut self, code: Vec<u8>, abi: Vec<u8>) -> &mut Self {
+        self.packages.push((code, abi));
+        self
+    }
+
+    pub fn initial_epoch(&mut self, epoch: u64) -> &mut Self {
+        self.initial_epoch = Some(epoch);
+        self
+    }
+
+    pub fn initial_time_ms(&mut self, time_ms: i64) -> &mut Self {
+        self.initial_time_ms = Some(time_ms);
+        self
+    }
+
+    /// Registers a validator and stakes XRD to it, depositing the stake units into the virtual
+    /// account of its key. The validator set is activated with an epoch change at the end of
+    /// genesis.
+    pub fn validator(&mut self, key: EcdsaSecp256k1PublicKey, stake: Decimal) -> &mut Self {
+        self.validators.push((key, stake));
+        self
+    }
+
+    /// Sets the initial fee configuration, instead of the default cost unit price and fee table.
+    pub fn fee_config(&mut self, fee_config: FeeConfig) -> &mut Self {
+        self.fee_config = Some(fee_config);
+        self
+    }
+
+    /// Builds the genesis transaction, failing if the XRD allocations and validator stakes don't
+    /// fit within the XRD max supply.
+    pub fn build(&self) -> Result<SystemTransaction, GenesisError> {
+        let allocated = self.allocated_xrd()?;
+
+        let mut blobs = Vec::new();
+        let mut id_allocator = IdAllocator::new(IdSpace::Transaction);
+        let create_faucet_package = {
+            let faucet_code = include_bytes!("../../../assets/faucet.wasm").to_vec();
+            let faucet_abi = include_bytes!("../../../assets/faucet.abi").to_vec();
+            let inst = Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+                    function_name: PackageFunction::Publish.to_string(),
+                },
+                args: scrypto_encode(&PackagePublishInvocation {
+                    code: Blob(hash(&faucet_code)),
+                    abi: Blob(hash(&faucet_abi)),
+                    royalty_config: HashMap::new(),
+                    metadata: HashMap::new(),
+                    access_rules: AccessRules::new()
+                        .default(AccessRule::DenyAll, AccessRule::DenyAll),
+                })
+                .unwrap(),
+            };
+
+            blobs.push(faucet_code);
+            blobs.push(faucet_abi);
+
+            inst
+        };
+        let create_ecdsa_secp256k1_token = {
+            let metadata: HashMap<String, String> = HashMap::new();
+            let mut access_rules = HashMap::new();
+            access_rules.insert(
+                ResourceMethodAuthKey::Withdraw,
+                (rule!(allow_all), rule!(deny_all)),
+            );
+            let initial_supply: Option<MintParams> = None;
+
+            // TODO: Create token at a specific address
+            Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                    function_name: ResourceManagerFunction::Create.to_string(),
+                },
+                args: scrypto_encode(&ResourceManagerCreateInvocation {
+                    resource_type: ResourceType::NonFungible {
+                        id_type: NonFungibleIdType::Bytes,
+                    },
+                    metadata,
+                    access_rules,
+                    mint_params: initial_supply,
+                    max_supply: None,
+                    non_fungible_data_schema: None,
+                })
+                .unwrap(),
+            }
         };
 
-        blobs.push(faucet_code);
-        blobs.push(faucet_abi);
-
-        inst
-    };
-    let create_account_package = {
-        let account_code = include_bytes!("../../../assets/account.wasm").to_vec();
-        let account_abi = include_bytes!("../../../assets/account.abi").to_vec();
-        let inst = Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: PACKAGE_BLUEPRINT.to_string(),
-                function_name: PackageFunction::Publish.to_string(),
-            },
-            args: scrypto_encode(&PackagePublishInvocation {
-                code: Blob(hash(&account_code)),
-                abi: Blob(hash(&account_abi)),
-                royalty_config: HashMap::new(),
-                metadata: HashMap::new(),
-                access_rules: AccessRules::new().default(AccessRule::DenyAll, AccessRule::DenyAll),
-            })
-            .unwrap(),
+        // TODO: Perhaps combine with ecdsa token?
+        let create_system_token = {
+            let metadata: HashMap<String, String> = HashMap::new();
+            let mut access_rules = HashMap::new();
+            access_rules.insert(
+                ResourceMethodAuthKey::Withdraw,
+                (rule!(allow_all), rule!(deny_all)),
+            );
+            let initial_supply: Option<MintParams> = None;
+
+            // TODO: Create token at a specific address
+            Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                    function_name: ResourceManagerFunction::Create.to_string(),
+                },
+                args: scrypto_encode(&ResourceManagerCreateInvocation {
+                    resource_type: ResourceType::NonFungible {
+                        id_type: NonFungibleIdType::Bytes,
+                    },
+                    metadata,
+                    access_rules,
+                    mint_params: initial_supply,
+                    max_supply: None,
+                    non_fungible_data_schema: None,
+                })
+                .unwrap(),
+            }
         };
 
-        blobs.push(account_code);
-        blobs.push(account_abi);
-
-        inst
-    };
-    let create_ecdsa_secp256k1_token = {
-        let metadata: HashMap<String, String> = HashMap::new();
-        let mut access_rules = HashMap::new();
-        access_rules.insert(
-            ResourceMethodAuthKey::Withdraw,
-            (rule!(allow_all), rule!(deny_all)),
-        );
-        let initial_supply: Option<MintParams> = None;
-
-        // TODO: Create token at a specific address
-        Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
-                function_name: ResourceManagerFunction::Create.to_string(),
-            },
-            args: scrypto_encode(&ResourceManagerCreateInvocation {
-                resource_type: ResourceType::NonFungible {
-                    id_type: NonFungibleIdType::Bytes,
+        let create_xrd_token = {
+            let metadata = self.xrd_metadata.clone();
+
+            let mut access_rules = HashMap::new();
+            access_rules.insert(
+                ResourceMethodAuthKey::Withdraw,
+                (rule!(allow_all), rule!(deny_all)),
+            );
+
+            let initial_supply: Option<MintParams> = Option::Some(MintParams::Fungible {
+                amount: XRD_MAX_SUPPLY.into(),
+            });
+
+            Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                    function_name: ResourceManagerFunction::Create.to_string(),
                 },
-                metadata,
-                access_rules,
-                mint_params: initial_supply,
-            })
-            .unwrap(),
-        }
-    };
-
-    // TODO: Perhaps combine with ecdsa token?
-    let create_system_token = {
-        let metadata: HashMap<String, String> = HashMap::new();
-        let mut access_rules = HashMap::new();
-        access_rules.insert(
-            ResourceMethodAuthKey::Withdraw,
-            (rule!(allow_all), rule!(deny_all)),
-        );
-        let initial_supply: Option<MintParams> = None;
-
-        // TODO: Create token at a specific address
-        Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
-                function_name: ResourceManagerFunction::Create.to_string(),
-            },
-            args: scrypto_encode(&ResourceManagerCreateInvocation {
-                resource_type: ResourceType::NonFungible {
-                    id_type: NonFungibleIdType::Bytes,
+                args: scrypto_encode(&ResourceManagerCreateInvocation {
+                    resource_type: ResourceType::Fungible { divisibility: 18 },
+                    metadata,
+                    access_rules,
+                    mint_params: initial_supply,
+                    max_supply: None,
+                    non_fungible_data_schema: None,
+                })
+                .unwrap(),
+            }
+        };
+
+        let take_xrd = Instruction::TakeFromWorktopByAmount {
+            amount: Decimal::from(XRD_MAX_SUPPLY) - allocated,
+            resource_address: RADIX_TOKEN,
+        };
+
+        let create_xrd_faucet = {
+            let bucket = Bucket(id_allocator.new_bucket_id().unwrap());
+            Instruction::CallFunction {
+                function_ident: ScryptoFunctionIdent {
+                    package: ScryptoPackage::Global(FAUCET_PACKAGE),
+                    blueprint_name: FAUCET_BLUEPRINT.to_string(),
+                    function_name: "new".to_string(),
                 },
-                metadata,
-                access_rules,
-                mint_params: initial_supply,
-            })
-            .unwrap(),
-        }
-    };
-
-    let create_xrd_token = {
-        let mut metadata = HashMap::new();
-        metadata.insert("symbol".to_owned(), XRD_SYMBOL.to_owned());
-        metadata.insert("name".to_owned(), XRD_NAME.to_owned());
-        metadata.insert("description".to_owned(), XRD_DESCRIPTION.to_owned());
-        metadata.insert("url".to_owned(), XRD_URL.to_owned());
-
-        let mut access_rules = HashMap::new();
-        access_rules.insert(
-            ResourceMethodAuthKey::Withdraw,
-            (rule!(allow_all), rule!(deny_all)),
-        );
+                args: args!(bucket),
+            }
+        };
 
-        let initial_supply: Option<MintParams> = Option::Some(MintParams::Fungible {
-            amount: XRD_MAX_SUPPLY.into(),
-        });
-
-        Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
-                function_name: ResourceManagerFunction::Create.to_string(),
-            },
-            args: scrypto_encode(&ResourceManagerCreateInvocation {
-                resource_type: ResourceType::Fungible { divisibility: 18 },
-                metadata,
-                access_rules,
-                mint_params: initial_supply,
-            })
-            .unwrap(),
-        }
-    };
-
-    let take_xrd = Instruction::TakeFromWorktop {
-        resource_address: RADIX_TOKEN,
-    };
-
-    let create_xrd_faucet = {
-        let bucket = Bucket(id_allocator.new_bucket_id().unwrap());
-        Instruction::CallFunction {
-            function_ident: ScryptoFunctionIdent {
-                package: ScryptoPackage::Global(FAUCET_PACKAGE),
-                blueprint_name: FAUCET_BLUEPRINT.to_string(),
-                function_name: "new".to_string(),
-            },
-            args: args!(bucket),
-        }
-    };
-
-    let create_epoch_manager = {
-        Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: EPOCH_MANAGER_BLUEPRINT.to_string(),
-                function_name: EpochManagerFunction::Create.to_string(),
-            },
-            args: args!(),
-        }
-    };
-
-    let create_clock = {
-        Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: CLOCK_BLUEPRINT.to_string(),
-                function_name: ClockFunction::Create.to_string(),
-            },
-            args: args!(),
-        }
-    };
-
-    let create_eddsa_ed25519_token = {
-        let metadata: HashMap<String, String> = HashMap::new();
-        let mut access_rules = HashMap::new();
-        access_rules.insert(
-            ResourceMethodAuthKey::Withdraw,
-            (rule!(allow_all), rule!(deny_all)),
-        );
-        let initial_supply: Option<MintParams> = None;
-
-        // TODO: Create token at a specific address
-        Instruction::CallNativeFunction {
-            function_ident: NativeFunctionIdent {
-                blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
-                function_name: ResourceManagerFunction::Create.to_string(),
-            },
-            args: scrypto_encode(&ResourceManagerCreateInvocation {
-                resource_type: ResourceType::NonFungible {
-                    id_type: NonFungibleIdType::Bytes,
+        let create_epoch_manager = {
+            Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: EPOCH_MANAGER_BLUEPRINT.to_string(),
+                    function_name: EpochManagerFunction::Create.to_string(),
                 },
-                metadata,
-                access_rules,
-                mint_params: initial_supply,
-            })
-            .unwrap(),
-        }
-    };
+                args: scrypto_encode(&EpochManagerCreateInvocation {
+                    max_validators: MAX_VALIDATORS,
+                    unbonding_delay_in_epochs: UNBONDING_DELAY_IN_EPOCHS,
+                    fee_config: self.fee_config.clone().unwrap_or_else(default_fee_config),
+                })
+                .unwrap(),
+            }
+        };
 
-    let manifest = TransactionManifest {
-        instructions: vec![
+        let create_clock = {
+            Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: CLOCK_BLUEPRINT.to_string(),
+                    function_name: ClockFunction::Create.to_string(),
+                },
+                args: args!(),
+            }
+        };
+
+        let create_eddsa_ed25519_token = {
+            let metadata: HashMap<String, String> = HashMap::new();
+            let mut access_rules = HashMap::new();
+            access_rules.insert(
+                ResourceMethodAuthKey::Withdraw,
+                (rule!(allow_all), rule!(deny_all)),
+            );
+            let initial_supply: Option<MintParams> = None;
+
+            // TODO: Create token at a specific address
+            Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                    function_name: ResourceManagerFunction::Create.to_string(),
+                },
+                args: scrypto_encode(&ResourceManagerCreateInvocation {
+                    resource_type: ResourceType::NonFungible {
+                        id_type: NonFungibleIdType::Bytes,
+                    },
+                    metadata,
+                    access_rules,
+                    mint_params: initial_supply,
+                    max_supply: None,
+                    non_fungible_data_schema: None,
+                })
+                .unwrap(),
+            }
+        };
+
+        let mut instructions = vec![
             create_faucet_package,
-            create_account_package,
             create_ecdsa_secp256k1_token,
             create_system_token,
             create_xrd_token,
@@ -245,31 +337,186 @@ pub fn create_genesis() -> SystemTransaction {
             create_epoch_manager,
             create_clock,
             create_eddsa_ed25519_token,
-        ],
-        blobs,
-    };
+        ];
+
+        for (code, abi) in &self.packages {
+            instructions.push(Instruction::CallNativeFunction {
+                function_ident: NativeFunctionIdent {
+                    blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+                    function_name: PackageFunction::Publish.to_string(),
+                },
+                args: scrypto_encode(&PackagePublishInvocation {
+                    code: Blob(hash(code)),
+                    abi: Blob(hash(abi)),
+                    royalty_config: HashMap::new(),
+                    metadata: HashMap::new(),
+                    access_rules: AccessRules::new()
+                        .default(AccessRule::DenyAll, AccessRule::DenyAll),
+                })
+                .unwrap(),
+            });
+            blobs.push(code.clone());
+            blobs.push(abi.clone());
+        }
 
-    SystemTransaction { manifest }
+        for (account, amount) in &self.xrd_allocations {
+            instructions.push(Instruction::TakeFromWorktopByAmount {
+                amount: *amount,
+                resource_address: RADIX_TOKEN,
+            });
+            let bucket = Bucket(id_allocator.new_bucket_id().unwrap());
+            instructions.push(Instruction::CallMethod {
+                method_ident: ScryptoMethodIdent {
+                    receiver: ScryptoReceiver::Global(*account),
+                    method_name: "deposit".to_string(),
+                },
+                args: args!(bucket),
+            });
+        }
+
+        // All stakes are taken upfront, so that each validator's stake units are the only
+        // resource on the worktop when they are deposited.
+        let mut stakes = Vec::new();
+        for (key, stake) in &self.validators {
+            instructions.push(Instruction::TakeFromWorktopByAmount {
+                amount: *stake,
+                resource_address: RADIX_TOKEN,
+            });
+            stakes.push((*key, Bucket(id_allocator.new_bucket_id().unwrap())));
+        }
+        for (key, stake) in stakes {
+            instructions.push(Instruction::CallNativeMethod {
+                method_ident: NativeMethodIdent {
+                    receiver: RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                    method_name: EpochManagerMethod::RegisterValidator.to_string(),
+                },
+                args: scrypto_encode(&EpochManagerRegisterValidatorInvocation {
+                    receiver: EPOCH_MANAGER,
+                    key,
+                })
+                .unwrap(),
+            });
+            instructions.push(Instruction::CallNativeMethod {
+                method_ident: NativeMethodIdent {
+                    receiver: RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                    method_name: EpochManagerMethod::Stake.to_string(),
+                },
+                args: scrypto_encode(&EpochManagerStakeInvocation {
+                    receiver: EPOCH_MANAGER,
+                    key,
+                    stake,
+                })
+                .unwrap(),
+            });
+            instructions.push(Instruction::CallMethod {
+                method_ident: ScryptoMethodIdent {
+                    receiver: ScryptoReceiver::Global(
+                        ComponentAddress::virtual_account_from_public_key(&key),
+                    ),
+                    method_name: "deposit_batch".to_string(),
+                },
+                args: args!(Expression::entire_worktop()),
+            });
+        }
+        if !self.validators.is_empty() {
+            instructions.push(Instruction::CallNativeMethod {
+                method_ident: NativeMethodIdent {
+                    receiver: RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                    method_name: EpochManagerMethod::NextEpoch.to_string(),
+                },
+                args: scrypto_encode(&EpochManagerNextEpochInvocation {
+                    receiver: EPOCH_MANAGER,
+                })
+                .unwrap(),
+            });
+        }
+
+        if let Some(epoch) = self.initial_epoch {
+            instructions.push(Instruction::CallNativeMethod {
+                method_ident: NativeMethodIdent {
+                    receiver: RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                    method_name: EpochManagerMethod::SetEpoch.to_string(),
+                },
+                args: scrypto_encode(&EpochManagerSetEpochInvocation {
+                    receiver: EPOCH_MANAGER,
+                    epoch,
+                })
+                .unwrap(),
+            });
+        }
+        if let Some(current_time_ms) = self.initial_time_ms {
+            instructions.push(Instruction::CallNativeMethod {
+                method_ident: NativeMethodIdent {
+                    receiver: RENodeId::Global(GlobalAddress::System(CLOCK)),
+                    method_name: ClockMethod::SetCurrentTime.to_string(),
+                },
+                args: scrypto_encode(&ClockSetCurrentTimeInvocation {
+                    receiver: CLOCK,
+                    current_time_ms,
+                })
+                .unwrap(),
+            });
+        }
+
+        let manifest = TransactionManifest {
+            instructions,
+            blobs,
+        };
+
+        Ok(SystemTransaction { manifest })
+    }
+
+    /// The XRD deposited into accounts and staked to validators, the rest going to the faucet.
+    fn allocated_xrd(&self) -> Result<Decimal, GenesisError> {
+        let max_supply = Decimal::from(XRD_MAX_SUPPLY);
+        let mut allocated = Decimal::zero();
+        for amount in self
+            .xrd_allocations
+            .iter()
+            .map(|(_, amount)| *amount)
+            .chain(self.validators.iter().map(|(_, stake)| *stake))
+        {
+            if amount.is_negative() {
+                return Err(GenesisError::NegativeXrdAmount(amount));
+            }
+            // Compared against the remaining supply so that the sum can't overflow
+            if amount > max_supply - allocated {
+                return Err(GenesisError::XrdMaxSupplyExceeded);
+            }
+            allocated += amount;
+        }
+        Ok(allocated)
+    }
+
+    pub fn genesis_result(&self, invoke_result: &Vec<Vec<u8>>) -> GenesisReceipt {
+        let extra_packages = invoke_result[9..9 + self.packages.len()]
+            .iter()
+            .map(|output| scrypto_decode(output).unwrap())
+            .collect();
+
+        GenesisReceipt {
+            extra_packages,
+            ..genesis_result(invoke_result)
+        }
+    }
 }
 
 pub fn genesis_result(invoke_result: &Vec<Vec<u8>>) -> GenesisReceipt {
     let faucet_package: PackageAddress = scrypto_decode(&invoke_result[0]).unwrap();
-    let account_package: PackageAddress = scrypto_decode(&invoke_result[1]).unwrap();
     let (ecdsa_secp256k1_token, _bucket): (ResourceAddress, Option<Bucket>) =
-        scrypto_decode(&invoke_result[2]).unwrap();
+        scrypto_decode(&invoke_result[1]).unwrap();
     let (system_token, _bucket): (ResourceAddress, Option<Bucket>) =
-        scrypto_decode(&invoke_result[3]).unwrap();
+        scrypto_decode(&invoke_result[2]).unwrap();
     let (xrd_token, _bucket): (ResourceAddress, Option<Bucket>) =
-        scrypto_decode(&invoke_result[4]).unwrap();
-    let faucet_component: ComponentAddress = scrypto_decode(&invoke_result[6]).unwrap();
-    let epoch_manager: SystemAddress = scrypto_decode(&invoke_result[7]).unwrap();
-    let clock: SystemAddress = scrypto_decode(&invoke_result[8]).unwrap();
+        scrypto_decode(&invoke_result[3]).unwrap();
+    let faucet_component: ComponentAddress = scrypto_decode(&invoke_result[5]).unwrap();
+    let epoch_manager: SystemAddress = scrypto_decode(&invoke_result[6]).unwrap();
+    let clock: SystemAddress = scrypto_decode(&invoke_result[7]).unwrap();
     let (eddsa_ed25519_token, _bucket): (ResourceAddress, Option<Bucket>) =
-        scrypto_decode(&invoke_result[9]).unwrap();
+        scrypto_decode(&invoke_result[8]).unwrap();
 
     GenesisReceipt {
         faucet_package,
-        account_package,
         ecdsa_secp256k1_token,
         system_token,
         xrd_token,
@@ -277,10 +524,25 @@ pub fn genesis_result(invoke_result: &Vec<Vec<u8>>) -> GenesisReceipt {
         epoch_manager,
         clock,
         eddsa_ed25519_token,
+        extra_packages: Vec::new(),
     }
 }
 
 pub fn bootstrap<S>(substate_store: &mut S) -> Option<TransactionReceipt>
+where
+    S: ReadableSubstateStore + WriteableSubstateStore,
+{
+    bootstrap_with_genesis(substate_store, &GenesisBuilder::new())
+        .expect("The default genesis is valid")
+}
+
+/// Executes and commits the given genesis, unless the substate store is already bootstrapped.
+///
+/// Nothing is committed if the genesis transaction doesn't succeed.
+pub fn bootstrap_with_genesis<S>(
+    substate_store: &mut S,
+    genesis: &GenesisBuilder,
+) -> Result<Option<TransactionReceipt>, GenesisError>
 where
     S: ReadableSubstateStore + WriteableSubstateStore,
 {
@@ -300,7 +562,7 @@ where
             ),
         };
 
-        let genesis_transaction = create_genesis();
+        let genesis_transaction = genesis.build()?;
 
         let transaction_receipt = execute_transaction(
             substate_store,
@@ -310,13 +572,22 @@ where
             &genesis_transaction.get_executable(),
         );
 
-        let commit_result = transaction_receipt.expect_commit();
-        commit_result.outcome.expect_success();
+        let commit_result = match &transaction_receipt.result {
+            TransactionResult::Commit(commit_result) => commit_result,
+            TransactionResult::Reject(reject_result) => {
+                return Err(GenesisError::TransactionRejected(Box::new(
+                    reject_result.error.clone(),
+                )));
+            }
+        };
+        if let TransactionOutcome::Failure(error) = &commit_result.outcome {
+            return Err(GenesisError::TransactionFailed(Box::new(error.clone())));
+        }
         commit_result.state_updates.commit(substate_store);
 
-        Some(transaction_receipt)
+        Ok(Some(transaction_receipt))
     } else {
-        None
+        Ok(None)
     }
 }
 
@@ -338,7 +609,7 @@ mod tests {
             wasm_metering_config,
         };
         let substate_store = TypedInMemorySubstateStore::new();
-        let genesis_transaction = create_genesis();
+        let genesis_transaction = GenesisBuilder::new().build().unwrap();
 
         let transaction_receipt = execute_transaction(
             &substate_store,
@@ -353,7 +624,6 @@ mod tests {
         let genesis_receipt = genesis_result(&invoke_result);
 
         assert_eq!(genesis_receipt.faucet_package, FAUCET_PACKAGE);
-        assert_eq!(genesis_receipt.account_package, ACCOUNT_PACKAGE);
         assert_eq!(genesis_receipt.ecdsa_secp256k1_token, ECDSA_SECP256K1_TOKEN);
         assert_eq!(genesis_receipt.system_token, SYSTEM_TOKEN);
         assert_eq!(genesis_receipt.xrd_token, RADIX_TOKEN);
@@ -362,4 +632,54 @@ mod tests {
         assert_eq!(genesis_receipt.clock, CLOCK);
         assert_eq!(genesis_receipt.eddsa_ed25519_token, EDDSA_ED25519_TOKEN);
     }
+
+    #[test]
+    fn configured_genesis_receipt_should_match_constants() {
+        let wasm_engine = DefaultWasmEngine::default();
+        let wasm_instrumenter = WasmInstrumenter::default();
+        let wasm_metering_config =
+            WasmMeteringConfig::new(InstructionCostRules::tiered(1, 5, 10, 5000), 1024);
+        let scrypto_interpreter = ScryptoInterpreter {
+            wasm_engine,
+            wasm_instrumenter,
+            wasm_metering_config,
+        };
+        let substate_store = TypedInMemorySubstateStore::new();
+        let key = EcdsaSecp256k1PublicKey::from_str(
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        )
+        .unwrap();
+        let mut genesis = GenesisBuilder::new();
+        genesis
+            .xrd_metadata("name", "Test Radix")
+            .allocate_xrd_to_public_key(&key, 1000.into())
+            .package(
+                include_bytes!("../../../assets/faucet.wasm").to_vec(),
+                include_bytes!("../../../assets/faucet.abi").to_vec(),
+            )
+            .initial_epoch(10)
+            .initial_time_ms(60_000)
+            .validator(key, 100.into());
+        let genesis_transaction = genesis.build().unwrap();
+
+        let transaction_receipt = execute_transaction(
+            &substate_store,
+            &scrypto_interpreter,
+            &FeeReserveConfig::default(),
+            &ExecutionConfig::default(),
+            &genesis_transaction.get_executable(),
+        );
+
+        let commit_result = transaction_receipt.expect_commit();
+        let invoke_result = commit_result.outcome.expect_success();
+        let genesis_receipt = genesis.genesis_result(&invoke_result);
+
+        assert_eq!(genesis_receipt.faucet_package, FAUCET_PACKAGE);
+        assert_eq!(genesis_receipt.xrd_token, RADIX_TOKEN);
+        assert_eq!(genesis_receipt.faucet_component, FAUCET_COMPONENT);
+        assert_eq!(genesis_receipt.epoch_manager, EPOCH_MANAGER);
+        assert_eq!(genesis_receipt.clock, CLOCK);
+        assert_eq!(genesis_receipt.eddsa_ed25519_token, EDDSA_ED25519_TOKEN);
+        assert_eq!(genesis_receipt.extra_packages.len(), 1);
+    }
 }
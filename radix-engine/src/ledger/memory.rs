@@ -1,21 +1,63 @@
 use crate::ledger::*;
 use crate::ledger::{OutputValue, WriteableSubstateStore};
-use crate::model::PersistedSubstate;
+use crate::state_manager::{ReadableTreeStore, StateHashTree, TreeNode, TreeNodeKey};
+use crate::transaction::IntentHashTrackingSubstateStore;
 use crate::types::*;
-use radix_engine_interface::api::types::{
-    KeyValueStoreId, KeyValueStoreOffset, RENodeId, SubstateId, SubstateOffset,
-};
+use radix_engine_interface::api::types::SubstateId;
+use sbor::rust::mem;
+use transaction::validation::IntentHashStore;
 
 /// A substate store that stores all typed substates in host memory.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct TypedInMemorySubstateStore {
     substates: HashMap<SubstateId, OutputValue>,
+    /// The ids of the substates, ordered by their encoded keys for range scans.
+    substate_keys: BTreeMap<Vec<u8>, SubstateId>,
+    state_version: u64,
+    /// The values of each substate, keyed by the state version they were committed at.
+    /// Only kept if the store was created in history mode.
+    history: Option<HashMap<SubstateId, BTreeMap<u64, OutputValue>>>,
+    /// The `end_epoch_exclusive` of each committed intent hash.
+    intent_hashes: HashMap<Hash, u64>,
+    /// The committed intent hashes, keyed by their `end_epoch_exclusive`.
+    intent_hashes_by_end_epoch: BTreeMap<u64, BTreeSet<Hash>>,
+    /// The nodes of the `StateHashTree` over the substates.
+    tree_nodes: HashMap<TreeNodeKey, TreeNode>,
 }
 
+/// Stores are equal if they hold the same substates, regardless of how the commits which produced
+/// them were batched.
+impl PartialEq for TypedInMemorySubstateStore {
+    fn eq(&self, other: &Self) -> bool {
+        self.substates == other.substates
+    }
+}
+
+impl Eq for TypedInMemorySubstateStore {}
+
 impl TypedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
             substates: HashMap::new(),
+            substate_keys: BTreeMap::new(),
+            state_version: 0,
+            history: None,
+            intent_hashes: HashMap::new(),
+            intent_hashes_by_end_epoch: BTreeMap::new(),
+            tree_nodes: HashMap::new(),
+        }
+    }
+
+    /// Creates a store which keeps the full history of substate values.
+    pub fn with_history() -> Self {
+        Self {
+            substates: HashMap::new(),
+            substate_keys: BTreeMap::new(),
+            state_version: 0,
+            history: Some(HashMap::new()),
+            intent_hashes: HashMap::new(),
+            intent_hashes_by_end_epoch: BTreeMap::new(),
+            tree_nodes: HashMap::new(),
         }
     }
 
@@ -24,6 +66,24 @@ impl TypedInMemorySubstateStore {
         bootstrap(&mut substate_store);
         substate_store
     }
+
+    pub fn with_bootstrap_and_history() -> Self {
+        let mut substate_store = Self::with_history();
+        bootstrap(&mut substate_store);
+        substate_store
+    }
+
+    fn insert(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        if let Some(history) = &mut self.history {
+            history
+                .entry(substate_id.clone())
+                .or_default()
+                .insert(self.state_version, substate.clone());
+        }
+        self.substate_keys
+            .insert(encode_substate_key(&substate_id), substate_id.clone());
+        self.substates.insert(substate_id, substate);
+    }
 }
 
 impl Default for TypedInMemorySubstateStore {
@@ -36,36 +96,159 @@ impl ReadableSubstateStore for TypedInMemorySubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.substates.get(substate_id).cloned()
     }
+
+    fn as_iterable(&self) -> Option<&dyn IterableSubstateStore> {
+        Some(self)
+    }
 }
 
 impl WriteableSubstateStore for TypedInMemorySubstateStore {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
-        self.substates.insert(substate_id, substate);
+        self.commit_batch(vec![(substate_id, substate)]);
+    }
+
+    fn commit_batch(&mut self, substates: Vec<(SubstateId, OutputValue)>) {
+        let mut tree = StateHashTree::new(&self.tree_nodes);
+        tree.update_substates(&substates);
+        for (key, node) in tree.into_changes() {
+            match node {
+                Some(node) => self.tree_nodes.insert(key, node),
+                None => self.tree_nodes.remove(&key),
+            };
+        }
+
+        self.state_version += 1;
+        for (substate_id, substate) in substates {
+            self.insert(substate_id, substate);
+        }
+    }
+
+    fn state_root(&self) -> Option<Hash> {
+        Some(StateHashTree::new(&self.tree_nodes).root())
+    }
+}
+
+impl ReadableTreeStore for TypedInMemorySubstateStore {
+    fn get_tree_node(&self, key: &TreeNodeKey) -> Option<TreeNode> {
+        self.tree_nodes.get_tree_node(key)
     }
 }
 
-impl QueryableSubstateStore for TypedInMemorySubstateStore {
-    fn get_kv_store_entries(
+impl HistoricalSubstateStore for TypedInMemorySubstateStore {
+    fn get_state_version(&self) -> u64 {
+        self.state_version
+    }
+
+    fn keeps_history(&self) -> bool {
+        self.history.is_some()
+    }
+
+    fn get_substate_at(&self, substate_id: &SubstateId, state_version: u64) -> Option<OutputValue> {
+        self.history
+            .as_ref()?
+            .get(substate_id)
+            .and_then(|versions| versions.range(..=state_version).next_back())
+            .map(|(_, substate)| substate.clone())
+    }
+
+    fn list_substates_at(
         &self,
-        kv_store_id: &KeyValueStoreId,
-    ) -> HashMap<sbor::rust::vec::Vec<u8>, PersistedSubstate> {
-        self.substates
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+        state_version: u64,
+    ) -> SubstatePage {
+        let history = match &self.history {
+            Some(history) => history,
+            None => return SubstatePage::empty(),
+        };
+        let prefix = range.key_prefix();
+        let start = match cursor {
+            Some(cursor) => encode_substate_key(cursor).max(prefix.clone()),
+            None => prefix.clone(),
+        };
+        let substates = self
+            .substate_keys
+            .range(start..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .filter_map(|(_, substate_id)| {
+                let (_, substate) = history
+                    .get(substate_id)?
+                    .range(..=state_version)
+                    .next_back()?;
+                Some((substate_id.clone(), substate.clone()))
+            });
+
+        SubstatePage::from_sorted(substates, range, cursor, limit)
+    }
+}
+
+impl IntentHashStore for TypedInMemorySubstateStore {
+    fn get_intent_hash_end_epoch(&self, intent_hash: &Hash) -> Option<u64> {
+        self.intent_hashes.get(intent_hash).cloned()
+    }
+
+    fn put_intent_hash(&mut self, intent_hash: Hash, end_epoch_exclusive: u64) {
+        self.intent_hashes.insert(intent_hash, end_epoch_exclusive);
+        self.intent_hashes_by_end_epoch
+            .entry(end_epoch_exclusive)
+            .or_default()
+            .insert(intent_hash);
+    }
+
+    fn remove_expired_intent_hashes(&mut self, current_epoch: u64) {
+        let unexpired = self
+            .intent_hashes_by_end_epoch
+            .split_off(&(current_epoch + 1));
+        let expired = mem::replace(&mut self.intent_hashes_by_end_epoch, unexpired);
+        for intent_hash in expired.into_values().flatten() {
+            self.intent_hashes.remove(&intent_hash);
+        }
+    }
+
+    fn list_intent_hashes(&self) -> Vec<(Hash, u64)> {
+        self.intent_hashes_by_end_epoch
             .iter()
-            .filter_map(|(key, value)| {
-                if let SubstateId(
-                    RENodeId::KeyValueStore(id),
-                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
-                ) = key
-                {
-                    if id == kv_store_id {
-                        Some((key.clone(), value.substate.clone()))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+            .flat_map(|(end_epoch_exclusive, intent_hashes)| {
+                intent_hashes
+                    .iter()
+                    .map(|intent_hash| (*intent_hash, *end_epoch_exclusive))
             })
             .collect()
     }
 }
+
+impl IntentHashTrackingSubstateStore for TypedInMemorySubstateStore {
+    fn commit_batch_with_intent_hashes(
+        &mut self,
+        substates: Vec<(SubstateId, OutputValue)>,
+        intent_hashes: Vec<(Hash, u64)>,
+    ) {
+        self.commit_batch(substates);
+        for (intent_hash, end_epoch_exclusive) in intent_hashes {
+            self.put_intent_hash(intent_hash, end_epoch_exclusive);
+        }
+    }
+}
+
+impl IterableSubstateStore for TypedInMemorySubstateStore {
+    fn list_substates(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> SubstatePage {
+        let prefix = range.key_prefix();
+        let start = match cursor {
+            Some(cursor) => encode_substate_key(cursor).max(prefix.clone()),
+            None => prefix.clone(),
+        };
+        let substates = self
+            .substate_keys
+            .range(start..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, substate_id)| (substate_id.clone(), self.substates[substate_id].clone()));
+
+        SubstatePage::from_sorted(substates, range, cursor, limit)
+    }
+}
@@ -1,9 +1,13 @@
 mod bootstrap;
 mod memory;
 mod query;
+mod snapshot;
 mod traits;
 
-pub use bootstrap::{bootstrap, genesis_result, GenesisReceipt};
+pub use bootstrap::{
+    bootstrap, bootstrap_with_genesis, genesis_result, GenesisBuilder, GenesisError, GenesisReceipt,
+};
 pub use memory::TypedInMemorySubstateStore;
 pub use query::*;
+pub use snapshot::*;
 pub use traits::*;
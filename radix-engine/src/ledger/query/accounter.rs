@@ -45,13 +45,13 @@ impl Accounting {
     }
 
     pub fn add_vault(&mut self, vault: &VaultSubstate) {
-        match self.balances.entry(vault.0.resource_address()) {
+        match self.balances.entry(vault.resource.resource_address()) {
             Entry::Occupied(mut e) => {
-                let new_amount = vault.0.amount() + *e.get();
+                let new_amount = vault.resource.amount() + *e.get();
                 e.insert(new_amount);
             }
             Entry::Vacant(e) => {
-                e.insert(vault.0.amount());
+                e.insert(vault.resource.amount());
             }
         }
     }
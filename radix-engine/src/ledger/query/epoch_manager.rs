@@ -0,0 +1,43 @@
+use crate::ledger::ReadableSubstateStore;
+use crate::model::{
+    EpochManagerSubstate, FeeConfigSubstate, GlobalAddressSubstate, PersistedSubstate,
+};
+use radix_engine_interface::api::types::{
+    EpochManagerOffset, GlobalAddress, GlobalOffset, RENodeId, SubstateId, SubstateOffset,
+};
+use radix_engine_interface::constants::EPOCH_MANAGER;
+
+fn read_epoch_manager_substate<S: ReadableSubstateStore + ?Sized>(
+    substate_store: &S,
+    offset: EpochManagerOffset,
+) -> Option<PersistedSubstate> {
+    let global = substate_store.get_substate(&SubstateId(
+        RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+        SubstateOffset::Global(GlobalOffset::Global),
+    ))?;
+    let global: GlobalAddressSubstate = global.substate.to_runtime().into();
+
+    let output = substate_store.get_substate(&SubstateId(
+        global.node_deref(),
+        SubstateOffset::EpochManager(offset),
+    ))?;
+    Some(output.substate)
+}
+
+/// Reads the current epoch, if the ledger has been bootstrapped.
+pub fn read_epoch<S: ReadableSubstateStore + ?Sized>(substate_store: &S) -> Option<u64> {
+    match read_epoch_manager_substate(substate_store, EpochManagerOffset::EpochManager)? {
+        PersistedSubstate::EpochManager(EpochManagerSubstate { epoch }) => Some(epoch),
+        _ => None,
+    }
+}
+
+/// Reads the fee configuration held by the epoch manager, if the ledger has been bootstrapped.
+pub fn read_fee_config<S: ReadableSubstateStore + ?Sized>(
+    substate_store: &S,
+) -> Option<FeeConfigSubstate> {
+    match read_epoch_manager_substate(substate_store, EpochManagerOffset::FeeConfig)? {
+        PersistedSubstate::FeeConfig(fee_config) => Some(fee_config),
+        _ => None,
+    }
+}
@@ -1,7 +1,9 @@
 mod accounter;
+mod epoch_manager;
 mod traverse;
 mod vault_finder;
 
 pub use accounter::*;
+pub use epoch_manager::*;
 pub use traverse::*;
 pub use vault_finder::*;
@@ -0,0 +1,50 @@
+use crate::ledger::*;
+use radix_engine_interface::api::types::SubstateId;
+
+/// A read-only view of a historical substate store, as of a past state version.
+pub struct SubstateStoreSnapshot<'s, S: HistoricalSubstateStore> {
+    substate_store: &'s S,
+    state_version: u64,
+}
+
+impl<'s, S: HistoricalSubstateStore> SubstateStoreSnapshot<'s, S> {
+    /// Returns `None` if the store doesn't keep history or hasn't reached the given state
+    /// version yet.
+    pub fn new(substate_store: &'s S, state_version: u64) -> Option<Self> {
+        if !substate_store.keeps_history() || state_version > substate_store.get_state_version() {
+            return None;
+        }
+
+        Some(Self {
+            substate_store,
+            state_version,
+        })
+    }
+
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+}
+
+impl<'s, S: HistoricalSubstateStore> ReadableSubstateStore for SubstateStoreSnapshot<'s, S> {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.substate_store
+            .get_substate_at(substate_id, self.state_version)
+    }
+
+    fn as_iterable(&self) -> Option<&dyn IterableSubstateStore> {
+        Some(self)
+    }
+}
+
+impl<'s, S: HistoricalSubstateStore> IterableSubstateStore for SubstateStoreSnapshot<'s, S> {
+    fn list_substates(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> SubstatePage {
+        self.substate_store
+            .list_substates_at(range, cursor, limit, self.state_version)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::model::PersistedSubstate;
 use crate::types::*;
-use radix_engine_interface::api::types::{KeyValueStoreId, SubstateId};
+use radix_engine_interface::api::types::{KeyValueStoreId, RENodeId, SubstateId};
+use radix_engine_interface::data::SCRYPTO_SBOR_V1_PAYLOAD_PREFIX;
 
 pub trait QueryableSubstateStore {
     fn get_kv_store_entries(
@@ -9,6 +10,28 @@ pub trait QueryableSubstateStore {
     ) -> HashMap<Vec<u8>, PersistedSubstate>;
 }
 
+impl<S: IterableSubstateStore> QueryableSubstateStore for S {
+    fn get_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+    ) -> HashMap<Vec<u8>, PersistedSubstate> {
+        self.list_all_substates(&SubstateRange::Node(RENodeId::KeyValueStore(*kv_store_id)))
+            .into_iter()
+            .filter_map(|(substate_id, output_value)| {
+                if let SubstateId(
+                    _,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+                ) = substate_id
+                {
+                    Some((key, output_value.substate))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct OutputId {
@@ -26,12 +49,189 @@ pub struct OutputValue {
 
 pub trait ReadableSubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue>;
+
+    /// Returns this store as an `IterableSubstateStore`, if it supports range scans.
+    fn as_iterable(&self) -> Option<&dyn IterableSubstateStore> {
+        None
+    }
 }
 
 pub trait WriteableSubstateStore {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue);
+
+    /// Writes all the given substates as a single atomic unit, so that either all or none of
+    /// them end up in the store.
+    ///
+    /// The default implementation puts the substates one by one, which is only suitable for
+    /// stores whose writes can't fail part-way through (e.g. typed in-memory stores).
+    fn commit_batch(&mut self, substates: Vec<(SubstateId, OutputValue)>) {
+        for (substate_id, substate) in substates {
+            self.put_substate(substate_id, substate);
+        }
+    }
+
+    /// Returns the root of the `StateHashTree` which the store updates with every commit, if it
+    /// maintains one.
+    fn state_root(&self) -> Option<Hash> {
+        None
+    }
+}
+
+/// A substate store which can read substates as they were at a past state version.
+///
+/// The state version starts at 0 for an empty store and is incremented once by every commit,
+/// whether it's a `commit_batch` of many substates or a `put_substate` of a single one.
+pub trait HistoricalSubstateStore {
+    fn get_state_version(&self) -> u64;
+
+    /// Whether the store was created in history mode, which it needs to read past substates.
+    fn keeps_history(&self) -> bool;
+
+    /// Returns the latest value of the substate committed at or before the given state version.
+    /// Always returns `None` if the store doesn't keep history.
+    fn get_substate_at(&self, substate_id: &SubstateId, state_version: u64) -> Option<OutputValue>;
+
+    /// Returns up to `limit` substates within the range as they were at the given state version,
+    /// starting after `cursor` (exclusive). Always returns an empty page if the store doesn't
+    /// keep history.
+    fn list_substates_at(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+        state_version: u64,
+    ) -> SubstatePage;
 }
 
 pub trait SubstateStore: ReadableSubstateStore + WriteableSubstateStore {}
 
 impl<T: ReadableSubstateStore + WriteableSubstateStore> SubstateStore for T {}
+
+/// Selects a set of substates to be scanned by an `IterableSubstateStore`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubstateRange {
+    /// Every substate in the store.
+    All,
+    /// Every substate of the given RENode.
+    Node(RENodeId),
+    /// The substates of the given RENode whose offset is of the same kind (top-level
+    /// `SubstateOffset` variant) as the given one, e.g. all entries of a key value store.
+    NodeOffsets(RENodeId, SubstateOffset),
+}
+
+impl SubstateRange {
+    pub fn contains(&self, substate_id: &SubstateId) -> bool {
+        match self {
+            SubstateRange::All => true,
+            SubstateRange::Node(node_id) => substate_id.0.eq(node_id),
+            SubstateRange::NodeOffsets(node_id, offset) => {
+                substate_id.0.eq(node_id)
+                    && core::mem::discriminant(&substate_id.1) == core::mem::discriminant(offset)
+            }
+        }
+    }
+
+    /// Returns the prefix shared by the encoded keys of all substates in this range.
+    pub fn key_prefix(&self) -> Vec<u8> {
+        match self {
+            SubstateRange::All => vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX],
+            SubstateRange::Node(node_id) | SubstateRange::NodeOffsets(node_id, ..) => {
+                encode_node_key_prefix(node_id)
+            }
+        }
+    }
+}
+
+/// A single page of a substate range scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstatePage {
+    pub substates: Vec<(SubstateId, OutputValue)>,
+    /// The cursor to resume the scan from, or `None` if the range has been exhausted.
+    pub next_cursor: Option<SubstateId>,
+}
+
+impl SubstatePage {
+    pub fn empty() -> Self {
+        Self {
+            substates: Vec::new(),
+            next_cursor: None,
+        }
+    }
+
+    /// Builds a page out of the candidate substates of a scan, which must be sorted by their
+    /// encoded keys (see `encode_substate_key`).
+    pub fn from_sorted<I: Iterator<Item = (SubstateId, OutputValue)>>(
+        sorted: I,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> Self {
+        let cursor_key = cursor.map(encode_substate_key);
+        let mut matching = sorted
+            .filter(|(substate_id, _)| range.contains(substate_id))
+            .filter(|(substate_id, _)| match &cursor_key {
+                Some(cursor_key) => encode_substate_key(substate_id).gt(cursor_key),
+                None => true,
+            });
+
+        let substates: Vec<(SubstateId, OutputValue)> = matching.by_ref().take(limit).collect();
+        let next_cursor = if matching.next().is_some() {
+            substates.last().map(|(substate_id, _)| substate_id.clone())
+        } else {
+            None
+        };
+
+        Self {
+            substates,
+            next_cursor,
+        }
+    }
+}
+
+/// A substate store which supports ordered range scans.
+///
+/// Substates are visited in the order of their encoded keys (see `encode_substate_key`), which
+/// is the same for all backends, so a cursor obtained from one store can be used on any other.
+pub trait IterableSubstateStore {
+    /// Returns up to `limit` substates within the range, starting after `cursor` (exclusive).
+    fn list_substates(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> SubstatePage;
+
+    /// Returns all substates within the range, following cursors until it is exhausted.
+    fn list_all_substates(&self, range: &SubstateRange) -> Vec<(SubstateId, OutputValue)> {
+        let mut substates = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self.list_substates(range, cursor.as_ref(), 1000);
+            substates.extend(page.substates);
+            match page.next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+        substates
+    }
+}
+
+/// Encodes a substate id into the key used for ordering substates in range scans.
+pub fn encode_substate_key(substate_id: &SubstateId) -> Vec<u8> {
+    scrypto_encode(substate_id).expect("Could not encode substate id")
+}
+
+/// Returns the prefix shared by the encoded keys of all substates of the given RENode.
+pub fn encode_node_key_prefix(node_id: &RENodeId) -> Vec<u8> {
+    // The encoded substate id ends with the encoded offset (without the payload prefix),
+    // so stripping it off leaves the part which only depends on the node id.
+    let offset = SubstateOffset::Global(GlobalOffset::Global);
+    let offset_len = scrypto_encode(&offset)
+        .expect("Could not encode substate offset")
+        .len()
+        - 1;
+    let mut key = encode_substate_key(&SubstateId(*node_id, offset));
+    key.truncate(key.len() - offset_len);
+    key
+}
@@ -21,6 +21,18 @@ pub fn export_abi<S: ReadableSubstateStore>(
     package_address: PackageAddress,
     blueprint_name: &str,
 ) -> Result<abi::BlueprintAbi, ExportError> {
+    // The account is native, so its ABI isn't stored in a package
+    if package_address.eq(&ACCOUNT_PACKAGE) {
+        return if blueprint_name.eq(ACCOUNT_BLUEPRINT) {
+            Ok(Account::blueprint_abi())
+        } else {
+            Err(ExportError::BlueprintNotFound(
+                package_address,
+                blueprint_name.to_owned(),
+            ))
+        };
+    }
+
     let global_substate: RuntimeSubstate = substate_store
         .get_substate(&SubstateId(
             RENodeId::Global(GlobalAddress::Package(package_address)),
@@ -62,6 +74,9 @@ pub fn export_abi_by_component<S: ReadableSubstateStore>(
         .map(|s| s.substate.to_runtime())
         .ok_or(ExportError::ComponentNotFound(component_address))?;
     let component_id = global.global().node_deref();
+    if let RENodeId::Account(..) = component_id {
+        return Ok(Account::blueprint_abi());
+    }
 
     let component_value: RuntimeSubstate = substate_store
         .get_substate(&SubstateId(
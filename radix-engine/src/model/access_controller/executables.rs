@@ -0,0 +1,766 @@
+use crate::engine::{
+    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags,
+    NativeExecutor, NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod,
+    ResolverApi, RuntimeError, SystemApi,
+};
+use crate::model::{AccessControllerSubstate, AccessRulesChainSubstate, GlobalAddressSubstate};
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_interface::api::api::InvokableModel;
+use radix_engine_interface::api::types::{
+    AccessControllerFunction, AccessControllerMethod, AccessControllerOffset,
+    AccessRulesChainOffset, GlobalAddress, LockHandle, NativeFunction, NativeMethod, RENodeId,
+    SubstateOffset,
+};
+use radix_engine_interface::model::*;
+use radix_engine_interface::rule;
+use radix_engine_interface::time::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AccessControllerError {
+    /// The primary role was locked down by the recovery role
+    OperationRequiresUnlockedPrimaryRole,
+    RecoveryProposalAlreadyExists {
+        proposer: Role,
+    },
+    NoRecoveryProposalExists {
+        proposer: Role,
+    },
+    RecoveryProposalMismatch {
+        proposer: Role,
+    },
+    NoTimedRecoveryInProgress,
+    TimedRecoveryDelayHasNotElapsed,
+    TimedRecoveryDelayOverflow,
+}
+
+pub struct AccessController;
+
+impl AccessController {
+    pub fn access_rules(rule_set: &RuleSet) -> AccessRules {
+        let primary = &rule_set.primary_role;
+        let recovery = &rule_set.recovery_role;
+        let confirmation = &rule_set.confirmation_role;
+
+        let mut access_rules = AccessRules::new().default(rule!(deny_all), rule!(deny_all));
+        for (method, access_rule) in [
+            (AccessControllerMethod::CreateProof, primary.clone()),
+            (
+                AccessControllerMethod::InitiateRecoveryAsPrimary,
+                primary.clone(),
+            ),
+            (
+                AccessControllerMethod::CancelPrimaryRoleRecoveryProposal,
+                primary.clone(),
+            ),
+            (
+                AccessControllerMethod::InitiateRecoveryAsRecovery,
+                recovery.clone(),
+            ),
+            (
+                AccessControllerMethod::CancelRecoveryRoleRecoveryProposal,
+                recovery.clone(),
+            ),
+            (
+                AccessControllerMethod::TimedConfirmRecovery,
+                recovery.clone(),
+            ),
+            (AccessControllerMethod::LockPrimaryRole, recovery.clone()),
+            (AccessControllerMethod::UnlockPrimaryRole, recovery.clone()),
+            (
+                AccessControllerMethod::QuickConfirmPrimaryRoleRecoveryProposal,
+                any_of(&[recovery, confirmation]),
+            ),
+            (
+                AccessControllerMethod::QuickConfirmRecoveryRoleRecoveryProposal,
+                any_of(&[primary, confirmation]),
+            ),
+            (
+                AccessControllerMethod::StopTimedRecovery,
+                any_of(&[primary, recovery, confirmation]),
+            ),
+        ] {
+            access_rules.set_access_rule_and_mutability(
+                AccessRuleKey::Native(NativeFn::Method(NativeMethod::AccessController(method))),
+                access_rule,
+                rule!(deny_all),
+            );
+        }
+        access_rules
+    }
+
+    fn lock<Y>(node_id: RENodeId, flags: LockFlags, api: &mut Y) -> Result<LockHandle, RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        api.lock_substate(node_id, offset, flags)
+    }
+
+    fn current_time<Y>(api: &mut Y) -> Result<Instant, RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        api.invoke(ClockGetCurrentTimeInvocation {
+            receiver: CLOCK,
+            precision: TimePrecision::Minute,
+        })
+    }
+
+    /// Checks that the proposal matches the one currently made by the proposer.
+    /// Expects the access controller substate to be locked.
+    fn check_proposal<Y>(
+        handle: LockHandle,
+        proposer: Role,
+        proposal: &RecoveryProposal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let substate_ref = api.get_ref(handle)?;
+        match substate_ref.access_controller().recovery_proposal(proposer) {
+            Some(existing) if existing.as_ref() == proposal => Ok(()),
+            Some(..) => Err(error(AccessControllerError::RecoveryProposalMismatch {
+                proposer,
+            })),
+            None => Err(error(AccessControllerError::NoRecoveryProposalExists {
+                proposer,
+            })),
+        }
+    }
+
+    /// Replaces the rule set and timed recovery delay with the proposed ones and resets any
+    /// ongoing recovery. Expects the access controller substate to be locked mutably.
+    fn apply_recovery<Y>(
+        node_id: RENodeId,
+        handle: LockHandle,
+        proposal: RecoveryProposal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            let access_controller = substate_mut.access_controller();
+            access_controller.timed_recovery_delay_in_minutes =
+                proposal.timed_recovery_delay_in_minutes;
+            access_controller.is_primary_role_locked = false;
+            access_controller.primary_role_recovery_proposal = None;
+            access_controller.recovery_role_recovery_proposal = None;
+            access_controller.timed_recovery_allowed_after = None;
+        }
+
+        let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let rules_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(rules_handle)?;
+            substate_mut.access_rules_chain().access_rules_chain =
+                vec![AccessController::access_rules(&proposal.rule_set)];
+        }
+        api.drop_lock(rules_handle)?;
+
+        Ok(())
+    }
+}
+
+/// Combines the rules such that any one of them being satisfied satisfies the result.
+fn any_of(rules: &[&AccessRule]) -> AccessRule {
+    let mut nodes = Vec::new();
+    for rule in rules {
+        match rule {
+            AccessRule::AllowAll => return AccessRule::AllowAll,
+            AccessRule::DenyAll => {}
+            AccessRule::Protected(node) => nodes.push(node.clone()),
+        }
+    }
+
+    if nodes.is_empty() {
+        AccessRule::DenyAll
+    } else {
+        AccessRule::Protected(AccessRuleNode::AnyOf(nodes))
+    }
+}
+
+fn error(error: AccessControllerError) -> RuntimeError {
+    RuntimeError::ApplicationError(ApplicationError::AccessControllerError(error))
+}
+
+fn resolve_method<W: WasmEngine, D: ResolverApi<W>>(
+    receiver: ComponentAddress,
+    method: AccessControllerMethod,
+    mut call_frame_update: CallFrameUpdate,
+    deref: &mut D,
+) -> Result<(REActor, CallFrameUpdate, RENodeId), RuntimeError> {
+    let receiver = RENodeId::Global(GlobalAddress::Component(receiver));
+    let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+    let actor = REActor::Method(
+        ResolvedMethod::Native(NativeMethod::AccessController(method)),
+        resolved_receiver,
+    );
+
+    Ok((actor, call_frame_update, resolved_receiver.receiver))
+}
+
+/// The call frame update of methods which read the current time.
+fn clock_ref() -> CallFrameUpdate {
+    CallFrameUpdate::copy_ref(RENodeId::Global(GlobalAddress::System(CLOCK)))
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccessControllerCreateGlobalInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let actor = REActor::Function(ResolvedFunction::Native(NativeFunction::AccessController(
+            AccessControllerFunction::CreateGlobal,
+        )));
+        let call_frame_update =
+            CallFrameUpdate::move_node(RENodeId::Bucket(self.controlled_asset.0));
+        let executor = NativeExecutor(self);
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerCreateGlobalInvocation {
+    type Output = ComponentAddress;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(ComponentAddress, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let resource_address = api.invoke(BucketGetResourceAddressInvocation {
+            receiver: self.controlled_asset.0,
+        })?;
+        let vault = api.invoke(ResourceManagerCreateVaultInvocation {
+            receiver: resource_address,
+        })?;
+        api.invoke(VaultPutInvocation {
+            receiver: vault.0,
+            bucket: self.controlled_asset,
+        })?;
+
+        let underlying_node_id = api.allocate_node_id(RENodeType::AccessController)?;
+        api.create_node(
+            underlying_node_id,
+            RENode::AccessController(
+                AccessControllerSubstate::new(vault.0, self.timed_recovery_delay_in_minutes),
+                AccessRulesChainSubstate {
+                    access_rules_chain: vec![AccessController::access_rules(&self.rule_set)],
+                },
+            ),
+        )?;
+
+        let global_node_id = api.allocate_node_id(RENodeType::GlobalAccessController)?;
+        api.create_node(
+            global_node_id,
+            RENode::Global(GlobalAddressSubstate::AccessController(
+                underlying_node_id.into(),
+            )),
+        )?;
+
+        let component_address: ComponentAddress = global_node_id.into();
+        Ok((component_address, CallFrameUpdate::copy_ref(global_node_id)))
+    }
+}
+
+pub struct AccessControllerCreateProofExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccessControllerCreateProofInvocation {
+    type Exec = NativeExecutor<AccessControllerCreateProofExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::CreateProof,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerCreateProofExecutable(receiver));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerCreateProofExecutable {
+    type Output = Proof;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Proof, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let handle = AccessController::lock(self.0, LockFlags::read_only(), api)?;
+        let (is_primary_role_locked, vault_id) = {
+            let substate_ref = api.get_ref(handle)?;
+            let access_controller = substate_ref.access_controller();
+            (
+                access_controller.is_primary_role_locked,
+                access_controller.controlled_asset,
+            )
+        };
+        if is_primary_role_locked {
+            return Err(error(
+                AccessControllerError::OperationRequiresUnlockedPrimaryRole,
+            ));
+        }
+
+        let proof = api.invoke(VaultCreateProofInvocation { receiver: vault_id })?;
+        api.drop_lock(handle)?;
+
+        let proof_id = proof.0;
+        Ok((proof, CallFrameUpdate::move_node(RENodeId::Proof(proof_id))))
+    }
+}
+
+pub struct AccessControllerInitiateRecoveryExecutable(RENodeId, Role, RecoveryProposal);
+
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for AccessControllerInitiateRecoveryAsPrimaryInvocation
+{
+    type Exec = NativeExecutor<AccessControllerInitiateRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::InitiateRecoveryAsPrimary,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerInitiateRecoveryExecutable(
+            receiver,
+            Role::Primary,
+            self.proposal,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for AccessControllerInitiateRecoveryAsRecoveryInvocation
+{
+    type Exec = NativeExecutor<AccessControllerInitiateRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::InitiateRecoveryAsRecovery,
+            clock_ref(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerInitiateRecoveryExecutable(
+            receiver,
+            Role::Recovery,
+            self.proposal,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerInitiateRecoveryExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let proposer = self.1;
+        let handle = AccessController::lock(self.0, LockFlags::MUTABLE, api)?;
+        let timed_recovery_delay_in_minutes = {
+            let substate_ref = api.get_ref(handle)?;
+            let access_controller = substate_ref.access_controller();
+            if access_controller.recovery_proposal(proposer).is_some() {
+                return Err(error(
+                    AccessControllerError::RecoveryProposalAlreadyExists { proposer },
+                ));
+            }
+            access_controller.timed_recovery_delay_in_minutes
+        };
+
+        // Only recoveries proposed by the recovery role may be confirmed once the delay elapses
+        let timed_recovery_allowed_after = match (proposer, timed_recovery_delay_in_minutes) {
+            (Role::Recovery, Some(delay)) => Some(
+                AccessController::current_time(api)?
+                    .add_minutes(delay as i64)
+                    .ok_or(error(AccessControllerError::TimedRecoveryDelayOverflow))?,
+            ),
+            _ => None,
+        };
+
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            let access_controller = substate_mut.access_controller();
+            *access_controller.recovery_proposal_mut(proposer) = Some(Box::new(self.2));
+            if timed_recovery_allowed_after.is_some() {
+                access_controller.timed_recovery_allowed_after = timed_recovery_allowed_after;
+            }
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccessControllerQuickConfirmRecoveryExecutable(RENodeId, Role, RecoveryProposal);
+
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation
+{
+    type Exec = NativeExecutor<AccessControllerQuickConfirmRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::QuickConfirmPrimaryRoleRecoveryProposal,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerQuickConfirmRecoveryExecutable(
+            receiver,
+            Role::Primary,
+            self.proposal,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation
+{
+    type Exec = NativeExecutor<AccessControllerQuickConfirmRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::QuickConfirmRecoveryRoleRecoveryProposal,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerQuickConfirmRecoveryExecutable(
+            receiver,
+            Role::Recovery,
+            self.proposal,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerQuickConfirmRecoveryExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let handle = AccessController::lock(self.0, LockFlags::MUTABLE, api)?;
+        AccessController::check_proposal(handle, self.1, &self.2, api)?;
+        AccessController::apply_recovery(self.0, handle, self.2, api)?;
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccessControllerTimedConfirmRecoveryExecutable(RENodeId, RecoveryProposal);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccessControllerTimedConfirmRecoveryInvocation {
+    type Exec = NativeExecutor<AccessControllerTimedConfirmRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::TimedConfirmRecovery,
+            clock_ref(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerTimedConfirmRecoveryExecutable(
+            receiver,
+            self.proposal,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerTimedConfirmRecoveryExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let handle = AccessController::lock(self.0, LockFlags::MUTABLE, api)?;
+        AccessController::check_proposal(handle, Role::Recovery, &self.1, api)?;
+        let timed_recovery_allowed_after = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .access_controller()
+                .timed_recovery_allowed_after
+                .ok_or(error(AccessControllerError::NoTimedRecoveryInProgress))?
+        };
+
+        let current_time = AccessController::current_time(api)?;
+        if !current_time.compare(timed_recovery_allowed_after, TimeComparisonOperator::Gte) {
+            return Err(error(
+                AccessControllerError::TimedRecoveryDelayHasNotElapsed,
+            ));
+        }
+
+        AccessController::apply_recovery(self.0, handle, self.1, api)?;
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccessControllerCancelRecoveryExecutable(RENodeId, Role);
+
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for AccessControllerCancelPrimaryRoleRecoveryProposalInvocation
+{
+    type Exec = NativeExecutor<AccessControllerCancelRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::CancelPrimaryRoleRecoveryProposal,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerCancelRecoveryExecutable(
+            receiver,
+            Role::Primary,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for AccessControllerCancelRecoveryRoleRecoveryProposalInvocation
+{
+    type Exec = NativeExecutor<AccessControllerCancelRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::CancelRecoveryRoleRecoveryProposal,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerCancelRecoveryExecutable(
+            receiver,
+            Role::Recovery,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerCancelRecoveryExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let proposer = self.1;
+        let handle = AccessController::lock(self.0, LockFlags::MUTABLE, api)?;
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            let access_controller = substate_mut.access_controller();
+            if access_controller
+                .recovery_proposal_mut(proposer)
+                .take()
+                .is_none()
+            {
+                return Err(error(AccessControllerError::NoRecoveryProposalExists {
+                    proposer,
+                }));
+            }
+            if let Role::Recovery = proposer {
+                access_controller.timed_recovery_allowed_after = None;
+            }
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccessControllerSetPrimaryRoleLockedExecutable(RENodeId, bool);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccessControllerLockPrimaryRoleInvocation {
+    type Exec = NativeExecutor<AccessControllerSetPrimaryRoleLockedExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::LockPrimaryRole,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerSetPrimaryRoleLockedExecutable(
+            receiver, true,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccessControllerUnlockPrimaryRoleInvocation {
+    type Exec = NativeExecutor<AccessControllerSetPrimaryRoleLockedExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::UnlockPrimaryRole,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerSetPrimaryRoleLockedExecutable(
+            receiver, false,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerSetPrimaryRoleLockedExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let handle = AccessController::lock(self.0, LockFlags::MUTABLE, api)?;
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            substate_mut.access_controller().is_primary_role_locked = self.1;
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccessControllerStopTimedRecoveryExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccessControllerStopTimedRecoveryInvocation {
+    type Exec = NativeExecutor<AccessControllerStopTimedRecoveryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccessControllerMethod::StopTimedRecovery,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccessControllerStopTimedRecoveryExecutable(receiver));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccessControllerStopTimedRecoveryExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let handle = AccessController::lock(self.0, LockFlags::MUTABLE, api)?;
+        {
+            // The recovery role's proposal stays in place and may still be quick confirmed
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            let access_controller = substate_mut.access_controller();
+            if access_controller
+                .timed_recovery_allowed_after
+                .take()
+                .is_none()
+            {
+                return Err(error(AccessControllerError::NoTimedRecoveryInProgress));
+            }
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
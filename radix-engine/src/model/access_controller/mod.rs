@@ -0,0 +1,5 @@
+mod executables;
+mod substates;
+
+pub use executables::*;
+pub use substates::*;
@@ -0,0 +1,52 @@
+use crate::types::*;
+use radix_engine_interface::api::types::VaultId;
+use radix_engine_interface::time::Instant;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccessControllerSubstate {
+    /// The vault holding the badge that the access controller creates proofs of
+    pub controlled_asset: VaultId,
+
+    /// The delay after which a recovery proposed by the recovery role may be confirmed without
+    /// the confirmation role, if timed recovery is enabled
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+
+    /// Whether the primary role has been locked down by the recovery role
+    pub is_primary_role_locked: bool,
+
+    pub primary_role_recovery_proposal: Option<Box<RecoveryProposal>>,
+    pub recovery_role_recovery_proposal: Option<Box<RecoveryProposal>>,
+
+    /// When the recovery role's proposal may be confirmed through timed recovery
+    pub timed_recovery_allowed_after: Option<Instant>,
+}
+
+impl AccessControllerSubstate {
+    pub fn new(controlled_asset: VaultId, timed_recovery_delay_in_minutes: Option<u32>) -> Self {
+        Self {
+            controlled_asset,
+            timed_recovery_delay_in_minutes,
+            is_primary_role_locked: false,
+            primary_role_recovery_proposal: None,
+            recovery_role_recovery_proposal: None,
+            timed_recovery_allowed_after: None,
+        }
+    }
+
+    pub fn recovery_proposal(&self, proposer: Role) -> &Option<Box<RecoveryProposal>> {
+        match proposer {
+            Role::Primary => &self.primary_role_recovery_proposal,
+            Role::Recovery => &self.recovery_role_recovery_proposal,
+            Role::Confirmation => panic!("The confirmation role can't propose recoveries"),
+        }
+    }
+
+    pub fn recovery_proposal_mut(&mut self, proposer: Role) -> &mut Option<Box<RecoveryProposal>> {
+        match proposer {
+            Role::Primary => &mut self.primary_role_recovery_proposal,
+            Role::Recovery => &mut self.recovery_role_recovery_proposal,
+            Role::Confirmation => panic!("The confirmation role can't propose recoveries"),
+        }
+    }
+}
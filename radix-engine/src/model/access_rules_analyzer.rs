@@ -0,0 +1,413 @@
+use radix_engine_interface::data::{sbor_type_id, SchemaPath, ScryptoCustomTypeId};
+use radix_engine_interface::model::*;
+use sbor::SborTypeId;
+
+use crate::types::*;
+
+/// Where the rule of a method in one layer of an access rules chain comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessRuleSource {
+    Method,
+    Group(String),
+    /// The method belongs to a group which has no rule, so the default rule applies
+    MissingGroup(String),
+    Default,
+}
+
+/// The kind of value a dynamic rule expects its schema path to point to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicValueKind {
+    Resource,
+    ResourceOrNonFungible,
+    ResourceOrNonFungibleList,
+    Decimal,
+    Count,
+}
+
+impl DynamicValueKind {
+    fn matches(&self, ty: &Type) -> bool {
+        let is_resource = |ty: &Type| {
+            sbor_type_id(ty) == Some(SborTypeId::Custom(ScryptoCustomTypeId::ResourceAddress))
+        };
+        let is_resource_or_non_fungible = |ty: &Type| {
+            is_resource(ty)
+                || sbor_type_id(ty)
+                    == Some(SborTypeId::Custom(ScryptoCustomTypeId::NonFungibleAddress))
+        };
+
+        match self {
+            DynamicValueKind::Resource => is_resource(ty),
+            DynamicValueKind::ResourceOrNonFungible => is_resource_or_non_fungible(ty),
+            DynamicValueKind::ResourceOrNonFungibleList => match ty {
+                Type::Vec { element_type }
+                | Type::Array { element_type, .. }
+                | Type::TreeSet { element_type }
+                | Type::HashSet { element_type } => is_resource_or_non_fungible(element_type),
+                _ => false,
+            },
+            DynamicValueKind::Decimal => {
+                sbor_type_id(ty) == Some(SborTypeId::Custom(ScryptoCustomTypeId::Decimal))
+            }
+            DynamicValueKind::Count => sbor_type_id(ty) == Some(SborTypeId::U8),
+        }
+    }
+}
+
+/// A method whose access is analyzed. The functions and methods of all the blueprints of a
+/// package share its access rules by name, so they are qualified by their blueprint.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MethodKey {
+    pub blueprint_name: Option<String>,
+    pub rule_key: AccessRuleKey,
+}
+
+impl MethodKey {
+    pub fn blueprint(blueprint_name: &str, rule_key: AccessRuleKey) -> Self {
+        Self {
+            blueprint_name: Some(blueprint_name.to_string()),
+            rule_key,
+        }
+    }
+}
+
+impl From<AccessRuleKey> for MethodKey {
+    fn from(rule_key: AccessRuleKey) -> Self {
+        Self {
+            blueprint_name: None,
+            rule_key,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessRulesIssue {
+    /// The method belongs to a group which has no rule, so the default rule applies instead
+    MissingGroup {
+        layer: usize,
+        key: MethodKey,
+        group: String,
+    },
+    /// A rule exists for a method which the blueprint does not have
+    UnknownMethod { layer: usize, key: AccessRuleKey },
+    /// A dynamic rule points to a field which does not exist in the component state, so the
+    /// rule can never be satisfied
+    InvalidSchemaPath {
+        layer: usize,
+        key: MethodKey,
+        path: SchemaPath,
+    },
+    /// A dynamic rule points to a field of the component state which can't hold the value it
+    /// expects, so the rule can never be satisfied
+    SchemaPathTypeMismatch {
+        layer: usize,
+        key: MethodKey,
+        path: SchemaPath,
+        expected: DynamicValueKind,
+        actual: Type,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodAccess {
+    pub key: MethodKey,
+    /// The rule of every layer of the access rules chain, all of which must be satisfied
+    pub layers: Vec<(AccessRuleSource, AccessRule)>,
+}
+
+impl MethodAccess {
+    pub fn falls_back_to_default(&self) -> bool {
+        self.layers.iter().any(|(source, _)| {
+            matches!(
+                source,
+                AccessRuleSource::Default | AccessRuleSource::MissingGroup(..)
+            )
+        })
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.layers
+            .iter()
+            .all(|(_, rule)| matches!(rule, AccessRule::AllowAll))
+    }
+
+    pub fn is_unreachable(&self) -> bool {
+        self.layers
+            .iter()
+            .any(|(_, rule)| matches!(rule, AccessRule::DenyAll))
+    }
+
+    /// The badges mentioned by the rules of this method. Dynamic badges are resolved from the
+    /// component state when the method is called.
+    pub fn badges(&self) -> BTreeSet<SoftResourceOrNonFungible> {
+        let mut badges = BTreeSet::new();
+        for (_, rule) in &self.layers {
+            if let AccessRule::Protected(node) = rule {
+                visit_rule_node(node, &mut |proof_rule| {
+                    collect_badges(proof_rule, &mut badges)
+                });
+            }
+        }
+        badges
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessRulesAnalysis {
+    pub methods: Vec<MethodAccess>,
+    pub issues: Vec<AccessRulesIssue>,
+}
+
+fn visit_rule_node<F: FnMut(&ProofRule)>(node: &AccessRuleNode, visit: &mut F) {
+    match node {
+        AccessRuleNode::ProofRule(proof_rule) => visit(proof_rule),
+        AccessRuleNode::AnyOf(nodes) | AccessRuleNode::AllOf(nodes) => {
+            for node in nodes {
+                visit_rule_node(node, visit);
+            }
+        }
+    }
+}
+
+fn collect_badges(proof_rule: &ProofRule, badges: &mut BTreeSet<SoftResourceOrNonFungible>) {
+    let mut collect_list = |list: &SoftResourceOrNonFungibleList| match list {
+        SoftResourceOrNonFungibleList::Static(resources) => badges.extend(resources.clone()),
+        SoftResourceOrNonFungibleList::Dynamic(path) => {
+            badges.insert(SoftResourceOrNonFungible::Dynamic(path.clone()));
+        }
+    };
+    match proof_rule {
+        ProofRule::Require(resource) => {
+            badges.insert(resource.clone());
+        }
+        ProofRule::AmountOf(_, resource) => {
+            badges.insert(match resource {
+                SoftResource::Static(address) => {
+                    SoftResourceOrNonFungible::StaticResource(*address)
+                }
+                SoftResource::Dynamic(path) => SoftResourceOrNonFungible::Dynamic(path.clone()),
+            });
+        }
+        ProofRule::CountOf(_, list) | ProofRule::AllOf(list) | ProofRule::AnyOf(list) => {
+            collect_list(list)
+        }
+        ProofRule::AllowedAfter(..)
+        | ProofRule::AllowedBefore(..)
+        | ProofRule::WithinEpochRange(..) => {}
+    }
+}
+
+fn collect_dynamic_paths(proof_rule: &ProofRule, paths: &mut Vec<(SchemaPath, DynamicValueKind)>) {
+    let collect_resource = |resource: &SoftResourceOrNonFungible, paths: &mut Vec<_>| {
+        if let SoftResourceOrNonFungible::Dynamic(path) = resource {
+            paths.push((path.clone(), DynamicValueKind::ResourceOrNonFungible));
+        }
+    };
+    let collect_list = |list: &SoftResourceOrNonFungibleList, paths: &mut Vec<_>| match list {
+        SoftResourceOrNonFungibleList::Static(resources) => {
+            for resource in resources {
+                collect_resource(resource, paths);
+            }
+        }
+        SoftResourceOrNonFungibleList::Dynamic(path) => {
+            paths.push((path.clone(), DynamicValueKind::ResourceOrNonFungibleList));
+        }
+    };
+    match proof_rule {
+        ProofRule::Require(resource) => collect_resource(resource, paths),
+        ProofRule::AmountOf(amount, resource) => {
+            if let SoftDecimal::Dynamic(path) = amount {
+                paths.push((path.clone(), DynamicValueKind::Decimal));
+            }
+            if let SoftResource::Dynamic(path) = resource {
+                paths.push((path.clone(), DynamicValueKind::Resource));
+            }
+        }
+        ProofRule::CountOf(count, list) => {
+            if let SoftCount::Dynamic(path) = count {
+                paths.push((path.clone(), DynamicValueKind::Count));
+            }
+            collect_list(list, paths);
+        }
+        ProofRule::AllOf(list) | ProofRule::AnyOf(list) => collect_list(list, paths),
+        ProofRule::AllowedAfter(..)
+        | ProofRule::AllowedBefore(..)
+        | ProofRule::WithinEpochRange(..) => {}
+    }
+}
+
+/// Statically analyzes which rules of an access rules chain apply to the given methods, and
+/// reports rules which can't work as intended against the given component state schema.
+pub fn analyze_access_rules(
+    access_rules_chain: &[AccessRules],
+    methods: &[AccessRuleKey],
+    schema: &Type,
+) -> AccessRulesAnalysis {
+    let methods: Vec<(MethodKey, &Type)> = methods
+        .iter()
+        .map(|key| (MethodKey::from(key.clone()), schema))
+        .collect();
+
+    analyze_access_rules_with_schemas(access_rules_chain, &methods)
+}
+
+/// Like [`analyze_access_rules`], but with a state schema of its own for each method.
+fn analyze_access_rules_with_schemas(
+    access_rules_chain: &[AccessRules],
+    methods: &[(MethodKey, &Type)],
+) -> AccessRulesAnalysis {
+    let mut analysis = AccessRulesAnalysis {
+        methods: Vec::new(),
+        issues: Vec::new(),
+    };
+
+    for (key, schema) in methods {
+        let mut layers = Vec::new();
+        for (layer, access_rules) in access_rules_chain.iter().enumerate() {
+            let source = match access_rules.get_all_method_auth().get(&key.rule_key) {
+                None => AccessRuleSource::Default,
+                Some(AccessRuleEntry::AccessRule(..)) => AccessRuleSource::Method,
+                Some(AccessRuleEntry::Group(group)) => {
+                    if access_rules.get_all_grouped_auth().contains_key(group) {
+                        AccessRuleSource::Group(group.clone())
+                    } else {
+                        analysis.issues.push(AccessRulesIssue::MissingGroup {
+                            layer,
+                            key: key.clone(),
+                            group: group.clone(),
+                        });
+                        AccessRuleSource::MissingGroup(group.clone())
+                    }
+                }
+            };
+            let rule = access_rules.get(&key.rule_key).clone();
+
+            if let AccessRule::Protected(node) = &rule {
+                let mut paths = Vec::new();
+                visit_rule_node(node, &mut |proof_rule| {
+                    collect_dynamic_paths(proof_rule, &mut paths)
+                });
+                for (path, expected) in paths {
+                    match path.to_type(schema) {
+                        None => analysis.issues.push(AccessRulesIssue::InvalidSchemaPath {
+                            layer,
+                            key: key.clone(),
+                            path,
+                        }),
+                        Some(actual) if !expected.matches(actual) => {
+                            analysis
+                                .issues
+                                .push(AccessRulesIssue::SchemaPathTypeMismatch {
+                                    layer,
+                                    key: key.clone(),
+                                    path,
+                                    expected,
+                                    actual: actual.clone(),
+                                })
+                        }
+                        Some(..) => {}
+                    }
+                }
+            }
+
+            layers.push((source, rule));
+        }
+        analysis.methods.push(MethodAccess {
+            key: key.clone(),
+            layers,
+        });
+    }
+
+    for (layer, access_rules) in access_rules_chain.iter().enumerate() {
+        let mut unknown: Vec<&AccessRuleKey> = access_rules
+            .get_all_method_auth()
+            .keys()
+            .filter(|key| matches!(key, AccessRuleKey::ScryptoMethod(..)))
+            .filter(|key| !methods.iter().any(|(method, _)| method.rule_key == **key))
+            .collect();
+        unknown.sort();
+        for key in unknown {
+            analysis.issues.push(AccessRulesIssue::UnknownMethod {
+                layer,
+                key: key.clone(),
+            });
+        }
+    }
+
+    analysis
+}
+
+/// Analyzes the access rules chain of a component against the methods and state of its
+/// blueprint.
+pub fn analyze_blueprint_access_rules(
+    access_rules_chain: &[AccessRules],
+    blueprint_abi: &BlueprintAbi,
+) -> AccessRulesAnalysis {
+    let methods: Vec<AccessRuleKey> = blueprint_abi
+        .fns
+        .iter()
+        .filter(|f| f.mutability.is_some())
+        .map(|f| AccessRuleKey::ScryptoMethod(f.ident.clone()))
+        .collect();
+
+    analyze_access_rules(access_rules_chain, &methods, &blueprint_abi.structure)
+}
+
+/// Analyzes the access rules chain of a package against its royalty methods and the functions
+/// and methods of its blueprints. Methods are checked against the state of their blueprint,
+/// while functions have no state for dynamic rules to point to. The functions and methods are
+/// keyed by their blueprint, as different blueprints may have functions of the same name.
+pub fn analyze_package_access_rules(
+    access_rules_chain: &[AccessRules],
+    blueprint_abis: &HashMap<String, BlueprintAbi>,
+) -> AccessRulesAnalysis {
+    // Native methods are authorized without any state
+    let mut methods: Vec<(MethodKey, &Type)> =
+        [PackageMethod::SetRoyaltyConfig, PackageMethod::ClaimRoyalty]
+            .into_iter()
+            .map(|method| {
+                (
+                    AccessRuleKey::Native(NativeFn::Method(NativeMethod::Package(method))).into(),
+                    &Type::Any,
+                )
+            })
+            .collect();
+
+    let mut blueprint_names: Vec<&String> = blueprint_abis.keys().collect();
+    blueprint_names.sort();
+    for blueprint_name in blueprint_names {
+        let blueprint_abi = &blueprint_abis[blueprint_name];
+        for f in &blueprint_abi.fns {
+            let schema = if f.mutability.is_some() {
+                &blueprint_abi.structure
+            } else {
+                &Type::Unit
+            };
+            methods.push((
+                MethodKey::blueprint(
+                    blueprint_name,
+                    AccessRuleKey::ScryptoMethod(f.ident.clone()),
+                ),
+                schema,
+            ));
+        }
+    }
+
+    analyze_access_rules_with_schemas(access_rules_chain, &methods)
+}
+
+/// Analyzes the access rules chain of a native account against the methods of the account
+/// blueprint, which are authorized as native methods without any state.
+pub fn analyze_account_access_rules(
+    access_rules_chain: &[AccessRules],
+    blueprint_abi: &BlueprintAbi,
+) -> AccessRulesAnalysis {
+    let methods: Vec<AccessRuleKey> = blueprint_abi
+        .fns
+        .iter()
+        .filter(|f| f.mutability.is_some())
+        .filter_map(|f| AccountMethod::from_str(&f.ident).ok())
+        .map(|method| AccessRuleKey::Native(NativeFn::Method(NativeMethod::Account(method))))
+        .collect();
+
+    analyze_access_rules(access_rules_chain, &methods, &Type::Any)
+}
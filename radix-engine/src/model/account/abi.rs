@@ -0,0 +1,214 @@
+use crate::model::Account;
+use crate::types::*;
+use radix_engine_interface::abi::{Describe, SelfMutability};
+
+impl Account {
+    /// The ABI of the account blueprint, as exposed to manifests and blueprints calling the
+    /// account through [`ACCOUNT_PACKAGE`] and [`ACCOUNT_BLUEPRINT`].
+    pub fn blueprint_abi() -> BlueprintAbi {
+        let structure = Type::Struct {
+            name: ACCOUNT_BLUEPRINT.to_string(),
+            fields: Fields::Named {
+                named: vec![
+                    (
+                        "vaults".to_string(),
+                        Type::KeyValueStore {
+                            key_type: Box::new(ResourceAddress::describe()),
+                            value_type: Box::new(Vault::describe()),
+                        },
+                    ),
+                    (
+                        "deposit_policy".to_string(),
+                        AccountDepositPolicy::describe(),
+                    ),
+                ],
+            },
+        };
+
+        let fns = vec![
+            Self::function_abi(
+                AccountFunction::New,
+                vec![AccessRule::describe()],
+                ComponentAddress::describe(),
+            ),
+            Self::function_abi(
+                AccountFunction::NewWithResource,
+                vec![AccessRule::describe(), Bucket::describe()],
+                ComponentAddress::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::Balance,
+                SelfMutability::Immutable,
+                vec![ResourceAddress::describe()],
+                Decimal::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::LockFee,
+                SelfMutability::Mutable,
+                vec![Decimal::describe()],
+                Type::Unit,
+            ),
+            Self::method_abi(
+                AccountMethod::LockContingentFee,
+                SelfMutability::Mutable,
+                vec![Decimal::describe()],
+                Type::Unit,
+            ),
+            Self::method_abi(
+                AccountMethod::LockSponsoredFee,
+                SelfMutability::Mutable,
+                vec![Decimal::describe(), FeeSponsorship::describe()],
+                Type::Unit,
+            ),
+            Self::method_abi(
+                AccountMethod::Deposit,
+                SelfMutability::Mutable,
+                vec![Bucket::describe()],
+                Type::Unit,
+            ),
+            Self::method_abi(
+                AccountMethod::DepositBatch,
+                SelfMutability::Mutable,
+                vec![Vec::<Bucket>::describe()],
+                Type::Unit,
+            ),
+            Self::method_abi(
+                AccountMethod::TryDepositOrRefund,
+                SelfMutability::Mutable,
+                vec![Bucket::describe()],
+                Option::<Bucket>::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::TryDepositBatchOrRefund,
+                SelfMutability::Mutable,
+                vec![Vec::<Bucket>::describe()],
+                Vec::<Bucket>::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::SetDepositPolicy,
+                SelfMutability::Mutable,
+                vec![AccountDepositPolicy::describe()],
+                Type::Unit,
+            ),
+            Self::method_abi(
+                AccountMethod::GetDepositPolicy,
+                SelfMutability::Immutable,
+                vec![],
+                AccountDepositPolicy::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::Withdraw,
+                SelfMutability::Mutable,
+                vec![ResourceAddress::describe()],
+                Bucket::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::WithdrawByAmount,
+                SelfMutability::Mutable,
+                vec![Decimal::describe(), ResourceAddress::describe()],
+                Bucket::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::WithdrawByIds,
+                SelfMutability::Mutable,
+                vec![
+                    BTreeSet::<NonFungibleId>::describe(),
+                    ResourceAddress::describe(),
+                ],
+                Bucket::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::LockFeeAndWithdraw,
+                SelfMutability::Mutable,
+                vec![Decimal::describe(), ResourceAddress::describe()],
+                Bucket::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::LockFeeAndWithdrawByAmount,
+                SelfMutability::Mutable,
+                vec![
+                    Decimal::describe(),
+                    Decimal::describe(),
+                    ResourceAddress::describe(),
+                ],
+                Bucket::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::LockFeeAndWithdrawByIds,
+                SelfMutability::Mutable,
+                vec![
+                    Decimal::describe(),
+                    BTreeSet::<NonFungibleId>::describe(),
+                    ResourceAddress::describe(),
+                ],
+                Bucket::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::CreateProof,
+                SelfMutability::Immutable,
+                vec![ResourceAddress::describe()],
+                Proof::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::CreateProofByAmount,
+                SelfMutability::Immutable,
+                vec![Decimal::describe(), ResourceAddress::describe()],
+                Proof::describe(),
+            ),
+            Self::method_abi(
+                AccountMethod::CreateProofByIds,
+                SelfMutability::Immutable,
+                vec![
+                    BTreeSet::<NonFungibleId>::describe(),
+                    ResourceAddress::describe(),
+                ],
+                Proof::describe(),
+            ),
+        ];
+
+        BlueprintAbi {
+            structure,
+            fns,
+            events: vec![],
+        }
+    }
+
+    fn function_abi(function: AccountFunction, args: Vec<Type>, output: Type) -> Fn {
+        Self::fn_abi(function.as_ref(), None, args, output)
+    }
+
+    fn method_abi(
+        method: AccountMethod,
+        mutability: SelfMutability,
+        args: Vec<Type>,
+        output: Type,
+    ) -> Fn {
+        Self::fn_abi(method.as_ref(), Some(mutability), args, output)
+    }
+
+    /// Describes a function or method the way the `blueprint!` macro does, with positional
+    /// arguments wrapped in an input struct.
+    fn fn_abi(
+        ident: &str,
+        mutability: Option<SelfMutability>,
+        args: Vec<Type>,
+        output: Type,
+    ) -> Fn {
+        let export_name = format!("{}_{}", ACCOUNT_BLUEPRINT, ident);
+        let named = args
+            .into_iter()
+            .enumerate()
+            .map(|(i, arg)| (format!("arg{}", i), arg))
+            .collect();
+        Fn {
+            ident: ident.to_string(),
+            mutability,
+            input: Type::Struct {
+                name: format!("{}_Input", export_name),
+                fields: Fields::Named { named },
+            },
+            output,
+            export_name,
+        }
+    }
+}
@@ -0,0 +1,1128 @@
+use crate::engine::{
+    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags,
+    NativeExecutor, NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod,
+    ResolverApi, RuntimeError, SystemApi,
+};
+use crate::model::{AccessRulesChainSubstate, AccountSubstate, GlobalAddressSubstate};
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_interface::api::api::InvokableModel;
+use radix_engine_interface::api::types::{
+    AccountFunction, AccountMethod, AccountOffset, GlobalAddress, LockHandle, NativeFunction,
+    NativeMethod, RENodeId, SubstateOffset, VaultId,
+};
+use radix_engine_interface::model::*;
+use radix_engine_interface::rule;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AccountError {
+    VaultNotFound(ResourceAddress),
+    ResourceNotAccepted(ResourceAddress),
+}
+
+pub struct Account;
+
+impl Account {
+    pub fn access_rules(withdraw_rule: AccessRule) -> AccessRules {
+        let mut access_rules = AccessRules::new().default(withdraw_rule.clone(), withdraw_rule);
+        // Anyone may query and deposit into an account, subject to its deposit policy
+        for method in [
+            AccountMethod::Balance,
+            AccountMethod::Deposit,
+            AccountMethod::DepositBatch,
+            AccountMethod::TryDepositOrRefund,
+            AccountMethod::TryDepositBatchOrRefund,
+            AccountMethod::GetDepositPolicy,
+        ] {
+            access_rules.set_access_rule_and_mutability(
+                AccessRuleKey::Native(NativeFn::Method(NativeMethod::Account(method))),
+                rule!(allow_all),
+                rule!(deny_all),
+            );
+        }
+        access_rules
+    }
+
+    fn create<Y>(
+        withdraw_rule: AccessRule,
+        vaults: BTreeMap<ResourceAddress, VaultId>,
+        api: &mut Y,
+    ) -> Result<(ComponentAddress, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let underlying_node_id = api.allocate_node_id(RENodeType::Account)?;
+        api.create_node(
+            underlying_node_id,
+            RENode::Account(
+                AccountSubstate {
+                    vaults,
+                    deposit_policy: AccountDepositPolicy::default(),
+                },
+                AccessRulesChainSubstate {
+                    access_rules_chain: vec![Account::access_rules(withdraw_rule)],
+                },
+            ),
+        )?;
+
+        let global_node_id = api.allocate_node_id(RENodeType::GlobalAccount)?;
+        api.create_node(
+            global_node_id,
+            RENode::Global(GlobalAddressSubstate::Account(underlying_node_id.into())),
+        )?;
+
+        let component_address: ComponentAddress = global_node_id.into();
+        Ok((component_address, CallFrameUpdate::copy_ref(global_node_id)))
+    }
+
+    fn accepts<Y>(
+        handle: LockHandle,
+        resource_address: &ResourceAddress,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let substate_ref = api.get_ref(handle)?;
+        Ok(substate_ref
+            .account()
+            .deposit_policy
+            .accepts(resource_address))
+    }
+
+    /// Puts the bucket into the vault of its resource, creating the vault on first deposit.
+    /// Expects the account substate to be locked mutably.
+    fn deposit<Y>(
+        handle: LockHandle,
+        resource_address: ResourceAddress,
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let vault_id = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .account()
+                .vaults
+                .get(&resource_address)
+                .cloned()
+        };
+
+        match vault_id {
+            Some(vault_id) => {
+                api.invoke(VaultPutInvocation {
+                    receiver: vault_id,
+                    bucket,
+                })?;
+            }
+            None => {
+                let vault = api.invoke(ResourceManagerCreateVaultInvocation {
+                    receiver: resource_address,
+                })?;
+                api.invoke(VaultPutInvocation {
+                    receiver: vault.0,
+                    bucket,
+                })?;
+                let mut substate_mut = api.get_ref_mut(handle)?;
+                substate_mut
+                    .account()
+                    .vaults
+                    .insert(resource_address, vault.0);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Locks the account substate, which keeps the vault of the resource visible until the
+    /// returned lock is dropped.
+    fn lock_vault<Y>(
+        node_id: RENodeId,
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<(LockHandle, VaultId), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let vault_id = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .account()
+                .vaults
+                .get(&resource_address)
+                .cloned()
+        };
+
+        match vault_id {
+            Some(vault_id) => Ok((handle, vault_id)),
+            None => {
+                api.drop_lock(handle)?;
+                Err(RuntimeError::ApplicationError(
+                    ApplicationError::AccountError(AccountError::VaultNotFound(resource_address)),
+                ))
+            }
+        }
+    }
+
+    fn lock_fee<Y>(
+        node_id: RENodeId,
+        amount: Decimal,
+        contingent: bool,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, vault_id) = Account::lock_vault(node_id, RADIX_TOKEN, api)?;
+        api.invoke(VaultLockFeeInvocation {
+            receiver: vault_id,
+            amount,
+            contingent,
+        })?;
+        api.drop_lock(handle)?;
+
+        Ok(())
+    }
+
+    fn lock_sponsored_fee<Y>(
+        node_id: RENodeId,
+        amount: Decimal,
+        sponsorship: FeeSponsorship,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, vault_id) = Account::lock_vault(node_id, RADIX_TOKEN, api)?;
+        api.invoke(VaultLockSponsoredFeeInvocation {
+            receiver: vault_id,
+            amount,
+            sponsorship,
+        })?;
+        api.drop_lock(handle)?;
+
+        Ok(())
+    }
+
+    fn withdraw<Y>(
+        node_id: RENodeId,
+        resource_address: ResourceAddress,
+        amount: Option<Decimal>,
+        api: &mut Y,
+    ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, vault_id) = Account::lock_vault(node_id, resource_address, api)?;
+        let amount = match amount {
+            Some(amount) => amount,
+            None => api.invoke(VaultGetAmountInvocation { receiver: vault_id })?,
+        };
+        let bucket = api.invoke(VaultTakeInvocation {
+            receiver: vault_id,
+            amount,
+        })?;
+        api.drop_lock(handle)?;
+
+        let bucket_id = bucket.0;
+        Ok((
+            bucket,
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+
+    fn withdraw_by_ids<Y>(
+        node_id: RENodeId,
+        resource_address: ResourceAddress,
+        ids: BTreeSet<NonFungibleId>,
+        api: &mut Y,
+    ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, vault_id) = Account::lock_vault(node_id, resource_address, api)?;
+        let bucket = api.invoke(VaultTakeNonFungiblesInvocation {
+            receiver: vault_id,
+            non_fungible_ids: ids,
+        })?;
+        api.drop_lock(handle)?;
+
+        let bucket_id = bucket.0;
+        Ok((
+            bucket,
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+}
+
+fn resolve_method<W: WasmEngine, D: ResolverApi<W>>(
+    receiver: ComponentAddress,
+    method: AccountMethod,
+    mut call_frame_update: CallFrameUpdate,
+    deref: &mut D,
+) -> Result<(REActor, CallFrameUpdate, RENodeId), RuntimeError> {
+    let receiver = RENodeId::Global(GlobalAddress::Component(receiver));
+    let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+    let actor = REActor::Method(
+        ResolvedMethod::Native(NativeMethod::Account(method)),
+        resolved_receiver,
+    );
+
+    Ok((actor, call_frame_update, resolved_receiver.receiver))
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountNewInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let actor = REActor::Function(ResolvedFunction::Native(NativeFunction::Account(
+            AccountFunction::New,
+        )));
+        let call_frame_update = CallFrameUpdate::empty();
+        let executor = NativeExecutor(self);
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountNewInvocation {
+    type Output = ComponentAddress;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(ComponentAddress, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::create(self.withdraw_rule, BTreeMap::new(), api)
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountNewWithResourceInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let actor = REActor::Function(ResolvedFunction::Native(NativeFunction::Account(
+            AccountFunction::NewWithResource,
+        )));
+        let call_frame_update = CallFrameUpdate::move_node(RENodeId::Bucket(self.bucket.0));
+        let executor = NativeExecutor(self);
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountNewWithResourceInvocation {
+    type Output = ComponentAddress;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(ComponentAddress, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let resource_address = api.invoke(BucketGetResourceAddressInvocation {
+            receiver: self.bucket.0,
+        })?;
+        let vault = api.invoke(ResourceManagerCreateVaultInvocation {
+            receiver: resource_address,
+        })?;
+        api.invoke(VaultPutInvocation {
+            receiver: vault.0,
+            bucket: self.bucket,
+        })?;
+
+        let mut vaults = BTreeMap::new();
+        vaults.insert(resource_address, vault.0);
+        Account::create(self.withdraw_rule, vaults, api)
+    }
+}
+
+pub struct AccountBalanceExecutable(RENodeId, ResourceAddress);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountBalanceInvocation {
+    type Exec = NativeExecutor<AccountBalanceExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::Balance,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountBalanceExecutable(receiver, self.resource_address));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountBalanceExecutable {
+    type Output = Decimal;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Decimal, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let vault_id = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.account().vaults.get(&self.1).cloned()
+        };
+        let balance = match vault_id {
+            Some(vault_id) => api.invoke(VaultGetAmountInvocation { receiver: vault_id })?,
+            None => Decimal::zero(),
+        };
+        api.drop_lock(handle)?;
+
+        Ok((balance, CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccountLockFeeExecutable(RENodeId, Decimal, bool);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountLockFeeInvocation {
+    type Exec = NativeExecutor<AccountLockFeeExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::LockFee,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountLockFeeExecutable(receiver, self.amount, false));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountLockContingentFeeInvocation {
+    type Exec = NativeExecutor<AccountLockFeeExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::LockContingentFee,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountLockFeeExecutable(receiver, self.amount, true));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountLockFeeExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::lock_fee(self.0, self.1, self.2, api)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccountLockSponsoredFeeExecutable(RENodeId, Decimal, FeeSponsorship);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountLockSponsoredFeeInvocation {
+    type Exec = NativeExecutor<AccountLockSponsoredFeeExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::LockSponsoredFee,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountLockSponsoredFeeExecutable(
+            receiver,
+            self.amount,
+            self.sponsorship,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountLockSponsoredFeeExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::lock_sponsored_fee(self.0, self.1, self.2, api)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccountDepositExecutable(RENodeId, Vec<Bucket>);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountDepositInvocation {
+    type Exec = NativeExecutor<AccountDepositExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::Deposit,
+            CallFrameUpdate::move_node(RENodeId::Bucket(self.bucket.0)),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountDepositExecutable(receiver, vec![self.bucket]));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountDepositBatchInvocation {
+    type Exec = NativeExecutor<AccountDepositExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let call_frame_update = CallFrameUpdate {
+            nodes_to_move: self
+                .buckets
+                .iter()
+                .map(|bucket| RENodeId::Bucket(bucket.0))
+                .collect(),
+            node_refs_to_copy: HashSet::new(),
+        };
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::DepositBatch,
+            call_frame_update,
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountDepositExecutable(receiver, self.buckets));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountDepositExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        for bucket in self.1 {
+            let resource_address =
+                api.invoke(BucketGetResourceAddressInvocation { receiver: bucket.0 })?;
+            if !Account::accepts(handle, &resource_address, api)? {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::AccountError(AccountError::ResourceNotAccepted(
+                        resource_address,
+                    )),
+                ));
+            }
+            Account::deposit(handle, resource_address, bucket, api)?;
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccountTryDepositOrRefundExecutable(RENodeId, Bucket);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountTryDepositOrRefundInvocation {
+    type Exec = NativeExecutor<AccountTryDepositOrRefundExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::TryDepositOrRefund,
+            CallFrameUpdate::move_node(RENodeId::Bucket(self.bucket.0)),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountTryDepositOrRefundExecutable(receiver, self.bucket));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountTryDepositOrRefundExecutable {
+    type Output = Option<Bucket>;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Option<Bucket>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let bucket = self.1;
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let resource_address =
+            api.invoke(BucketGetResourceAddressInvocation { receiver: bucket.0 })?;
+        let (refund, call_frame_update) = if Account::accepts(handle, &resource_address, api)? {
+            Account::deposit(handle, resource_address, bucket, api)?;
+            (None, CallFrameUpdate::empty())
+        } else {
+            let bucket_id = bucket.0;
+            (
+                Some(bucket),
+                CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+            )
+        };
+        api.drop_lock(handle)?;
+
+        Ok((refund, call_frame_update))
+    }
+}
+
+pub struct AccountTryDepositBatchOrRefundExecutable(RENodeId, Vec<Bucket>);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountTryDepositBatchOrRefundInvocation {
+    type Exec = NativeExecutor<AccountTryDepositBatchOrRefundExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let call_frame_update = CallFrameUpdate {
+            nodes_to_move: self
+                .buckets
+                .iter()
+                .map(|bucket| RENodeId::Bucket(bucket.0))
+                .collect(),
+            node_refs_to_copy: HashSet::new(),
+        };
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::TryDepositBatchOrRefund,
+            call_frame_update,
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountTryDepositBatchOrRefundExecutable(
+            receiver,
+            self.buckets,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountTryDepositBatchOrRefundExecutable {
+    type Output = Vec<Bucket>;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Vec<Bucket>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let mut refunds = Vec::new();
+        for bucket in self.1 {
+            let resource_address =
+                api.invoke(BucketGetResourceAddressInvocation { receiver: bucket.0 })?;
+            if Account::accepts(handle, &resource_address, api)? {
+                Account::deposit(handle, resource_address, bucket, api)?;
+            } else {
+                refunds.push(bucket);
+            }
+        }
+        api.drop_lock(handle)?;
+
+        let call_frame_update = CallFrameUpdate {
+            nodes_to_move: refunds
+                .iter()
+                .map(|bucket| RENodeId::Bucket(bucket.0))
+                .collect(),
+            node_refs_to_copy: HashSet::new(),
+        };
+
+        Ok((refunds, call_frame_update))
+    }
+}
+
+pub struct AccountWithdrawExecutable(RENodeId, ResourceAddress, Option<Decimal>);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountWithdrawInvocation {
+    type Exec = NativeExecutor<AccountWithdrawExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::Withdraw,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountWithdrawExecutable(
+            receiver,
+            self.resource_address,
+            None,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountWithdrawByAmountInvocation {
+    type Exec = NativeExecutor<AccountWithdrawExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::WithdrawByAmount,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountWithdrawExecutable(
+            receiver,
+            self.resource_address,
+            Some(self.amount),
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountWithdrawExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::withdraw(self.0, self.1, self.2, api)
+    }
+}
+
+pub struct AccountWithdrawByIdsExecutable(RENodeId, ResourceAddress, BTreeSet<NonFungibleId>);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountWithdrawByIdsInvocation {
+    type Exec = NativeExecutor<AccountWithdrawByIdsExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::WithdrawByIds,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountWithdrawByIdsExecutable(
+            receiver,
+            self.resource_address,
+            self.ids,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountWithdrawByIdsExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::withdraw_by_ids(self.0, self.1, self.2, api)
+    }
+}
+
+pub struct AccountLockFeeAndWithdrawExecutable {
+    node_id: RENodeId,
+    amount_to_lock: Decimal,
+    resource_address: ResourceAddress,
+    amount: Option<Decimal>,
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountLockFeeAndWithdrawInvocation {
+    type Exec = NativeExecutor<AccountLockFeeAndWithdrawExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::LockFeeAndWithdraw,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountLockFeeAndWithdrawExecutable {
+            node_id: receiver,
+            amount_to_lock: self.amount_to_lock,
+            resource_address: self.resource_address,
+            amount: None,
+        });
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountLockFeeAndWithdrawByAmountInvocation {
+    type Exec = NativeExecutor<AccountLockFeeAndWithdrawExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::LockFeeAndWithdrawByAmount,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountLockFeeAndWithdrawExecutable {
+            node_id: receiver,
+            amount_to_lock: self.amount_to_lock,
+            resource_address: self.resource_address,
+            amount: Some(self.amount),
+        });
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountLockFeeAndWithdrawExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::lock_fee(self.node_id, self.amount_to_lock, false, api)?;
+        Account::withdraw(self.node_id, self.resource_address, self.amount, api)
+    }
+}
+
+pub struct AccountLockFeeAndWithdrawByIdsExecutable {
+    node_id: RENodeId,
+    amount_to_lock: Decimal,
+    resource_address: ResourceAddress,
+    ids: BTreeSet<NonFungibleId>,
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountLockFeeAndWithdrawByIdsInvocation {
+    type Exec = NativeExecutor<AccountLockFeeAndWithdrawByIdsExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::LockFeeAndWithdrawByIds,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountLockFeeAndWithdrawByIdsExecutable {
+            node_id: receiver,
+            amount_to_lock: self.amount_to_lock,
+            resource_address: self.resource_address,
+            ids: self.ids,
+        });
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountLockFeeAndWithdrawByIdsExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        Account::lock_fee(self.node_id, self.amount_to_lock, false, api)?;
+        Account::withdraw_by_ids(self.node_id, self.resource_address, self.ids, api)
+    }
+}
+
+pub enum AccountProofAmount {
+    All,
+    Amount(Decimal),
+    Ids(BTreeSet<NonFungibleId>),
+}
+
+pub struct AccountCreateProofExecutable(RENodeId, ResourceAddress, AccountProofAmount);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountCreateProofInvocation {
+    type Exec = NativeExecutor<AccountCreateProofExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::CreateProof,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountCreateProofExecutable(
+            receiver,
+            self.resource_address,
+            AccountProofAmount::All,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountCreateProofByAmountInvocation {
+    type Exec = NativeExecutor<AccountCreateProofExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::CreateProofByAmount,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountCreateProofExecutable(
+            receiver,
+            self.resource_address,
+            AccountProofAmount::Amount(self.amount),
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountCreateProofByIdsInvocation {
+    type Exec = NativeExecutor<AccountCreateProofExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::CreateProofByIds,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountCreateProofExecutable(
+            receiver,
+            self.resource_address,
+            AccountProofAmount::Ids(self.ids),
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountCreateProofExecutable {
+    type Output = Proof;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Proof, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, vault_id) = Account::lock_vault(self.0, self.1, api)?;
+        let proof = match self.2 {
+            AccountProofAmount::All => {
+                api.invoke(VaultCreateProofInvocation { receiver: vault_id })?
+            }
+            AccountProofAmount::Amount(amount) => {
+                api.invoke(VaultCreateProofByAmountInvocation {
+                    receiver: vault_id,
+                    amount,
+                })?
+            }
+            AccountProofAmount::Ids(ids) => api.invoke(VaultCreateProofByIdsInvocation {
+                receiver: vault_id,
+                ids,
+            })?,
+        };
+        api.drop_lock(handle)?;
+
+        let proof_id = proof.0;
+        Ok((proof, CallFrameUpdate::move_node(RENodeId::Proof(proof_id))))
+    }
+}
+
+pub struct AccountSetDepositPolicyExecutable(RENodeId, AccountDepositPolicy);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountSetDepositPolicyInvocation {
+    type Exec = NativeExecutor<AccountSetDepositPolicyExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::SetDepositPolicy,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountSetDepositPolicyExecutable(
+            receiver,
+            self.deposit_policy,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountSetDepositPolicyExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            substate_mut.account().deposit_policy = self.1;
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct AccountGetDepositPolicyExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for AccountGetDepositPolicyInvocation {
+    type Exec = NativeExecutor<AccountGetDepositPolicyExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let (actor, call_frame_update, receiver) = resolve_method(
+            self.receiver,
+            AccountMethod::GetDepositPolicy,
+            CallFrameUpdate::empty(),
+            deref,
+        )?;
+        let executor = NativeExecutor(AccountGetDepositPolicyExecutable(receiver));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for AccountGetDepositPolicyExecutable {
+    type Output = AccountDepositPolicy;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(AccountDepositPolicy, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Account(AccountOffset::Account);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let deposit_policy = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.account().deposit_policy.clone()
+        };
+        api.drop_lock(handle)?;
+
+        Ok((deposit_policy, CallFrameUpdate::empty()))
+    }
+}
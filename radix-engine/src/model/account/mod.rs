@@ -0,0 +1,6 @@
+mod abi;
+mod executables;
+mod substates;
+
+pub use executables::*;
+pub use substates::*;
@@ -0,0 +1,10 @@
+use crate::types::*;
+use radix_engine_interface::api::types::VaultId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AccountSubstate {
+    /// The vaults of the account, one per resource deposited so far
+    pub vaults: BTreeMap<ResourceAddress, VaultId>,
+    pub deposit_policy: AccountDepositPolicy,
+}
@@ -1,9 +1,9 @@
 use crate::engine::{
-    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, InterpreterError,
-    LockFlags, NativeExecutor, NativeProcedure, REActor, ResolvedMethod, ResolverApi, RuntimeError,
-    SystemApi,
+    deref_and_update, ApplicationError, AuthModule, CallFrameUpdate, ExecutableInvocation,
+    InterpreterError, LockFlags, NativeExecutor, NativeProcedure, REActor, ResolvedMethod,
+    ResolverApi, RuntimeError, SystemApi,
 };
-use crate::model::{MethodAuthorization, MethodAuthorizationError};
+use crate::model::{AuthTrace, MethodAuthorization, MethodAuthorizationError};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::api::{EngineApi, Invocation, InvokableModel};
@@ -17,7 +17,11 @@ use radix_engine_interface::model::*;
 pub enum AccessRulesChainError {
     BlueprintFunctionNotFound(String),
     InvalidIndex(u32),
-    Unauthorized(MethodAuthorization, MethodAuthorizationError),
+    Unauthorized(
+        MethodAuthorization,
+        MethodAuthorizationError,
+        Option<Box<AuthTrace>>,
+    ),
     ProtectedMethod(AccessRuleKey),
 }
 
@@ -195,6 +199,7 @@ impl NativeProcedure for AccessRulesSetMethodAccessRuleInvocation {
 
         // Manual Auth
         {
+            let ledger_state = AuthModule::ledger_state(&authorization, api)?;
             let owned_node_ids = api.sys_get_visible_nodes()?;
             let node_id = owned_node_ids
                 .into_iter()
@@ -206,13 +211,13 @@ impl NativeProcedure for AccessRulesSetMethodAccessRuleInvocation {
             let substate_ref = api.get_ref(handle)?;
             let auth_zone_stack = substate_ref.auth_zone_stack();
 
-            auth_zone_stack.check_auth(false, authorization).map_err(
-                |(authorization, error)| {
+            auth_zone_stack
+                .check_auth(false, authorization, &ledger_state)
+                .map_err(|(authorization, error, trace)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
+                        AccessRulesChainError::Unauthorized(authorization, error, trace),
                     ))
-                },
-            )?;
+                })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -287,6 +292,7 @@ impl NativeProcedure for AccessRulesSetGroupAccessRuleInvocation {
 
         // Manual Auth
         {
+            let ledger_state = AuthModule::ledger_state(&authorization, api)?;
             let owned_node_ids = api.sys_get_visible_nodes()?;
             let node_id = owned_node_ids
                 .into_iter()
@@ -299,10 +305,10 @@ impl NativeProcedure for AccessRulesSetGroupAccessRuleInvocation {
             let auth_zone_substate = substate_ref.auth_zone_stack();
 
             auth_zone_substate
-                .check_auth(false, authorization)
-                .map_err(|(authorization, error)| {
+                .check_auth(false, authorization, &ledger_state)
+                .map_err(|(authorization, error, trace)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
+                        AccessRulesChainError::Unauthorized(authorization, error, trace),
                     ))
                 })?;
         }
@@ -407,6 +413,7 @@ impl NativeProcedure for AccessRulesSetMethodMutabilityInvocation {
 
         // Manual Auth
         {
+            let ledger_state = AuthModule::ledger_state(&authorization, api)?;
             let owned_node_ids = api.sys_get_visible_nodes()?;
             let node_id = owned_node_ids
                 .into_iter()
@@ -418,13 +425,13 @@ impl NativeProcedure for AccessRulesSetMethodMutabilityInvocation {
             let substate_ref = api.get_ref(handle)?;
             let auth_zone_stack = substate_ref.auth_zone_stack();
 
-            auth_zone_stack.check_auth(false, authorization).map_err(
-                |(authorization, error)| {
+            auth_zone_stack
+                .check_auth(false, authorization, &ledger_state)
+                .map_err(|(authorization, error, trace)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
+                        AccessRulesChainError::Unauthorized(authorization, error, trace),
                     ))
-                },
-            )?;
+                })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -499,6 +506,7 @@ impl NativeProcedure for AccessRulesSetGroupMutabilityInvocation {
 
         // Manual Auth
         {
+            let ledger_state = AuthModule::ledger_state(&authorization, api)?;
             let owned_node_ids = api.sys_get_visible_nodes()?;
             let node_id = owned_node_ids
                 .into_iter()
@@ -511,10 +519,10 @@ impl NativeProcedure for AccessRulesSetGroupMutabilityInvocation {
             let auth_zone_substate = substate_ref.auth_zone_stack();
 
             auth_zone_substate
-                .check_auth(false, authorization)
-                .map_err(|(authorization, error)| {
+                .check_auth(false, authorization, &ledger_state)
+                .map_err(|(authorization, error, trace)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
+                        AccessRulesChainError::Unauthorized(authorization, error, trace),
                     ))
                 })?;
         }
@@ -1,10 +1,10 @@
 use crate::engine::{
-    ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
+    ApplicationError, AuthModule, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
     NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
     SystemApi,
 };
 use crate::model::{
-    convert, InvokeError, MethodAuthorization, MethodAuthorizationError, ProofError,
+    convert, AuthTrace, InvokeError, MethodAuthorization, MethodAuthorizationError, ProofError,
 };
 use crate::types::*;
 use crate::wasm::WasmEngine;
@@ -26,7 +26,11 @@ pub enum AuthZoneError {
     CouldNotGetProof,
     CouldNotGetResource,
     NoMethodSpecified,
-    AssertAccessRuleError(MethodAuthorization, MethodAuthorizationError),
+    AssertAccessRuleError(
+        MethodAuthorization,
+        MethodAuthorizationError,
+        Option<Box<AuthTrace>>,
+    ),
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for AuthZonePopInvocation {
@@ -491,19 +495,25 @@ impl NativeProcedure for AuthZoneAssertAccessRuleInvocation {
     where
         Y: SystemApi,
     {
+        let authorizations = vec![convert(
+            &Type::Any,
+            &IndexedScryptoValue::unit(),
+            &self.access_rule,
+        )];
+        let ledger_state = AuthModule::ledger_state(&authorizations, api)?;
+
         let node_id = RENodeId::AuthZoneStack(self.receiver);
         let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
         let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate_ref = api.get_ref(handle)?;
         let auth_zone_stack = substate_ref.auth_zone_stack();
-        let authorization = convert(&Type::Any, &IndexedScryptoValue::unit(), &self.access_rule);
 
         // Authorization check
         auth_zone_stack
-            .check_auth(false, vec![authorization])
-            .map_err(|(authorization, error)| {
+            .check_auth(false, authorizations, &ledger_state)
+            .map_err(|(authorization, error, trace)| {
                 RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
-                    AuthZoneError::AssertAccessRuleError(authorization, error),
+                    AuthZoneError::AssertAccessRuleError(authorization, error, trace),
                 ))
             })?;
 
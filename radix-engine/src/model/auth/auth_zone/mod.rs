@@ -1,5 +1,7 @@
 mod executables;
 mod substates;
+mod trace;
 
 pub use executables::*;
 pub use substates::*;
+pub use trace::*;
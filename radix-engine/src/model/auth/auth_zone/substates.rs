@@ -1,11 +1,20 @@
 use crate::model::MethodAuthorizationError::NotAuthorized;
 use crate::model::{
-    AuthZoneError, HardAuthRule, HardCount, HardDecimal, HardProofRule, HardProofRuleResourceList,
-    HardResourceOrNonFungible, InvokeError, MethodAuthorization, MethodAuthorizationError,
-    ProofSubstate,
+    AuthTrace, AuthTraceNode, AuthTraceRule, AuthZoneError, HardAuthRule, HardCount, HardDecimal,
+    HardProofRule, HardProofRuleResourceList, HardResourceOrNonFungible, InvokeError,
+    MethodAuthorization, MethodAuthorizationError, ProofSubstate,
 };
 use crate::types::*;
-use sbor::rust::ops::Fn;
+use radix_engine_interface::time::*;
+use sbor::rust::ops::FnMut;
+
+/// The ledger state which time-based proof rules are verified against. Only the parts required
+/// by the authorizations being checked are loaded, see `AuthModule::ledger_state`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuthLedgerState {
+    pub current_time: Option<Instant>,
+    pub current_epoch: Option<u64>,
+}
 
 struct AuthVerification;
 
@@ -31,16 +40,20 @@ impl AuthVerification {
     pub fn check_auth_zones<P>(
         mut barriers_crossings_allowed: u32,
         auth_zones: &AuthZoneStackSubstate,
-        check: P,
+        mut check: P,
     ) -> bool
     where
-        P: Fn(&AuthZone) -> bool,
+        P: FnMut(&AuthZone) -> bool,
     {
         for auth_zone in auth_zones.auth_zones.iter().rev() {
             if check(auth_zone) {
                 return true;
             }
 
+            if auth_zone.isolated {
+                return false;
+            }
+
             if auth_zone.barrier {
                 if barriers_crossings_allowed == 0 {
                     return false;
@@ -100,88 +113,209 @@ impl AuthVerification {
         })
     }
 
+    fn verify_ledger_state(satisfied: Option<bool>) -> Result<(), MethodAuthorizationError> {
+        // Rules which can't be checked against the loaded ledger state are never satisfied
+        if satisfied.unwrap_or(false) {
+            Ok(())
+        } else {
+            Err(NotAuthorized)
+        }
+    }
+
+    /// Runs `verify`, recording it as a node of the trace if one is being collected
+    fn traced<R, V>(
+        trace: Option<&mut Vec<AuthTraceNode>>,
+        rule: R,
+        verify: V,
+    ) -> Result<(), MethodAuthorizationError>
+    where
+        R: FnOnce() -> AuthTraceRule,
+        V: FnOnce(Option<&mut Vec<AuthTraceNode>>) -> Result<(), MethodAuthorizationError>,
+    {
+        match trace {
+            Some(trace) => {
+                let mut children = Vec::new();
+                let result = verify(Some(&mut children));
+                trace.push(AuthTraceNode {
+                    rule: rule(),
+                    passed: result.is_ok(),
+                    children,
+                });
+                result
+            }
+            None => verify(None),
+        }
+    }
+
+    fn verify_listed_resource_rule(
+        barrier_crossings_allowed: u32,
+        resource_rule: &HardResourceOrNonFungible,
+        auth_zone: &AuthZoneStackSubstate,
+        trace: Option<&mut Vec<AuthTraceNode>>,
+    ) -> bool {
+        let passed =
+            Self::verify_resource_rule(barrier_crossings_allowed, resource_rule, auth_zone);
+        if let Some(trace) = trace {
+            trace.push(AuthTraceNode {
+                rule: AuthTraceRule::Resource(resource_rule.clone()),
+                passed,
+                children: Vec::new(),
+            });
+        }
+        passed
+    }
+
     pub fn verify_proof_rule(
         barrier_crossings_allowed: u32,
         proof_rule: &HardProofRule,
         auth_zone: &AuthZoneStackSubstate,
+        ledger_state: &AuthLedgerState,
+        trace: Option<&mut Vec<AuthTraceNode>>,
     ) -> Result<(), MethodAuthorizationError> {
-        match proof_rule {
-            HardProofRule::Require(resource) => {
-                if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
-                    Ok(())
-                } else {
-                    Err(NotAuthorized)
-                }
-            }
-            HardProofRule::AmountOf(HardDecimal::Amount(amount), resource) => {
-                if Self::check_has_amount(barrier_crossings_allowed, resource, *amount, auth_zone) {
-                    Ok(())
-                } else {
-                    Err(NotAuthorized)
-                }
-            }
-            HardProofRule::AllOf(HardProofRuleResourceList::List(resources)) => {
-                for resource in resources {
-                    if !Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
-                        return Err(NotAuthorized);
+        Self::traced(
+            trace,
+            || AuthTraceRule::ProofRule(proof_rule.clone()),
+            |mut trace| match proof_rule {
+                HardProofRule::Require(resource) => {
+                    if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
+                        Ok(())
+                    } else {
+                        Err(NotAuthorized)
                     }
                 }
-
-                Ok(())
-            }
-            HardProofRule::AnyOf(HardProofRuleResourceList::List(resources)) => {
-                for resource in resources {
-                    if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
-                        return Ok(());
+                HardProofRule::AmountOf(HardDecimal::Amount(amount), resource) => {
+                    if Self::check_has_amount(
+                        barrier_crossings_allowed,
+                        resource,
+                        *amount,
+                        auth_zone,
+                    ) {
+                        Ok(())
+                    } else {
+                        Err(NotAuthorized)
                     }
                 }
+                HardProofRule::AllOf(HardProofRuleResourceList::List(resources)) => {
+                    for resource in resources {
+                        if !Self::verify_listed_resource_rule(
+                            barrier_crossings_allowed,
+                            resource,
+                            auth_zone,
+                            trace.as_deref_mut(),
+                        ) {
+                            return Err(NotAuthorized);
+                        }
+                    }
 
-                Err(NotAuthorized)
-            }
-            HardProofRule::CountOf(
-                HardCount::Count(count),
-                HardProofRuleResourceList::List(resources),
-            ) => {
-                let mut left = count.clone();
-                for resource in resources {
-                    if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
-                        left -= 1;
-                        if left == 0 {
+                    Ok(())
+                }
+                HardProofRule::AnyOf(HardProofRuleResourceList::List(resources)) => {
+                    for resource in resources {
+                        if Self::verify_listed_resource_rule(
+                            barrier_crossings_allowed,
+                            resource,
+                            auth_zone,
+                            trace.as_deref_mut(),
+                        ) {
                             return Ok(());
                         }
                     }
+
+                    Err(NotAuthorized)
                 }
-                Err(NotAuthorized)
-            }
-            _ => Err(NotAuthorized),
-        }
+                HardProofRule::CountOf(
+                    HardCount::Count(count),
+                    HardProofRuleResourceList::List(resources),
+                ) => {
+                    let mut left = count.clone();
+                    for resource in resources {
+                        if Self::verify_listed_resource_rule(
+                            barrier_crossings_allowed,
+                            resource,
+                            auth_zone,
+                            trace.as_deref_mut(),
+                        ) {
+                            left -= 1;
+                            if left == 0 {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Err(NotAuthorized)
+                }
+                HardProofRule::AllowedAfter(instant) => Self::verify_ledger_state(
+                    ledger_state
+                        .current_time
+                        .map(|now| now.compare(*instant, TimeComparisonOperator::Gte)),
+                ),
+                HardProofRule::AllowedBefore(instant) => Self::verify_ledger_state(
+                    ledger_state
+                        .current_time
+                        .map(|now| now.compare(*instant, TimeComparisonOperator::Lt)),
+                ),
+                HardProofRule::WithinEpochRange(start, end) => Self::verify_ledger_state(
+                    ledger_state
+                        .current_epoch
+                        .map(|epoch| *start <= epoch && epoch < *end),
+                ),
+                _ => Err(NotAuthorized),
+            },
+        )
     }
 
     pub fn verify_auth_rule(
         barrier_crossings_allowed: u32,
         auth_rule: &HardAuthRule,
         auth_zone: &AuthZoneStackSubstate,
+        ledger_state: &AuthLedgerState,
+        trace: Option<&mut Vec<AuthTraceNode>>,
     ) -> Result<(), MethodAuthorizationError> {
         match auth_rule {
-            HardAuthRule::ProofRule(rule) => {
-                Self::verify_proof_rule(barrier_crossings_allowed, rule, auth_zone)
-            }
-            HardAuthRule::AnyOf(rules) => {
-                if !rules.iter().any(|r| {
-                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone).is_ok()
-                }) {
-                    return Err(NotAuthorized);
-                }
-                Ok(())
-            }
-            HardAuthRule::AllOf(rules) => {
-                if rules.iter().any(|r| {
-                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone).is_err()
-                }) {
-                    return Err(NotAuthorized);
-                }
-                Ok(())
-            }
+            HardAuthRule::ProofRule(rule) => Self::verify_proof_rule(
+                barrier_crossings_allowed,
+                rule,
+                auth_zone,
+                ledger_state,
+                trace,
+            ),
+            HardAuthRule::AnyOf(rules) => Self::traced(
+                trace,
+                || AuthTraceRule::AnyOf,
+                |mut trace| {
+                    if !rules.iter().any(|r| {
+                        Self::verify_auth_rule(
+                            barrier_crossings_allowed,
+                            r,
+                            auth_zone,
+                            ledger_state,
+                            trace.as_deref_mut(),
+                        )
+                        .is_ok()
+                    }) {
+                        return Err(NotAuthorized);
+                    }
+                    Ok(())
+                },
+            ),
+            HardAuthRule::AllOf(rules) => Self::traced(
+                trace,
+                || AuthTraceRule::AllOf,
+                |mut trace| {
+                    if rules.iter().any(|r| {
+                        Self::verify_auth_rule(
+                            barrier_crossings_allowed,
+                            r,
+                            auth_zone,
+                            ledger_state,
+                            trace.as_deref_mut(),
+                        )
+                        .is_err()
+                    }) {
+                        return Err(NotAuthorized);
+                    }
+                    Ok(())
+                },
+            ),
         }
     }
 
@@ -189,14 +323,26 @@ impl AuthVerification {
         barrier_crossings_allowed: u32,
         method_auth: &MethodAuthorization,
         auth_zone: &AuthZoneStackSubstate,
+        ledger_state: &AuthLedgerState,
+        trace: Option<&mut Vec<AuthTraceNode>>,
     ) -> Result<(), MethodAuthorizationError> {
         match method_auth {
-            MethodAuthorization::Protected(rule) => {
-                Self::verify_auth_rule(barrier_crossings_allowed, rule, auth_zone)
-            }
+            MethodAuthorization::Protected(rule) => Self::verify_auth_rule(
+                barrier_crossings_allowed,
+                rule,
+                auth_zone,
+                ledger_state,
+                trace,
+            ),
             MethodAuthorization::AllowAll => Ok(()),
-            MethodAuthorization::DenyAll => Err(NotAuthorized),
-            MethodAuthorization::Unsupported => Err(MethodAuthorizationError::UnsupportedMethod),
+            MethodAuthorization::DenyAll => {
+                Self::traced(trace, || AuthTraceRule::DenyAll, |_| Err(NotAuthorized))
+            }
+            MethodAuthorization::Unsupported => Self::traced(
+                trace,
+                || AuthTraceRule::Unsupported,
+                |_| Err(MethodAuthorizationError::UnsupportedMethod),
+            ),
         }
     }
 }
@@ -205,6 +351,8 @@ impl AuthVerification {
 #[derive(Debug)]
 pub struct AuthZoneStackSubstate {
     auth_zones: Vec<AuthZone>,
+    /// Whether failed authorizations are verified once more, to record how they were evaluated
+    trace_failures: bool,
 }
 
 impl AuthZoneStackSubstate {
@@ -212,6 +360,7 @@ impl AuthZoneStackSubstate {
         proofs: Vec<ProofSubstate>,
         virtual_resources: BTreeSet<ResourceAddress>,
         virtual_non_fungibles: BTreeSet<NonFungibleAddress>,
+        trace_failures: bool,
     ) -> Self {
         Self {
             auth_zones: vec![AuthZone::new_with_virtual_proofs(
@@ -220,6 +369,7 @@ impl AuthZoneStackSubstate {
                 virtual_non_fungibles,
                 false,
             )],
+            trace_failures,
         }
     }
 
@@ -227,25 +377,116 @@ impl AuthZoneStackSubstate {
         &self,
         is_barrier: bool,
         method_auths: Vec<MethodAuthorization>,
-    ) -> Result<(), (MethodAuthorization, MethodAuthorizationError)> {
+        ledger_state: &AuthLedgerState,
+    ) -> Result<
+        (),
+        (
+            MethodAuthorization,
+            MethodAuthorizationError,
+            Option<Box<AuthTrace>>,
+        ),
+    > {
         let mut barrier_crossings_allowed = 1u32;
         if is_barrier {
             barrier_crossings_allowed -= 1;
         }
 
         for method_auth in method_auths {
-            AuthVerification::verify_method_auth(barrier_crossings_allowed, &method_auth, &self)
-                .map_err(|e| (method_auth, e))?;
+            if let Err(error) = AuthVerification::verify_method_auth(
+                barrier_crossings_allowed,
+                &method_auth,
+                &self,
+                ledger_state,
+                None,
+            ) {
+                if !self.trace_failures {
+                    return Err((method_auth, error, None));
+                }
+
+                // Only failures pay for tracing, by verifying once more
+                let mut trace = Vec::new();
+                let _ = AuthVerification::verify_method_auth(
+                    barrier_crossings_allowed,
+                    &method_auth,
+                    &self,
+                    ledger_state,
+                    Some(&mut trace),
+                );
+                let root = trace.pop().expect("Failed authorization was not traced");
+                let trace =
+                    Box::new(self.auth_trace(barrier_crossings_allowed, root, ledger_state));
+                return Err((method_auth, error, Some(trace)));
+            }
         }
 
         Ok(())
     }
 
+    fn auth_trace(
+        &self,
+        barrier_crossings_allowed: u32,
+        root: AuthTraceNode,
+        ledger_state: &AuthLedgerState,
+    ) -> AuthTrace {
+        let mut trace = AuthTrace {
+            root,
+            proofs: Vec::new(),
+            virtual_resources: BTreeSet::new(),
+            virtual_non_fungibles: BTreeSet::new(),
+            current_time: ledger_state.current_time,
+            current_epoch: ledger_state.current_epoch,
+        };
+        AuthVerification::check_auth_zones(barrier_crossings_allowed, self, |auth_zone| {
+            trace
+                .proofs
+                .extend(auth_zone.proofs.iter().map(|p| p.snapshot()));
+            trace
+                .virtual_resources
+                .extend(auth_zone.virtual_resources.iter().cloned());
+            trace
+                .virtual_non_fungibles
+                .extend(auth_zone.virtual_non_fungibles.iter().cloned());
+            false
+        });
+        trace
+    }
+
     pub fn new_frame(&mut self, barrier: bool) {
         let auth_zone = AuthZone::empty(barrier);
         self.auth_zones.push(auth_zone);
     }
 
+    pub fn new_frame_with_virtual_non_fungibles(
+        &mut self,
+        barrier: bool,
+        virtual_non_fungibles: BTreeSet<NonFungibleAddress>,
+    ) {
+        let auth_zone = AuthZone::new_with_virtual_proofs(
+            vec![],
+            BTreeSet::new(),
+            virtual_non_fungibles,
+            barrier,
+        );
+        self.auth_zones.push(auth_zone);
+    }
+
+    /// Pushes a frame which hides the frames below it from authorization, whatever the barrier
+    /// crossings allowed.
+    pub fn new_isolated_frame(
+        &mut self,
+        virtual_resources: BTreeSet<ResourceAddress>,
+        virtual_non_fungibles: BTreeSet<NonFungibleAddress>,
+    ) {
+        let mut auth_zone = AuthZone::new_with_virtual_proofs(
+            vec![],
+            virtual_resources,
+            virtual_non_fungibles,
+            true,
+        );
+        auth_zone.isolated = true;
+        self.auth_zones.push(auth_zone);
+    }
+
     pub fn pop_frame(&mut self) {
         if let Some(mut auth_zone) = self.auth_zones.pop() {
             auth_zone.clear()
@@ -274,6 +515,7 @@ pub struct AuthZone {
     virtual_resources: BTreeSet<ResourceAddress>,
     virtual_non_fungibles: BTreeSet<NonFungibleAddress>,
     barrier: bool,
+    isolated: bool,
 }
 
 impl AuthZone {
@@ -283,6 +525,7 @@ impl AuthZone {
             virtual_resources: BTreeSet::new(),
             virtual_non_fungibles: BTreeSet::new(),
             barrier,
+            isolated: false,
         }
     }
 
@@ -297,6 +540,7 @@ impl AuthZone {
             virtual_resources,
             virtual_non_fungibles,
             barrier,
+            isolated: false,
         }
     }
 
@@ -0,0 +1,196 @@
+use crate::engine::ProofSnapshot;
+use crate::model::{
+    HardCount, HardDecimal, HardProofRule, HardProofRuleResourceList, HardResourceOrNonFungible,
+    LockedAmountOrIds,
+};
+use crate::types::*;
+use radix_engine_interface::address::AddressDisplayContext;
+use radix_engine_interface::time::Instant;
+use utils::ContextualDisplay;
+
+/// The rule evaluated at a node of an authorization trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum AuthTraceRule {
+    DenyAll,
+    Unsupported,
+    AnyOf,
+    AllOf,
+    ProofRule(HardProofRule),
+    /// A single resource of a `require_any_of`, `require_all_of` or `require_n_of` proof rule
+    Resource(HardResourceOrNonFungible),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AuthTraceNode {
+    pub rule: AuthTraceRule,
+    pub passed: bool,
+    /// The nodes evaluated to decide this one, in evaluation order. Evaluation stops as soon as
+    /// the outcome is known, so later children may be missing.
+    pub children: Vec<AuthTraceNode>,
+}
+
+/// How a failed authorization was evaluated, along with the proofs and ledger state it was
+/// verified against. Dynamic rules appear with the values they resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct AuthTrace {
+    pub root: AuthTraceNode,
+    /// The proofs of all auth zones reachable from the checking frame
+    pub proofs: Vec<ProofSnapshot>,
+    pub virtual_resources: BTreeSet<ResourceAddress>,
+    pub virtual_non_fungibles: BTreeSet<NonFungibleAddress>,
+    pub current_time: Option<Instant>,
+    pub current_epoch: Option<u64>,
+}
+
+fn format_resource(
+    resource: &HardResourceOrNonFungible,
+    context: &AddressDisplayContext,
+) -> String {
+    match resource {
+        HardResourceOrNonFungible::NonFungible(non_fungible_address) => {
+            non_fungible_address.display(*context).to_string()
+        }
+        HardResourceOrNonFungible::Resource(resource_address) => {
+            resource_address.display(*context).to_string()
+        }
+        HardResourceOrNonFungible::SoftResourceNotFound => "<resource not found>".to_string(),
+    }
+}
+
+fn format_rule(rule: &AuthTraceRule, context: &AddressDisplayContext) -> String {
+    match rule {
+        AuthTraceRule::DenyAll => "deny_all".to_string(),
+        AuthTraceRule::Unsupported => "unsupported".to_string(),
+        AuthTraceRule::AnyOf => "any of".to_string(),
+        AuthTraceRule::AllOf => "all of".to_string(),
+        AuthTraceRule::Resource(resource) => format_resource(resource, context),
+        AuthTraceRule::ProofRule(proof_rule) => {
+            let list = |name: &str, list: &HardProofRuleResourceList| match list {
+                HardProofRuleResourceList::List(..) => name.to_string(),
+                HardProofRuleResourceList::SoftResourceListNotFound => {
+                    format!("{}(<resource list not found>)", name)
+                }
+            };
+            match proof_rule {
+                HardProofRule::Require(resource) => {
+                    format!("require({})", format_resource(resource, context))
+                }
+                HardProofRule::AmountOf(amount, resource) => format!(
+                    "require_amount({}, {})",
+                    match amount {
+                        HardDecimal::Amount(amount) => amount.to_string(),
+                        HardDecimal::SoftDecimalNotFound => "<amount not found>".to_string(),
+                    },
+                    format_resource(resource, context)
+                ),
+                HardProofRule::AllOf(resources) => list("require_all_of", resources),
+                HardProofRule::AnyOf(resources) => list("require_any_of", resources),
+                HardProofRule::CountOf(count, resources) => format!(
+                    "{} with count {}",
+                    list("require_n_of", resources),
+                    match count {
+                        HardCount::Count(count) => count.to_string(),
+                        HardCount::SoftCountNotFound => "<count not found>".to_string(),
+                    }
+                ),
+                HardProofRule::AllowedAfter(instant) => {
+                    format!("allowed_after({})", instant.seconds_since_unix_epoch)
+                }
+                HardProofRule::AllowedBefore(instant) => {
+                    format!("allowed_before({})", instant.seconds_since_unix_epoch)
+                }
+                HardProofRule::WithinEpochRange(start, end) => {
+                    format!("within_epoch_range({}, {})", start, end)
+                }
+            }
+        }
+    }
+}
+
+fn format_node<F: fmt::Write>(
+    f: &mut F,
+    node: &AuthTraceNode,
+    prefix: &str,
+    is_last: bool,
+    context: &AddressDisplayContext,
+) -> fmt::Result {
+    write!(
+        f,
+        "\n{}{} [{}] {}",
+        prefix,
+        if is_last { "└─" } else { "├─" },
+        if node.passed { "PASS" } else { "FAIL" },
+        format_rule(&node.rule, context)
+    )?;
+    let child_prefix = format!("{}{}", prefix, if is_last { "   " } else { "│  " });
+    for (i, child) in node.children.iter().enumerate() {
+        format_node(
+            f,
+            child,
+            &child_prefix,
+            i == node.children.len() - 1,
+            context,
+        )?;
+    }
+    Ok(())
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for AuthTrace {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        write!(f, "Rule:")?;
+        format_node(f, &self.root, "", true, context)?;
+
+        write!(
+            f,
+            "\nProofs: {}",
+            self.proofs.len() + self.virtual_resources.len() + self.virtual_non_fungibles.len()
+        )?;
+        for proof in &self.proofs {
+            write!(
+                f,
+                "\n- {}: {}",
+                proof.resource_address.display(*context),
+                match &proof.total_locked {
+                    LockedAmountOrIds::Amount(amount) => amount.to_string(),
+                    LockedAmountOrIds::Ids(ids) => ids
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                }
+            )?;
+        }
+        for resource_address in &self.virtual_resources {
+            write!(f, "\n- {} (virtual)", resource_address.display(*context))?;
+        }
+        for non_fungible_address in &self.virtual_non_fungibles {
+            write!(
+                f,
+                "\n- {} (virtual)",
+                non_fungible_address.display(*context)
+            )?;
+        }
+
+        if let Some(current_time) = self.current_time {
+            write!(
+                f,
+                "\nCurrent time: {}",
+                current_time.seconds_since_unix_epoch
+            )?;
+        }
+        if let Some(current_epoch) = self.current_epoch {
+            write!(f, "\nCurrent epoch: {}", current_epoch)?;
+        }
+
+        Ok(())
+    }
+}
@@ -200,6 +200,9 @@ fn soft_to_hard_proof_rule(
             let hard_resources = soft_to_hard_resource_list(schema, resources, value);
             HardProofRule::CountOf(hard_count, hard_resources)
         }
+        ProofRule::AllowedAfter(instant) => HardProofRule::AllowedAfter(*instant),
+        ProofRule::AllowedBefore(instant) => HardProofRule::AllowedBefore(*instant),
+        ProofRule::WithinEpochRange(start, end) => HardProofRule::WithinEpochRange(*start, *end),
     }
 }
 
@@ -54,12 +54,14 @@ impl NativeProcedure for ClockCreateInvocation {
         let underlying_node_id = system_api.allocate_node_id(RENodeType::Clock)?;
 
         let auth_non_fungible = NonFungibleAddress::new(SYSTEM_TOKEN, AuthModule::supervisor_id());
+        // The system may also set the time, which genesis relies on
+        let system_non_fungible = NonFungibleAddress::new(SYSTEM_TOKEN, AuthModule::system_id());
         let mut access_rules = AccessRules::new();
         access_rules.set_method_access_rule(
             AccessRuleKey::Native(NativeFn::Method(NativeMethod::Clock(
                 ClockMethod::SetCurrentTime,
             ))),
-            rule!(require(auth_non_fungible)),
+            rule!(require(auth_non_fungible) || require(system_non_fungible)),
         );
         access_rules.set_method_access_rule(
             AccessRuleKey::Native(NativeFn::Method(NativeMethod::Clock(
@@ -1,6 +1,13 @@
 use crate::types::*;
+use radix_engine_interface::time::Instant;
 
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
 pub struct CurrentTimeRoundedToMinutesSubstate {
     pub current_time_rounded_to_minutes_ms: i64,
 }
+
+impl CurrentTimeRoundedToMinutesSubstate {
+    pub fn instant(&self) -> Instant {
+        Instant::new(self.current_time_rounded_to_minutes_ms / 1000)
+    }
+}
@@ -1,15 +1,16 @@
 use crate::engine::{
-    deref_and_update, AuthModule, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
-    NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod, ResolverApi, RuntimeError,
-    SystemApi,
+    deref_and_update, ApplicationError, AuthModule, CallFrameUpdate, ExecutableInvocation,
+    LockFlags, NativeExecutor, NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod,
+    ResolverApi, RuntimeError, SystemApi,
 };
 use crate::model::{
-    AccessRulesChainSubstate, EpochManagerSubstate, GlobalAddressSubstate, HardAuthRule,
-    HardProofRule, HardResourceOrNonFungible, MethodAuthorization,
+    AccessRulesChainSubstate, EpochManagerSubstate, FeeConfigSubstate, GlobalAddressSubstate,
+    HardAuthRule, HardProofRule, HardResourceOrNonFungible, MethodAuthorization, Validator,
+    ValidatorSetSubstate,
 };
 use crate::types::*;
 use crate::wasm::WasmEngine;
-use radix_engine_interface::api::api::EngineApi;
+use radix_engine_interface::api::api::{EngineApi, InvokableModel};
 use radix_engine_interface::api::types::{
     EpochManagerFunction, EpochManagerMethod, EpochManagerOffset, GlobalAddress, NativeFunction,
     NativeMethod, RENodeId, SubstateOffset,
@@ -17,9 +18,19 @@ use radix_engine_interface::api::types::{
 use radix_engine_interface::model::*;
 use radix_engine_interface::rule;
 
-#[derive(Debug, Clone, Eq, PartialEq, TypeId, Encode, Decode)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[scrypto(TypeId, Encode, Decode)]
 pub enum EpochManagerError {
     InvalidRequestData(DecodeError),
+    ValidatorAlreadyRegistered(EcdsaSecp256k1PublicKey),
+    ValidatorNotFound(EcdsaSecp256k1PublicKey),
+    ValidatorNotRegistered(EcdsaSecp256k1PublicKey),
+    InvalidStakeResource(ResourceAddress),
+    InvalidStakeUnitResource(ResourceAddress),
+    InvalidUnstakeClaimResource(ResourceAddress),
+    UnstakeClaimNotReady { claimable_epoch: u64 },
+    UnexpectedFeeConfigVersion { expected: u64, actual: u64 },
+    InvalidCostUnitPrice(Decimal),
 }
 
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
@@ -57,26 +68,63 @@ impl NativeProcedure for EpochManagerCreateInvocation {
         let underlying_node_id = api.allocate_node_id(RENodeType::EpochManager)?;
 
         let epoch_manager = EpochManagerSubstate { epoch: 0 };
+        let validator_set = ValidatorSetSubstate {
+            max_validators: self.max_validators,
+            unbonding_delay_in_epochs: self.unbonding_delay_in_epochs,
+            validators: BTreeMap::new(),
+            active_set: ValidatorSet::new(),
+        };
+        EpochManager::validate_fee_config(&self.fee_config)?;
+        let fee_config = FeeConfigSubstate {
+            version: 0,
+            config: self.fee_config,
+        };
 
         let auth_non_fungible = NonFungibleAddress::new(SYSTEM_TOKEN, AuthModule::supervisor_id());
+        // The system may also drive epochs, which genesis relies on
+        let system_non_fungible = NonFungibleAddress::new(SYSTEM_TOKEN, AuthModule::system_id());
         let mut access_rules = AccessRules::new();
         access_rules.set_method_access_rule(
             AccessRuleKey::Native(NativeFn::Method(NativeMethod::EpochManager(
                 EpochManagerMethod::SetEpoch,
             ))),
-            rule!(require(auth_non_fungible)),
+            rule!(require(auth_non_fungible.clone()) || require(system_non_fungible.clone())),
         );
         access_rules.set_method_access_rule(
             AccessRuleKey::Native(NativeFn::Method(NativeMethod::EpochManager(
-                EpochManagerMethod::GetCurrentEpoch,
+                EpochManagerMethod::NextEpoch,
+            ))),
+            rule!(require(auth_non_fungible.clone()) || require(system_non_fungible.clone())),
+        );
+        access_rules.set_method_access_rule(
+            AccessRuleKey::Native(NativeFn::Method(NativeMethod::EpochManager(
+                EpochManagerMethod::UpdateFeeConfig,
             ))),
-            rule!(allow_all),
+            rule!(require(auth_non_fungible) || require(system_non_fungible)),
         );
+        // Registration is checked against the validator key within the methods
+        for method in [
+            EpochManagerMethod::GetCurrentEpoch,
+            EpochManagerMethod::GetValidatorSet,
+            EpochManagerMethod::RegisterValidator,
+            EpochManagerMethod::UnregisterValidator,
+            EpochManagerMethod::GetValidator,
+            EpochManagerMethod::Stake,
+            EpochManagerMethod::Unstake,
+            EpochManagerMethod::ClaimXrd,
+        ] {
+            access_rules.set_method_access_rule(
+                AccessRuleKey::Native(NativeFn::Method(NativeMethod::EpochManager(method))),
+                rule!(allow_all),
+            );
+        }
 
         api.create_node(
             underlying_node_id,
             RENode::EpochManager(
                 epoch_manager,
+                validator_set,
+                fee_config,
                 AccessRulesChainSubstate {
                     access_rules_chain: vec![access_rules],
                 },
@@ -193,7 +241,832 @@ impl NativeProcedure for EpochManagerSetEpochExecutable {
     }
 }
 
+pub struct EpochManagerNextEpochExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerNextEpochInvocation {
+    type Exec = NativeExecutor<EpochManagerNextEpochExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(EpochManagerMethod::NextEpoch)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerNextEpochExecutable(resolved_receiver.receiver));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerNextEpochExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let validator_set_handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let (max_validators, candidates) = {
+            let substate_ref = api.get_ref(validator_set_handle)?;
+            let validator_set = substate_ref.validator_set();
+            let candidates: Vec<(EcdsaSecp256k1PublicKey, VaultId)> = validator_set
+                .validators
+                .iter()
+                .filter(|(_, validator)| validator.is_registered)
+                .map(|(key, validator)| (*key, validator.stake_vault_id))
+                .collect();
+            (validator_set.max_validators, candidates)
+        };
+
+        let mut staked = Vec::new();
+        for (key, stake_vault_id) in candidates {
+            let stake = api.invoke(VaultGetAmountInvocation {
+                receiver: stake_vault_id,
+            })?;
+            if stake.is_positive() {
+                staked.push((key, stake));
+            }
+        }
+        // Largest stake first, ties broken by key so that the outcome is deterministic
+        staked.sort_by(|(k1, s1), (k2, s2)| s2.cmp(s1).then(k1.cmp(k2)));
+        let active_set: ValidatorSet = staked.into_iter().take(max_validators as usize).collect();
+
+        {
+            let mut substate_mut = api.get_ref_mut(validator_set_handle)?;
+            substate_mut.validator_set().active_set = active_set;
+        }
+        api.drop_lock(validator_set_handle)?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::EpochManager);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(handle)?;
+        substate_mut.epoch_manager().epoch += 1;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct EpochManagerGetValidatorSetExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerGetValidatorSetInvocation {
+    type Exec = NativeExecutor<EpochManagerGetValidatorSetExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(
+                EpochManagerMethod::GetValidatorSet,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerGetValidatorSetExecutable(
+            resolved_receiver.receiver,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerGetValidatorSetExecutable {
+    type Output = ValidatorSet;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(ValidatorSet, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate_ref = api.get_ref(handle)?;
+        let active_set = substate_ref.validator_set().active_set.clone();
+
+        Ok((active_set, CallFrameUpdate::empty()))
+    }
+}
+
+pub struct EpochManagerRegisterValidatorExecutable(RENodeId, EcdsaSecp256k1PublicKey);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerRegisterValidatorInvocation {
+    type Exec = NativeExecutor<EpochManagerRegisterValidatorExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        // The stake vaults of a new validator are created from XRD
+        let mut call_frame_update =
+            CallFrameUpdate::copy_ref(RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)));
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(
+                EpochManagerMethod::RegisterValidator,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerRegisterValidatorExecutable(
+            resolved_receiver.receiver,
+            self.key,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerRegisterValidatorExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        // Only the validator's key, or the system at genesis, may register it
+        let auth_zone_id = api
+            .get_visible_node_ids()?
+            .into_iter()
+            .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
+            .expect("AuthZone does not exist");
+        api.invoke(AuthZoneAssertAccessRuleInvocation {
+            receiver: auth_zone_id.into(),
+            access_rule: rule!(
+                require(NonFungibleAddress::from_public_key(&self.1))
+                    || require(NonFungibleAddress::new(
+                        SYSTEM_TOKEN,
+                        AuthModule::system_id()
+                    ))
+            ),
+        })?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let is_registered = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .validator_set()
+                .validators
+                .get(&self.1)
+                .map(|validator| validator.is_registered)
+        };
+
+        match is_registered {
+            Some(true) => {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::EpochManagerError(
+                        EpochManagerError::ValidatorAlreadyRegistered(self.1),
+                    ),
+                ));
+            }
+            Some(false) => {
+                let mut substate_mut = api.get_ref_mut(handle)?;
+                let validator = substate_mut
+                    .validator_set()
+                    .validators
+                    .get_mut(&self.1)
+                    .unwrap();
+                validator.is_registered = true;
+            }
+            None => {
+                let access_rules = EpochManager::validator_resource_access_rules();
+                let mut metadata = HashMap::new();
+                metadata.insert("validator".to_owned(), self.1.to_string());
+
+                let (stake_unit_resource, _) = api.invoke(ResourceManagerCreateInvocation {
+                    resource_type: ResourceType::Fungible { divisibility: 18 },
+                    metadata: metadata.clone(),
+                    access_rules: access_rules.clone(),
+                    mint_params: None,
+                    max_supply: None,
+                    non_fungible_data_schema: None,
+                })?;
+                let (unstake_claim_resource, _) = api.invoke(ResourceManagerCreateInvocation {
+                    resource_type: ResourceType::NonFungible {
+                        id_type: NonFungibleIdType::U64,
+                    },
+                    metadata,
+                    access_rules,
+                    mint_params: None,
+                    max_supply: None,
+                    non_fungible_data_schema: None,
+                })?;
+                let stake_vault = api.invoke(ResourceManagerCreateVaultInvocation {
+                    receiver: RADIX_TOKEN,
+                })?;
+                let unstake_vault = api.invoke(ResourceManagerCreateVaultInvocation {
+                    receiver: RADIX_TOKEN,
+                })?;
+                let validator = Validator {
+                    is_registered: true,
+                    stake_vault_id: stake_vault.0,
+                    unstake_vault_id: unstake_vault.0,
+                    stake_unit_resource,
+                    unstake_claim_resource,
+                    next_unstake_claim_id: 0,
+                };
+                let mut substate_mut = api.get_ref_mut(handle)?;
+                substate_mut
+                    .validator_set()
+                    .validators
+                    .insert(self.1, validator);
+            }
+        }
+        api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct EpochManagerUnregisterValidatorExecutable(RENodeId, EcdsaSecp256k1PublicKey);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerUnregisterValidatorInvocation {
+    type Exec = NativeExecutor<EpochManagerUnregisterValidatorExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(
+                EpochManagerMethod::UnregisterValidator,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerUnregisterValidatorExecutable(
+            resolved_receiver.receiver,
+            self.key,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerUnregisterValidatorExecutable {
+    type Output = ();
+
+    fn main<Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        // Only the validator's key may unregister it
+        let auth_zone_id = api
+            .get_visible_node_ids()?
+            .into_iter()
+            .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
+            .expect("AuthZone does not exist");
+        api.invoke(AuthZoneAssertAccessRuleInvocation {
+            receiver: auth_zone_id.into(),
+            access_rule: rule!(require(NonFungibleAddress::from_public_key(&self.1))),
+        })?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(handle)?;
+        let validator = substate_mut
+            .validator_set()
+            .validators
+            .get_mut(&self.1)
+            .ok_or(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::ValidatorNotFound(self.1)),
+            ))?;
+        if !validator.is_registered {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::ValidatorNotRegistered(
+                    self.1,
+                )),
+            ));
+        }
+        // The validator stays in the active set until the next epoch change
+        validator.is_registered = false;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct EpochManagerGetValidatorExecutable(RENodeId, EcdsaSecp256k1PublicKey);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerGetValidatorInvocation {
+    type Exec = NativeExecutor<EpochManagerGetValidatorExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(EpochManagerMethod::GetValidator)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerGetValidatorExecutable(
+            resolved_receiver.receiver,
+            self.key,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerGetValidatorExecutable {
+    type Output = Option<ValidatorInfo>;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Option<ValidatorInfo>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let validator = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .validator_set()
+                .validators
+                .get(&self.1)
+                .cloned()
+        };
+
+        let info = match validator {
+            Some(validator) => {
+                let stake = api.invoke(VaultGetAmountInvocation {
+                    receiver: validator.stake_vault_id,
+                })?;
+                Some(ValidatorInfo {
+                    is_registered: validator.is_registered,
+                    stake,
+                    stake_unit_resource: validator.stake_unit_resource,
+                    unstake_claim_resource: validator.unstake_claim_resource,
+                })
+            }
+            None => None,
+        };
+        api.drop_lock(handle)?;
+
+        Ok((info, CallFrameUpdate::empty()))
+    }
+}
+
+pub struct EpochManagerStakeExecutable(RENodeId, EcdsaSecp256k1PublicKey, Bucket);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerStakeInvocation {
+    type Exec = NativeExecutor<EpochManagerStakeExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::move_node(RENodeId::Bucket(self.stake.0));
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(EpochManagerMethod::Stake)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerStakeExecutable(
+            resolved_receiver.receiver,
+            self.key,
+            self.stake,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerStakeExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let validator = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .validator_set()
+                .validators
+                .get(&self.1)
+                .cloned()
+        }
+        .ok_or(RuntimeError::ApplicationError(
+            ApplicationError::EpochManagerError(EpochManagerError::ValidatorNotFound(self.1)),
+        ))?;
+        if !validator.is_registered {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::ValidatorNotRegistered(
+                    self.1,
+                )),
+            ));
+        }
+
+        let resource_address = api.invoke(BucketGetResourceAddressInvocation {
+            receiver: self.2 .0,
+        })?;
+        if resource_address != RADIX_TOKEN {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::InvalidStakeResource(
+                    resource_address,
+                )),
+            ));
+        }
+
+        let amount = api.invoke(BucketGetAmountInvocation {
+            receiver: self.2 .0,
+        })?;
+        let stake = api.invoke(VaultGetAmountInvocation {
+            receiver: validator.stake_vault_id,
+        })?;
+        let total_stake_units = api.invoke(ResourceManagerGetTotalSupplyInvocation {
+            receiver: validator.stake_unit_resource,
+        })?;
+        // Stake units track the validator's share of the stake vault
+        let stake_units = if stake.is_zero() || total_stake_units.is_zero() {
+            amount
+        } else {
+            amount * total_stake_units / stake
+        };
+
+        api.invoke(VaultPutInvocation {
+            receiver: validator.stake_vault_id,
+            bucket: self.2,
+        })?;
+        let bucket = api.invoke(ResourceManagerMintInvocation {
+            receiver: validator.stake_unit_resource,
+            mint_params: MintParams::Fungible {
+                amount: stake_units,
+            },
+        })?;
+        api.drop_lock(handle)?;
+
+        let bucket_id = bucket.0;
+        Ok((
+            bucket,
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+}
+
+pub struct EpochManagerUnstakeExecutable(RENodeId, EcdsaSecp256k1PublicKey, Bucket);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerUnstakeInvocation {
+    type Exec = NativeExecutor<EpochManagerUnstakeExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update =
+            CallFrameUpdate::move_node(RENodeId::Bucket(self.stake_units.0));
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(EpochManagerMethod::Unstake)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerUnstakeExecutable(
+            resolved_receiver.receiver,
+            self.key,
+            self.stake_units,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerUnstakeExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let epoch = {
+            let offset = SubstateOffset::EpochManager(EpochManagerOffset::EpochManager);
+            let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.epoch_manager().epoch
+        };
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let validator = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .validator_set()
+                .validators
+                .get(&self.1)
+                .cloned()
+        }
+        .ok_or(RuntimeError::ApplicationError(
+            ApplicationError::EpochManagerError(EpochManagerError::ValidatorNotFound(self.1)),
+        ))?;
+        let unbonding_delay_in_epochs = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.validator_set().unbonding_delay_in_epochs
+        };
+
+        let resource_address = api.invoke(BucketGetResourceAddressInvocation {
+            receiver: self.2 .0,
+        })?;
+        if resource_address != validator.stake_unit_resource {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::InvalidStakeUnitResource(
+                    resource_address,
+                )),
+            ));
+        }
+
+        let stake_units = api.invoke(BucketGetAmountInvocation {
+            receiver: self.2 .0,
+        })?;
+        let stake = api.invoke(VaultGetAmountInvocation {
+            receiver: validator.stake_vault_id,
+        })?;
+        let total_stake_units = api.invoke(ResourceManagerGetTotalSupplyInvocation {
+            receiver: validator.stake_unit_resource,
+        })?;
+        let amount = if total_stake_units.is_zero() {
+            Decimal::zero()
+        } else {
+            stake_units * stake / total_stake_units
+        };
+
+        api.invoke(ResourceManagerBurnInvocation {
+            receiver: validator.stake_unit_resource,
+            bucket: self.2,
+        })?;
+        let unstaked = api.invoke(VaultTakeInvocation {
+            receiver: validator.stake_vault_id,
+            amount,
+        })?;
+        api.invoke(VaultPutInvocation {
+            receiver: validator.unstake_vault_id,
+            bucket: unstaked,
+        })?;
+
+        let claim = UnstakeClaim {
+            amount,
+            claimable_epoch: epoch + unbonding_delay_in_epochs,
+        };
+        let mut entries = HashMap::new();
+        entries.insert(
+            NonFungibleId::U64(validator.next_unstake_claim_id),
+            (
+                scrypto_encode(&claim).unwrap(),
+                scrypto_encode(&()).unwrap(),
+            ),
+        );
+        let bucket = api.invoke(ResourceManagerMintInvocation {
+            receiver: validator.unstake_claim_resource,
+            mint_params: MintParams::NonFungible { entries },
+        })?;
+
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            let validator = substate_mut
+                .validator_set()
+                .validators
+                .get_mut(&self.1)
+                .unwrap();
+            validator.next_unstake_claim_id += 1;
+        }
+        api.drop_lock(handle)?;
+
+        let bucket_id = bucket.0;
+        Ok((
+            bucket,
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+}
+
+pub struct EpochManagerClaimXrdExecutable(RENodeId, EcdsaSecp256k1PublicKey, Bucket);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerClaimXrdInvocation {
+    type Exec = NativeExecutor<EpochManagerClaimXrdExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update =
+            CallFrameUpdate::move_node(RENodeId::Bucket(self.unstake_claims.0));
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(EpochManagerMethod::ClaimXrd)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerClaimXrdExecutable(
+            resolved_receiver.receiver,
+            self.key,
+            self.unstake_claims,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerClaimXrdExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let epoch = {
+            let offset = SubstateOffset::EpochManager(EpochManagerOffset::EpochManager);
+            let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.epoch_manager().epoch
+        };
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorSet);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let validator = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref
+                .validator_set()
+                .validators
+                .get(&self.1)
+                .cloned()
+        }
+        .ok_or(RuntimeError::ApplicationError(
+            ApplicationError::EpochManagerError(EpochManagerError::ValidatorNotFound(self.1)),
+        ))?;
+
+        let resource_address = api.invoke(BucketGetResourceAddressInvocation {
+            receiver: self.2 .0,
+        })?;
+        if resource_address != validator.unstake_claim_resource {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(
+                    EpochManagerError::InvalidUnstakeClaimResource(resource_address),
+                ),
+            ));
+        }
+
+        let ids = api.invoke(BucketGetNonFungibleIdsInvocation {
+            receiver: self.2 .0,
+        })?;
+        let mut amount = Decimal::zero();
+        for id in ids {
+            let [immutable_data, _] = api.invoke(ResourceManagerGetNonFungibleInvocation {
+                receiver: validator.unstake_claim_resource,
+                id,
+            })?;
+            let claim: UnstakeClaim = scrypto_decode(&immutable_data).map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                    EpochManagerError::InvalidRequestData(e),
+                ))
+            })?;
+            if claim.claimable_epoch > epoch {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::EpochManagerError(EpochManagerError::UnstakeClaimNotReady {
+                        claimable_epoch: claim.claimable_epoch,
+                    }),
+                ));
+            }
+            amount += claim.amount;
+        }
+
+        api.invoke(ResourceManagerBurnInvocation {
+            receiver: validator.unstake_claim_resource,
+            bucket: self.2,
+        })?;
+        let bucket = api.invoke(VaultTakeInvocation {
+            receiver: validator.unstake_vault_id,
+            amount,
+        })?;
+        api.drop_lock(handle)?;
+
+        let bucket_id = bucket.0;
+        Ok((
+            bucket,
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+}
+
+pub struct EpochManagerUpdateFeeConfigExecutable(RENodeId, u64, FeeConfig);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerUpdateFeeConfigInvocation {
+    type Exec = NativeExecutor<EpochManagerUpdateFeeConfigExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::EpochManager(
+                EpochManagerMethod::UpdateFeeConfig,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(EpochManagerUpdateFeeConfigExecutable(
+            resolved_receiver.receiver,
+            self.version,
+            self.fee_config,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for EpochManagerUpdateFeeConfigExecutable {
+    type Output = ();
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        EpochManager::validate_fee_config(&self.2)?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::FeeConfig);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = system_api.get_ref_mut(handle)?;
+        let fee_config = substate_mut.fee_config();
+        // Updates apply in order and only once
+        let expected = fee_config.version + 1;
+        if self.1 != expected {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(
+                    EpochManagerError::UnexpectedFeeConfigVersion {
+                        expected,
+                        actual: self.1,
+                    },
+                ),
+            ));
+        }
+        fee_config.version = self.1;
+        fee_config.config = self.2;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
 impl EpochManager {
+    fn validate_fee_config(fee_config: &FeeConfig) -> Result<(), RuntimeError> {
+        // The fee reserve works with the price in attos as a `u128`
+        if u128::try_from(fee_config.cost_unit_price.0).is_err() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::InvalidCostUnitPrice(
+                    fee_config.cost_unit_price,
+                )),
+            ));
+        }
+        Ok(())
+    }
+
     pub fn function_auth(func: &EpochManagerFunction) -> Vec<MethodAuthorization> {
         match func {
             EpochManagerFunction::Create => {
@@ -205,4 +1078,26 @@ impl EpochManager {
             }
         }
     }
+
+    /// Mint and burn of a validator's stake units and unstake claims are restricted to the
+    /// epoch manager itself.
+    fn validator_resource_access_rules() -> HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>
+    {
+        let epoch_manager_badge =
+            NonFungibleAddress::new(SYSTEM_TOKEN, AuthModule::epoch_manager_id());
+        let mut access_rules = HashMap::new();
+        access_rules.insert(
+            ResourceMethodAuthKey::Withdraw,
+            (rule!(allow_all), rule!(deny_all)),
+        );
+        access_rules.insert(
+            ResourceMethodAuthKey::Mint,
+            (rule!(require(epoch_manager_badge.clone())), rule!(deny_all)),
+        );
+        access_rules.insert(
+            ResourceMethodAuthKey::Burn,
+            (rule!(require(epoch_manager_badge)), rule!(deny_all)),
+        );
+        access_rules
+    }
 }
@@ -4,3 +4,37 @@ use crate::types::*;
 pub struct EpochManagerSubstate {
     pub epoch: u64,
 }
+
+/// The fee configuration applied to every transaction, replaced by system transactions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct FeeConfigSubstate {
+    /// Incremented by every update, starting from 0 at genesis
+    pub version: u64,
+    pub config: FeeConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ValidatorSetSubstate {
+    /// The maximum number of validators in an epoch's active set.
+    pub max_validators: u32,
+    /// The number of epochs unstaked XRD stays locked before it can be claimed.
+    pub unbonding_delay_in_epochs: u64,
+    pub validators: BTreeMap<EcdsaSecp256k1PublicKey, Validator>,
+    /// The active set of the current epoch, computed on the last epoch change.
+    pub active_set: ValidatorSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct Validator {
+    pub is_registered: bool,
+    /// Holds the XRD staked to this validator.
+    pub stake_vault_id: VaultId,
+    /// Holds unstaked XRD until it is claimed.
+    pub unstake_vault_id: VaultId,
+    pub stake_unit_resource: ResourceAddress,
+    pub unstake_claim_resource: ResourceAddress,
+    pub next_unstake_claim_id: u64,
+}
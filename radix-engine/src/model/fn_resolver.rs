@@ -1,9 +1,10 @@
 use crate::types::*;
 use radix_engine_interface::api::types::{
-    AccessRulesChainMethod, AuthZoneStackMethod, BucketMethod, EpochManagerFunction,
-    EpochManagerMethod, GlobalAddress, NativeFunction, NativeMethod, PackageFunction, ProofMethod,
-    RENodeId, ResourceManagerFunction, ResourceManagerMethod, TransactionProcessorFunction,
-    VaultMethod, WorktopMethod,
+    AccessControllerFunction, AccessControllerMethod, AccessRulesChainMethod, AccountFunction,
+    AccountMethod, AuthZoneStackMethod, BucketMethod, EpochManagerFunction, EpochManagerMethod,
+    GlobalAddress, NativeFunction, NativeMethod, PackageFunction, ProofMethod, RENodeId,
+    ResourceManagerFunction, ResourceManagerMethod, TransactionProcessorFunction, VaultMethod,
+    WorktopMethod,
 };
 
 pub fn resolve_native_function(
@@ -17,6 +18,12 @@ pub fn resolve_native_function(
         CLOCK_BLUEPRINT => ClockFunction::from_str(function_name)
             .ok()
             .map(NativeFunction::Clock),
+        ACCOUNT_BLUEPRINT => AccountFunction::from_str(function_name)
+            .ok()
+            .map(NativeFunction::Account),
+        ACCESS_CONTROLLER_BLUEPRINT => AccessControllerFunction::from_str(function_name)
+            .ok()
+            .map(NativeFunction::AccessController),
         RESOURCE_MANAGER_BLUEPRINT => ResourceManagerFunction::from_str(function_name)
             .ok()
             .map(NativeFunction::ResourceManager),
@@ -38,6 +45,20 @@ pub fn resolve_native_method(receiver: RENodeId, method_name: &str) -> Option<Na
                 .ok()
                 .map(NativeMethod::Package)
         }
+        RENodeId::Account(_)
+        | RENodeId::Global(GlobalAddress::Component(
+            ComponentAddress::Account(..)
+            | ComponentAddress::EcdsaSecp256k1VirtualAccount(..)
+            | ComponentAddress::EddsaEd25519VirtualAccount(..),
+        )) => AccountMethod::from_str(method_name)
+            .ok()
+            .map(NativeMethod::Account),
+        RENodeId::AccessController(_)
+        | RENodeId::Global(GlobalAddress::Component(ComponentAddress::AccessController(..))) => {
+            AccessControllerMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::AccessController)
+        }
         RENodeId::Component(_) | RENodeId::Global(GlobalAddress::Component(_)) => {
             ComponentMethod::from_str(method_name)
                 .ok()
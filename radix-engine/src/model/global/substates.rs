@@ -10,6 +10,8 @@ pub enum GlobalAddressSubstate {
     Package(PackageId),
     EpochManager(EpochManagerId),
     Clock(ClockId),
+    Account(AccountId),
+    AccessController(AccessControllerId),
 }
 
 impl GlobalAddressSubstate {
@@ -20,6 +22,8 @@ impl GlobalAddressSubstate {
             GlobalAddressSubstate::Package(id) => RENodeId::Package(*id),
             GlobalAddressSubstate::EpochManager(id) => RENodeId::EpochManager(*id),
             GlobalAddressSubstate::Clock(id) => RENodeId::Clock(*id),
+            GlobalAddressSubstate::Account(id) => RENodeId::Account(*id),
+            GlobalAddressSubstate::AccessController(id) => RENodeId::AccessController(*id),
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::engine::{
-    deref_and_update, CallFrameUpdate, ExecutableInvocation, InterpreterError, LockFlags,
-    NativeExecutor, NativeProcedure, REActor, ResolvedMethod, ResolverApi, RuntimeError, SystemApi,
+    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, InterpreterError,
+    LockFlags, NativeExecutor, NativeProcedure, REActor, ResolvedMethod, ResolverApi, RuntimeError,
+    SystemApi,
 };
 use crate::types::*;
 use crate::wasm::WasmEngine;
@@ -8,6 +9,16 @@ use radix_engine_interface::api::api::EngineApi;
 use radix_engine_interface::api::types::{NativeMethod, RENodeId, SubstateOffset};
 use radix_engine_interface::model::*;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum MetadataError {
+    UnexpectedValueType {
+        key: String,
+        expected: MetadataValueType,
+    },
+    InvalidUrl(String),
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for MetadataSetInvocation {
     type Exec = NativeExecutor<Self>;
 
@@ -52,7 +63,9 @@ impl NativeProcedure for MetadataSetInvocation {
 
         let mut substate_ref_mut = system_api.get_ref_mut(handle)?;
         let metadata = substate_ref_mut.metadata();
-        metadata.metadata.insert(self.key, self.value);
+        metadata
+            .insert(self.key, self.value)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::MetadataError(e)))?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
@@ -91,7 +104,7 @@ impl<W: WasmEngine> ExecutableInvocation<W> for MetadataGetInvocation {
 }
 
 impl NativeProcedure for MetadataGetInvocation {
-    type Output = Option<String>;
+    type Output = Option<MetadataValue>;
 
     fn main<Y>(self, api: &mut Y) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
     where
@@ -1,14 +1,57 @@
+use crate::model::MetadataError;
 use crate::types::*;
+use radix_engine_interface::model::{is_valid_metadata_url, well_known_metadata_type};
 
 /// A transient resource container.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct MetadataSubstate {
-    pub metadata: HashMap<String, String>,
+    pub metadata: HashMap<String, MetadataValue>,
 }
 
 impl MetadataSubstate {
-    pub fn insert(&mut self, key: String, value: String) {
+    /// Creates the metadata from plain strings, which become values of the type of their key if
+    /// the key is well-known.
+    pub fn from_strings(metadata: HashMap<String, String>) -> Result<Self, MetadataError> {
+        let mut substate = Self {
+            metadata: HashMap::new(),
+        };
+        for (key, value) in metadata {
+            let value = match well_known_metadata_type(&key) {
+                Some(MetadataValueType::Url) => MetadataValue::Url(value),
+                _ => MetadataValue::String(value),
+            };
+            substate.insert(key, value)?;
+        }
+        Ok(substate)
+    }
+
+    pub fn insert(&mut self, key: String, value: MetadataValue) -> Result<(), MetadataError> {
+        validate_metadata_entry(&key, &value)?;
         self.metadata.insert(key, value);
+        Ok(())
+    }
+}
+
+/// Checks the value of a well-known key has the type of the key, and that all URLs are valid.
+pub fn validate_metadata_entry(key: &str, value: &MetadataValue) -> Result<(), MetadataError> {
+    if let Some(expected) = well_known_metadata_type(key) {
+        if !value.is_of_type(&expected) {
+            return Err(MetadataError::UnexpectedValueType {
+                key: key.to_string(),
+                expected,
+            });
+        }
+    }
+    validate_urls(value)
+}
+
+fn validate_urls(value: &MetadataValue) -> Result<(), MetadataError> {
+    match value {
+        MetadataValue::Url(url) if !is_valid_metadata_url(url) => {
+            Err(MetadataError::InvalidUrl(url.clone()))
+        }
+        MetadataValue::List(values) => values.iter().try_for_each(validate_urls),
+        _ => Ok(()),
     }
 }
@@ -1,6 +1,7 @@
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 use radix_engine_interface::scrypto;
+use radix_engine_interface::time::Instant;
 use sbor::rust::vec::Vec;
 use sbor::*;
 
@@ -58,6 +59,9 @@ pub enum HardProofRule {
     AllOf(HardProofRuleResourceList),
     AnyOf(HardProofRuleResourceList),
     CountOf(HardCount, HardProofRuleResourceList),
+    AllowedAfter(Instant),
+    AllowedBefore(Instant),
+    WithinEpochRange(u64, u64),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -68,6 +72,20 @@ pub enum HardAuthRule {
     AllOf(Vec<HardAuthRule>),
 }
 
+impl HardAuthRule {
+    fn any_proof_rule<P>(&self, predicate: &P) -> bool
+    where
+        P: Fn(&HardProofRule) -> bool,
+    {
+        match self {
+            HardAuthRule::ProofRule(rule) => predicate(rule),
+            HardAuthRule::AnyOf(rules) | HardAuthRule::AllOf(rules) => {
+                rules.iter().any(|r| r.any_proof_rule(predicate))
+            }
+        }
+    }
+}
+
 /// Authorization of a method call
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[scrypto(TypeId, Encode, Decode)]
@@ -77,3 +95,30 @@ pub enum MethodAuthorization {
     DenyAll,
     Unsupported,
 }
+
+impl MethodAuthorization {
+    fn any_proof_rule<P>(&self, predicate: P) -> bool
+    where
+        P: Fn(&HardProofRule) -> bool,
+    {
+        match self {
+            MethodAuthorization::Protected(rule) => rule.any_proof_rule(&predicate),
+            _ => false,
+        }
+    }
+
+    /// Whether verifying this authorization requires the current ledger time
+    pub fn requires_current_time(&self) -> bool {
+        self.any_proof_rule(|rule| {
+            matches!(
+                rule,
+                HardProofRule::AllowedAfter(..) | HardProofRule::AllowedBefore(..)
+            )
+        })
+    }
+
+    /// Whether verifying this authorization requires the current epoch
+    pub fn requires_current_epoch(&self) -> bool {
+        self.any_proof_rule(|rule| matches!(rule, HardProofRule::WithinEpochRange(..)))
+    }
+}
@@ -1,4 +1,7 @@
 mod abi_extractor;
+mod access_controller;
+mod access_rules_analyzer;
+mod account;
 mod auth;
 mod auth_converter;
 mod clock;
@@ -21,6 +24,9 @@ mod transaction_processor;
 pub use self::scrypto::*;
 pub use crate::engine::InvokeError;
 pub use abi_extractor::*;
+pub use access_controller::*;
+pub use access_rules_analyzer::*;
+pub use account::*;
 pub use auth::*;
 pub use auth_converter::convert;
 pub use clock::*;
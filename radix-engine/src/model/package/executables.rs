@@ -81,9 +81,8 @@ impl NativeProcedure for PackagePublishInvocation {
         let package_royalty_accumulator = PackageRoyaltyAccumulatorSubstate {
             royalty: Resource::new_empty(RADIX_TOKEN, ResourceType::Fungible { divisibility: 18 }),
         };
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let metadata_substate = MetadataSubstate::from_strings(self.metadata)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::MetadataError(e)))?;
         let access_rules = AccessRulesChainSubstate {
             access_rules_chain: vec![self.access_rules],
         };
@@ -161,9 +160,8 @@ impl NativeProcedure for PackagePublishWithOwnerInvocation {
         let package_royalty_accumulator = PackageRoyaltyAccumulatorSubstate {
             royalty: Resource::new_empty(RADIX_TOKEN, ResourceType::Fungible { divisibility: 18 }),
         };
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let metadata_substate = MetadataSubstate::from_strings(self.metadata)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::MetadataError(e)))?;
 
         let global_node_id = api.allocate_node_id(RENodeType::GlobalPackage)?;
         let package_address: PackageAddress = global_node_id.into();
@@ -1,7 +1,7 @@
 use crate::engine::{
-    ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
-    NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
-    SystemApi,
+    ApplicationError, CallFrameUpdate, Event, ExecutableInvocation, LockFlags, NativeEvent,
+    NativeExecutor, NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver,
+    ResolverApi, ResourceEvent, ResourceEventKind, RuntimeError, SystemApi, TrackedEvent,
 };
 use crate::model::{BucketSubstate, ProofError, ResourceOperationError};
 use crate::types::*;
@@ -60,6 +60,9 @@ impl NativeProcedure for BucketTakeInvocation {
                 BucketError::ResourceOperationError(e),
             ))
         })?;
+        api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+            ResourceEvent::new(ResourceEventKind::BucketTake(self.receiver), &container),
+        ))))?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -155,6 +158,9 @@ impl NativeProcedure for BucketTakeNonFungiblesInvocation {
                 BucketError::ResourceOperationError(e),
             ))
         })?;
+        api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+            ResourceEvent::new(ResourceEventKind::BucketTake(self.receiver), &container),
+        ))))?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -276,16 +282,26 @@ impl NativeProcedure for BucketPutInvocation {
         let offset = SubstateOffset::Bucket(BucketOffset::Bucket);
         let bucket_handle = system_api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
 
-        let other_bucket = system_api
+        let other_bucket: BucketSubstate = system_api
             .drop_node(RENodeId::Bucket(self.bucket.0))?
             .into();
-        let mut substate_mut = system_api.get_ref_mut(bucket_handle)?;
-        let bucket = substate_mut.bucket();
-        bucket.put(other_bucket).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::BucketError(
-                BucketError::ResourceOperationError(e),
-            ))
-        })?;
+        let event = ResourceEvent::new(
+            ResourceEventKind::BucketPut(self.receiver),
+            &other_bucket.peek_resource(),
+        );
+
+        {
+            let mut substate_mut = system_api.get_ref_mut(bucket_handle)?;
+            let bucket = substate_mut.bucket();
+            bucket.put(other_bucket).map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::BucketError(
+                    BucketError::ResourceOperationError(e),
+                ))
+            })?;
+        }
+        system_api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+            event,
+        ))))?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
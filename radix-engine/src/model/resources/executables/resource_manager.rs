@@ -1,7 +1,8 @@
 use crate::engine::{
-    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags,
-    NativeExecutor, NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod,
-    ResolverApi, RuntimeError, SystemApi,
+    deref_and_update, ApplicationError, CallFrameUpdate, Event, ExecutableInvocation, LockFlags,
+    NativeEvent, NativeExecutor, NativeProcedure, REActor, RENode, ResolvedFunction,
+    ResolvedMethod, ResolverApi, ResourceEvent, ResourceEventKind, RuntimeError, SystemApi,
+    TrackedEvent,
 };
 use crate::model::{
     AccessRulesChainSubstate, BucketSubstate, GlobalAddressSubstate, InvokeError, MetadataSubstate,
@@ -19,7 +20,7 @@ use radix_engine_interface::api::types::{
 };
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::AccessRule::{AllowAll, DenyAll};
-use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Recall, Withdraw};
+use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Freeze, Recall, Withdraw};
 use radix_engine_interface::model::*;
 use radix_engine_interface::{dec, rule, scrypto};
 
@@ -43,6 +44,10 @@ pub enum ResourceManagerError {
     MismatchingBucketResource,
     ResourceAddressAlreadySet,
     NonFungibleIdTypeDoesNotMatch(NonFungibleIdType, NonFungibleIdType),
+    MaxSupplyExceeded(Decimal),
+    NonFungibleDataSchemaNotFound,
+    ImmutableNonFungibleField(String),
+    NonFungibleFieldNotFound(String),
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerBucketBurnInvocation {
@@ -117,6 +122,10 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerCreateWithOwnerIn
             ResourceMethodAuthKey::Recall,
             (DenyAll, rule!(require(owner_badge.clone()))),
         );
+        access_rules.insert(
+            ResourceMethodAuthKey::Freeze,
+            (DenyAll, rule!(require(owner_badge.clone()))),
+        );
         access_rules.insert(Mint, (DenyAll, rule!(require(owner_badge.clone()))));
         access_rules.insert(Burn, (DenyAll, rule!(require(owner_badge.clone()))));
         access_rules.insert(
@@ -139,6 +148,8 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerCreateWithOwnerIn
             metadata: self.metadata,
             access_rules,
             mint_params: self.mint_params,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
         };
 
         let executor = NativeExecutor(invocation);
@@ -149,6 +160,8 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerCreateWithOwnerIn
 fn build_resource_manager_substate<Y>(
     resource_address: ResourceAddress,
     resource_type: ResourceType,
+    max_supply: Option<Decimal>,
+    non_fungible_data_schema: Option<NonFungibleDataSchema>,
     mint_params: Option<MintParams>,
     api: &mut Y,
 ) -> Result<(ResourceManagerSubstate, Option<Bucket>), RuntimeError>
@@ -163,17 +176,30 @@ where
         )?;
         let nf_store_id: NonFungibleStoreId = nf_store_node_id.into();
 
-        let mut resource_manager =
-            ResourceManagerSubstate::new(resource_type, Some(nf_store_id), resource_address)
-                .map_err(|e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                    }
-                    InvokeError::Downstream(e) => e,
-                })?;
+        let mut resource_manager = ResourceManagerSubstate::new(
+            resource_type,
+            max_supply,
+            non_fungible_data_schema,
+            Some(nf_store_id),
+            resource_address,
+        )
+        .map_err(|e| match e {
+            InvokeError::Error(e) => {
+                RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
+            }
+            InvokeError::Downstream(e) => e,
+        })?;
 
         let bucket = if let Some(mint_params) = mint_params {
             if let MintParams::NonFungible { entries } = mint_params {
+                resource_manager
+                    .check_max_supply(entries.len().into())
+                    .map_err(|e| match e {
+                        InvokeError::Error(e) => RuntimeError::ApplicationError(
+                            ApplicationError::ResourceManagerError(e),
+                        ),
+                        InvokeError::Downstream(e) => e,
+                    })?;
                 for (non_fungible_id, data) in &entries {
                     if non_fungible_id.id_type() != id_type {
                         return Err(RuntimeError::ApplicationError(
@@ -185,6 +211,14 @@ where
                             ),
                         ));
                     }
+                    resource_manager
+                        .check_non_fungible_data(&data.0, &data.1)
+                        .map_err(|e| match e {
+                            InvokeError::Error(e) => RuntimeError::ApplicationError(
+                                ApplicationError::ResourceManagerError(e),
+                            ),
+                            InvokeError::Downstream(e) => e,
+                        })?;
 
                     let offset = SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(
                         non_fungible_id.clone(),
@@ -193,14 +227,16 @@ where
                         api.lock_substate(nf_store_node_id, offset, LockFlags::MUTABLE)?;
                     let mut substate_mut = api.get_ref_mut(non_fungible_handle)?;
                     let non_fungible_mut = substate_mut.non_fungible();
-                    *non_fungible_mut = NonFungibleSubstate(Some(
-                        NonFungible::new(data.0.clone(), data.1.clone()), // FIXME: verify data
-                    ));
+                    *non_fungible_mut =
+                        NonFungibleSubstate(Some(NonFungible::new(data.0.clone(), data.1.clone())));
                     api.drop_lock(non_fungible_handle)?;
                 }
                 resource_manager.total_supply = entries.len().into();
                 let ids = entries.into_keys().collect();
                 let container = Resource::new_non_fungible(resource_address, ids, id_type);
+                api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+                    ResourceEvent::new(ResourceEventKind::Mint, &container),
+                ))))?;
                 let node_id = api.allocate_node_id(RENodeType::Bucket)?;
                 api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
                 let bucket_id = node_id.into();
@@ -218,15 +254,19 @@ where
 
         (resource_manager, bucket)
     } else {
-        let mut resource_manager =
-            ResourceManagerSubstate::new(resource_type, None, resource_address).map_err(
-                |e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                    }
-                    InvokeError::Downstream(e) => e,
-                },
-            )?;
+        let mut resource_manager = ResourceManagerSubstate::new(
+            resource_type,
+            max_supply,
+            non_fungible_data_schema,
+            None,
+            resource_address,
+        )
+        .map_err(|e| match e {
+            InvokeError::Error(e) => {
+                RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
+            }
+            InvokeError::Downstream(e) => e,
+        })?;
 
         let bucket = if let Some(mint_params) = mint_params {
             if let MintParams::Fungible { amount } = mint_params {
@@ -244,9 +284,20 @@ where
                         ),
                     ));
                 }
+                resource_manager
+                    .check_max_supply(amount)
+                    .map_err(|e| match e {
+                        InvokeError::Error(e) => RuntimeError::ApplicationError(
+                            ApplicationError::ResourceManagerError(e),
+                        ),
+                        InvokeError::Downstream(e) => e,
+                    })?;
                 resource_manager.total_supply = amount;
                 let container =
                     Resource::new_fungible(resource_address, resource_type.divisibility(), amount);
+                api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+                    ResourceEvent::new(ResourceEventKind::Mint, &container),
+                ))))?;
                 let node_id = api.allocate_node_id(RENodeType::Bucket)?;
                 api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
                 let bucket_id = node_id.into();
@@ -318,6 +369,13 @@ fn build_substates(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
             ResourceManagerMethod::UpdateNonFungibleData,
         ))),
+        update_non_fungible_data_access_rule.clone(),
+        update_non_fungible_data_mutability.clone(),
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::UpdateNonFungibleDataField,
+        ))),
         update_non_fungible_data_access_rule,
         update_non_fungible_data_mutability,
     );
@@ -342,6 +400,13 @@ fn build_substates(
         AllowAll,
         DenyAll,
     );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::GetMaxSupply,
+        ))),
+        AllowAll,
+        DenyAll,
+    );
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
             ResourceManagerMethod::CreateVault,
@@ -391,6 +456,9 @@ fn build_substates(
     let (recall_access_rule, recall_mutability) = access_rules_map
         .remove(&ResourceMethodAuthKey::Recall)
         .unwrap_or((DenyAll, rule!(deny_all)));
+    let (freeze_access_rule, freeze_mutability) = access_rules_map
+        .remove(&ResourceMethodAuthKey::Freeze)
+        .unwrap_or((DenyAll, rule!(deny_all)));
 
     let mut vault_access_rules = AccessRules::new();
     vault_access_rules.set_group_access_rule_and_mutability(
@@ -403,6 +471,11 @@ fn build_substates(
         recall_access_rule,
         recall_mutability,
     );
+    vault_access_rules.set_group_access_rule_and_mutability(
+        "freeze".to_string(),
+        freeze_access_rule,
+        freeze_mutability,
+    );
     vault_access_rules.set_group_and_mutability(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(VaultMethod::Take))),
         "withdraw".to_string(),
@@ -420,6 +493,30 @@ fn build_substates(
         "withdraw".to_string(),
         DenyAll,
     );
+    vault_access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(
+            VaultMethod::LockSponsoredFee,
+        ))),
+        "withdraw".to_string(),
+        DenyAll,
+    );
+    vault_access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(
+            VaultMethod::BurnNonFungibles,
+        ))),
+        "withdraw".to_string(),
+        DenyAll,
+    );
+    vault_access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(VaultMethod::Freeze))),
+        "freeze".to_string(),
+        DenyAll,
+    );
+    vault_access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(VaultMethod::Unfreeze))),
+        "freeze".to_string(),
+        DenyAll,
+    );
 
     vault_access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(VaultMethod::Put))),
@@ -492,13 +589,14 @@ impl NativeProcedure for ResourceManagerCreateInvocation {
         let (resource_manager_substate, bucket) = build_resource_manager_substate(
             resource_address,
             self.resource_type,
+            self.max_supply,
+            self.non_fungible_data_schema,
             self.mint_params,
             api,
         )?;
         let (substate, vault_substate) = build_substates(self.access_rules);
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let metadata_substate = MetadataSubstate::from_strings(self.metadata)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::MetadataError(e)))?;
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
         api.create_node(
@@ -597,6 +695,10 @@ impl NativeProcedure for ResourceManagerBurnExecutable {
             resource_manager.total_supply -= bucket.total_amount();
         }
 
+        system_api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+            ResourceEvent::new(ResourceEventKind::Burn, &bucket.peek_resource()),
+        ))))?;
+
         // Burn non-fungible
         let substate_ref = system_api.get_ref(resman_handle)?;
         let resource_manager = substate_ref.resource_manager();
@@ -684,6 +786,7 @@ impl NativeProcedure for ResourceManagerUpdateVaultAuthExecutable {
                 }
                 Withdraw => substate.access_rules_chain[0].get_group_mutability("withdraw"),
                 Recall => substate.access_rules_chain[0].get_group_mutability("recall"),
+                Freeze => substate.access_rules_chain[0].get_group_mutability("freeze"),
             }
             .clone();
 
@@ -710,6 +813,10 @@ impl NativeProcedure for ResourceManagerUpdateVaultAuthExecutable {
                 let group_key = "recall".to_string();
                 substate.access_rules_chain[0].set_group_access_rule(group_key, self.2);
             }
+            VaultMethodAuthKey::Freeze => {
+                let group_key = "freeze".to_string();
+                substate.access_rules_chain[0].set_group_access_rule(group_key, self.2);
+            }
         }
 
         Ok(((), CallFrameUpdate::empty()))
@@ -777,6 +884,7 @@ impl NativeProcedure for ResourceManagerLockVaultAuthExecutable {
                 }
                 Withdraw => substate.access_rules_chain[0].get_group_mutability("withdraw"),
                 Recall => substate.access_rules_chain[0].get_group_mutability("recall"),
+                Freeze => substate.access_rules_chain[0].get_group_mutability("freeze"),
             }
             .clone();
 
@@ -803,6 +911,10 @@ impl NativeProcedure for ResourceManagerLockVaultAuthExecutable {
                 let group_key = "recall".to_string();
                 substate.access_rules_chain[0].set_group_mutability(group_key, self.2);
             }
+            Freeze => {
+                let group_key = "freeze".to_string();
+                substate.access_rules_chain[0].set_group_mutability(group_key, self.2);
+            }
         }
 
         Ok(((), CallFrameUpdate::empty()))
@@ -971,6 +1083,9 @@ impl NativeProcedure for ResourceManagerMintExecutable {
                 })?;
             result
         };
+        api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+            ResourceEvent::new(ResourceEventKind::Mint, &resource),
+        ))))?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(resource)))?;
@@ -1110,6 +1225,53 @@ impl NativeProcedure for ResourceManagerGetTotalSupplyExecutable {
     }
 }
 
+impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerGetMaxSupplyInvocation {
+    type Exec = NativeExecutor<ResourceManagerGetMaxSupplyExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::GetMaxSupply,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerGetMaxSupplyExecutable(
+            resolved_receiver.receiver,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerGetMaxSupplyExecutable(RENodeId);
+
+impl NativeProcedure for ResourceManagerGetMaxSupplyExecutable {
+    type Output = Option<Decimal>;
+
+    fn main<'a, Y>(
+        self,
+        system_api: &mut Y,
+    ) -> Result<(Option<Decimal>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate_ref = system_api.get_ref(resman_handle)?;
+        let max_supply = substate_ref.resource_manager().max_supply;
+
+        Ok((max_supply, CallFrameUpdate::empty()))
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerUpdateNonFungibleDataInvocation {
     type Exec = NativeExecutor<ResourceManagerUpdateNonFungibleDataExecutable>;
 
@@ -1162,6 +1324,14 @@ impl NativeProcedure for ResourceManagerUpdateNonFungibleDataExecutable {
                 InvokeError::Downstream(runtime_error) => runtime_error,
             })?;
         let resource_address = resource_manager.resource_address;
+        resource_manager
+            .check_mutable_non_fungible_data(&self.2)
+            .map_err(|e| match e {
+                InvokeError::Error(e) => {
+                    RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
+                }
+                InvokeError::Downstream(runtime_error) => runtime_error,
+            })?;
 
         let node_id = RENodeId::NonFungibleStore(nf_store_id);
         let offset =
@@ -1187,6 +1357,100 @@ impl NativeProcedure for ResourceManagerUpdateNonFungibleDataExecutable {
     }
 }
 
+impl<W: WasmEngine> ExecutableInvocation<W>
+    for ResourceManagerUpdateNonFungibleDataFieldInvocation
+{
+    type Exec = NativeExecutor<ResourceManagerUpdateNonFungibleDataFieldExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::UpdateNonFungibleDataField,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerUpdateNonFungibleDataFieldExecutable(
+            resolved_receiver.receiver,
+            self.id,
+            self.field,
+            self.data,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerUpdateNonFungibleDataFieldExecutable(
+    RENodeId,
+    NonFungibleId,
+    String,
+    Vec<u8>,
+);
+
+impl NativeProcedure for ResourceManagerUpdateNonFungibleDataFieldExecutable {
+    type Output = ();
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+
+        let substate_ref = system_api.get_ref(resman_handle)?;
+        let resource_manager = substate_ref.resource_manager().clone();
+        let nf_store_id = resource_manager
+            .nf_store_id
+            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))
+            .map_err(|e| match e {
+                InvokeError::Error(e) => {
+                    RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
+                }
+                InvokeError::Downstream(runtime_error) => runtime_error,
+            })?;
+
+        let node_id = RENodeId::NonFungibleStore(nf_store_id);
+        let offset =
+            SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(self.1.clone()));
+
+        let non_fungible_handle = system_api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = system_api.get_ref_mut(non_fungible_handle)?;
+        let non_fungible_mut = substate_mut.non_fungible();
+        if let Some(ref mut non_fungible) = non_fungible_mut.0 {
+            let mutable_data = resource_manager
+                .update_non_fungible_data_field(&non_fungible.mutable_data(), &self.2, &self.3)
+                .map_err(|e| match e {
+                    InvokeError::Error(e) => {
+                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
+                    }
+                    InvokeError::Downstream(runtime_error) => runtime_error,
+                })?;
+            non_fungible.set_mutable_data(mutable_data);
+        } else {
+            let non_fungible_address =
+                NonFungibleAddress::new(resource_manager.resource_address, self.1);
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ResourceManagerError(ResourceManagerError::NonFungibleNotFound(
+                    non_fungible_address,
+                )),
+            ));
+        }
+
+        system_api.drop_lock(non_fungible_handle)?;
+        system_api.drop_lock(resman_handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerNonFungibleExistsInvocation {
     type Exec = NativeExecutor<ResourceManagerNonFungibleExistsExecutable>;
 
@@ -1,16 +1,19 @@
 use crate::engine::{
-    ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
-    NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
-    SystemApi,
+    ApplicationError, CallFrameUpdate, Event, ExecutableInvocation, LockFlags, NativeEvent,
+    NativeExecutor, NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver,
+    ResolverApi, ResourceEvent, ResourceEventKind, RuntimeError, SystemApi, TrackedEvent,
 };
 use crate::fee::FeeReserveError;
 use crate::model::{
-    BucketSubstate, InvokeError, ProofError, ResourceContainerId, ResourceOperationError,
+    BucketSubstate, InvokeError, ProofError, Resource, ResourceContainerId, ResourceOperationError,
+    VaultRuntimeSubstate,
 };
 use crate::types::*;
 use crate::wasm::WasmEngine;
+use radix_engine_interface::api::api::InvokableModel;
 use radix_engine_interface::api::types::{
-    GlobalAddress, NativeMethod, RENodeId, SubstateOffset, VaultMethod, VaultOffset,
+    EpochManagerOffset, GlobalAddress, NativeMethod, RENodeId, SubstateOffset, VaultId,
+    VaultMethod, VaultOffset,
 };
 use radix_engine_interface::model::*;
 
@@ -26,6 +29,11 @@ pub enum VaultError {
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance,
     LockFeeRepayFailure(FeeReserveError),
+    VaultFrozen,
+    FeeSponsorshipExpired {
+        expires_at_epoch: u64,
+        current_epoch: u64,
+    },
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for VaultRecallInvocation {
@@ -78,9 +86,13 @@ impl NativeProcedure for VaultTakeInvocation {
         let vault_handle =
             api.lock_substate(RENodeId::Vault(self.receiver), offset, LockFlags::MUTABLE)?;
 
+        let is_recall = is_recall(api);
         let container = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
+            if !is_recall {
+                check_not_frozen(vault)?;
+            }
             vault.take(self.amount).map_err(|e| match e {
                 InvokeError::Error(e) => {
                     RuntimeError::ApplicationError(ApplicationError::VaultError(e))
@@ -88,6 +100,7 @@ impl NativeProcedure for VaultTakeInvocation {
                 InvokeError::Downstream(runtime_error) => runtime_error,
             })?
         };
+        emit_withdraw_event(api, self.receiver, &container)?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -132,17 +145,27 @@ impl NativeProcedure for VaultPutInvocation {
         let offset = SubstateOffset::Vault(VaultOffset::Vault);
         let vault_handle = system_api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
 
-        let bucket = system_api
+        let bucket: BucketSubstate = system_api
             .drop_node(RENodeId::Bucket(self.bucket.0))?
             .into();
+        let event = ResourceEvent::new(
+            ResourceEventKind::VaultDeposit(self.receiver),
+            &bucket.peek_resource(),
+        );
 
-        let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
-        let vault = substate_mut.vault();
-        vault.put(bucket).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::VaultError(
-                VaultError::ResourceOperationError(e),
-            ))
-        })?;
+        {
+            let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
+            let vault = substate_mut.vault();
+            check_not_frozen(vault)?;
+            vault.put(bucket).map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::VaultError(
+                    VaultError::ResourceOperationError(e),
+                ))
+            })?;
+        }
+        system_api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+            event,
+        ))))?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
@@ -173,48 +196,124 @@ impl NativeProcedure for VaultLockFeeInvocation {
     where
         Y: SystemApi,
     {
-        let node_id = RENodeId::Vault(self.receiver);
-        let offset = SubstateOffset::Vault(VaultOffset::Vault);
-        let vault_handle = system_api.lock_substate(
-            node_id,
-            offset,
-            LockFlags::MUTABLE | LockFlags::UNMODIFIED_BASE | LockFlags::FORCE_WRITE,
-        )?;
+        let contingent = self.contingent;
+        lock_fee_from_vault(self.receiver, self.amount, system_api, |api, fee| {
+            api.lock_fee(self.receiver, fee, contingent)
+        })?;
 
-        let fee = {
-            let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
-            let vault = substate_mut.vault();
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
 
-            // Check resource and take amount
-            if vault.resource_address() != RADIX_TOKEN {
+impl<W: WasmEngine> ExecutableInvocation<W> for VaultLockSponsoredFeeInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Vault(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::LockSponsoredFee)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for VaultLockSponsoredFeeInvocation {
+    type Output = ();
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        if let Some(expires_at_epoch) = self.sponsorship.expires_at_epoch {
+            let handle = system_api.lock_substate(
+                RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let current_epoch = substate_ref.epoch_manager().epoch;
+            system_api.drop_lock(handle)?;
+
+            if self.sponsorship.is_expired(current_epoch) {
                 return Err(RuntimeError::ApplicationError(
-                    ApplicationError::VaultError(VaultError::LockFeeNotRadixToken),
+                    ApplicationError::VaultError(VaultError::FeeSponsorshipExpired {
+                        expires_at_epoch,
+                        current_epoch,
+                    }),
                 ));
             }
+        }
 
-            // Take fee from the vault
-            vault.take(self.amount).map_err(|_| {
-                RuntimeError::ApplicationError(ApplicationError::VaultError(
-                    VaultError::LockFeeInsufficientBalance,
-                ))
-            })?
-        };
+        let sponsorship = self.sponsorship;
+        lock_fee_from_vault(self.receiver, self.amount, system_api, |api, fee| {
+            api.lock_sponsored_fee(self.receiver, fee, sponsorship)
+        })?;
 
-        // Refill fee reserve
-        let changes = system_api.lock_fee(self.receiver, fee, self.contingent)?;
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
 
-        // Return changes
-        {
-            let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
-            let vault = substate_mut.vault();
-            vault
-                .borrow_resource_mut()
-                .put(changes)
-                .expect("Failed to return fee changes to a locking-fee vault");
+/// Takes a fee from a vault, hands it to the fee reserve through `lock` and returns the
+/// change to the vault.
+fn lock_fee_from_vault<Y, F>(
+    vault_id: VaultId,
+    amount: Decimal,
+    system_api: &mut Y,
+    lock: F,
+) -> Result<(), RuntimeError>
+where
+    Y: SystemApi,
+    F: FnOnce(&mut Y, Resource) -> Result<Resource, RuntimeError>,
+{
+    let node_id = RENodeId::Vault(vault_id);
+    let offset = SubstateOffset::Vault(VaultOffset::Vault);
+    let vault_handle = system_api.lock_substate(
+        node_id,
+        offset,
+        LockFlags::MUTABLE | LockFlags::UNMODIFIED_BASE | LockFlags::FORCE_WRITE,
+    )?;
+
+    let fee = {
+        let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
+        let vault = substate_mut.vault();
+
+        check_not_frozen(vault)?;
+
+        // Check resource and take amount
+        if vault.resource_address() != RADIX_TOKEN {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::LockFeeNotRadixToken),
+            ));
         }
 
-        Ok(((), CallFrameUpdate::empty()))
+        // Take fee from the vault
+        vault.take(amount).map_err(|_| {
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::LockFeeInsufficientBalance,
+            ))
+        })?
+    };
+
+    // Refill fee reserve
+    let changes = lock(system_api, fee)?;
+
+    // Return changes
+    {
+        let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
+        let vault = substate_mut.vault();
+        vault
+            .borrow_resource_mut()
+            .put(changes)
+            .expect("Failed to return fee changes to a locking-fee vault");
     }
+
+    Ok(())
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for VaultRecallNonFungiblesInvocation {
@@ -267,9 +366,13 @@ impl NativeProcedure for VaultTakeNonFungiblesInvocation {
         let offset = SubstateOffset::Vault(VaultOffset::Vault);
         let vault_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
 
+        let is_recall = is_recall(api);
         let container = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
+            if !is_recall {
+                check_not_frozen(vault)?;
+            }
             vault
                 .take_non_fungibles(&self.non_fungible_ids)
                 .map_err(|e| match e {
@@ -279,6 +382,7 @@ impl NativeProcedure for VaultTakeNonFungiblesInvocation {
                     InvokeError::Downstream(runtime_error) => runtime_error,
                 })?
         };
+        emit_withdraw_event(api, self.receiver, &container)?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -447,6 +551,7 @@ impl NativeProcedure for VaultCreateProofInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
+            check_not_frozen(vault)?;
             vault
                 .create_proof(ResourceContainerId::Vault(self.receiver))
                 .map_err(|e| {
@@ -499,6 +604,7 @@ impl NativeProcedure for VaultCreateProofByAmountInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
+            check_not_frozen(vault)?;
             vault
                 .create_proof_by_amount(self.amount, ResourceContainerId::Vault(self.receiver))
                 .map_err(|e| {
@@ -551,6 +657,7 @@ impl NativeProcedure for VaultCreateProofByIdsInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
+            check_not_frozen(vault)?;
             vault
                 .create_proof_by_ids(&self.ids, ResourceContainerId::Vault(self.receiver))
                 .map_err(|e| {
@@ -570,3 +677,164 @@ impl NativeProcedure for VaultCreateProofByIdsInvocation {
         ))
     }
 }
+
+impl<W: WasmEngine> ExecutableInvocation<W> for VaultBurnNonFungiblesInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Vault(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::BurnNonFungibles)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for VaultBurnNonFungiblesInvocation {
+    type Output = ();
+
+    fn main<'a, Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let node_id = RENodeId::Vault(self.receiver);
+        let offset = SubstateOffset::Vault(VaultOffset::Vault);
+        let vault_handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let resource_address = {
+            let substate_ref = api.get_ref(vault_handle)?;
+            substate_ref.vault().resource_address()
+        };
+        api.drop_lock(vault_handle)?;
+
+        // The withdrawal goes through the vault and the burn through the resource manager, so
+        // both the withdraw and the burn auth of the resource apply.
+        let bucket = api.invoke(VaultTakeNonFungiblesInvocation {
+            receiver: self.receiver,
+            non_fungible_ids: self.non_fungible_ids,
+        })?;
+        api.invoke(ResourceManagerBurnInvocation {
+            receiver: resource_address,
+            bucket,
+        })?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for VaultFreezeInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Vault(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::Freeze)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for VaultFreezeInvocation {
+    type Output = ();
+
+    fn main<'a, Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Vault(self.receiver);
+        let offset = SubstateOffset::Vault(VaultOffset::Vault);
+        let vault_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+
+        let mut substate_mut = api.get_ref_mut(vault_handle)?;
+        substate_mut.vault().set_frozen(true);
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for VaultUnfreezeInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Vault(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::Unfreeze)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for VaultUnfreezeInvocation {
+    type Output = ();
+
+    fn main<'a, Y>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Vault(self.receiver);
+        let offset = SubstateOffset::Vault(VaultOffset::Vault);
+        let vault_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+
+        let mut substate_mut = api.get_ref_mut(vault_handle)?;
+        substate_mut.vault().set_frozen(false);
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+fn check_not_frozen(vault: &VaultRuntimeSubstate) -> Result<(), RuntimeError> {
+    if vault.is_frozen() {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::VaultError(VaultError::VaultFrozen),
+        ));
+    }
+    Ok(())
+}
+
+/// Recalls take resource through the same procedures as withdrawals, but are allowed on frozen
+/// vaults.
+fn is_recall<Y: SystemApi>(api: &Y) -> bool {
+    matches!(
+        api.get_actor(),
+        REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Vault(
+                VaultMethod::Recall | VaultMethod::RecallNonFungibles,
+            )),
+            ..,
+        )
+    )
+}
+
+/// Emits the event for resource taken from a vault, which is either a withdrawal or a recall
+/// depending on the method invoked.
+fn emit_withdraw_event<Y: SystemApi>(
+    api: &mut Y,
+    vault_id: VaultId,
+    resource: &Resource,
+) -> Result<(), RuntimeError> {
+    let kind = if is_recall(api) {
+        ResourceEventKind::VaultRecall(vault_id)
+    } else {
+        ResourceEventKind::VaultWithdraw(vault_id)
+    };
+    api.emit_event(Event::Tracked(TrackedEvent::Native(NativeEvent::Resource(
+        ResourceEvent::new(kind, resource),
+    ))))
+}
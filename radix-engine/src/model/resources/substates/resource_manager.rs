@@ -1,6 +1,7 @@
 use crate::model::{InvokeError, NonFungible, Resource, ResourceManagerError};
 use crate::types::*;
 use radix_engine_interface::api::types::NonFungibleStoreId;
+use radix_engine_interface::data::{match_schema_with_value, ScryptoValue};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 
@@ -10,22 +11,38 @@ pub struct ResourceManagerSubstate {
     pub resource_type: ResourceType,
     pub resource_address: ResourceAddress, // TODO: Figure out a way to remove?
     pub total_supply: Decimal,
+    /// The cap on the total supply, which can't be changed after creation.
+    pub max_supply: Option<Decimal>,
+    /// The schema which the data of minted and updated non-fungibles is validated against.
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
     pub nf_store_id: Option<NonFungibleStoreId>,
 }
 
 impl ResourceManagerSubstate {
     pub fn new(
         resource_type: ResourceType,
+        max_supply: Option<Decimal>,
+        non_fungible_data_schema: Option<NonFungibleDataSchema>,
         nf_store_id: Option<NonFungibleStoreId>,
         resource_address: ResourceAddress,
     ) -> Result<ResourceManagerSubstate, InvokeError<ResourceManagerError>> {
+        if non_fungible_data_schema.is_some() && nf_store_id.is_none() {
+            return Err(InvokeError::Error(ResourceManagerError::NotNonFungible));
+        }
+
         let resource_manager = ResourceManagerSubstate {
             resource_type,
             total_supply: 0.into(),
+            max_supply,
+            non_fungible_data_schema,
             nf_store_id,
             resource_address,
         };
 
+        if let Some(max_supply) = max_supply {
+            resource_manager.check_amount(max_supply)?;
+        }
+
         Ok(resource_manager)
     }
 
@@ -44,6 +61,93 @@ impl ResourceManagerSubstate {
         }
     }
 
+    pub fn check_max_supply(
+        &self,
+        amount: Decimal,
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        match self.max_supply {
+            Some(max_supply) if self.total_supply + amount > max_supply => Err(InvokeError::Error(
+                ResourceManagerError::MaxSupplyExceeded(max_supply),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks both parts of the data of a non-fungible against the schema, if there's one.
+    pub fn check_non_fungible_data(
+        &self,
+        immutable_data: &[u8],
+        mutable_data: &[u8],
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        if let Some(schema) = &self.non_fungible_data_schema {
+            check_data(&schema.immutable, immutable_data)?;
+            check_data(&schema.mutable, mutable_data)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the mutable part of the data of a non-fungible against the schema, if there's one.
+    pub fn check_mutable_non_fungible_data(
+        &self,
+        mutable_data: &[u8],
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        if let Some(schema) = &self.non_fungible_data_schema {
+            check_data(&schema.mutable, mutable_data)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the mutable data with the value of the named field replaced, after checking the
+    /// field is a mutable one of the schema and the value matches its type.
+    pub fn update_non_fungible_data_field(
+        &self,
+        mutable_data: &[u8],
+        field: &str,
+        value: &[u8],
+    ) -> Result<Vec<u8>, InvokeError<ResourceManagerError>> {
+        let schema = self
+            .non_fungible_data_schema
+            .as_ref()
+            .ok_or(InvokeError::Error(
+                ResourceManagerError::NonFungibleDataSchemaNotFound,
+            ))?;
+
+        let (index, ty) = match find_field(&schema.mutable, field) {
+            Some(found) => found,
+            None if find_field(&schema.immutable, field).is_some() => {
+                return Err(InvokeError::Error(
+                    ResourceManagerError::ImmutableNonFungibleField(field.to_string()),
+                ))
+            }
+            None => {
+                return Err(InvokeError::Error(
+                    ResourceManagerError::NonFungibleFieldNotFound(field.to_string()),
+                ))
+            }
+        };
+
+        let value: ScryptoValue = scrypto_decode(value)
+            .map_err(|_| InvokeError::Error(ResourceManagerError::InvalidNonFungibleData))?;
+        if !match_schema_with_value(ty, &value) {
+            return Err(InvokeError::Error(
+                ResourceManagerError::InvalidNonFungibleData,
+            ));
+        }
+
+        let mut data: ScryptoValue = scrypto_decode(mutable_data)
+            .map_err(|_| InvokeError::Error(ResourceManagerError::InvalidNonFungibleData))?;
+        match &mut data {
+            SborValue::Tuple { fields } if index < fields.len() => fields[index] = value,
+            _ => {
+                return Err(InvokeError::Error(
+                    ResourceManagerError::InvalidNonFungibleData,
+                ))
+            }
+        }
+
+        Ok(scrypto_encode(&data).expect("Failed to encode non-fungible data"))
+    }
+
     pub fn burn(&mut self, amount: Decimal) {
         self.total_supply -= amount;
     }
@@ -76,6 +180,7 @@ impl ResourceManagerSubstate {
                     ResourceManagerError::MaxMintAmountExceeded,
                 ));
             }
+            self.check_max_supply(amount)?;
 
             self.total_supply += amount;
 
@@ -109,6 +214,7 @@ impl ResourceManagerSubstate {
         // check amount
         let amount: Decimal = entries.len().into();
         self.check_amount(amount)?;
+        self.check_max_supply(amount)?;
 
         self.total_supply += amount;
 
@@ -125,6 +231,8 @@ impl ResourceManagerSubstate {
                 ));
             }
 
+            self.check_non_fungible_data(&data.0, &data.1)?;
+
             let non_fungible = NonFungible::new(data.0, data.1);
             ids.insert(id.clone());
             non_fungibles.insert(id, non_fungible);
@@ -136,3 +244,30 @@ impl ResourceManagerSubstate {
         ))
     }
 }
+
+fn check_data(ty: &Type, data: &[u8]) -> Result<(), InvokeError<ResourceManagerError>> {
+    let value: ScryptoValue = scrypto_decode(data)
+        .map_err(|_| InvokeError::Error(ResourceManagerError::InvalidNonFungibleData))?;
+    if match_schema_with_value(ty, &value) {
+        Ok(())
+    } else {
+        Err(InvokeError::Error(
+            ResourceManagerError::InvalidNonFungibleData,
+        ))
+    }
+}
+
+/// Finds the position and type of a named field of a struct schema.
+fn find_field<'t>(ty: &'t Type, field: &str) -> Option<(usize, &'t Type)> {
+    match ty {
+        Type::Struct {
+            fields: Fields::Named { named },
+            ..
+        } => named
+            .iter()
+            .enumerate()
+            .find(|(_, (name, _))| name == field)
+            .map(|(index, (_, ty))| (index, ty)),
+        _ => None,
+    }
+}
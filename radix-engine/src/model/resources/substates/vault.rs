@@ -8,17 +8,23 @@ use sbor::rust::ops::Deref;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
-pub struct VaultSubstate(pub Resource);
+pub struct VaultSubstate {
+    pub resource: Resource,
+    /// A frozen vault can't be withdrawn from, deposited into, used to lock fees or to create
+    /// proofs, until it's unfrozen. Its resource can still be recalled.
+    pub frozen: bool,
+}
 
 impl VaultSubstate {
     pub fn resource_address(&self) -> ResourceAddress {
-        self.0.resource_address()
+        self.resource.resource_address()
     }
 }
 
 #[derive(Debug)]
 pub struct VaultRuntimeSubstate {
     resource: Rc<RefCell<LockableResource>>,
+    frozen: bool,
 }
 
 impl VaultRuntimeSubstate {
@@ -53,20 +59,32 @@ impl VaultRuntimeSubstate {
             },
         };
 
-        VaultSubstate(resource)
+        VaultSubstate {
+            resource,
+            frozen: self.frozen,
+        }
     }
 
     pub fn to_persisted(self) -> Result<VaultSubstate, ResourceOperationError> {
+        let frozen = self.frozen;
         Rc::try_unwrap(self.resource)
             .map_err(|_| ResourceOperationError::ResourceLocked)
             .map(|c| c.into_inner())
             .map(Into::into)
-            .map(|r| VaultSubstate(r))
+            .map(|resource| VaultSubstate { resource, frozen })
     }
 
     pub fn new(resource: Resource) -> Self {
         Self {
             resource: Rc::new(RefCell::new(resource.into())),
+            frozen: false,
+        }
+    }
+
+    pub fn from_persisted(substate: VaultSubstate) -> Self {
+        Self {
+            resource: Rc::new(RefCell::new(substate.resource.into())),
+            frozen: substate.frozen,
         }
     }
 
@@ -184,6 +202,14 @@ impl VaultRuntimeSubstate {
         self.borrow_resource().is_empty()
     }
 
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    pub fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
     pub fn borrow_resource(&self) -> Ref<LockableResource> {
         self.resource.borrow()
     }
@@ -12,7 +12,11 @@ use radix_engine_interface::data::IndexedScryptoValue;
 pub enum PersistedSubstate {
     Global(GlobalAddressSubstate),
     EpochManager(EpochManagerSubstate),
+    ValidatorSet(ValidatorSetSubstate),
+    FeeConfig(FeeConfigSubstate),
     CurrentTimeRoundedToMinutes(CurrentTimeRoundedToMinutesSubstate),
+    Account(AccountSubstate),
+    AccessController(AccessControllerSubstate),
     ResourceManager(ResourceManagerSubstate),
     AccessRulesChain(AccessRulesChainSubstate),
     Metadata(MetadataSubstate),
@@ -69,9 +73,13 @@ impl PersistedSubstate {
         match self {
             PersistedSubstate::Global(value) => RuntimeSubstate::Global(value),
             PersistedSubstate::EpochManager(value) => RuntimeSubstate::EpochManager(value),
+            PersistedSubstate::ValidatorSet(value) => RuntimeSubstate::ValidatorSet(value),
+            PersistedSubstate::FeeConfig(value) => RuntimeSubstate::FeeConfig(value),
             PersistedSubstate::CurrentTimeRoundedToMinutes(value) => {
                 RuntimeSubstate::CurrentTimeRoundedToMinutes(value)
             }
+            PersistedSubstate::Account(value) => RuntimeSubstate::Account(value),
+            PersistedSubstate::AccessController(value) => RuntimeSubstate::AccessController(value),
             PersistedSubstate::AccessRulesChain(value) => RuntimeSubstate::AccessRulesChain(value),
             PersistedSubstate::Metadata(value) => RuntimeSubstate::Metadata(value),
             PersistedSubstate::ResourceManager(value) => RuntimeSubstate::ResourceManager(value),
@@ -91,7 +99,7 @@ impl PersistedSubstate {
                 RuntimeSubstate::PackageRoyaltyAccumulator(value)
             }
             PersistedSubstate::Vault(value) => {
-                RuntimeSubstate::Vault(VaultRuntimeSubstate::new(value.0))
+                RuntimeSubstate::Vault(VaultRuntimeSubstate::from_persisted(value))
             }
             PersistedSubstate::NonFungible(value) => RuntimeSubstate::NonFungible(value),
             PersistedSubstate::KeyValueStoreEntry(value) => {
@@ -109,7 +117,11 @@ pub enum PersistError {
 pub enum RuntimeSubstate {
     Global(GlobalAddressSubstate),
     EpochManager(EpochManagerSubstate),
+    ValidatorSet(ValidatorSetSubstate),
+    FeeConfig(FeeConfigSubstate),
     CurrentTimeRoundedToMinutes(CurrentTimeRoundedToMinutesSubstate),
+    Account(AccountSubstate),
+    AccessController(AccessControllerSubstate),
     ResourceManager(ResourceManagerSubstate),
     AccessRulesChain(AccessRulesChainSubstate),
     Metadata(MetadataSubstate),
@@ -135,12 +147,18 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::Global(value) => PersistedSubstate::Global(value.clone()),
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value.clone()),
+            RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value.clone()),
+            RuntimeSubstate::FeeConfig(value) => PersistedSubstate::FeeConfig(value.clone()),
             RuntimeSubstate::AccessRulesChain(value) => {
                 PersistedSubstate::AccessRulesChain(value.clone())
             }
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value.clone())
             }
+            RuntimeSubstate::Account(value) => PersistedSubstate::Account(value.clone()),
+            RuntimeSubstate::AccessController(value) => {
+                PersistedSubstate::AccessController(value.clone())
+            }
             RuntimeSubstate::Metadata(value) => PersistedSubstate::Metadata(value.clone()),
             RuntimeSubstate::ResourceManager(value) => {
                 PersistedSubstate::ResourceManager(value.clone())
@@ -186,10 +204,14 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::Global(value) => PersistedSubstate::Global(value),
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value),
+            RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value),
+            RuntimeSubstate::FeeConfig(value) => PersistedSubstate::FeeConfig(value),
             RuntimeSubstate::AccessRulesChain(value) => PersistedSubstate::AccessRulesChain(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value)
             }
+            RuntimeSubstate::Account(value) => PersistedSubstate::Account(value),
+            RuntimeSubstate::AccessController(value) => PersistedSubstate::AccessController(value),
             RuntimeSubstate::Metadata(value) => PersistedSubstate::Metadata(value),
             RuntimeSubstate::ResourceManager(value) => PersistedSubstate::ResourceManager(value),
             RuntimeSubstate::ComponentInfo(value) => PersistedSubstate::ComponentInfo(value),
@@ -261,9 +283,13 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::Global(value) => SubstateRefMut::Global(value),
             RuntimeSubstate::EpochManager(value) => SubstateRefMut::EpochManager(value),
+            RuntimeSubstate::ValidatorSet(value) => SubstateRefMut::ValidatorSet(value),
+            RuntimeSubstate::FeeConfig(value) => SubstateRefMut::FeeConfig(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRefMut::CurrentTimeRoundedToMinutes(value)
             }
+            RuntimeSubstate::Account(value) => SubstateRefMut::Account(value),
+            RuntimeSubstate::AccessController(value) => SubstateRefMut::AccessController(value),
             RuntimeSubstate::AccessRulesChain(value) => SubstateRefMut::AccessRulesChain(value),
             RuntimeSubstate::Metadata(value) => SubstateRefMut::Metadata(value),
             RuntimeSubstate::ResourceManager(value) => SubstateRefMut::ResourceManager(value),
@@ -297,9 +323,13 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::Global(value) => SubstateRef::Global(value),
             RuntimeSubstate::EpochManager(value) => SubstateRef::EpochManager(value),
+            RuntimeSubstate::ValidatorSet(value) => SubstateRef::ValidatorSet(value),
+            RuntimeSubstate::FeeConfig(value) => SubstateRef::FeeConfig(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRef::CurrentTimeRoundedToMinutes(value)
             }
+            RuntimeSubstate::Account(value) => SubstateRef::Account(value),
+            RuntimeSubstate::AccessController(value) => SubstateRef::AccessController(value),
             RuntimeSubstate::AccessRulesChain(value) => SubstateRef::AccessRulesChain(value),
             RuntimeSubstate::Metadata(value) => SubstateRef::Metadata(value),
             RuntimeSubstate::ResourceManager(value) => SubstateRef::ResourceManager(value),
@@ -412,12 +442,36 @@ impl Into<RuntimeSubstate> for EpochManagerSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for ValidatorSetSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::ValidatorSet(self)
+    }
+}
+
+impl Into<RuntimeSubstate> for FeeConfigSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::FeeConfig(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for CurrentTimeRoundedToMinutesSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::CurrentTimeRoundedToMinutes(self)
     }
 }
 
+impl Into<RuntimeSubstate> for AccountSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::Account(self)
+    }
+}
+
+impl Into<RuntimeSubstate> for AccessControllerSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::AccessController(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for PackageInfoSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::PackageInfo(self)
@@ -610,6 +664,26 @@ impl Into<EpochManagerSubstate> for RuntimeSubstate {
     }
 }
 
+impl Into<AccountSubstate> for RuntimeSubstate {
+    fn into(self) -> AccountSubstate {
+        if let RuntimeSubstate::Account(account) = self {
+            account
+        } else {
+            panic!("Not an account");
+        }
+    }
+}
+
+impl Into<AccessControllerSubstate> for RuntimeSubstate {
+    fn into(self) -> AccessControllerSubstate {
+        if let RuntimeSubstate::AccessController(access_controller) = self {
+            access_controller
+        } else {
+            panic!("Not an access controller");
+        }
+    }
+}
+
 impl Into<GlobalAddressSubstate> for RuntimeSubstate {
     fn into(self) -> GlobalAddressSubstate {
         if let RuntimeSubstate::Global(substate) = self {
@@ -678,7 +752,11 @@ pub enum SubstateRef<'a> {
     Vault(&'a VaultRuntimeSubstate),
     ResourceManager(&'a ResourceManagerSubstate),
     EpochManager(&'a EpochManagerSubstate),
+    ValidatorSet(&'a ValidatorSetSubstate),
+    FeeConfig(&'a FeeConfigSubstate),
     CurrentTimeRoundedToMinutes(&'a CurrentTimeRoundedToMinutesSubstate),
+    Account(&'a AccountSubstate),
+    AccessController(&'a AccessControllerSubstate),
     AccessRulesChain(&'a AccessRulesChainSubstate),
     Metadata(&'a MetadataSubstate),
     Global(&'a GlobalAddressSubstate),
@@ -689,9 +767,13 @@ impl<'a> SubstateRef<'a> {
         match self {
             SubstateRef::Global(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::EpochManager(value) => IndexedScryptoValue::from_typed(*value),
+            SubstateRef::ValidatorSet(value) => IndexedScryptoValue::from_typed(*value),
+            SubstateRef::FeeConfig(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::CurrentTimeRoundedToMinutes(value) => {
                 IndexedScryptoValue::from_typed(*value)
             }
+            SubstateRef::Account(value) => IndexedScryptoValue::from_typed(*value),
+            SubstateRef::AccessController(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::ResourceManager(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::ComponentInfo(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::ComponentState(value) => IndexedScryptoValue::from_typed(*value),
@@ -725,6 +807,20 @@ impl<'a> SubstateRef<'a> {
         }
     }
 
+    pub fn validator_set(&self) -> &ValidatorSetSubstate {
+        match self {
+            SubstateRef::ValidatorSet(validator_set_substate) => *validator_set_substate,
+            _ => panic!("Not a validator set substate"),
+        }
+    }
+
+    pub fn fee_config(&self) -> &FeeConfigSubstate {
+        match self {
+            SubstateRef::FeeConfig(fee_config_substate) => *fee_config_substate,
+            _ => panic!("Not a fee config substate"),
+        }
+    }
+
     pub fn component_state(&self) -> &ComponentStateSubstate {
         match self {
             SubstateRef::ComponentState(state) => *state,
@@ -851,6 +947,20 @@ impl<'a> SubstateRef<'a> {
         }
     }
 
+    pub fn account(&self) -> &AccountSubstate {
+        match self {
+            SubstateRef::Account(value) => *value,
+            _ => panic!("Not account"),
+        }
+    }
+
+    pub fn access_controller(&self) -> &AccessControllerSubstate {
+        match self {
+            SubstateRef::AccessController(value) => *value,
+            _ => panic!("Not access controller"),
+        }
+    }
+
     pub fn references_and_owned_nodes(&self) -> (HashSet<GlobalAddress>, HashSet<RENodeId>) {
         match self {
             SubstateRef::Global(global) => {
@@ -868,6 +978,12 @@ impl<'a> SubstateRef<'a> {
                     GlobalAddressSubstate::Clock(clock_id) => {
                         owned_nodes.insert(RENodeId::Clock(*clock_id))
                     }
+                    GlobalAddressSubstate::Account(account_id) => {
+                        owned_nodes.insert(RENodeId::Account(*account_id))
+                    }
+                    GlobalAddressSubstate::AccessController(access_controller_id) => {
+                        owned_nodes.insert(RENodeId::AccessController(*access_controller_id))
+                    }
                     GlobalAddressSubstate::Package(package_id) => {
                         owned_nodes.insert(RENodeId::Package(*package_id))
                     }
@@ -895,6 +1011,31 @@ impl<'a> SubstateRef<'a> {
                 references.insert(GlobalAddress::Package(substate.package_address));
                 (references, HashSet::new())
             }
+            SubstateRef::ValidatorSet(substate) => {
+                let mut references = HashSet::new();
+                let mut owned_nodes = HashSet::new();
+                for validator in substate.validators.values() {
+                    references.insert(GlobalAddress::Resource(validator.stake_unit_resource));
+                    references.insert(GlobalAddress::Resource(validator.unstake_claim_resource));
+                    owned_nodes.insert(RENodeId::Vault(validator.stake_vault_id));
+                    owned_nodes.insert(RENodeId::Vault(validator.unstake_vault_id));
+                }
+                (references, owned_nodes)
+            }
+            SubstateRef::Account(substate) => {
+                let mut references = HashSet::new();
+                let mut owned_nodes = HashSet::new();
+                for (resource_address, vault_id) in &substate.vaults {
+                    references.insert(GlobalAddress::Resource(*resource_address));
+                    owned_nodes.insert(RENodeId::Vault(*vault_id));
+                }
+                (references, owned_nodes)
+            }
+            SubstateRef::AccessController(substate) => {
+                let mut owned_nodes = HashSet::new();
+                owned_nodes.insert(RENodeId::Vault(substate.controlled_asset));
+                (HashSet::new(), owned_nodes)
+            }
             SubstateRef::ResourceManager(substate) => {
                 let mut owned_nodes = HashSet::new();
                 if let Some(nf_store_id) = substate.nf_store_id {
@@ -946,7 +1087,11 @@ pub enum SubstateRefMut<'a> {
     Vault(&'a mut VaultRuntimeSubstate),
     ResourceManager(&'a mut ResourceManagerSubstate),
     EpochManager(&'a mut EpochManagerSubstate),
+    ValidatorSet(&'a mut ValidatorSetSubstate),
+    FeeConfig(&'a mut FeeConfigSubstate),
     CurrentTimeRoundedToMinutes(&'a mut CurrentTimeRoundedToMinutesSubstate),
+    Account(&'a mut AccountSubstate),
+    AccessController(&'a mut AccessControllerSubstate),
     AccessRulesChain(&'a mut AccessRulesChainSubstate),
     Metadata(&'a mut MetadataSubstate),
     Global(&'a mut GlobalAddressSubstate),
@@ -1071,6 +1216,20 @@ impl<'a> SubstateRefMut<'a> {
         }
     }
 
+    pub fn validator_set(&mut self) -> &mut ValidatorSetSubstate {
+        match self {
+            SubstateRefMut::ValidatorSet(value) => *value,
+            _ => panic!("Not validator set"),
+        }
+    }
+
+    pub fn fee_config(&mut self) -> &mut FeeConfigSubstate {
+        match self {
+            SubstateRefMut::FeeConfig(value) => *value,
+            _ => panic!("Not fee config"),
+        }
+    }
+
     pub fn current_time_rounded_to_minutes(&mut self) -> &mut CurrentTimeRoundedToMinutesSubstate {
         match self {
             SubstateRefMut::CurrentTimeRoundedToMinutes(value) => *value,
@@ -1078,6 +1237,20 @@ impl<'a> SubstateRefMut<'a> {
         }
     }
 
+    pub fn account(&mut self) -> &mut AccountSubstate {
+        match self {
+            SubstateRefMut::Account(value) => *value,
+            _ => panic!("Not account"),
+        }
+    }
+
+    pub fn access_controller(&mut self) -> &mut AccessControllerSubstate {
+        match self {
+            SubstateRefMut::AccessController(value) => *value,
+            _ => panic!("Not access controller"),
+        }
+    }
+
     pub fn access_rules_chain(&mut self) -> &mut AccessRulesChainSubstate {
         match self {
             SubstateRefMut::AccessRulesChain(value) => *value,
@@ -2,8 +2,8 @@ use native_sdk::resource::{ComponentAuthZone, SysBucket, SysProof, Worktop};
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::api::{EngineApi, Invocation, Invokable, InvokableModel};
 use radix_engine_interface::api::types::{
-    BucketId, GlobalAddress, NativeFn, NativeFunction, NativeFunctionIdent, NativeMethodIdent,
-    ProofId, RENodeId, TransactionProcessorFunction,
+    AuthZoneStackOffset, BucketId, GlobalAddress, NativeFn, NativeFunction, NativeFunctionIdent,
+    NativeMethodIdent, ProofId, RENodeId, SubstateOffset, TransactionProcessorFunction,
 };
 use radix_engine_interface::data::{IndexedScryptoValue, ValueReplacingError};
 use radix_engine_interface::model::*;
@@ -24,6 +24,7 @@ use crate::wasm::WasmEngine;
 pub struct TransactionProcessorRunInvocation<'a> {
     pub runtime_validations: Cow<'a, [RuntimeValidationRequest]>,
     pub instructions: Cow<'a, [Instruction]>,
+    pub subintents: Cow<'a, [ExecutableSubintent<'a>]>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,6 +46,8 @@ pub enum TransactionProcessorError {
     NativeFunctionNotFound(NativeFunctionIdent),
     NativeMethodNotFound(NativeMethodIdent),
     IdAllocationError(IdAllocationError),
+    SubintentNotFound(u32),
+    SubintentBucketNotReturned(BucketId),
 }
 
 impl<'a> Invocation for TransactionProcessorRunInvocation<'a> {
@@ -69,7 +72,11 @@ impl<'a, W: WasmEngine> ExecutableInvocation<W> for TransactionProcessorRunInvoc
         }
 
         // TODO: This can be refactored out once any type in sbor is implemented
-        for instruction in self.instructions.as_ref() {
+        let subintent_instructions = self
+            .subintents
+            .iter()
+            .flat_map(|subintent| subintent.instructions.iter());
+        for instruction in self.instructions.iter().chain(subintent_instructions) {
             match instruction {
                 Instruction::CallFunction { args, .. }
                 | Instruction::CallMethod { args, .. }
@@ -292,400 +299,486 @@ impl TransactionProcessor {
             }))
             .map_err(InvokeError::Downstream)?;
 
-            let result = match inst {
-                Instruction::TakeFromWorktop { resource_address } => id_allocator
-                    .new_bucket_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        Worktop::sys_take_all(*resource_address, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|bucket| {
-                                bucket_id_mapping.insert(new_id, bucket.0);
-                                IndexedScryptoValue::from_typed(&bucket)
-                            })
-                    }),
-                Instruction::TakeFromWorktopByAmount {
-                    amount,
-                    resource_address,
-                } => id_allocator
-                    .new_bucket_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        Worktop::sys_take_amount(*resource_address, *amount, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|bucket| {
-                                bucket_id_mapping.insert(new_id, bucket.0);
-                                IndexedScryptoValue::from_typed(&bucket)
-                            })
-                    }),
-                Instruction::TakeFromWorktopByIds {
-                    ids,
-                    resource_address,
-                } => id_allocator
-                    .new_bucket_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        Worktop::sys_take_non_fungibles(*resource_address, ids.clone(), api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|bucket| {
-                                bucket_id_mapping.insert(new_id, bucket.0);
-                                IndexedScryptoValue::from_typed(&bucket)
-                            })
-                    }),
-                Instruction::ReturnToWorktop { bucket_id } => bucket_id_mapping
-                    .remove(bucket_id)
-                    .map(|real_id| {
-                        Worktop::sys_put(Bucket(real_id), api)
-                            .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .unwrap_or(Err(InvokeError::Error(
-                        TransactionProcessorError::BucketNotFound(*bucket_id),
-                    ))),
-                Instruction::AssertWorktopContains { resource_address } => {
-                    Worktop::sys_assert_contains(*resource_address, api)
-                        .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+            let result = Self::process_instruction(
+                inst,
+                input.subintents.as_ref(),
+                &mut proof_id_mapping,
+                &mut bucket_id_mapping,
+                &mut id_allocator,
+                api,
+            )?;
+            outputs.push(result);
+
+            api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteInstruction {
+                instruction_index: idx,
+                instruction: &inst,
+            }))
+            .map_err(InvokeError::Downstream)?;
+        }
+
+        api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteManifest))
+            .map_err(InvokeError::Downstream)?;
+
+        Ok(outputs
+            .into_iter()
+            .map(|sv| sv.raw)
+            .collect::<Vec<Vec<u8>>>())
+    }
+    fn process_instruction<Y>(
+        inst: &Instruction,
+        subintents: &[ExecutableSubintent],
+        proof_id_mapping: &mut HashMap<ProofId, ProofId>,
+        bucket_id_mapping: &mut HashMap<BucketId, BucketId>,
+        id_allocator: &mut IdAllocator,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, InvokeError<TransactionProcessorError>>
+    where
+        Y: SystemApi
+            + EngineApi<RuntimeError>
+            + Invokable<ScryptoInvocation, RuntimeError>
+            + InvokableModel<RuntimeError>,
+    {
+        match inst {
+            Instruction::TakeFromWorktop { resource_address } => id_allocator
+                .new_bucket_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    Worktop::sys_take_all(*resource_address, api)
                         .map_err(InvokeError::Downstream)
-                }
-                Instruction::AssertWorktopContainsByAmount {
-                    amount,
-                    resource_address,
-                } => Worktop::sys_assert_contains_amount(*resource_address, *amount, api)
-                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
-                    .map_err(InvokeError::Downstream),
-                Instruction::AssertWorktopContainsByIds {
-                    ids,
-                    resource_address,
-                } => {
-                    Worktop::sys_assert_contains_non_fungibles(*resource_address, ids.clone(), api)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::TakeFromWorktopByAmount {
+                amount,
+                resource_address,
+            } => id_allocator
+                .new_bucket_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    Worktop::sys_take_amount(*resource_address, *amount, api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::TakeFromWorktopByIds {
+                ids,
+                resource_address,
+            } => id_allocator
+                .new_bucket_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    Worktop::sys_take_non_fungibles(*resource_address, ids.clone(), api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::ReturnToWorktop { bucket_id } => bucket_id_mapping
+                .remove(bucket_id)
+                .map(|real_id| {
+                    Worktop::sys_put(Bucket(real_id), api)
                         .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
                         .map_err(InvokeError::Downstream)
-                }
+                })
+                .unwrap_or(Err(InvokeError::Error(
+                    TransactionProcessorError::BucketNotFound(*bucket_id),
+                ))),
+            Instruction::AssertWorktopContains { resource_address } => {
+                Worktop::sys_assert_contains(*resource_address, api)
+                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                    .map_err(InvokeError::Downstream)
+            }
+            Instruction::AssertWorktopContainsByAmount {
+                amount,
+                resource_address,
+            } => Worktop::sys_assert_contains_amount(*resource_address, *amount, api)
+                .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                .map_err(InvokeError::Downstream),
+            Instruction::AssertWorktopContainsByIds {
+                ids,
+                resource_address,
+            } => Worktop::sys_assert_contains_non_fungibles(*resource_address, ids.clone(), api)
+                .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                .map_err(InvokeError::Downstream),
 
-                Instruction::PopFromAuthZone {} => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_pop(api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::ClearAuthZone => {
-                    proof_id_mapping.clear();
-                    ComponentAuthZone::sys_clear(api)
+            Instruction::PopFromAuthZone {} => id_allocator
+                .new_proof_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_pop(api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::ClearAuthZone => {
+                proof_id_mapping.clear();
+                ComponentAuthZone::sys_clear(api)
+                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                    .map_err(InvokeError::Downstream)
+            }
+            Instruction::PushToAuthZone { proof_id } => proof_id_mapping
+                .remove(proof_id)
+                .ok_or(InvokeError::Error(
+                    TransactionProcessorError::ProofNotFound(*proof_id),
+                ))
+                .and_then(|real_id| {
+                    let proof = Proof(real_id);
+                    ComponentAuthZone::sys_push(proof, api)
                         .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
                         .map_err(InvokeError::Downstream)
-                }
-                Instruction::PushToAuthZone { proof_id } => proof_id_mapping
-                    .remove(proof_id)
-                    .ok_or(InvokeError::Error(
-                        TransactionProcessorError::ProofNotFound(*proof_id),
-                    ))
-                    .and_then(|real_id| {
-                        let proof = Proof(real_id);
-                        ComponentAuthZone::sys_push(proof, api)
-                            .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
-                            .map_err(InvokeError::Downstream)
-                    }),
-                Instruction::CreateProofFromAuthZone { resource_address } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_create_proof(*resource_address, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::CreateProofFromAuthZoneByAmount {
-                    amount,
-                    resource_address,
-                } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_create_proof_by_amount(
-                            *amount,
-                            *resource_address,
-                            api,
-                        )
+                }),
+            Instruction::CreateProofFromAuthZone { resource_address } => id_allocator
+                .new_proof_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_create_proof(*resource_address, api)
                         .map_err(InvokeError::Downstream)
                         .map(|proof| {
                             proof_id_mapping.insert(new_id, proof.0);
                             IndexedScryptoValue::from_typed(&proof)
                         })
-                    }),
-                Instruction::CreateProofFromAuthZoneByIds {
-                    ids,
-                    resource_address,
-                } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_create_proof_by_ids(ids, *resource_address, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::CreateProofFromBucket { bucket_id } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        bucket_id_mapping
-                            .get(bucket_id)
-                            .cloned()
-                            .map(|real_bucket_id| (new_id, real_bucket_id))
-                            .ok_or(InvokeError::Error(
-                                TransactionProcessorError::BucketNotFound(new_id),
-                            ))
-                    })
-                    .and_then(|(new_id, real_bucket_id)| {
-                        let bucket = Bucket(real_bucket_id);
-                        bucket
-                            .sys_create_proof(api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::CloneProof { proof_id } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        proof_id_mapping
-                            .get(proof_id)
-                            .cloned()
-                            .map(|real_id| {
-                                let proof = Proof(real_id);
-                                proof
-                                    .sys_clone(api)
-                                    .map_err(InvokeError::Downstream)
-                                    .map(|proof| {
-                                        proof_id_mapping.insert(new_id, proof.0);
-                                        IndexedScryptoValue::from_typed(&proof)
-                                    })
-                            })
-                            .unwrap_or(Err(InvokeError::Error(
-                                TransactionProcessorError::ProofNotFound(*proof_id),
-                            )))
-                    }),
-                Instruction::DropProof { proof_id } => proof_id_mapping
-                    .remove(proof_id)
-                    .map(|real_id| {
-                        let proof = Proof(real_id);
-                        proof
-                            .sys_drop(api)
-                            .map(|_| IndexedScryptoValue::unit())
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .unwrap_or(Err(InvokeError::Error(
-                        TransactionProcessorError::ProofNotFound(*proof_id),
-                    ))),
-                Instruction::DropAllProofs => {
-                    for (_, real_id) in proof_id_mapping.drain() {
-                        let proof = Proof(real_id);
-                        proof
-                            .sys_drop(api)
-                            .map(|_| IndexedScryptoValue::unit())
-                            .map_err(InvokeError::Downstream)?;
-                    }
-                    ComponentAuthZone::sys_clear(api)
-                        .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                }),
+            Instruction::CreateProofFromAuthZoneByAmount {
+                amount,
+                resource_address,
+            } => id_allocator
+                .new_proof_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_create_proof_by_amount(*amount, *resource_address, api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::CreateProofFromAuthZoneByIds {
+                ids,
+                resource_address,
+            } => id_allocator
+                .new_proof_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_create_proof_by_ids(ids, *resource_address, api)
                         .map_err(InvokeError::Downstream)
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::CreateProofFromBucket { bucket_id } => id_allocator
+                .new_proof_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    bucket_id_mapping
+                        .get(bucket_id)
+                        .cloned()
+                        .map(|real_bucket_id| (new_id, real_bucket_id))
+                        .ok_or(InvokeError::Error(
+                            TransactionProcessorError::BucketNotFound(new_id),
+                        ))
+                })
+                .and_then(|(new_id, real_bucket_id)| {
+                    let bucket = Bucket(real_bucket_id);
+                    bucket
+                        .sys_create_proof(api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::CloneProof { proof_id } => id_allocator
+                .new_proof_id()
+                .map_err(|e| InvokeError::Error(TransactionProcessorError::IdAllocationError(e)))
+                .and_then(|new_id| {
+                    proof_id_mapping
+                        .get(proof_id)
+                        .cloned()
+                        .map(|real_id| {
+                            let proof = Proof(real_id);
+                            proof
+                                .sys_clone(api)
+                                .map_err(InvokeError::Downstream)
+                                .map(|proof| {
+                                    proof_id_mapping.insert(new_id, proof.0);
+                                    IndexedScryptoValue::from_typed(&proof)
+                                })
+                        })
+                        .unwrap_or(Err(InvokeError::Error(
+                            TransactionProcessorError::ProofNotFound(*proof_id),
+                        )))
+                }),
+            Instruction::DropProof { proof_id } => proof_id_mapping
+                .remove(proof_id)
+                .map(|real_id| {
+                    let proof = Proof(real_id);
+                    proof
+                        .sys_drop(api)
+                        .map(|_| IndexedScryptoValue::unit())
+                        .map_err(InvokeError::Downstream)
+                })
+                .unwrap_or(Err(InvokeError::Error(
+                    TransactionProcessorError::ProofNotFound(*proof_id),
+                ))),
+            Instruction::DropAllProofs => {
+                for (_, real_id) in proof_id_mapping.drain() {
+                    let proof = Proof(real_id);
+                    proof
+                        .sys_drop(api)
+                        .map(|_| IndexedScryptoValue::unit())
+                        .map_err(InvokeError::Downstream)?;
                 }
-                Instruction::CallFunction {
-                    function_ident,
-                    args,
-                } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_FUNCTION arguments"),
-                    )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
+                ComponentAuthZone::sys_clear(api)
+                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                    .map_err(InvokeError::Downstream)
+            }
+            Instruction::CallFunction {
+                function_ident,
+                args,
+            } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args).expect("Invalid CALL_FUNCTION arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, api))
+                .and_then(|args| {
+                    if is_account_function(function_ident) {
+                        parse_and_invoke_account_fn(
+                            ScryptoInvocation::Function(function_ident.clone(), args.raw),
+                            api,
+                        )
+                    } else {
                         api.invoke(ParsedScryptoInvocation::Function(
                             function_ident.clone(),
                             args,
                         ))
-                        .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-                Instruction::CallMethod { method_ident, args } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_METHOD arguments"),
-                    )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        api.invoke(ParsedScryptoInvocation::Method(method_ident.clone(), args))
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-                Instruction::PublishPackageWithOwner {
-                    code,
-                    abi,
-                    owner_badge,
-                } => api
-                    .invoke(PackagePublishWithOwnerInvocation {
-                        code: code.clone(),
-                        abi: abi.clone(),
-                        royalty_config: HashMap::new(),
-                        metadata: HashMap::new(),
-                        owner_badge: owner_badge.clone(),
-                    })
-                    .map(|address| IndexedScryptoValue::from_typed(&address))
-                    .map_err(InvokeError::Downstream),
-                Instruction::CallNativeFunction {
-                    function_ident,
-                    args,
-                } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_NATIVE_FUNCTION arguments"),
-                    )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        let native_function = resolve_native_function(
-                            &function_ident.blueprint_name,
-                            &function_ident.function_name,
-                        )
-                        .ok_or(InvokeError::Error(
-                            TransactionProcessorError::NativeFunctionNotFound(
-                                function_ident.clone(),
-                            ),
-                        ))?;
-                        parse_and_invoke_native_fn(
-                            NativeFn::Function(native_function),
-                            args.raw,
+                    }
+                    .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api).map_err(InvokeError::Downstream)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::Downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::CallMethod { method_ident, args } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args).expect("Invalid CALL_METHOD arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, api))
+                .and_then(|args| {
+                    if is_account_method(method_ident) {
+                        parse_and_invoke_account_fn(
+                            ScryptoInvocation::Method(method_ident.clone(), args.raw),
                             api,
                         )
-                        .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-                Instruction::CallNativeMethod { method_ident, args } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_NATIVE_METHOD arguments"),
+                    } else {
+                        api.invoke(ParsedScryptoInvocation::Method(method_ident.clone(), args))
+                    }
+                    .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api).map_err(InvokeError::Downstream)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::YieldToSubintent { subintent_index } => subintents
+                .get(*subintent_index as usize)
+                .ok_or(InvokeError::Error(
+                    TransactionProcessorError::SubintentNotFound(*subintent_index),
+                ))
+                .and_then(|subintent| Self::run_subintent(subintent, api)),
+            Instruction::PublishPackageWithOwner {
+                code,
+                abi,
+                owner_badge,
+            } => api
+                .invoke(PackagePublishWithOwnerInvocation {
+                    code: code.clone(),
+                    abi: abi.clone(),
+                    royalty_config: HashMap::new(),
+                    metadata: HashMap::new(),
+                    owner_badge: owner_badge.clone(),
+                })
+                .map(|address| IndexedScryptoValue::from_typed(&address))
+                .map_err(InvokeError::Downstream),
+            Instruction::CallNativeFunction {
+                function_ident,
+                args,
+            } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args)
+                        .expect("Invalid CALL_NATIVE_FUNCTION arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, api))
+                .and_then(|args| {
+                    let native_function = resolve_native_function(
+                        &function_ident.blueprint_name,
+                        &function_ident.function_name,
                     )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        let native_method =
-                            resolve_native_method(method_ident.receiver, &method_ident.method_name)
-                                .ok_or(InvokeError::Error(
-                                    TransactionProcessorError::NativeMethodNotFound(
-                                        method_ident.clone(),
-                                    ),
-                                ))?;
-
-                        parse_and_invoke_native_fn(NativeFn::Method(native_method), args.raw, api)
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-            }?;
-            outputs.push(result);
+                    .ok_or(InvokeError::Error(
+                        TransactionProcessorError::NativeFunctionNotFound(function_ident.clone()),
+                    ))?;
+                    parse_and_invoke_native_fn(NativeFn::Function(native_function), args.raw, api)
+                        .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api).map_err(InvokeError::Downstream)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::Downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::CallNativeMethod { method_ident, args } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args)
+                        .expect("Invalid CALL_NATIVE_METHOD arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, api))
+                .and_then(|args| {
+                    let native_method =
+                        resolve_native_method(method_ident.receiver, &method_ident.method_name)
+                            .ok_or(InvokeError::Error(
+                                TransactionProcessorError::NativeMethodNotFound(
+                                    method_ident.clone(),
+                                ),
+                            ))?;
 
-            api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteInstruction {
-                instruction_index: idx,
-                instruction: &inst,
-            }))
-            .map_err(InvokeError::Downstream)?;
+                    parse_and_invoke_native_fn(NativeFn::Method(native_method), args.raw, api)
+                        .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api).map_err(InvokeError::Downstream)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
         }
+    }
 
-        api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteManifest))
-            .map_err(InvokeError::Downstream)?;
+    /// Runs the instructions of a sub-intent against the shared worktop. The buckets and proofs
+    /// of the sub-intent are named separately, and its auth zone only holds the proofs of its own
+    /// signers.
+    fn run_subintent<Y>(
+        subintent: &ExecutableSubintent,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, InvokeError<TransactionProcessorError>>
+    where
+        Y: SystemApi
+            + EngineApi<RuntimeError>
+            + Invokable<ScryptoInvocation, RuntimeError>
+            + InvokableModel<RuntimeError>,
+    {
+        let auth_zone_id = Self::auth_zone_stack_id(api)?;
+        let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+        let handle = api.lock_substate(auth_zone_id, offset.clone(), LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            let auth_zone_params = &subintent.auth_zone_params;
+            substate_mut.auth_zone_stack().new_isolated_frame(
+                auth_zone_params
+                    .virtualizable_proofs_resource_addresses
+                    .clone(),
+                auth_zone_params.initial_proofs.iter().cloned().collect(),
+            );
+        }
+        api.drop_lock(handle)?;
 
-        Ok(outputs
+        let mut proof_id_mapping = HashMap::new();
+        let mut bucket_id_mapping = HashMap::new();
+        let mut outputs = Vec::new();
+        let mut id_allocator = IdAllocator::new(IdSpace::Transaction);
+
+        for inst in subintent.instructions.as_ref() {
+            let result = Self::process_instruction(
+                inst,
+                &[],
+                &mut proof_id_mapping,
+                &mut bucket_id_mapping,
+                &mut id_allocator,
+                api,
+            )?;
+            outputs.push(result.raw);
+        }
+
+        // Resources may only be handed over through the worktop
+        if let Some(bucket_id) = bucket_id_mapping.keys().next() {
+            return Err(InvokeError::Error(
+                TransactionProcessorError::SubintentBucketNotReturned(*bucket_id),
+            ));
+        }
+        for (_, real_id) in proof_id_mapping.drain() {
+            Proof(real_id)
+                .sys_drop(api)
+                .map_err(InvokeError::Downstream)?;
+        }
+
+        let handle = api.lock_substate(auth_zone_id, offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(handle)?;
+            substate_mut.auth_zone_stack().pop_frame();
+        }
+        api.drop_lock(handle)?;
+
+        Ok(IndexedScryptoValue::from_typed(&outputs))
+    }
+
+    fn auth_zone_stack_id<Y>(api: &mut Y) -> Result<RENodeId, RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let auth_zone_id = api
+            .get_visible_node_ids()?
             .into_iter()
-            .map(|sv| sv.raw)
-            .collect::<Vec<Vec<u8>>>())
+            .find(|node_id| matches!(node_id, RENodeId::AuthZoneStack(..)))
+            .expect("Auth zone stack is not visible to the transaction processor");
+        Ok(auth_zone_id)
     }
 }
@@ -4,6 +4,8 @@ use crate::types::*;
 pub struct CommitReceipt {
     pub inputs: Vec<OutputId>,
     pub outputs: Vec<OutputId>,
+    /// The root of the `StateHashTree` after the commit, if one is maintained.
+    pub state_root: Option<Hash>,
 }
 
 impl CommitReceipt {
@@ -11,6 +13,7 @@ impl CommitReceipt {
         CommitReceipt {
             inputs: Vec::new(),
             outputs: Vec::new(),
+            state_root: None,
         }
     }
 
@@ -1,7 +1,9 @@
 pub mod commit_receipt;
 pub mod staging;
 pub mod state_diff;
+pub mod state_hash_tree;
 
 pub use commit_receipt::*;
 pub use staging::*;
 pub use state_diff::*;
+pub use state_hash_tree::*;
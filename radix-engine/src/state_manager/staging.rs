@@ -103,9 +103,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> StagedSubstateStoreM
 
         self.merge_to_parent_recurse(node.parent_id, true);
 
-        for (substate_id, output) in node.outputs {
-            self.root.put_substate(substate_id, output);
-        }
+        self.root.commit_batch(node.outputs.into_iter().collect());
 
         if !remove_children {
             self.set_root_parent(id);
@@ -137,6 +135,64 @@ impl<'t, 's, S: ReadableSubstateStore> ReadableSubstateStore for StagedSubstateS
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.get_substate_recurse(substate_id, self.id)
     }
+
+    fn as_iterable(&self) -> Option<&dyn IterableSubstateStore> {
+        // Staged outputs can only be scanned on top of a root store which can be scanned
+        self.stores
+            .root
+            .as_iterable()
+            .map(|_| self as &dyn IterableSubstateStore)
+    }
+}
+
+impl<'t, 's, S: ReadableSubstateStore> IterableSubstateStore for StagedSubstateStore<'t, 's, S> {
+    fn list_substates(
+        &self,
+        range: &SubstateRange,
+        cursor: Option<&SubstateId>,
+        limit: usize,
+    ) -> SubstatePage {
+        let root = self
+            .stores
+            .root
+            .as_iterable()
+            .expect("Root store does not support range scans");
+        // Staged outputs only add or replace substates, so the next `limit + 1` substates of the
+        // root store are enough to fill the page and tell whether there are more
+        let cursor_key = cursor.map(encode_substate_key);
+        let mut substates: BTreeMap<Vec<u8>, (SubstateId, OutputValue)> = root
+            .list_substates(range, cursor, limit.saturating_add(1))
+            .substates
+            .into_iter()
+            .map(|(substate_id, output)| (encode_substate_key(&substate_id), (substate_id, output)))
+            .collect();
+
+        // Apply the staged outputs past the cursor, from the oldest ancestor down to this node
+        let mut ids = Vec::new();
+        let mut id = self.id;
+        while id != 0 {
+            ids.push(id);
+            id = self.stores.nodes.get(&id).unwrap().parent_id;
+        }
+        for id in ids.into_iter().rev() {
+            let node = self.stores.nodes.get(&id).unwrap();
+            for (substate_id, output) in &node.outputs {
+                if !range.contains(substate_id) {
+                    continue;
+                }
+                let key = encode_substate_key(substate_id);
+                let past_cursor = match &cursor_key {
+                    Some(cursor_key) => key.gt(cursor_key),
+                    None => true,
+                };
+                if past_cursor {
+                    substates.insert(key, (substate_id.clone(), output.clone()));
+                }
+            }
+        }
+
+        SubstatePage::from_sorted(substates.into_values(), range, cursor, limit)
+    }
 }
 
 impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstateStore<'t, 's, S> {
@@ -152,7 +208,7 @@ impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstate
 
 #[cfg(test)]
 mod tests {
-    use crate::ledger::TypedInMemorySubstateStore;
+    use crate::ledger::*;
     use crate::state_manager::StagedSubstateStoreManager;
 
     #[test]
@@ -183,4 +239,68 @@ mod tests {
         let node = stores.nodes.get(&child_node10).expect("Should exist");
         assert_eq!(node.parent_id, child_node9);
     }
+
+    #[test]
+    fn test_list_substates_includes_staged_outputs() {
+        // Arrange
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let mut expected = store.list_all_substates(&SubstateRange::All);
+        let mut stores = StagedSubstateStoreManager::new(&mut store);
+        let (substate_id, mut output) = expected[0].clone();
+        let child_node1 = stores.new_child_node(0);
+        output.version += 1;
+        stores
+            .get_output_store(child_node1)
+            .put_substate(substate_id.clone(), output.clone());
+        let child_node2 = stores.new_child_node(child_node1);
+        output.version += 1;
+        stores
+            .get_output_store(child_node2)
+            .put_substate(substate_id, output.clone());
+        expected[0].1 = output;
+
+        // Act
+        let staged_store = stores.get_output_store(child_node2);
+        let substates = staged_store
+            .as_iterable()
+            .expect("Should be iterable")
+            .list_all_substates(&SubstateRange::All);
+
+        // Assert
+        assert_eq!(substates, expected);
+    }
+
+    #[test]
+    fn test_list_substates_can_be_paginated_over_staged_outputs() {
+        // Arrange
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let mut expected = store.list_all_substates(&SubstateRange::All);
+        let mut stores = StagedSubstateStoreManager::new(&mut store);
+        let child_node = stores.new_child_node(0);
+        let middle = expected.len() / 2;
+        let (substate_id, mut output) = expected[middle].clone();
+        output.version += 1;
+        stores
+            .get_output_store(child_node)
+            .put_substate(substate_id, output.clone());
+        expected[middle].1 = output;
+
+        // Act
+        let staged_store = stores.get_output_store(child_node);
+        let iterable = staged_store.as_iterable().expect("Should be iterable");
+        let mut substates = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = iterable.list_substates(&SubstateRange::All, cursor.as_ref(), 7);
+            assert!(page.substates.len() <= 7);
+            substates.extend(page.substates);
+            match page.next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+
+        // Assert
+        assert_eq!(substates, expected);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::ledger::*;
 use crate::state_manager::CommitReceipt;
+use crate::transaction::IntentHashTrackingSubstateStore;
 use crate::types::*;
 use radix_engine_interface::api::types::SubstateId;
 use radix_engine_interface::crypto::hash;
@@ -19,9 +20,30 @@ impl StateDiff {
         }
     }
 
-    /// Applies the state changes to some substate store.
+    /// Applies the state changes to some substate store, as a single atomic batch.
     pub fn commit<S: WriteableSubstateStore>(&self, store: &mut S) -> CommitReceipt {
+        let (mut receipt, batch) = self.to_batch();
+        store.commit_batch(batch);
+        receipt.state_root = store.state_root();
+        receipt
+    }
+
+    /// Applies the state changes to some substate store, together with the hashes of the intents
+    /// committed by the transaction, as a single atomic batch.
+    pub fn commit_with_intent_hashes<S: IntentHashTrackingSubstateStore>(
+        &self,
+        store: &mut S,
+        intent_hashes: Vec<(Hash, u64)>,
+    ) -> CommitReceipt {
+        let (mut receipt, batch) = self.to_batch();
+        store.commit_batch_with_intent_hashes(batch, intent_hashes);
+        receipt.state_root = store.state_root();
+        receipt
+    }
+
+    fn to_batch(&self) -> (CommitReceipt, Vec<(SubstateId, OutputValue)>) {
         let mut receipt = CommitReceipt::new();
+        let mut batch = Vec::new();
 
         for output_id in &self.down_substates {
             receipt.down(output_id.clone());
@@ -40,9 +62,9 @@ impl StateDiff {
                 version: output_value.version,
             };
             receipt.up(output_id);
-            store.put_substate(substate_id.clone(), output_value.clone());
+            batch.push((substate_id.clone(), output_value.clone()));
         }
 
-        receipt
+        (receipt, batch)
     }
 }
@@ -0,0 +1,488 @@
+use crate::ledger::*;
+use crate::model::{KeyValueStoreEntrySubstate, NonFungibleSubstate, PersistedSubstate};
+use crate::types::*;
+use radix_engine_interface::api::types::SubstateId;
+use radix_engine_interface::crypto::hash;
+
+/// The hash of an empty subtree, at any depth.
+const EMPTY_HASH: Hash = Hash([0u8; Hash::LENGTH]);
+
+/// The position of a node in a `StateHashTree`: its depth below the root, and the first `depth`
+/// bits of the leaf keys below it, with all other bits cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TreeNodeKey {
+    pub depth: u16,
+    pub path: Hash,
+}
+
+/// A non-empty node of a `StateHashTree`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum TreeNode {
+    /// A subtree holding two or more leaves, with its hash
+    Internal(Hash),
+    /// A subtree holding a single leaf, which is kept at the highest position where it's alone
+    Leaf { key: Hash, substate_hash: Hash },
+}
+
+/// A store of the nodes of a `StateHashTree`.
+pub trait ReadableTreeStore {
+    fn get_tree_node(&self, key: &TreeNodeKey) -> Option<TreeNode>;
+}
+
+impl ReadableTreeStore for HashMap<TreeNodeKey, TreeNode> {
+    fn get_tree_node(&self, key: &TreeNodeKey) -> Option<TreeNode> {
+        self.get(key).cloned()
+    }
+}
+
+/// A sparse Merkle tree committing to the hash of every substate in a store.
+///
+/// Leaves are keyed by the hash of the encoded `SubstateId`, so every substate has a fixed path
+/// in the tree, and proofs of both presence and absence can be produced. Paths are compressed: a
+/// subtree holding a single leaf is replaced by that leaf, so paths are only as long as needed to
+/// tell the keys apart, i.e. about `log2(n)` nodes for `n` substates.
+///
+/// The tree reads its nodes from a store and stages its changes, for the store to write them
+/// together with the substates they commit to.
+pub struct StateHashTree<'s, S: ReadableTreeStore> {
+    store: &'s S,
+    changes: HashMap<TreeNodeKey, Option<TreeNode>>,
+}
+
+/// The sibling hashes on the path from the root of a `StateHashTree` to a substate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct StateProof {
+    /// The hashes of the siblings of the nodes on the path, from the root downwards.
+    pub siblings: Vec<Hash>,
+    /// The key and substate hash of the leaf at the end of the path, if the path doesn't end in
+    /// an empty subtree.
+    pub leaf: Option<(Hash, Hash)>,
+}
+
+impl<'s, S: ReadableTreeStore> StateHashTree<'s, S> {
+    pub fn new(store: &'s S) -> Self {
+        Self {
+            store,
+            changes: HashMap::new(),
+        }
+    }
+
+    /// Returns the current state root.
+    pub fn root(&self) -> Hash {
+        self.get_node_hash(&TreeNodeKey::root())
+    }
+
+    /// Updates the leaves of the given substates, removing those which hold no value.
+    pub fn update_substates(&mut self, substates: &[(SubstateId, OutputValue)]) {
+        for (substate_id, output_value) in substates {
+            match committed_substate_hash(&output_value.substate) {
+                Some(substate_hash) => self.put(substate_id, substate_hash),
+                None => self.remove(substate_id),
+            }
+        }
+    }
+
+    /// Sets the hash of a substate, updating every node on the path to the root.
+    pub fn put(&mut self, substate_id: &SubstateId, substate_hash: Hash) {
+        let key = leaf_key(substate_id);
+        let leaf = TreeNode::Leaf { key, substate_hash };
+
+        let mut depth = 0;
+        let leaf_depth = loop {
+            let node_key = TreeNodeKey::new(&key, depth);
+            match self.get_node(&node_key) {
+                Some(TreeNode::Internal(..)) => depth += 1,
+                Some(TreeNode::Leaf {
+                    key: other_key,
+                    substate_hash: other_substate_hash,
+                }) if other_key != key => {
+                    // Both leaves move down to where their keys diverge
+                    let split_depth = first_differing_bit(&key, &other_key) + 1;
+                    self.set_node(
+                        TreeNodeKey::new(&other_key, split_depth),
+                        Some(TreeNode::Leaf {
+                            key: other_key,
+                            substate_hash: other_substate_hash,
+                        }),
+                    );
+                    self.set_node(TreeNodeKey::new(&key, split_depth), Some(leaf));
+                    break split_depth;
+                }
+                _ => {
+                    self.set_node(node_key, Some(leaf));
+                    break depth;
+                }
+            }
+        };
+
+        self.rehash_path(&key, leaf_depth);
+    }
+
+    /// Removes a substate, lifting any leaf left alone in its subtree.
+    pub fn remove(&mut self, substate_id: &SubstateId) {
+        let key = leaf_key(substate_id);
+
+        let mut depth = 0;
+        loop {
+            match self.get_node(&TreeNodeKey::new(&key, depth)) {
+                Some(TreeNode::Internal(..)) => depth += 1,
+                Some(TreeNode::Leaf { key: leaf_key, .. }) if leaf_key == key => break,
+                _ => return,
+            }
+        }
+        self.set_node(TreeNodeKey::new(&key, depth), None);
+
+        while depth > 0 {
+            let node_key = TreeNodeKey::new(&key, depth);
+            let sibling_key = node_key.sibling();
+            match (self.get_node(&node_key), self.get_node(&sibling_key)) {
+                (None, Some(leaf @ TreeNode::Leaf { .. })) => {
+                    self.set_node(sibling_key, None);
+                    self.set_node(TreeNodeKey::new(&key, depth - 1), Some(leaf));
+                }
+                (Some(leaf @ TreeNode::Leaf { .. }), None) => {
+                    self.set_node(node_key, None);
+                    self.set_node(TreeNodeKey::new(&key, depth - 1), Some(leaf));
+                }
+                (None, None) => {
+                    self.set_node(TreeNodeKey::new(&key, depth - 1), None);
+                }
+                _ => {
+                    self.rehash_path(&key, depth);
+                    return;
+                }
+            }
+            depth -= 1;
+        }
+    }
+
+    /// Returns a proof for the substate, which proves either its current hash or its absence.
+    pub fn prove(&self, substate_id: &SubstateId) -> StateProof {
+        let key = leaf_key(substate_id);
+        let mut siblings = Vec::new();
+        let mut depth = 0;
+        loop {
+            let node_key = TreeNodeKey::new(&key, depth);
+            match self.get_node(&node_key) {
+                Some(TreeNode::Internal(..)) => {
+                    depth += 1;
+                    siblings.push(self.get_node_hash(&TreeNodeKey::new(&key, depth).sibling()));
+                }
+                Some(TreeNode::Leaf {
+                    key: leaf_key,
+                    substate_hash,
+                }) => {
+                    return StateProof {
+                        siblings,
+                        leaf: Some((leaf_key, substate_hash)),
+                    }
+                }
+                None => {
+                    return StateProof {
+                        siblings,
+                        leaf: None,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the node changes staged by the updates, where `None` removes a node.
+    pub fn into_changes(self) -> HashMap<TreeNodeKey, Option<TreeNode>> {
+        self.changes
+    }
+
+    /// Rehashes the internal nodes above the given depth on the path to the key.
+    fn rehash_path(&mut self, key: &Hash, depth: u16) {
+        for depth in (0..depth).rev() {
+            let node_key = TreeNodeKey::new(key, depth);
+            let (left, right) = node_key.children();
+            let node_hash = node_hash(&self.get_node_hash(&left), &self.get_node_hash(&right));
+            self.set_node(node_key, Some(TreeNode::Internal(node_hash)));
+        }
+    }
+
+    fn get_node(&self, key: &TreeNodeKey) -> Option<TreeNode> {
+        match self.changes.get(key) {
+            Some(node) => node.clone(),
+            None => self.store.get_tree_node(key),
+        }
+    }
+
+    fn get_node_hash(&self, key: &TreeNodeKey) -> Hash {
+        self.get_node(key)
+            .map(|node| node.hash())
+            .unwrap_or(EMPTY_HASH)
+    }
+
+    fn set_node(&mut self, key: TreeNodeKey, node: Option<TreeNode>) {
+        self.changes.insert(key, node);
+    }
+}
+
+/// Computes the state root over all substates currently in the store, from scratch.
+pub fn compute_state_root<S: IterableSubstateStore>(store: &S) -> Hash {
+    let nodes = HashMap::new();
+    let mut tree = StateHashTree::new(&nodes);
+    tree.update_substates(&store.list_all_substates(&SubstateRange::All));
+    tree.root()
+}
+
+/// Returns the hash committed to the tree for a substate, or `None` if it holds no value, such
+/// as a removed key-value store entry or a burnt non-fungible.
+pub fn committed_substate_hash(substate: &PersistedSubstate) -> Option<Hash> {
+    match substate {
+        PersistedSubstate::KeyValueStoreEntry(KeyValueStoreEntrySubstate(None))
+        | PersistedSubstate::NonFungible(NonFungibleSubstate(None)) => None,
+        substate => Some(hash(scrypto_encode(substate).unwrap_or_else(|err| {
+            panic!(
+                "Could not encode committed substate: {:?}. Substate: {:?}",
+                err, substate
+            )
+        }))),
+    }
+}
+
+impl TreeNodeKey {
+    pub fn root() -> Self {
+        Self {
+            depth: 0,
+            path: EMPTY_HASH,
+        }
+    }
+
+    /// Returns the key of the node at the given depth on the path to the leaf key.
+    pub fn new(leaf_key: &Hash, depth: u16) -> Self {
+        let (bytes, bits) = (depth as usize / 8, depth as usize % 8);
+        let mut path = EMPTY_HASH;
+        path.0[..bytes].copy_from_slice(&leaf_key.0[..bytes]);
+        if bits > 0 {
+            path.0[bytes] = leaf_key.0[bytes] & !(0xffu8 >> bits);
+        }
+        Self { depth, path }
+    }
+
+    fn sibling(&self) -> Self {
+        let mut path = self.path;
+        let i = (self.depth - 1) as usize;
+        path.0[i / 8] ^= 0x80 >> (i % 8);
+        Self {
+            depth: self.depth,
+            path,
+        }
+    }
+
+    fn children(&self) -> (Self, Self) {
+        let left = Self {
+            depth: self.depth + 1,
+            path: self.path,
+        };
+        (left, left.sibling())
+    }
+}
+
+impl TreeNode {
+    pub fn hash(&self) -> Hash {
+        match self {
+            TreeNode::Internal(node_hash) => *node_hash,
+            TreeNode::Leaf { key, substate_hash } => leaf_hash(key, substate_hash),
+        }
+    }
+}
+
+impl StateProof {
+    /// Checks that the substate has the given hash in the state with the given root.
+    pub fn verify_inclusion(
+        &self,
+        root: &Hash,
+        substate_id: &SubstateId,
+        substate_hash: &Hash,
+    ) -> bool {
+        let key = leaf_key(substate_id);
+        self.leaf == Some((key, *substate_hash)) && self.compute_root(&key) == *root
+    }
+
+    /// Checks that the substate does not exist in the state with the given root.
+    pub fn verify_exclusion(&self, root: &Hash, substate_id: &SubstateId) -> bool {
+        let key = leaf_key(substate_id);
+        let depth = self.siblings.len() as u16;
+        let ends_elsewhere = match &self.leaf {
+            // Another leaf alone in the subtree on the path to the key
+            Some((leaf_key, _)) => {
+                *leaf_key != key
+                    && TreeNodeKey::new(leaf_key, depth) == TreeNodeKey::new(&key, depth)
+            }
+            None => true,
+        };
+        ends_elsewhere && self.compute_root(&key) == *root
+    }
+
+    fn compute_root(&self, key: &Hash) -> Hash {
+        let mut current = match &self.leaf {
+            Some((leaf_key, substate_hash)) => leaf_hash(leaf_key, substate_hash),
+            None => EMPTY_HASH,
+        };
+        for (depth, sibling) in self.siblings.iter().enumerate().rev() {
+            current = if bit_at(key, depth as u16) {
+                node_hash(sibling, &current)
+            } else {
+                node_hash(&current, sibling)
+            };
+        }
+        current
+    }
+}
+
+fn leaf_key(substate_id: &SubstateId) -> Hash {
+    hash(encode_substate_key(substate_id))
+}
+
+fn leaf_hash(key: &Hash, substate_hash: &Hash) -> Hash {
+    hash([key.0, substate_hash.0].concat())
+}
+
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    hash([left.0, right.0].concat())
+}
+
+fn bit_at(key: &Hash, depth: u16) -> bool {
+    let depth = depth as usize;
+    key.0[depth / 8] & (0x80 >> (depth % 8)) != 0
+}
+
+fn first_differing_bit(a: &Hash, b: &Hash) -> u16 {
+    let i =
+        a.0.iter()
+            .zip(b.0.iter())
+            .position(|(a, b)| a != b)
+            .expect("Keys are equal");
+    (i * 8) as u16 + (a.0[i] ^ b.0[i]).leading_zeros() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ledger::*;
+    use crate::model::{KeyValueStoreEntrySubstate, PersistedSubstate};
+    use crate::state_manager::{
+        committed_substate_hash, compute_state_root, StateDiff, StateHashTree, TreeNode,
+        TreeNodeKey,
+    };
+    use crate::types::*;
+    use radix_engine_interface::api::types::{KeyValueStoreOffset, RENodeId, SubstateId};
+    use radix_engine_interface::crypto::hash;
+
+    fn kv_store_entry(key: u8, value: Option<Vec<u8>>) -> (SubstateId, OutputValue) {
+        (
+            SubstateId(
+                RENodeId::KeyValueStore([0u8; 36]),
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(vec![key])),
+            ),
+            OutputValue {
+                substate: PersistedSubstate::KeyValueStoreEntry(KeyValueStoreEntrySubstate(value)),
+                version: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn test_incrementally_updated_root_matches_rebuilt_root() {
+        // Arrange
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let root_before = store.state_root();
+        let substates = store.list_all_substates(&SubstateRange::All);
+        let mut state_diff = StateDiff::new();
+        let (first_id, first_value) = substates.first().unwrap().clone();
+        let (last_id, last_value) = substates.last().unwrap().clone();
+        state_diff.up_substates.insert(
+            first_id,
+            OutputValue {
+                substate: last_value.substate.clone(),
+                version: first_value.version + 1,
+            },
+        );
+        state_diff.up_substates.insert(
+            last_id,
+            OutputValue {
+                substate: first_value.substate,
+                version: last_value.version + 1,
+            },
+        );
+
+        // Act
+        let receipt = state_diff.commit(&mut store);
+
+        // Assert
+        assert_ne!(receipt.state_root, root_before);
+        assert_eq!(receipt.state_root, Some(compute_state_root(&store)));
+    }
+
+    #[test]
+    fn test_proofs_of_inclusion_and_exclusion() {
+        // Arrange
+        let store = TypedInMemorySubstateStore::with_bootstrap();
+        let tree = StateHashTree::new(&store);
+        let root = tree.root();
+        let (substate_id, output_value) =
+            store.list_all_substates(&SubstateRange::All).pop().unwrap();
+        let substate_hash = committed_substate_hash(&output_value.substate).unwrap();
+        let (missing_substate_id, _) = kv_store_entry(0, None);
+
+        // Act
+        let proof = tree.prove(&substate_id);
+        let missing_proof = tree.prove(&missing_substate_id);
+
+        // Assert
+        assert!(proof.verify_inclusion(&root, &substate_id, &substate_hash));
+        assert!(!proof.verify_inclusion(&root, &substate_id, &hash("other")));
+        assert!(!proof.verify_exclusion(&root, &substate_id));
+        assert!(missing_proof.verify_exclusion(&root, &missing_substate_id));
+        assert!(!missing_proof.verify_inclusion(&root, &missing_substate_id, &substate_hash));
+        assert!(!proof.verify_exclusion(&root, &missing_substate_id));
+    }
+
+    #[test]
+    fn test_removed_substates_are_dropped_from_the_tree() {
+        // Arrange
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let root_before = store.state_root().unwrap();
+        let entries: Vec<_> = (0..10u8)
+            .map(|key| kv_store_entry(key, Some(vec![key])))
+            .collect();
+        store.commit_batch(entries.clone());
+
+        // Act
+        let removed_entries: Vec<_> = (0..10u8).map(|key| kv_store_entry(key, None)).collect();
+        store.commit_batch(removed_entries);
+
+        // Assert
+        let root = store.state_root().unwrap();
+        assert_eq!(root, root_before);
+        let (substate_id, _) = &entries[0];
+        assert!(StateHashTree::new(&store)
+            .prove(substate_id)
+            .verify_exclusion(&root, substate_id));
+    }
+
+    #[test]
+    fn test_paths_are_compressed() {
+        // Arrange
+        let store = TypedInMemorySubstateStore::with_bootstrap();
+        let substates = store.list_all_substates(&SubstateRange::All);
+        let nodes: HashMap<TreeNodeKey, TreeNode> = HashMap::new();
+
+        // Act
+        let mut tree = StateHashTree::new(&nodes);
+        tree.update_substates(&substates);
+        let node_count = tree
+            .into_changes()
+            .into_values()
+            .filter(Option::is_some)
+            .count();
+
+        // Assert
+        // A trie over random keys has about `n / ln(2)` internal nodes on average
+        assert!(node_count < 3 * substates.len());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::engine::ScryptoInterpreter;
+use crate::ledger::{read_epoch, OutputValue, ReadableSubstateStore, WriteableSubstateStore};
+use crate::transaction::{
+    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt, TransactionResult,
+};
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_interface::api::types::SubstateId;
+use transaction::model::Executable;
+use transaction::validation::IntentHashStore;
+
+/// A substate store which keeps the hashes of the intents committed to it.
+pub trait IntentHashTrackingSubstateStore: WriteableSubstateStore + IntentHashStore {
+    /// Writes the substates and the intent hashes committed by a transaction as a single atomic
+    /// batch, so that a crash can't leave the transaction committed but replayable.
+    fn commit_batch_with_intent_hashes(
+        &mut self,
+        substates: Vec<(SubstateId, OutputValue)>,
+        intent_hashes: Vec<(Hash, u64)>,
+    );
+}
+
+/// Executes a transaction and, if it's committed, writes its state updates together with its
+/// intent hashes, so that its intents are rejected until their epoch ranges have ended.
+///
+/// The intent hashes which have expired by the current epoch are pruned on every commit.
+pub fn execute_and_commit_transaction_with_intent_hashes<
+    S: ReadableSubstateStore + IntentHashTrackingSubstateStore,
+    W: WasmEngine,
+>(
+    substate_store: &mut S,
+    scrypto_interpreter: &ScryptoInterpreter<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    execution_config: &ExecutionConfig,
+    transaction: &Executable,
+) -> TransactionReceipt {
+    let receipt = execute_transaction(
+        substate_store,
+        scrypto_interpreter,
+        fee_reserve_config,
+        execution_config,
+        transaction,
+    );
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        commit
+            .state_updates
+            .commit_with_intent_hashes(substate_store, transaction.intent_hashes());
+
+        if let Some(epoch) = read_epoch(substate_store) {
+            substate_store.remove_expired_intent_hashes(epoch);
+        }
+    }
+    receipt
+}
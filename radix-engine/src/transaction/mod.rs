@@ -1,7 +1,9 @@
+mod intent_hash_tracker;
 mod preview_executor;
 mod transaction_executor;
 mod transaction_receipt;
 
+pub use intent_hash_tracker::*;
 pub use preview_executor::*;
 pub use transaction_executor::*;
 pub use transaction_receipt::*;
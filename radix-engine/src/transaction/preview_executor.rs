@@ -1,3 +1,4 @@
+use radix_engine_constants::PREVIEW_COST_UNIT_LIMIT_MARGIN_PERCENTAGE;
 use radix_engine_interface::core::NetworkDefinition;
 use transaction::errors::TransactionValidationError;
 use transaction::model::PreviewIntent;
@@ -6,6 +7,7 @@ use transaction::validation::NotarizedTransactionValidator;
 use transaction::validation::ValidationConfig;
 
 use crate::engine::ScryptoInterpreter;
+use crate::fee::CostBreakdown;
 use crate::ledger::*;
 use crate::transaction::TransactionReceipt;
 use crate::transaction::*;
@@ -15,11 +17,22 @@ use crate::wasm::WasmEngine;
 pub struct PreviewResult {
     pub intent: PreviewIntent,
     pub receipt: TransactionReceipt,
+    /// The cost unit limit to submit the transaction with, leaving a margin for changes
+    /// to the ledger state between preview and execution
+    pub recommended_cost_unit_limit: u32,
+}
+
+impl PreviewResult {
+    /// The cost breakdown by instruction and invocation, if requested by the preview flags.
+    pub fn cost_breakdown(&self) -> Option<&CostBreakdown> {
+        self.receipt.execution.fee_summary.cost_breakdown.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreviewError {
     TransactionValidationError(TransactionValidationError),
+    UnknownStateVersion(u64),
 }
 
 pub fn execute_preview<S: ReadableSubstateStore, W: WasmEngine, IHM: IntentHashManager>(
@@ -42,13 +55,51 @@ pub fn execute_preview<S: ReadableSubstateStore, W: WasmEngine, IHM: IntentHashM
             substate_store,
             scrypto_interpreter,
             &FeeReserveConfig::default(),
-            &ExecutionConfig::default(),
+            &ExecutionConfig {
+                cost_breakdown: preview_intent.flags.cost_breakdown,
+                ..ExecutionConfig::default()
+            },
             &executable,
         )
     };
 
+    let cost_unit_consumed = receipt.execution.fee_summary.cost_unit_consumed as u64;
+    let recommended_cost_unit_limit = (cost_unit_consumed
+        * (100 + PREVIEW_COST_UNIT_LIMIT_MARGIN_PERCENTAGE as u64)
+        / 100)
+        .clamp(
+            validation_config.min_cost_unit_limit as u64,
+            validation_config.max_cost_unit_limit as u64,
+        ) as u32;
+
     Ok(PreviewResult {
         intent: preview_intent,
         receipt,
+        recommended_cost_unit_limit,
     })
 }
+
+/// Executes a preview against the state of the ledger as of a past state version.
+pub fn execute_preview_at_state_version<
+    S: HistoricalSubstateStore,
+    W: WasmEngine,
+    IHM: IntentHashManager,
+>(
+    substate_store: &S,
+    state_version: u64,
+    scrypto_interpreter: &ScryptoInterpreter<W>,
+    intent_hash_manager: &IHM,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntent,
+) -> Result<PreviewResult, PreviewError> {
+    let snapshot = SubstateStoreSnapshot::new(substate_store, state_version)
+        .ok_or(PreviewError::UnknownStateVersion(state_version))?;
+
+    execute_preview(
+        &snapshot,
+        scrypto_interpreter,
+        intent_hash_manager,
+        network,
+        preview_intent,
+    )
+}
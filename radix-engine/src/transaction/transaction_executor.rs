@@ -1,20 +1,17 @@
 use crate::engine::Track;
 use crate::engine::*;
-use crate::fee::{FeeReserve, FeeTable, SystemLoanFeeReserve};
-use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
+use crate::fee::{decimal_to_u128, default_fee_config, FeeReserve, FeeTable, SystemLoanFeeReserve};
+use crate::ledger::{read_fee_config, ReadableSubstateStore, WriteableSubstateStore};
 use crate::model::*;
 use crate::transaction::*;
 use crate::types::*;
 use crate::wasm::*;
-use radix_engine_constants::{
-    DEFAULT_COST_UNIT_PRICE, DEFAULT_MAX_CALL_DEPTH, DEFAULT_SYSTEM_LOAN,
-};
+use radix_engine_constants::{DEFAULT_MAX_CALL_DEPTH, DEFAULT_SYSTEM_LOAN};
 use radix_engine_interface::api::api::Invokable;
 use sbor::rust::borrow::Cow;
 use transaction::model::*;
 
 pub struct FeeReserveConfig {
-    pub cost_unit_price: u128,
     pub system_loan: u32,
 }
 
@@ -27,7 +24,6 @@ impl Default for FeeReserveConfig {
 impl FeeReserveConfig {
     pub fn standard() -> Self {
         Self {
-            cost_unit_price: DEFAULT_COST_UNIT_PRICE,
             system_loan: DEFAULT_SYSTEM_LOAN,
         }
     }
@@ -37,6 +33,8 @@ pub struct ExecutionConfig {
     pub max_call_depth: usize,
     pub trace: bool,
     pub max_sys_call_trace_depth: usize,
+    /// Whether to attribute the cost units to instructions and invocations
+    pub cost_breakdown: bool,
 }
 
 impl Default for ExecutionConfig {
@@ -51,6 +49,7 @@ impl ExecutionConfig {
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
             trace: false,
             max_sys_call_trace_depth: 1,
+            cost_breakdown: false,
         }
     }
 
@@ -59,6 +58,7 @@ impl ExecutionConfig {
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
             trace: true,
             max_sys_call_trace_depth: 1,
+            cost_breakdown: false,
         }
     }
 }
@@ -93,20 +93,33 @@ where
         fee_reserve_config: &FeeReserveConfig,
         execution_config: &ExecutionConfig,
     ) -> TransactionReceipt {
+        // Fees are priced by the fee configuration on ledger, so they can change without an
+        // upgrade. Only a store without genesis, such as the one genesis runs against, lacks it.
+        let fee_config = read_fee_config(self.substate_store)
+            .map(|substate| substate.config)
+            .unwrap_or_else(default_fee_config);
+        let cost_unit_price = decimal_to_u128(fee_config.cost_unit_price);
+        let fee_table = FeeTable::from_config(&fee_config.fee_table);
+
         let fee_reserve = match transaction.fee_payment() {
             FeePayment::User {
                 cost_unit_limit,
                 tip_percentage,
             } => SystemLoanFeeReserve::new(
-                fee_reserve_config.cost_unit_price,
+                cost_unit_price,
                 *tip_percentage,
                 *cost_unit_limit,
                 fee_reserve_config.system_loan,
             ),
             FeePayment::NoFee => SystemLoanFeeReserve::no_fee(),
         };
+        let fee_reserve = if execution_config.cost_breakdown {
+            fee_reserve.with_cost_breakdown()
+        } else {
+            fee_reserve
+        };
 
-        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve)
+        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, fee_table)
     }
 
     fn execute_with_fee_reserve<R: FeeReserve>(
@@ -114,6 +127,7 @@ where
         transaction: &Executable,
         execution_config: &ExecutionConfig,
         fee_reserve: R,
+        fee_table: FeeTable,
     ) -> TransactionReceipt {
         let transaction_hash = transaction.transaction_hash();
         let auth_zone_params = transaction.auth_zone_params();
@@ -131,7 +145,7 @@ where
         }
 
         // Prepare state track and execution trace
-        let track = Track::new(self.substate_store, fee_reserve, FeeTable::new());
+        let track = Track::new(self.substate_store, fee_reserve, fee_table);
 
         // Apply pre execution costing
         let pre_execution_result = track.apply_pre_execution_costs(transaction);
@@ -172,7 +186,7 @@ where
                 transaction_hash.clone(),
                 auth_zone_params.clone(),
                 blobs,
-                execution_config.max_call_depth,
+                execution_config,
                 track,
                 self.scrypto_interpreter,
                 modules,
@@ -181,6 +195,7 @@ where
             let invoke_result = kernel.invoke(TransactionProcessorRunInvocation {
                 runtime_validations: Cow::Borrowed(transaction.runtime_validations()),
                 instructions: Cow::Borrowed(instructions),
+                subintents: Cow::Borrowed(transaction.subintents()),
             });
 
             kernel.finalize(invoke_result)
@@ -177,8 +177,8 @@ impl TransactionReceipt {
         match &self.result {
             TransactionResult::Commit(c) => match &c.outcome {
                 TransactionOutcome::Success(x) => x,
-                TransactionOutcome::Failure(err) => {
-                    panic!("Expected success but was failed:\n{:?}", err)
+                TransactionOutcome::Failure(..) => {
+                    panic!("Expected success but was failed:\n{:?}", self)
                 }
             },
             TransactionResult::Reject(err) => panic!("Transaction was rejected:\n{:?}", err),
@@ -253,6 +253,13 @@ macro_rules! prefix {
     };
 }
 
+fn format_non_fungible_ids(ids: &BTreeSet<NonFungibleId>) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 impl fmt::Debug for TransactionReceipt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.display(NO_NETWORK))
@@ -286,6 +293,19 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for TransactionReceipt {
             },
         )?;
 
+        if let TransactionResult::Commit(c) = result {
+            if let TransactionOutcome::Failure(e) = &c.outcome {
+                if let Some(trace) = e.auth_trace() {
+                    write!(
+                        f,
+                        "\n{}\n{}",
+                        "Authorization Trace:".bold().green(),
+                        trace.display(*context)
+                    )?;
+                }
+            }
+        }
+
         write!(
             f,
             "\n{} {} XRD used for execution, {} XRD used for royalty, {} XRD in bad debt",
@@ -393,6 +413,36 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for TransactionReceipt {
                     resource_address.display(bech32_encoder)
                 )?;
             }
+
+            write!(
+                f,
+                "\n{} {}",
+                "Resource Changes:".bold().green(),
+                c.resource_changes.len()
+            )?;
+            for (i, resource_change) in c.resource_changes.iter().enumerate() {
+                write!(
+                    f,
+                    "\n{} Resource: {}, Change: {}",
+                    prefix!(i, c.resource_changes),
+                    resource_change.resource_address.display(bech32_encoder),
+                    resource_change.amount
+                )?;
+                if !resource_change.added_ids.is_empty() {
+                    write!(
+                        f,
+                        ", Added: {}",
+                        format_non_fungible_ids(&resource_change.added_ids)
+                    )?;
+                }
+                if !resource_change.removed_ids.is_empty() {
+                    write!(
+                        f,
+                        ", Removed: {}",
+                        format_non_fungible_ids(&resource_change.removed_ids)
+                    )?;
+                }
+            }
         }
 
         Ok(())
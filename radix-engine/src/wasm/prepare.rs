@@ -621,6 +621,7 @@ mod tests {
                     output: scrypto::abi::Type::Unit,
                     export_name: "Test_f".to_string(),
                 }],
+                events: vec![],
             },
         );
         assert_invalid_wasm!(
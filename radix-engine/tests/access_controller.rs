@@ -0,0 +1,488 @@
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::AccessControllerError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+struct Roles {
+    primary: NonFungibleAddress,
+    recovery: NonFungibleAddress,
+    confirmation: NonFungibleAddress,
+}
+
+impl Roles {
+    fn new(test_runner: &mut TestRunner<TypedInMemorySubstateStore>) -> Self {
+        let mut new_badge = || {
+            let (public_key, _) = test_runner.new_key_pair();
+            NonFungibleAddress::from_public_key(&public_key)
+        };
+        Self {
+            primary: new_badge(),
+            recovery: new_badge(),
+            confirmation: new_badge(),
+        }
+    }
+
+    fn rule_set(&self) -> RuleSet {
+        RuleSet {
+            primary_role: rule!(require(self.primary.clone())),
+            recovery_role: rule!(require(self.recovery.clone())),
+            confirmation_role: rule!(require(self.confirmation.clone())),
+        }
+    }
+}
+
+/// Creates an access controller holding a freshly created badge, and an account which may only
+/// be withdrawn from with a proof of that badge.
+fn create_access_controller(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    rule_set: RuleSet,
+    timed_recovery_delay_in_minutes: Option<u32>,
+) -> (ComponentAddress, ComponentAddress) {
+    let (public_key, _, account) = test_runner.new_account(false);
+    let badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, badge)
+        .take_from_worktop(badge, |builder, bucket_id| {
+            builder.call_native_function(
+                ACCESS_CONTROLLER_BLUEPRINT,
+                AccessControllerFunction::CreateGlobal.as_ref(),
+                args!(Bucket(bucket_id), rule_set, timed_recovery_delay_in_minutes),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    let access_controller = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    let controlled_account = test_runner.new_account_with_auth_rule(&rule!(require(badge)));
+    (access_controller, controlled_account)
+}
+
+fn call_method(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    access_controller: ComponentAddress,
+    method: AccessControllerMethod,
+    args: Vec<u8>,
+    signer: &NonFungibleAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::Component(access_controller)),
+            method.as_ref(),
+            args,
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![signer.clone()])
+}
+
+/// Creates a proof of the controlled badge and uses it to withdraw from the controlled account.
+fn withdraw_with_controlled_badge(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    access_controller: ComponentAddress,
+    controlled_account: ComponentAddress,
+    signer: &NonFungibleAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::Component(access_controller)),
+            AccessControllerMethod::CreateProof.as_ref(),
+            args!(access_controller),
+        )
+        .withdraw_from_account_by_amount(controlled_account, 1.into(), RADIX_TOKEN)
+        .call_method(
+            controlled_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![signer.clone()])
+}
+
+fn is_auth_error(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+    )
+}
+
+fn is_access_controller_error(e: &RuntimeError, error: AccessControllerError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(e)) if *e == error
+    )
+}
+
+#[test]
+fn primary_role_can_use_controlled_badge() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let (access_controller, controlled_account) =
+        create_access_controller(&mut test_runner, roles.rule_set(), None);
+
+    // Act
+    let receipt = withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &roles.primary,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn recovery_role_cannot_use_controlled_badge() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let (access_controller, controlled_account) =
+        create_access_controller(&mut test_runner, roles.rule_set(), None);
+
+    // Act
+    let receipt = withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &roles.recovery,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn locked_primary_role_cannot_use_controlled_badge_until_unlocked() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let (access_controller, controlled_account) =
+        create_access_controller(&mut test_runner, roles.rule_set(), None);
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::LockPrimaryRole,
+        args!(access_controller),
+        &roles.recovery,
+    )
+    .expect_commit_success();
+
+    // Act
+    let locked_receipt = withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &roles.primary,
+    );
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::UnlockPrimaryRole,
+        args!(access_controller),
+        &roles.recovery,
+    )
+    .expect_commit_success();
+    let unlocked_receipt = withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &roles.primary,
+    );
+
+    // Assert
+    locked_receipt.expect_specific_failure(|e| {
+        is_access_controller_error(
+            e,
+            AccessControllerError::OperationRequiresUnlockedPrimaryRole,
+        )
+    });
+    unlocked_receipt.expect_commit_success();
+}
+
+#[test]
+fn primary_role_cannot_lock_itself() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let (access_controller, _) = create_access_controller(&mut test_runner, roles.rule_set(), None);
+
+    // Act
+    let receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::LockPrimaryRole,
+        args!(access_controller),
+        &roles.primary,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn quick_confirmed_recovery_replaces_rule_set() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let new_roles = Roles::new(&mut test_runner);
+    let (access_controller, controlled_account) =
+        create_access_controller(&mut test_runner, roles.rule_set(), None);
+    let proposal = RecoveryProposal {
+        rule_set: new_roles.rule_set(),
+        timed_recovery_delay_in_minutes: None,
+    };
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::InitiateRecoveryAsRecovery,
+        args!(access_controller, proposal.clone()),
+        &roles.recovery,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::QuickConfirmRecoveryRoleRecoveryProposal,
+        args!(access_controller, proposal),
+        &roles.confirmation,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &roles.primary,
+    )
+    .expect_specific_failure(is_auth_error);
+    withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &new_roles.primary,
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn quick_confirm_of_different_proposal_fails() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let new_roles = Roles::new(&mut test_runner);
+    let (access_controller, _) = create_access_controller(&mut test_runner, roles.rule_set(), None);
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::InitiateRecoveryAsPrimary,
+        args!(
+            access_controller,
+            RecoveryProposal {
+                rule_set: new_roles.rule_set(),
+                timed_recovery_delay_in_minutes: None,
+            }
+        ),
+        &roles.primary,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::QuickConfirmPrimaryRoleRecoveryProposal,
+        args!(
+            access_controller,
+            RecoveryProposal {
+                rule_set: new_roles.rule_set(),
+                timed_recovery_delay_in_minutes: Some(10),
+            }
+        ),
+        &roles.confirmation,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_access_controller_error(
+            e,
+            AccessControllerError::RecoveryProposalMismatch {
+                proposer: Role::Primary,
+            },
+        )
+    });
+}
+
+#[test]
+fn cancelled_recovery_proposal_cannot_be_confirmed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let new_roles = Roles::new(&mut test_runner);
+    let (access_controller, _) = create_access_controller(&mut test_runner, roles.rule_set(), None);
+    let proposal = RecoveryProposal {
+        rule_set: new_roles.rule_set(),
+        timed_recovery_delay_in_minutes: None,
+    };
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::InitiateRecoveryAsPrimary,
+        args!(access_controller, proposal.clone()),
+        &roles.primary,
+    )
+    .expect_commit_success();
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::CancelPrimaryRoleRecoveryProposal,
+        args!(access_controller),
+        &roles.primary,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::QuickConfirmPrimaryRoleRecoveryProposal,
+        args!(access_controller, proposal),
+        &roles.recovery,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_access_controller_error(
+            e,
+            AccessControllerError::NoRecoveryProposalExists {
+                proposer: Role::Primary,
+            },
+        )
+    });
+}
+
+#[test]
+fn timed_recovery_can_only_be_confirmed_after_delay() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let new_roles = Roles::new(&mut test_runner);
+    let (access_controller, controlled_account) =
+        create_access_controller(&mut test_runner, roles.rule_set(), Some(10));
+    let proposal = RecoveryProposal {
+        rule_set: new_roles.rule_set(),
+        timed_recovery_delay_in_minutes: None,
+    };
+    test_runner.set_current_time(1669663680000);
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::InitiateRecoveryAsRecovery,
+        args!(access_controller, proposal.clone()),
+        &roles.recovery,
+    )
+    .expect_commit_success();
+
+    // Act
+    test_runner.set_current_time(1669663680000 + 9 * 60 * 1000);
+    let early_receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::TimedConfirmRecovery,
+        args!(access_controller, proposal.clone()),
+        &roles.recovery,
+    );
+    test_runner.set_current_time(1669663680000 + 10 * 60 * 1000);
+    let receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::TimedConfirmRecovery,
+        args!(access_controller, proposal),
+        &roles.recovery,
+    );
+
+    // Assert
+    early_receipt.expect_specific_failure(|e| {
+        is_access_controller_error(e, AccessControllerError::TimedRecoveryDelayHasNotElapsed)
+    });
+    receipt.expect_commit_success();
+    withdraw_with_controlled_badge(
+        &mut test_runner,
+        access_controller,
+        controlled_account,
+        &new_roles.primary,
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn stopped_timed_recovery_cannot_be_timed_confirmed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let roles = Roles::new(&mut test_runner);
+    let new_roles = Roles::new(&mut test_runner);
+    let (access_controller, _) =
+        create_access_controller(&mut test_runner, roles.rule_set(), Some(10));
+    let proposal = RecoveryProposal {
+        rule_set: new_roles.rule_set(),
+        timed_recovery_delay_in_minutes: None,
+    };
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::InitiateRecoveryAsRecovery,
+        args!(access_controller, proposal.clone()),
+        &roles.recovery,
+    )
+    .expect_commit_success();
+    call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::StopTimedRecovery,
+        args!(access_controller),
+        &roles.primary,
+    )
+    .expect_commit_success();
+
+    // Act
+    test_runner.set_current_time(60 * 60 * 1000);
+    let receipt = call_method(
+        &mut test_runner,
+        access_controller,
+        AccessControllerMethod::TimedConfirmRecovery,
+        args!(access_controller, proposal),
+        &roles.recovery,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_access_controller_error(e, AccessControllerError::NoTimedRecoveryInProgress)
+    });
+}
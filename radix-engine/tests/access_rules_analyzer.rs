@@ -0,0 +1,282 @@
+use radix_engine::model::*;
+use radix_engine::types::*;
+use radix_engine_interface::abi::SelfMutability;
+use radix_engine_interface::data::SchemaPath;
+use radix_engine_interface::rule;
+
+fn method(ident: &str) -> Fn {
+    Fn {
+        ident: ident.to_string(),
+        mutability: Some(SelfMutability::Immutable),
+        input: Type::Unit,
+        output: Type::Unit,
+        export_name: format!("Vault_{}", ident),
+    }
+}
+
+fn blueprint_abi(methods: &[&str]) -> BlueprintAbi {
+    let mut fns: Vec<Fn> = methods.iter().map(|ident| method(ident)).collect();
+    fns.push(Fn {
+        ident: "instantiate".to_string(),
+        mutability: None,
+        input: Type::Unit,
+        output: Type::Unit,
+        export_name: "Vault_instantiate".to_string(),
+    });
+    BlueprintAbi {
+        structure: Type::Struct {
+            name: "Vault".to_string(),
+            fields: Fields::Named {
+                named: vec![
+                    ("admin_badge".to_string(), Type::ResourceAddress),
+                    ("threshold".to_string(), Type::Decimal),
+                ],
+            },
+        },
+        fns,
+        events: vec![],
+    }
+}
+
+fn key(ident: &str) -> AccessRuleKey {
+    AccessRuleKey::ScryptoMethod(ident.to_string())
+}
+
+fn method_key(ident: &str) -> MethodKey {
+    key(ident).into()
+}
+
+fn path(path: &str) -> SchemaPath {
+    path.parse().unwrap()
+}
+
+#[test]
+fn reports_badges_and_default_rule_fallbacks_of_methods() {
+    // Arrange
+    let access_rules = AccessRules::new()
+        .method("withdraw", rule!(require(RADIX_TOKEN)), AccessRule::DenyAll)
+        .method("deposit", rule!(allow_all), AccessRule::DenyAll)
+        .default(rule!(deny_all), AccessRule::DenyAll);
+    let abi = blueprint_abi(&["withdraw", "deposit", "burn"]);
+
+    // Act
+    let analysis = analyze_blueprint_access_rules(&[access_rules], &abi);
+
+    // Assert
+    assert_eq!(analysis.issues, vec![]);
+    let methods: Vec<(MethodKey, bool, bool, bool)> = analysis
+        .methods
+        .iter()
+        .map(|m| {
+            (
+                m.key.clone(),
+                m.falls_back_to_default(),
+                m.is_public(),
+                m.is_unreachable(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        methods,
+        vec![
+            (method_key("withdraw"), false, false, false),
+            (method_key("deposit"), false, true, false),
+            (method_key("burn"), true, false, true),
+        ]
+    );
+    assert_eq!(
+        analysis.methods[0].badges(),
+        BTreeSet::from([SoftResourceOrNonFungible::StaticResource(RADIX_TOKEN)])
+    );
+}
+
+#[test]
+fn reports_dynamic_rules_which_do_not_match_the_component_state() {
+    // Arrange
+    let access_rules = AccessRules::new()
+        .method(
+            "withdraw",
+            rule!(require("admin_badge")),
+            AccessRule::DenyAll,
+        )
+        .method(
+            "deposit",
+            rule!(require("missing_badge")),
+            AccessRule::DenyAll,
+        )
+        .method(
+            "burn",
+            rule!(require_amount("admin_badge", "threshold")),
+            AccessRule::DenyAll,
+        )
+        .default(rule!(deny_all), AccessRule::DenyAll);
+    let abi = blueprint_abi(&["withdraw", "deposit", "burn"]);
+
+    // Act
+    let analysis = analyze_blueprint_access_rules(&[access_rules], &abi);
+
+    // Assert
+    assert_eq!(
+        analysis.issues,
+        vec![
+            AccessRulesIssue::InvalidSchemaPath {
+                layer: 0,
+                key: method_key("deposit"),
+                path: path("missing_badge"),
+            },
+            AccessRulesIssue::SchemaPathTypeMismatch {
+                layer: 0,
+                key: method_key("burn"),
+                path: path("admin_badge"),
+                expected: DynamicValueKind::Decimal,
+                actual: Type::ResourceAddress,
+            },
+            AccessRulesIssue::SchemaPathTypeMismatch {
+                layer: 0,
+                key: method_key("burn"),
+                path: path("threshold"),
+                expected: DynamicValueKind::Resource,
+                actual: Type::Decimal,
+            },
+        ]
+    );
+    assert_eq!(
+        analysis.methods[0].badges(),
+        BTreeSet::from([SoftResourceOrNonFungible::Dynamic(path("admin_badge"))])
+    );
+}
+
+#[test]
+fn reports_missing_groups_and_unknown_methods() {
+    // Arrange
+    let mut access_rules = AccessRules::new().default(rule!(deny_all), AccessRule::DenyAll);
+    access_rules.set_group_and_mutability(
+        key("withdraw"),
+        "admin".to_string(),
+        AccessRule::DenyAll,
+    );
+    access_rules.set_method_access_rule(key("removed_method"), rule!(allow_all));
+    let abi = blueprint_abi(&["withdraw"]);
+
+    // Act
+    let analysis = analyze_blueprint_access_rules(&[access_rules], &abi);
+
+    // Assert
+    assert_eq!(
+        analysis.issues,
+        vec![
+            AccessRulesIssue::MissingGroup {
+                layer: 0,
+                key: method_key("withdraw"),
+                group: "admin".to_string(),
+            },
+            AccessRulesIssue::UnknownMethod {
+                layer: 0,
+                key: key("removed_method"),
+            },
+        ]
+    );
+    assert!(analysis.methods[0].falls_back_to_default());
+}
+
+#[test]
+fn every_layer_of_the_chain_must_allow_a_method_to_be_public() {
+    // Arrange
+    let first = AccessRules::new().default(rule!(allow_all), AccessRule::DenyAll);
+    let second = AccessRules::new()
+        .method("withdraw", rule!(require(RADIX_TOKEN)), AccessRule::DenyAll)
+        .default(rule!(allow_all), AccessRule::DenyAll);
+    let abi = blueprint_abi(&["withdraw", "deposit"]);
+
+    // Act
+    let analysis = analyze_blueprint_access_rules(&[first, second], &abi);
+
+    // Assert
+    assert!(!analysis.methods[0].is_public());
+    assert!(analysis.methods[1].is_public());
+    assert_eq!(analysis.methods[0].layers.len(), 2);
+    assert_eq!(analysis.methods[0].layers[0].0, AccessRuleSource::Default);
+    assert_eq!(analysis.methods[0].layers[1].0, AccessRuleSource::Method);
+}
+
+#[test]
+fn checks_package_rules_against_the_functions_and_methods_of_each_blueprint() {
+    // Arrange
+    let access_rules = AccessRules::new()
+        .method(
+            "withdraw",
+            rule!(require("admin_badge")),
+            AccessRule::DenyAll,
+        )
+        .method(
+            "instantiate",
+            rule!(require("admin_badge")),
+            AccessRule::DenyAll,
+        )
+        .default(rule!(deny_all), AccessRule::DenyAll);
+    let blueprint_abis = HashMap::from([
+        ("Vault".to_string(), blueprint_abi(&["withdraw"])),
+        ("Pool".to_string(), blueprint_abi(&[])),
+    ]);
+
+    // Act
+    let analysis = analyze_package_access_rules(&[access_rules], &blueprint_abis);
+
+    // Assert
+    let keys: Vec<MethodKey> = analysis.methods.iter().map(|m| m.key.clone()).collect();
+    assert_eq!(
+        keys,
+        vec![
+            AccessRuleKey::Native(NativeFn::Method(NativeMethod::Package(
+                PackageMethod::SetRoyaltyConfig
+            )))
+            .into(),
+            AccessRuleKey::Native(NativeFn::Method(NativeMethod::Package(
+                PackageMethod::ClaimRoyalty
+            )))
+            .into(),
+            MethodKey::blueprint("Pool", key("instantiate")),
+            MethodKey::blueprint("Vault", key("withdraw")),
+            MethodKey::blueprint("Vault", key("instantiate")),
+        ]
+    );
+    assert_eq!(
+        analysis.issues,
+        vec![
+            AccessRulesIssue::InvalidSchemaPath {
+                layer: 0,
+                key: MethodKey::blueprint("Pool", key("instantiate")),
+                path: path("admin_badge"),
+            },
+            AccessRulesIssue::InvalidSchemaPath {
+                layer: 0,
+                key: MethodKey::blueprint("Vault", key("instantiate")),
+                path: path("admin_badge"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn checks_account_rules_against_the_native_account_methods() {
+    // Arrange
+    let mut access_rules =
+        AccessRules::new().default(rule!(require(RADIX_TOKEN)), AccessRule::DenyAll);
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::Account(
+            AccountMethod::Deposit,
+        ))),
+        rule!(allow_all),
+        AccessRule::DenyAll,
+    );
+    let abi = blueprint_abi(&["deposit", "withdraw"]);
+
+    // Act
+    let analysis = analyze_account_access_rules(&[access_rules], &abi);
+
+    // Assert
+    assert_eq!(analysis.issues, vec![]);
+    assert!(analysis.methods[0].is_public());
+    assert!(!analysis.methods[1].is_public());
+    assert!(analysis.methods[1].falls_back_to_default());
+}
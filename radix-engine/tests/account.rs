@@ -1,5 +1,6 @@
-use radix_engine::engine::ResourceChange;
+use radix_engine::engine::{ApplicationError, ResourceChange, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::AccountError;
 use radix_engine::types::*;
 use radix_engine_interface::api::types::ScryptoMethodIdent;
 use radix_engine_interface::core::NetworkDefinition;
@@ -205,6 +206,172 @@ fn test_virtual_account_balance() {
     test_account_balance_internal(true)
 }
 
+fn set_deposit_policy(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+    deposit_policy: AccountDepositPolicy,
+) {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10u32.into())
+        .call_method(account, "set_deposit_policy", args!(deposit_policy))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+}
+
+fn deposit_rejected_by_deposit_policy_internal(deny_list: bool) {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (other_public_key, _, other_account) = test_runner.new_account(false);
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, other_account);
+    let deposit_policy = if deny_list {
+        AccountDepositPolicy::DenyList(BTreeSet::from([resource_address]))
+    } else {
+        AccountDepositPolicy::AllowList(BTreeSet::from([RADIX_TOKEN]))
+    };
+    set_deposit_policy(&mut test_runner, public_key, account, deposit_policy);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(other_account, 10u32.into())
+        .withdraw_from_account(other_account, resource_address)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&other_public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountError(
+                AccountError::ResourceNotAccepted(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn deposit_of_resource_not_in_allow_list_should_fail() {
+    deposit_rejected_by_deposit_policy_internal(false);
+}
+
+#[test]
+fn deposit_of_resource_in_deny_list_should_fail() {
+    deposit_rejected_by_deposit_policy_internal(true);
+}
+
+#[test]
+fn try_deposit_or_refund_returns_resource_not_accepted() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (other_public_key, _, other_account) = test_runner.new_account(false);
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, other_account);
+    set_deposit_policy(
+        &mut test_runner,
+        public_key,
+        account,
+        AccountDepositPolicy::AllowList(BTreeSet::from([RADIX_TOKEN])),
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(other_account, 10u32.into())
+        .withdraw_from_account(other_account, resource_address)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_method(account, "try_deposit_or_refund", args!(Bucket(bucket_id)))
+        })
+        .assert_worktop_contains_by_amount(100.into(), resource_address)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&other_public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .get_component_resources(account)
+            .get(&resource_address),
+        None
+    );
+    assert_eq!(
+        test_runner
+            .get_component_resources(other_account)
+            .get(&resource_address),
+        Some(&Decimal::from(100))
+    );
+}
+
+#[test]
+fn cannot_set_deposit_policy_of_other_account() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (_, _, other_account) = test_runner.new_account(false);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10u32.into())
+        .call_method(
+            other_account,
+            "set_deposit_policy",
+            args!(AccountDepositPolicy::AllowList(BTreeSet::new())),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn account_abi_should_describe_the_native_account() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_account(false);
+
+    // Act
+    let abi = test_runner.export_abi(ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT);
+
+    // Assert
+    assert_eq!(test_runner.export_abi_by_component(account), abi);
+    for f in &abi.fns {
+        if f.mutability.is_some() {
+            assert!(AccountMethod::from_str(&f.ident).is_ok(), "{}", f.ident);
+        } else {
+            assert!(AccountFunction::from_str(&f.ident).is_ok(), "{}", f.ident);
+        }
+    }
+    assert!(abi.contains_fn("try_deposit_or_refund"));
+    assert!(abi.contains_fn("lock_sponsored_fee"));
+}
+
 fn assert_resource_changes_for_transfer(
     resource_changes: &Vec<ResourceChange>,
     resource_address: ResourceAddress,
@@ -136,7 +136,7 @@ fn test_resource_auth(action: Action, update_auth: bool, use_other_auth: bool, e
             scrypto_encode(&MetadataSetInvocation {
                 receiver: RENodeId::Global(GlobalAddress::Resource(token_address)),
                 key: "key".to_string(),
-                value: "value".to_string(),
+                value: MetadataValue::String("value".to_string()),
             })
             .unwrap(),
         ),
@@ -0,0 +1,150 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::rule;
+use radix_engine_interface::time::Instant;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::AuthModule;
+
+const UNLOCK_TIME_SECONDS: i64 = 1669663680;
+
+fn withdraw(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    account: ComponentAddress,
+    signer_public_keys: &[PublicKey],
+) -> TransactionReceipt {
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), RADIX_TOKEN)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, AuthModule::pk_non_fungibles(signer_public_keys))
+}
+
+#[test]
+fn cannot_withdraw_before_allowed_after_instant() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(
+        require(auth) && allowed_after(Instant::new(UNLOCK_TIME_SECONDS))
+    ));
+    test_runner.set_current_time((UNLOCK_TIME_SECONDS - 60) * 1000);
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn can_withdraw_once_allowed_after_instant_is_reached() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(
+        require(auth) && allowed_after(Instant::new(UNLOCK_TIME_SECONDS))
+    ));
+    test_runner.set_current_time(UNLOCK_TIME_SECONDS * 1000);
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn time_rule_does_not_replace_proof_requirement() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let (other_pk, _, _) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(
+        require(auth) && allowed_after(Instant::new(UNLOCK_TIME_SECONDS))
+    ));
+    test_runner.set_current_time(UNLOCK_TIME_SECONDS * 1000);
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[other_pk.into()]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn can_only_withdraw_before_allowed_before_instant() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(
+        require(auth) && allowed_before(Instant::new(UNLOCK_TIME_SECONDS))
+    ));
+
+    // Act
+    test_runner.set_current_time((UNLOCK_TIME_SECONDS - 60) * 1000);
+    let receipt_before = withdraw(&mut test_runner, account, &[pk.into()]);
+    test_runner.set_current_time(UNLOCK_TIME_SECONDS * 1000);
+    let receipt_after = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    receipt_before.expect_commit_success();
+    receipt_after.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn can_only_withdraw_within_epoch_range() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let account =
+        test_runner.new_account_with_auth_rule(&rule!(require(auth) && within_epoch_range(5, 10)));
+
+    // Act
+    let mut receipts = Vec::new();
+    for epoch in [4, 5, 9, 10] {
+        test_runner.set_current_epoch(epoch);
+        receipts.push(withdraw(&mut test_runner, account, &[pk.into()]));
+    }
+
+    // Assert
+    receipts[0].expect_specific_failure(is_auth_error);
+    receipts[1].expect_commit_success();
+    receipts[2].expect_commit_success();
+    receipts[3].expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn time_rules_can_be_combined_with_or() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (admin_pk, _, admin) = test_runner.new_key_pair_with_auth_address();
+    let (owner_pk, _, owner) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(
+        require(admin) || (require(owner) && allowed_after(Instant::new(UNLOCK_TIME_SECONDS)))
+    ));
+    test_runner.set_current_time((UNLOCK_TIME_SECONDS - 60) * 1000);
+
+    // Act
+    let admin_receipt = withdraw(&mut test_runner, account, &[admin_pk.into()]);
+    let owner_receipt = withdraw(&mut test_runner, account, &[owner_pk.into()]);
+
+    // Assert
+    admin_receipt.expect_commit_success();
+    owner_receipt.expect_specific_failure(is_auth_error);
+}
@@ -0,0 +1,159 @@
+use radix_engine::engine::{AuthError, ModuleError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::{AuthTraceRule, HardProofRule, HardResourceOrNonFungible};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::AuthModule;
+
+fn withdraw(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    account: ComponentAddress,
+    signer_public_keys: &[PublicKey],
+) -> TransactionReceipt {
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), RADIX_TOKEN)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, AuthModule::pk_non_fungibles(signer_public_keys))
+}
+
+#[test]
+fn auth_failure_traces_each_evaluated_rule() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let (_, _, other_auth) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner
+        .new_account_with_auth_rule(&rule!(require(auth.clone()) && require(other_auth.clone())));
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    let trace = receipt
+        .expect_commit_failure()
+        .auth_trace()
+        .expect("Auth failure should be traced");
+    assert_eq!(trace.root.rule, AuthTraceRule::AllOf);
+    assert!(!trace.root.passed);
+    let children: Vec<(AuthTraceRule, bool)> = trace
+        .root
+        .children
+        .iter()
+        .map(|node| (node.rule.clone(), node.passed))
+        .collect();
+    assert_eq!(
+        children,
+        vec![
+            (
+                AuthTraceRule::ProofRule(HardProofRule::Require(auth.clone().into())),
+                true
+            ),
+            (
+                AuthTraceRule::ProofRule(HardProofRule::Require(other_auth.into())),
+                false
+            ),
+        ]
+    );
+    assert!(trace.virtual_non_fungibles.contains(&auth));
+}
+
+#[test]
+fn auth_failure_traces_resources_of_proof_rule_lists() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let (_, _, other_auth) = test_runner.new_key_pair_with_auth_address();
+    let (pk, _, _) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(require_any_of(vec![
+        auth.clone(),
+        other_auth.clone()
+    ])));
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    let trace = receipt
+        .expect_commit_failure()
+        .auth_trace()
+        .expect("Auth failure should be traced");
+    assert!(matches!(
+        trace.root.rule,
+        AuthTraceRule::ProofRule(HardProofRule::AnyOf(..))
+    ));
+    let resources: Vec<(AuthTraceRule, bool)> = trace
+        .root
+        .children
+        .iter()
+        .map(|node| (node.rule.clone(), node.passed))
+        .collect();
+    assert_eq!(
+        resources,
+        vec![
+            (
+                AuthTraceRule::Resource(HardResourceOrNonFungible::NonFungible(auth)),
+                false
+            ),
+            (
+                AuthTraceRule::Resource(HardResourceOrNonFungible::NonFungible(other_auth)),
+                false
+            ),
+        ]
+    );
+}
+
+#[test]
+fn auth_failure_trace_is_displayed_in_receipt() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let (pk, _, _) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(require(auth)));
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    let output = format!("{:?}", receipt);
+    assert!(output.contains("Authorization Trace:"));
+    assert!(output.contains("[FAIL] require("));
+}
+
+#[test]
+fn auth_failure_is_not_traced_without_tracing() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(false, &mut store);
+    let (_, _, auth) = test_runner.new_key_pair_with_auth_address();
+    let (pk, _, _) = test_runner.new_key_pair_with_auth_address();
+    let account = test_runner.new_account_with_auth_rule(&rule!(require(auth)));
+
+    // Act
+    let receipt = withdraw(&mut test_runner, account, &[pk.into()]);
+
+    // Assert
+    let error = receipt.expect_commit_failure();
+    assert!(matches!(
+        error,
+        RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+            trace: None,
+            ..
+        }))
+    ));
+    assert_eq!(error.auth_trace(), None);
+}
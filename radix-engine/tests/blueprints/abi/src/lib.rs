@@ -157,6 +157,7 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 export_name: "AbiComponent2_main".to_string(),
             },
         ],
+        events: vec![],
     };
 
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi).unwrap()
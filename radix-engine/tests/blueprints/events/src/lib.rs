@@ -0,0 +1,40 @@
+use scrypto::prelude::*;
+
+#[derive(TypeId, Encode, Decode, Describe)]
+pub struct RegisteredEvent {
+    pub value: u32,
+}
+
+#[derive(TypeId, Encode, Decode, Describe)]
+pub enum ChangedEvent {
+    Increased(u32),
+    Decreased(u32),
+}
+
+#[derive(TypeId, Encode, Decode, Describe)]
+pub struct UndeclaredEvent {
+    pub value: u32,
+}
+
+blueprint! {
+    #[events(RegisteredEvent, ChangedEvent)]
+    struct EventsTest {
+        value: u32,
+    }
+
+    impl EventsTest {
+        pub fn new(value: u32) -> ComponentAddress {
+            Runtime::emit_event(RegisteredEvent { value });
+            Self { value }.instantiate().globalize()
+        }
+
+        pub fn emit_undeclared_event() {
+            Runtime::emit_event(UndeclaredEvent { value: 1 });
+        }
+
+        pub fn increase(&mut self, amount: u32) {
+            self.value += amount;
+            Runtime::emit_event(ChangedEvent::Increased(amount));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct FeeSponsor {
+        xrd: Vault,
+        sponsorship: FeeSponsorship,
+    }
+
+    impl FeeSponsor {
+        pub fn new(xrd: Bucket) -> ComponentAddress {
+            Self {
+                xrd: Vault::with_bucket(xrd),
+                sponsorship: FeeSponsorship::new(Decimal::zero()),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn set_sponsorship(&mut self, sponsorship: FeeSponsorship) {
+            self.sponsorship = sponsorship;
+        }
+
+        pub fn lock_fee(&mut self, amount: Decimal) {
+            self.xrd.lock_sponsored_fee(amount, self.sponsorship.clone());
+        }
+
+        pub fn ping(&self) {}
+
+        pub fn ping_blueprint() {}
+    }
+}
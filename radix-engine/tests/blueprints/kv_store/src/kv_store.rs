@@ -88,5 +88,78 @@ blueprint! {
         pub fn clear_vector(&mut self) -> () {
             self.vector.clear()
         }
+
+        pub fn new_kv_store_with_entries(count: u32) -> ComponentAddress {
+            let map = KeyValueStore::new();
+            for i in 0..count {
+                map.insert(i.to_string(), i.to_string());
+            }
+            KeyValueStoreTest {
+                map,
+                vector: Vec::new(),
+                kv_stores: KeyValueStore::new(),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn insert(&mut self, key: String, value: String) {
+            self.map.insert(key, value)
+        }
+
+        pub fn remove(&mut self, key: String) -> Option<String> {
+            self.map.remove(&key)
+        }
+
+        pub fn contains_key(&self, key: String) -> bool {
+            self.map.contains_key(&key)
+        }
+
+        pub fn keys(&self, limit: u32) -> Vec<String> {
+            let mut keys = Vec::new();
+            let mut start_after = None;
+            loop {
+                let (page, next_start_after) = self.map.keys(start_after.as_ref(), limit);
+                keys.extend(page);
+                match next_start_after {
+                    Some(key) => start_after = Some(key),
+                    None => return keys,
+                }
+            }
+        }
+
+        pub fn entries(&self, limit: u32) -> Vec<(String, String)> {
+            let mut entries = Vec::new();
+            let mut start_after = None;
+            loop {
+                let (page, next_start_after) = self.map.entries(start_after.as_ref(), limit);
+                entries.extend(page);
+                match next_start_after {
+                    Some(key) => start_after = Some(key),
+                    None => return entries,
+                }
+            }
+        }
+
+        pub fn remove_kv_store(&mut self) -> () {
+            self.kv_stores.remove(&"hello".to_owned());
+        }
+
+        pub fn new_kv_store_then_iterate() -> Vec<String> {
+            let map = KeyValueStore::new();
+            map.insert("a".to_owned(), "1".to_owned());
+            map.insert("b".to_owned(), "2".to_owned());
+            map.insert("c".to_owned(), "3".to_owned());
+            map.remove(&"b".to_owned());
+            let (keys, _) = map.keys(None, 10);
+            KeyValueStoreTest {
+                map,
+                vector: Vec::new(),
+                kv_stores: KeyValueStore::new(),
+            }
+            .instantiate()
+            .globalize();
+            keys
+        }
     }
 }
@@ -7,6 +7,43 @@ pub struct Sandwich {
     pub available: bool,
 }
 
+#[derive(NonFungibleData)]
+pub struct Burger {
+    pub name: String,
+    #[scrypto(mutable)]
+    pub price: Decimal,
+}
+
+fn create_sandwiches_with_schema() -> (Bucket, ResourceAddress, Bucket) {
+    let mint_badge = ResourceBuilder::new_fungible()
+        .divisibility(DIVISIBILITY_NONE)
+        .initial_supply(1);
+    let resource_address = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
+        .metadata("name", "Katz's Sandwiches")
+        .data_schema::<Sandwich>()
+        .mintable(
+            rule!(require(mint_badge.resource_address())),
+            rule!(deny_all),
+        )
+        .burnable(rule!(allow_all), rule!(deny_all))
+        .updateable_non_fungible_data(
+            rule!(require(mint_badge.resource_address())),
+            rule!(deny_all),
+        )
+        .no_initial_supply();
+    let non_fungible = mint_badge.authorize(|| {
+        borrow_resource_manager!(resource_address).mint_non_fungible(
+            &NonFungibleId::U32(0),
+            Sandwich {
+                name: "Test".to_owned(),
+                available: false,
+            },
+        )
+    });
+
+    (mint_badge, resource_address, non_fungible)
+}
+
 blueprint! {
     struct NonFungibleTest {
         vault: Vault,
@@ -62,6 +99,98 @@ blueprint! {
             mint_badge
         }
 
+        pub fn update_non_fungible_field(field: String, value: bool) -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, non_fungible) = create_sandwiches_with_schema();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleId::U32(0),
+                    &field,
+                    value,
+                )
+            });
+
+            let sandwich: Sandwich = borrow_resource_manager!(resource_address)
+                .get_non_fungible_data(&NonFungibleId::U32(0));
+            assert_eq!(sandwich.name, "Test");
+            assert_eq!(sandwich.available, value);
+
+            (mint_badge, non_fungible)
+        }
+
+        pub fn update_non_fungible_field_with_string(
+            field: String,
+            value: String,
+        ) -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, non_fungible) = create_sandwiches_with_schema();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleId::U32(0),
+                    &field,
+                    value,
+                )
+            });
+
+            (mint_badge, non_fungible)
+        }
+
+        pub fn mint_non_fungible_with_mismatching_data() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, non_fungible) = create_sandwiches_with_schema();
+            let burger = mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).mint_non_fungible(
+                    &NonFungibleId::U32(1),
+                    Burger {
+                        name: "Test".to_owned(),
+                        price: dec!("5"),
+                    },
+                )
+            });
+            non_fungible.burn();
+
+            (mint_badge, burger)
+        }
+
+        pub fn burn_non_fungibles_from_vault(burnable: bool) {
+            let burn_rule = if burnable {
+                rule!(allow_all)
+            } else {
+                rule!(deny_all)
+            };
+            let bucket = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
+                .metadata("name", "Katz's Sandwiches")
+                .burnable(burn_rule, rule!(deny_all))
+                .initial_supply([
+                    (
+                        NonFungibleId::U32(0),
+                        Sandwich {
+                            name: "Zero".to_owned(),
+                            available: true,
+                        },
+                    ),
+                    (
+                        NonFungibleId::U32(1),
+                        Sandwich {
+                            name: "One".to_owned(),
+                            available: true,
+                        },
+                    ),
+                ]);
+            let resource_address = bucket.resource_address();
+            let mut vault = Vault::with_bucket(bucket);
+
+            vault.burn_non_fungibles(&BTreeSet::from([NonFungibleId::U32(0)]));
+
+            assert_eq!(vault.amount(), Decimal::one());
+            assert_eq!(
+                vault.non_fungible_ids(),
+                BTreeSet::from([NonFungibleId::U32(1)])
+            );
+            let resource_manager = borrow_resource_manager!(resource_address);
+            assert!(!resource_manager.non_fungible_exists(&NonFungibleId::U32(0)));
+            assert_eq!(resource_manager.total_supply(), Decimal::one());
+
+            NonFungibleTest { vault }.instantiate().globalize();
+        }
+
         pub fn create_burnable_non_fungible() -> Bucket {
             ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
                 .metadata("name", "Katz's Sandwiches")
@@ -38,6 +38,7 @@ pub extern "C" fn LargeReturnSize_abi(_input: *mut u8) -> *mut u8 {
             output: Type::Unit,
             export_name: "LargeReturnSize_f_main".to_string(),
         }],
+        events: vec![],
     };
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi).unwrap()
 }
@@ -60,6 +61,7 @@ pub extern "C" fn MaxReturnSize_abi(_input: *mut u8) -> *mut u8 {
             output: Type::Unit,
             export_name: "MaxReturnSize_f_main".to_string(),
         }],
+        events: vec![],
     };
 
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi).unwrap()
@@ -83,6 +85,7 @@ pub extern "C" fn ZeroReturnSize_abi(_input: *mut u8) -> *mut u8 {
             output: Type::Unit,
             export_name: "ZeroReturnSize_f_main".to_string(),
         }],
+        events: vec![],
     };
 
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi).unwrap()
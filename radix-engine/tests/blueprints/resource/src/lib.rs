@@ -53,6 +53,29 @@ blueprint! {
             (badge, tokens, token_address)
         }
 
+        pub fn create_fungible_with_max_supply_and_mint(
+            max_supply: Decimal,
+            amounts: Vec<Decimal>,
+        ) -> (Bucket, Vec<Bucket>, Option<Decimal>) {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let token_address = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken")
+                .max_supply(max_supply)
+                .mintable(rule!(require(badge.resource_address())), rule!(deny_all))
+                .no_initial_supply();
+            let resource_manager = borrow_resource_manager!(token_address);
+            let tokens = badge.authorize(|| {
+                amounts
+                    .into_iter()
+                    .map(|amount| resource_manager.mint(amount))
+                    .collect()
+            });
+            (badge, tokens, resource_manager.max_supply())
+        }
+
         pub fn create_fungible_wrong_resource_flags_should_fail() -> Bucket {
             let bucket = ResourceBuilder::new_fungible()
                 .divisibility(DIVISIBILITY_MAXIMUM)
@@ -125,7 +148,7 @@ blueprint! {
                 token_resource_manager.set_metadata("a".to_owned(), "b".to_owned());
                 assert_eq!(
                     token_resource_manager.get_metadata("a".to_owned()).unwrap(),
-                    "b".to_owned()
+                    MetadataValue::String("b".to_owned())
                 );
             });
 
@@ -139,10 +139,10 @@ fn publish_package_with_owner_succeeds() {
             };
 
             // TODO: Update the complex.abi and complex.code files that are used for testing.
-            // Using the WASM and ABI from the account blueprint here as they are up to date. The
+            // Using the WASM and ABI from the faucet blueprint here as they are up to date. The
             // complex.code and complex.abi files from the transaction crate are not.
-            let code_blob = include_bytes!("../../assets/account.wasm").to_vec();
-            let abi_blob = include_bytes!("../../assets/account.abi").to_vec();
+            let code_blob = include_bytes!("../../assets/faucet.wasm").to_vec();
+            let abi_blob = include_bytes!("../../assets/faucet.abi").to_vec();
 
             let manifest = format!(
                 include_str!("../../transaction/examples/package/publish_with_owner.rtm"),
@@ -274,6 +274,7 @@ fn test_manifest_with_restricted_minting_resource<F>(
                         ),
                     )]),
                     None,
+                    None,
                 )
                 .build(),
             vec![],
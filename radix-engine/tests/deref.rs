@@ -57,7 +57,6 @@ fn no_visible_component_nodes_on_deref_lock() {
     // Arrange
     let mut store = TypedInMemorySubstateStore::with_bootstrap();
     let mut test_runner = TestRunner::new(true, &mut store);
-    let (_, _, account) = test_runner.new_allocated_account();
     let package = test_runner.compile_and_publish("./tests/blueprints/deref");
 
     // Act
@@ -67,7 +66,7 @@ fn no_visible_component_nodes_on_deref_lock() {
             package,
             "Deref",
             "verify_no_visible_component_nodes_on_deref_lock",
-            args!(account),
+            args!(FAUCET_COMPONENT),
         )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![]);
@@ -81,7 +80,6 @@ fn no_visible_component_nodes_after_deref_lock_drop() {
     // Arrange
     let mut store = TypedInMemorySubstateStore::with_bootstrap();
     let mut test_runner = TestRunner::new(true, &mut store);
-    let (_, _, account) = test_runner.new_allocated_account();
     let package = test_runner.compile_and_publish("./tests/blueprints/deref");
 
     // Act
@@ -91,7 +89,7 @@ fn no_visible_component_nodes_after_deref_lock_drop() {
             package,
             "Deref",
             "verify_no_visible_component_nodes_after_deref_lock_drop",
-            args!(account),
+            args!(FAUCET_COMPONENT),
         )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![]);
@@ -1,5 +1,8 @@
-use radix_engine::engine::{ModuleError, RuntimeError};
+use radix_engine::engine::{ApplicationError, ModuleError, RuntimeError};
+use radix_engine::fee::default_fee_config;
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::{AuthZoneError, EpochManagerError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
@@ -66,7 +69,7 @@ fn epoch_manager_create_should_fail_with_supervisor_privilege() {
         .call_native_function(
             EPOCH_MANAGER_BLUEPRINT,
             EpochManagerFunction::Create.as_ref(),
-            args!(),
+            args!(100u32, 2u64, default_fee_config()),
         )
         .build();
     let receipt = test_runner.execute_manifest(
@@ -92,7 +95,7 @@ fn epoch_manager_create_should_succeed_with_system_privilege() {
         .call_native_function(
             EPOCH_MANAGER_BLUEPRINT,
             EpochManagerFunction::Create.as_ref(),
-            args!(),
+            args!(100u32, 2u64, default_fee_config()),
         )
         .build();
     let receipt = test_runner.execute_manifest(
@@ -103,3 +106,287 @@ fn epoch_manager_create_should_succeed_with_system_privilege() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn register_validator(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    key: EcdsaSecp256k1PublicKey,
+    initial_proofs: Vec<NonFungibleAddress>,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+            EpochManagerMethod::RegisterValidator.as_ref(),
+            args!(EPOCH_MANAGER, key),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, initial_proofs)
+}
+
+fn stake(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, amount, RADIX_TOKEN)
+        .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_native_method(
+                RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                EpochManagerMethod::Stake.as_ref(),
+                args!(EPOCH_MANAGER, key, Bucket(bucket_id)),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![NonFungibleAddress::from_public_key(&key)])
+}
+
+#[test]
+fn register_validator_with_key_signature_should_succeed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _) = test_runner.new_key_pair();
+
+    // Act
+    let receipt = register_validator(
+        &mut test_runner,
+        key,
+        vec![NonFungibleAddress::from_public_key(&key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let validator = test_runner.get_validator(key).unwrap();
+    assert!(validator.is_registered);
+    assert!(validator.stake.is_zero());
+}
+
+#[test]
+fn register_validator_without_key_signature_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _) = test_runner.new_key_pair();
+
+    // Act
+    let receipt = register_validator(&mut test_runner, key, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+    assert!(test_runner.get_validator(key).is_none());
+}
+
+#[test]
+fn register_validator_twice_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _) = test_runner.new_key_pair();
+    let proofs = vec![NonFungibleAddress::from_public_key(&key)];
+    register_validator(&mut test_runner, key, proofs.clone()).expect_commit_success();
+
+    // Act
+    let receipt = register_validator(&mut test_runner, key, proofs);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::ValidatorAlreadyRegistered(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn unregistered_validator_should_leave_validator_set_on_next_epoch() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _, account) = test_runner.new_account(false);
+    let proofs = vec![NonFungibleAddress::from_public_key(&key)];
+    register_validator(&mut test_runner, key, proofs.clone()).expect_commit_success();
+    stake(&mut test_runner, key, account, 100.into()).expect_commit_success();
+    test_runner.next_epoch();
+    assert!(test_runner.get_validator_set().contains_key(&key));
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+            EpochManagerMethod::UnregisterValidator.as_ref(),
+            args!(EPOCH_MANAGER, key),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, proofs);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(!test_runner.get_validator(key).unwrap().is_registered);
+    assert!(test_runner.get_validator_set().contains_key(&key));
+    test_runner.next_epoch();
+    assert!(!test_runner.get_validator_set().contains_key(&key));
+}
+
+#[test]
+fn next_epoch_should_include_staked_validators_only() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key1, _, account1) = test_runner.new_account(false);
+    let (key2, _, account2) = test_runner.new_account(false);
+    let (key3, _) = test_runner.new_key_pair();
+    for key in [key1, key2, key3] {
+        register_validator(
+            &mut test_runner,
+            key,
+            vec![NonFungibleAddress::from_public_key(&key)],
+        )
+        .expect_commit_success();
+    }
+    stake(&mut test_runner, key1, account1, 100.into()).expect_commit_success();
+    stake(&mut test_runner, key2, account2, 300.into()).expect_commit_success();
+    let epoch = test_runner.get_current_epoch();
+
+    // Act
+    test_runner.next_epoch();
+
+    // Assert
+    assert_eq!(test_runner.get_current_epoch(), epoch + 1);
+    let validator_set = test_runner.get_validator_set();
+    assert_eq!(
+        validator_set,
+        BTreeMap::from([(key1, dec!("100")), (key2, dec!("300"))])
+    );
+}
+
+#[test]
+fn stake_should_mint_stake_units() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _, account) = test_runner.new_account(false);
+    register_validator(
+        &mut test_runner,
+        key,
+        vec![NonFungibleAddress::from_public_key(&key)],
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = stake(&mut test_runner, key, account, 100.into());
+
+    // Assert
+    receipt.expect_commit_success();
+    let validator = test_runner.get_validator(key).unwrap();
+    assert_eq!(validator.stake, dec!("100"));
+    let resources = test_runner.get_component_resources(account);
+    assert_eq!(resources[&validator.stake_unit_resource], dec!("100"));
+}
+
+#[test]
+fn stake_to_unregistered_validator_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _, account) = test_runner.new_account(false);
+
+    // Act
+    let receipt = stake(&mut test_runner, key, account, 100.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::ValidatorNotFound(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn unstaked_xrd_should_only_be_claimable_after_unbonding_delay() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (key, _, account) = test_runner.new_account(false);
+    let proofs = vec![NonFungibleAddress::from_public_key(&key)];
+    register_validator(&mut test_runner, key, proofs.clone()).expect_commit_success();
+    stake(&mut test_runner, key, account, 100.into()).expect_commit_success();
+    let validator = test_runner.get_validator(key).unwrap();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 40.into(), validator.stake_unit_resource)
+        .take_from_worktop(validator.stake_unit_resource, |builder, bucket_id| {
+            builder.call_native_method(
+                RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                EpochManagerMethod::Unstake.as_ref(),
+                args!(EPOCH_MANAGER, key, Bucket(bucket_id)),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, proofs.clone())
+        .expect_commit_success();
+    assert_eq!(test_runner.get_validator(key).unwrap().stake, dec!("60"));
+    let claim_manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, validator.unstake_claim_resource)
+        .take_from_worktop(validator.unstake_claim_resource, |builder, bucket_id| {
+            builder.call_native_method(
+                RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+                EpochManagerMethod::ClaimXrd.as_ref(),
+                args!(EPOCH_MANAGER, key, Bucket(bucket_id)),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+
+    // Act
+    let early_receipt = test_runner.execute_manifest(claim_manifest.clone(), proofs.clone());
+    let epoch = test_runner.get_current_epoch();
+    test_runner.set_current_epoch(epoch + 2);
+    let xrd_before = test_runner.get_component_resources(account)[&RADIX_TOKEN];
+    let receipt = test_runner.execute_manifest(claim_manifest, proofs);
+
+    // Assert
+    early_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::UnstakeClaimNotReady { .. }
+            ))
+        )
+    });
+    receipt.expect_commit_success();
+    let resources = test_runner.get_component_resources(account);
+    assert!(resources[&RADIX_TOKEN] > xrd_before + dec!("39"));
+    assert!(resources[&validator.unstake_claim_resource].is_zero());
+}
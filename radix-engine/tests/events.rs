@@ -0,0 +1,230 @@
+use radix_engine::engine::{
+    KernelError, NativeEvent, ResourceEvent, ResourceEventKind, RuntimeError, ScryptoEvent,
+    TrackedEvent,
+};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[derive(Debug, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct RegisteredEvent {
+    pub value: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum ChangedEvent {
+    Increased(u32),
+    Decreased(u32),
+}
+
+fn scrypto_events(events: &[TrackedEvent]) -> Vec<ScryptoEvent> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            TrackedEvent::Scrypto(event) => Some(event.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn resource_events(events: &[TrackedEvent]) -> Vec<ResourceEvent> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            TrackedEvent::Native(NativeEvent::Resource(event)) => Some(event.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn declared_events_are_recorded_in_receipt() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/events");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "EventsTest", "new", args!(5u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+    let events = scrypto_events(&receipt.execution.events);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].emitter, GlobalAddress::Package(package_address));
+    assert_eq!(events[0].type_name, "RegisteredEvent");
+    assert_eq!(
+        events[0].decode::<RegisteredEvent>().unwrap(),
+        RegisteredEvent { value: 5 }
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "increase", args!(3u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let events = scrypto_events(&receipt.execution.events);
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].emitter,
+        GlobalAddress::Component(component_address)
+    );
+    assert_eq!(events[0].type_name, "ChangedEvent");
+    assert_eq!(
+        events[0].decode::<ChangedEvent>().unwrap(),
+        ChangedEvent::Increased(3)
+    );
+}
+
+#[test]
+fn undeclared_event_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/events");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "EventsTest",
+            "emit_undeclared_event",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::UndeclaredScryptoEvent(type_name))
+                if type_name == "UndeclaredEvent"
+        )
+    });
+}
+
+#[test]
+fn non_fungible_transfer_emits_resource_events() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let ids = BTreeSet::from([NonFungibleId::U32(2)]);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .withdraw_from_account_by_ids(account, &ids, resource_address)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+    let other_vault_id = test_runner.get_component_vaults(other_account, resource_address)[0];
+    let fee_vault_id = test_runner.get_component_vaults(account, RADIX_TOKEN)[0];
+    let events = resource_events(&receipt.execution.events);
+    let vault_events: Vec<&ResourceEvent> = events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e.kind,
+                ResourceEventKind::VaultWithdraw(..)
+                    | ResourceEventKind::VaultDeposit(..)
+                    | ResourceEventKind::VaultFeePayment(..)
+            )
+        })
+        .collect();
+    assert_eq!(
+        vault_events,
+        vec![
+            &ResourceEvent {
+                kind: ResourceEventKind::VaultWithdraw(vault_id),
+                resource_address,
+                amount: 1.into(),
+                non_fungible_ids: Some(ids.clone()),
+            },
+            &ResourceEvent {
+                kind: ResourceEventKind::VaultDeposit(other_vault_id),
+                resource_address,
+                amount: 1.into(),
+                non_fungible_ids: Some(ids),
+            },
+            &ResourceEvent {
+                kind: ResourceEventKind::VaultFeePayment(fee_vault_id),
+                resource_address: RADIX_TOKEN,
+                amount: receipt.execution.fee_summary.total_execution_cost_xrd,
+                non_fungible_ids: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn failed_transaction_only_emits_fee_payment_events() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .assert_worktop_contains_by_amount(1.into(), resource_address)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+    let fee_vault_id = test_runner.get_component_vaults(account, RADIX_TOKEN)[0];
+    assert_eq!(
+        resource_events(&receipt.execution.events),
+        vec![ResourceEvent {
+            kind: ResourceEventKind::VaultFeePayment(fee_vault_id),
+            resource_address: RADIX_TOKEN,
+            amount: receipt.execution.fee_summary.total_execution_cost_xrd,
+            non_fungible_ids: None,
+        }]
+    );
+}
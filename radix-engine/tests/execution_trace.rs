@@ -88,6 +88,57 @@ fn test_trace_resource_transfers() {
             && r.amount == -Decimal::from(total_fee_paid)));
 }
 
+#[test]
+fn test_trace_non_fungible_resource_changes() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let ids = BTreeSet::from([NonFungibleId::U32(1), NonFungibleId::U32(3)]);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .withdraw_from_account_by_ids(account, &ids, resource_address)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let account_component_id: ComponentId = test_runner.deref_component(account).unwrap().into();
+    let other_account_component_id: ComponentId =
+        test_runner.deref_component(other_account).unwrap().into();
+    let resource_changes = &receipt.expect_commit().resource_changes;
+
+    // Withdrawal
+    assert!(resource_changes
+        .iter()
+        .any(|r| r.resource_address == resource_address
+            && r.component_id == account_component_id
+            && r.amount == -Decimal::from(2)
+            && r.added_ids.is_empty()
+            && r.removed_ids == ids));
+
+    // Deposit
+    assert!(resource_changes
+        .iter()
+        .any(|r| r.resource_address == resource_address
+            && r.component_id == other_account_component_id
+            && r.amount == Decimal::from(2)
+            && r.added_ids == ids
+            && r.removed_ids.is_empty()));
+}
+
 #[test]
 fn test_trace_fee_payments() {
     // Arrange
@@ -0,0 +1,183 @@
+use radix_engine::engine::{ApplicationError, ModuleError, RuntimeError, ScryptoInterpreter};
+use radix_engine::fee::{default_fee_config, FeeSummary};
+use radix_engine::ledger::{read_fee_config, TypedInMemorySubstateStore};
+use radix_engine::model::EpochManagerError;
+use radix_engine::transaction::{
+    execute_and_commit_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt,
+};
+use radix_engine::types::*;
+use radix_engine::wasm::{
+    DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig,
+};
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::{Instruction, SystemTransaction, TransactionManifest};
+
+fn update_fee_config_instruction(version: u64, fee_config: FeeConfig) -> Instruction {
+    Instruction::CallNativeMethod {
+        method_ident: NativeMethodIdent {
+            receiver: RENodeId::Global(GlobalAddress::System(EPOCH_MANAGER)),
+            method_name: EpochManagerMethod::UpdateFeeConfig.to_string(),
+        },
+        args: scrypto_encode(&EpochManagerUpdateFeeConfigInvocation {
+            receiver: EPOCH_MANAGER,
+            version,
+            fee_config,
+        })
+        .unwrap(),
+    }
+}
+
+fn update_fee_config(
+    store: &mut TypedInMemorySubstateStore,
+    version: u64,
+    fee_config: FeeConfig,
+) -> TransactionReceipt {
+    let scrypto_interpreter = ScryptoInterpreter {
+        wasm_metering_config: WasmMeteringConfig::new(
+            InstructionCostRules::tiered(1, 5, 10, 5000),
+            1024,
+        ),
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_instrumenter: WasmInstrumenter::default(),
+    };
+    let transaction = SystemTransaction {
+        manifest: TransactionManifest {
+            instructions: vec![update_fee_config_instruction(version, fee_config)],
+            blobs: vec![],
+        },
+    };
+    execute_and_commit_transaction(
+        store,
+        &scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default(),
+        &transaction.get_executable(),
+    )
+}
+
+fn transfer_fee_summary(
+    store: &mut TypedInMemorySubstateStore,
+    public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+) -> FeeSummary {
+    let mut test_runner = TestRunner::new(true, store);
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10u32.into())
+        .withdraw_from_account_by_amount(account, 1u32.into(), RADIX_TOKEN)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    receipt.execution.fee_summary
+}
+
+#[test]
+fn genesis_should_store_default_fee_config() {
+    // Arrange
+    let store = TypedInMemorySubstateStore::with_bootstrap();
+
+    // Act
+    let fee_config = read_fee_config(&store).unwrap();
+
+    // Assert
+    assert_eq!(fee_config.version, 0);
+    assert_eq!(fee_config.config, default_fee_config());
+}
+
+#[test]
+fn transactions_should_be_charged_with_updated_fee_config() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let (public_key, _, account) = TestRunner::new(true, &mut store).new_allocated_account();
+    let before = transfer_fee_summary(&mut store, public_key, account);
+    let mut fee_config = default_fee_config();
+    fee_config.cost_unit_price = dec!("0.0000002");
+    fee_config.fee_table.tx_base_fee += 5_000;
+
+    // Act
+    let receipt = update_fee_config(&mut store, 1, fee_config.clone());
+    let after = transfer_fee_summary(&mut store, public_key, account);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(read_fee_config(&store).unwrap().version, 1);
+    assert_eq!(after.cost_unit_price, before.cost_unit_price * 2);
+    assert_eq!(after.cost_unit_consumed, before.cost_unit_consumed + 5_000);
+}
+
+#[test]
+fn fee_config_update_with_unexpected_version_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let fee_config = default_fee_config();
+    update_fee_config(&mut store, 1, fee_config.clone()).expect_commit_success();
+
+    // Act
+    let receipt = update_fee_config(&mut store, 1, fee_config);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::UnexpectedFeeConfigVersion {
+                    expected: 2,
+                    actual: 1
+                }
+            ))
+        )
+    });
+    assert_eq!(read_fee_config(&store).unwrap().version, 1);
+}
+
+#[test]
+fn fee_config_update_with_negative_cost_unit_price_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut fee_config = default_fee_config();
+    fee_config.cost_unit_price = dec!("-1");
+
+    // Act
+    let receipt = update_fee_config(&mut store, 1, fee_config);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::InvalidCostUnitPrice(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn fee_config_update_without_system_auth_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let mut manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .build();
+    manifest
+        .instructions
+        .push(update_fee_config_instruction(1, default_fee_config()));
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
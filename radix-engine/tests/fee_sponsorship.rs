@@ -0,0 +1,512 @@
+use radix_engine::engine::{
+    ApplicationError, CostingError, ModuleError, RejectionError, RuntimeError,
+};
+use radix_engine::fee::{CostedInvocation, FeeReserveError, SponsoredInvocation};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::VaultError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::model::TransactionHeader;
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
+
+struct SponsorSetup<'s> {
+    test_runner: TestRunner<'s, TypedInMemorySubstateStore>,
+    package_address: PackageAddress,
+    sponsor: ComponentAddress,
+}
+
+fn setup_sponsor<F>(store: &mut TypedInMemorySubstateStore, sponsorship: F) -> SponsorSetup<'_>
+where
+    F: FnOnce(PackageAddress, ComponentAddress) -> FeeSponsorship,
+{
+    let mut test_runner = TestRunner::new(true, store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/fee");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(account, 10u32.into())
+            .withdraw_from_account_by_amount(account, 100u32.into(), RADIX_TOKEN)
+            .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+                builder.call_function(
+                    package_address,
+                    "FeeSponsor",
+                    "new",
+                    args!(Bucket(bucket_id)),
+                );
+                builder
+            })
+            .build(),
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    let sponsor = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(account, 10u32.into())
+            .call_method(
+                sponsor,
+                "set_sponsorship",
+                args!(sponsorship(package_address, sponsor)),
+            )
+            .build(),
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    SponsorSetup {
+        test_runner,
+        package_address,
+        sponsor,
+    }
+}
+
+fn run_sponsored(setup: &mut SponsorSetup, lock_amount: Decimal) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .call_method(setup.sponsor, "lock_fee", args!(lock_amount))
+        .call_method(setup.sponsor, "ping", args!())
+        .build();
+    setup.test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn sponsor_pays_for_allowed_methods() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("10"))
+            .allow_method(sponsor, "lock_fee")
+            .allow_method(sponsor, "ping")
+    });
+
+    // Act
+    let receipt = run_sponsored(&mut setup, dec!("10"));
+
+    // Assert
+    receipt.expect_commit_success();
+    let fee_summary = &receipt.execution.fee_summary;
+    assert_eq!(fee_summary.fee_sponsorships.len(), 1);
+    let contributions = fee_summary.payer_contributions.as_ref().unwrap();
+    assert_eq!(contributions.len(), 1);
+    let contribution = contributions.values().next().unwrap();
+    assert!(contribution.sponsored);
+    assert_eq!(contribution.locked, dec!("10"));
+    assert_eq!(
+        contribution.paid,
+        fee_summary.total_execution_cost_xrd + fee_summary.total_royalty_cost_xrd
+    );
+    assert_eq!(contribution.refunded, dec!("10") - contribution.paid);
+}
+
+#[test]
+fn transaction_calling_method_not_allowed_by_sponsor_is_rejected_before_fee_is_locked() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("10")).allow_method(sponsor, "lock_fee")
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .call_method(setup.sponsor, "ping", args!())
+        .call_method(setup.sponsor, "lock_fee", args!(dec!("10")))
+        .build();
+    let receipt = setup.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let sponsor = setup.sponsor;
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::InvocationNotAllowedByFeeSponsorship {
+                invocation: SponsoredInvocation::Method {
+                    component_address,
+                    method,
+                },
+                ..
+            } if *component_address == sponsor && method == "ping"
+        )
+    });
+}
+
+#[test]
+fn transaction_calling_method_not_allowed_by_sponsor_fails_once_fee_is_locked() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (sponsor_key, _, sponsor_account) = test_runner.new_allocated_account();
+    let (user_key, _, user_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_sponsored_fee(
+            sponsor_account,
+            dec!("10"),
+            FeeSponsorship::new(dec!("10"))
+                .allow_method(sponsor_account, "lock_sponsored_fee")
+                .allow_method(user_account, "lock_fee"),
+        )
+        .lock_fee(user_account, dec!("10"))
+        .withdraw_from_account_by_amount(user_account, dec!("1"), RADIX_TOKEN)
+        .call_method(
+            user_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleAddress::from_public_key(&sponsor_key),
+            NonFungibleAddress::from_public_key(&user_key),
+        ],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::CostingError(
+                CostingError::FeeReserveError(FeeReserveError::InvocationNotAllowedBySponsorship {
+                    invocation: SponsoredInvocation::Method { method, .. },
+                    ..
+                })
+            )) if method == "withdraw_by_amount"
+        )
+    });
+    let fee_summary = &receipt.execution.fee_summary;
+    let contributions = fee_summary.payer_contributions.as_ref().unwrap();
+    assert_eq!(contributions.len(), 2);
+    let (sponsored, unsponsored): (Vec<_>, Vec<_>) =
+        contributions.values().partition(|c| c.sponsored);
+    assert_eq!(sponsored[0].locked, dec!("10"));
+    assert_eq!(sponsored[0].paid, Decimal::zero());
+    assert_eq!(sponsored[0].refunded, dec!("10"));
+    assert_eq!(unsponsored[0].paid, fee_summary.total_execution_cost_xrd);
+}
+
+#[test]
+fn transaction_calling_method_not_allowed_by_sponsor_is_rejected_without_other_payers() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("10")).allow_method(sponsor, "lock_fee")
+    });
+
+    // Act
+    let receipt = run_sponsored(&mut setup, dec!("10"));
+
+    // Assert
+    let sponsor = setup.sponsor;
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::InvocationNotAllowedByFeeSponsorship {
+                invocation: SponsoredInvocation::Method {
+                    component_address,
+                    method,
+                },
+                ..
+            } if *component_address == sponsor && method == "ping"
+        )
+    });
+}
+
+#[test]
+fn sponsored_lock_beyond_max_contribution_is_rejected() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("5"))
+            .allow_method(sponsor, "lock_fee")
+            .allow_method(sponsor, "ping")
+    });
+
+    // Act
+    let receipt = run_sponsored(&mut setup, dec!("10"));
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::FeeSponsorshipLimitExceeded {
+                max_contribution,
+                ..
+            } if *max_contribution == dec!("5")
+        )
+    });
+}
+
+#[test]
+fn expired_sponsorship_is_rejected() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("10"))
+            .allow_method(sponsor, "lock_fee")
+            .allow_method(sponsor, "ping")
+            .expires_at_epoch(5)
+    });
+    setup.test_runner.set_current_epoch(5);
+
+    // Act
+    let receipt = run_sponsored(&mut setup, dec!("10"));
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::FeeSponsorshipExpired {
+                expires_at_epoch: 5,
+                current_epoch: 5,
+            }
+        )
+    });
+}
+
+#[test]
+fn account_can_sponsor_fees_alongside_other_payers() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (sponsor_key, _, sponsor_account) = test_runner.new_allocated_account();
+    let (user_key, _, user_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(user_account, dec!("1"))
+        .lock_sponsored_fee(
+            sponsor_account,
+            dec!("10"),
+            FeeSponsorship::new(dec!("10"))
+                .allow_method(user_account, "lock_fee")
+                .allow_method(sponsor_account, "lock_sponsored_fee")
+                .allow_method(user_account, "withdraw_by_amount")
+                .allow_method(user_account, "deposit_batch"),
+        )
+        .withdraw_from_account_by_amount(user_account, dec!("1"), RADIX_TOKEN)
+        .call_method(
+            user_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleAddress::from_public_key(&sponsor_key),
+            NonFungibleAddress::from_public_key(&user_key),
+        ],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let fee_summary = &receipt.execution.fee_summary;
+    let contributions = fee_summary.payer_contributions.as_ref().unwrap();
+    assert_eq!(contributions.len(), 2);
+    let (sponsored, unsponsored): (Vec<_>, Vec<_>) =
+        contributions.values().partition(|c| c.sponsored);
+    assert_eq!(sponsored.len(), 1);
+    assert_eq!(sponsored[0].locked, dec!("10"));
+    // Payments are drawn from the most recently locked vault first
+    assert_eq!(
+        sponsored[0].paid,
+        fee_summary.total_execution_cost_xrd + fee_summary.total_royalty_cost_xrd
+    );
+    assert_eq!(unsponsored[0].paid, Decimal::zero());
+    assert_eq!(unsponsored[0].refunded, dec!("1"));
+}
+
+#[test]
+fn breaking_a_sponsorship_after_fee_is_locked_does_not_void_the_fee() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    test_runner.set_current_epoch(5);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, dec!("10"))
+        .lock_sponsored_fee(
+            account,
+            dec!("10"),
+            FeeSponsorship::new(dec!("10")).expires_at_epoch(5),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::FeeSponsorshipExpired { .. }
+            ))
+        )
+    });
+    let fee_summary = &receipt.execution.fee_summary;
+    let contributions = fee_summary.payer_contributions.as_ref().unwrap();
+    assert_eq!(contributions.len(), 1);
+    assert_eq!(
+        contributions.values().next().unwrap().paid,
+        fee_summary.total_execution_cost_xrd + fee_summary.total_royalty_cost_xrd
+    );
+}
+
+#[test]
+fn sponsor_pays_for_allowed_blueprint_functions() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |package_address, sponsor| {
+        FeeSponsorship::new(dec!("10"))
+            .allow_method(sponsor, "lock_fee")
+            .allow_function(package_address, "FeeSponsor", "ping_blueprint")
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .call_method(setup.sponsor, "lock_fee", args!(dec!("10")))
+        .call_function(
+            setup.package_address,
+            "FeeSponsor",
+            "ping_blueprint",
+            args!(),
+        )
+        .build();
+    let receipt = setup.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn transaction_calling_function_not_allowed_by_sponsor_is_rejected_without_other_payers() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("10")).allow_method(sponsor, "lock_fee")
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .call_method(setup.sponsor, "lock_fee", args!(dec!("10")))
+        .call_function(
+            setup.package_address,
+            "FeeSponsor",
+            "ping_blueprint",
+            args!(),
+        )
+        .build();
+    let receipt = setup.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::InvocationNotAllowedByFeeSponsorship {
+                invocation: SponsoredInvocation::Function { function, .. },
+                ..
+            } if function == "ping_blueprint"
+        )
+    });
+}
+
+#[test]
+fn native_functions_are_never_sponsored() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut setup = setup_sponsor(&mut store, |_, sponsor| {
+        FeeSponsorship::new(dec!("10")).allow_method(sponsor, "lock_fee")
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .call_method(setup.sponsor, "lock_fee", args!(dec!("10")))
+        .new_token_fixed(HashMap::new(), dec!("100"))
+        .build();
+    let receipt = setup.test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::InvocationNotAllowedByFeeSponsorship {
+                invocation: SponsoredInvocation::Other(CostedInvocation::Native(
+                    NativeFn::Function(..)
+                )),
+                ..
+            }
+        )
+    });
+}
+
+#[test]
+fn notary_can_pay_the_fees_of_signers_under_a_sponsorship() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, notary_key, notary_account) = test_runner.new_allocated_account();
+    let (_, user_key, user_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_sponsored_fee(
+            notary_account,
+            dec!("10"),
+            FeeSponsorship::new(dec!("10"))
+                .allow_method(notary_account, "lock_sponsored_fee")
+                .allow_method(user_account, "withdraw_by_amount")
+                .allow_method(user_account, "deposit_batch"),
+        )
+        .withdraw_from_account_by_amount(user_account, dec!("1"), RADIX_TOKEN)
+        .call_method(
+            user_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: notary_key.public_key().into(),
+            notary_as_signatory: true,
+            cost_unit_limit: 10_000_000,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .sign(&user_key)
+        .notarize(&notary_key)
+        .build();
+    let executable = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+        .validate(&transaction, &TestIntentHashManager::new())
+        .unwrap();
+    let receipt = test_runner.execute_transaction(&executable);
+
+    // Assert
+    receipt.expect_commit_success();
+    let fee_summary = &receipt.execution.fee_summary;
+    let contributions = fee_summary.payer_contributions.as_ref().unwrap();
+    assert_eq!(contributions.len(), 1);
+    let contribution = contributions.values().next().unwrap();
+    assert!(contribution.sponsored);
+    assert_eq!(
+        contribution.paid,
+        fee_summary.total_execution_cost_xrd + fee_summary.total_royalty_cost_xrd
+    );
+}
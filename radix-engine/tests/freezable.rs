@@ -0,0 +1,256 @@
+use radix_engine::engine::{
+    ApplicationError, AuthError, ModuleError, REActor, ResolvedMethod, ResolvedReceiver,
+    RuntimeError,
+};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::{MethodAuthorizationError, VaultError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::api::types::{NativeMethod, RENodeId};
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::{AuthModule, Instruction};
+
+fn call_vault_method(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    vault_id: VaultId,
+    method_name: &str,
+    args: Vec<u8>,
+    proof_from: Option<(ComponentAddress, ResourceAddress)>,
+    signer_public_keys: &[PublicKey],
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(FAUCET_COMPONENT, 10u32.into());
+    if let Some((account, badge)) = proof_from {
+        builder.create_proof_from_account(account, badge);
+    }
+    let manifest = builder
+        .add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Vault(vault_id),
+                method_name: method_name.to_string(),
+            },
+            args,
+        })
+        .0
+        .build();
+    test_runner.execute_manifest(manifest, AuthModule::pk_non_fungibles(signer_public_keys))
+}
+
+fn freeze(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    vault_id: VaultId,
+    account: ComponentAddress,
+    freeze_auth: ResourceAddress,
+    signer_public_key: PublicKey,
+) {
+    call_vault_method(
+        test_runner,
+        vault_id,
+        "freeze",
+        scrypto_encode(&VaultFreezeInvocation { receiver: vault_id }).unwrap(),
+        Some((account, freeze_auth)),
+        &[signer_public_key],
+    )
+    .expect_commit_success();
+}
+
+fn transfer(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    from: ComponentAddress,
+    to: ComponentAddress,
+    resource_address: ResourceAddress,
+    signer_public_key: PublicKey,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account_by_amount(from, Decimal::one(), resource_address)
+        .call_method(to, "deposit_batch", args!(Expression::entire_worktop()))
+        .build();
+    test_runner.execute_manifest(manifest, AuthModule::pk_non_fungibles(&[signer_public_key]))
+}
+
+fn is_vault_frozen_error(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultFrozen))
+    )
+}
+
+#[test]
+fn cannot_freeze_vault_without_freeze_auth() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (token, _) = test_runner.create_freezable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, token)[0];
+
+    // Act
+    let receipt = call_vault_method(
+        &mut test_runner,
+        vault_id,
+        "freeze",
+        scrypto_encode(&VaultFreezeInvocation { receiver: vault_id }).unwrap(),
+        None,
+        &[],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                actor: REActor::Method(
+                    ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::Freeze)),
+                    ResolvedReceiver {
+                        receiver: RENodeId::Vault(..),
+                        ..
+                    }
+                ),
+                error: MethodAuthorizationError::NotAuthorized,
+                ..
+            }))
+        )
+    });
+}
+
+#[test]
+fn cannot_withdraw_from_frozen_vault() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let (token, freeze_auth) = test_runner.create_freezable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, token)[0];
+    freeze(&mut test_runner, vault_id, account, freeze_auth, pk.into());
+
+    // Act
+    let receipt = transfer(&mut test_runner, account, other_account, token, pk.into());
+
+    // Assert
+    receipt.expect_specific_failure(is_vault_frozen_error);
+}
+
+#[test]
+fn cannot_deposit_into_frozen_vault() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, account) = test_runner.new_allocated_account();
+    let (other_pk, _, other_account) = test_runner.new_allocated_account();
+    let (token, freeze_auth) = test_runner.create_freezable_token(account);
+    transfer(&mut test_runner, account, other_account, token, pk.into()).expect_commit_success();
+    let vault_id = test_runner.get_component_vaults(other_account, token)[0];
+    freeze(&mut test_runner, vault_id, account, freeze_auth, pk.into());
+
+    // Act
+    let receipt = transfer(&mut test_runner, account, other_account, token, pk.into());
+
+    // Assert
+    receipt.expect_specific_failure(is_vault_frozen_error);
+    let receipt = transfer(
+        &mut test_runner,
+        other_account,
+        account,
+        token,
+        other_pk.into(),
+    );
+    receipt.expect_specific_failure(is_vault_frozen_error);
+}
+
+#[test]
+fn cannot_create_proof_from_frozen_vault() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, account) = test_runner.new_allocated_account();
+    let (token, freeze_auth) = test_runner.create_freezable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, token)[0];
+    freeze(&mut test_runner, vault_id, account, freeze_auth, pk.into());
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, token)
+        .build();
+    let receipt =
+        test_runner.execute_manifest(manifest, AuthModule::pk_non_fungibles(&[pk.into()]));
+
+    // Assert
+    receipt.expect_specific_failure(is_vault_frozen_error);
+}
+
+#[test]
+fn can_recall_from_frozen_vault() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let (token, freeze_auth) = test_runner.create_freezable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, token)[0];
+    freeze(&mut test_runner, vault_id, account, freeze_auth, pk.into());
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Vault(vault_id),
+                method_name: "recall".to_string(),
+            },
+            args: scrypto_encode(&VaultRecallInvocation {
+                receiver: vault_id,
+                amount: Decimal::one(),
+            })
+            .unwrap(),
+        })
+        .0
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .get_component_resources(other_account)
+            .get(&token),
+        Some(&Decimal::one())
+    );
+}
+
+#[test]
+fn can_withdraw_from_unfrozen_vault() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (pk, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let (token, freeze_auth) = test_runner.create_freezable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, token)[0];
+    freeze(&mut test_runner, vault_id, account, freeze_auth, pk.into());
+    call_vault_method(
+        &mut test_runner,
+        vault_id,
+        "unfreeze",
+        scrypto_encode(&VaultUnfreezeInvocation { receiver: vault_id }).unwrap(),
+        Some((account, freeze_auth)),
+        &[pk.into()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = transfer(&mut test_runner, account, other_account, token, pk.into());
+
+    // Assert
+    receipt.expect_commit_success();
+}
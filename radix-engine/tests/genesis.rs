@@ -0,0 +1,96 @@
+use radix_engine::ledger::{
+    bootstrap_with_genesis, GenesisBuilder, GenesisError, HistoricalSubstateStore,
+    TypedInMemorySubstateStore,
+};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+#[test]
+fn genesis_allocations_should_be_deposited() {
+    // Arrange
+    let public_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let mut genesis = GenesisBuilder::new();
+    genesis.allocate_xrd_to_public_key(&public_key, dec!("1000"));
+
+    // Act
+    let mut store = TypedInMemorySubstateStore::new();
+    bootstrap_with_genesis(&mut store, &genesis)
+        .expect("Invalid genesis")
+        .unwrap()
+        .expect_commit_success();
+
+    // Assert
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let resources = test_runner.get_component_resources(account);
+    assert_eq!(resources[&RADIX_TOKEN], dec!("1000"));
+}
+
+#[test]
+fn genesis_should_set_initial_epoch_and_validator_set() {
+    // Arrange
+    let key1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let key2 = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap().public_key();
+    let mut genesis = GenesisBuilder::new();
+    genesis
+        .initial_epoch(5)
+        .initial_time_ms(1_000_000)
+        .validator(key1, dec!("100"))
+        .validator(key2, dec!("200"));
+
+    // Act
+    let mut store = TypedInMemorySubstateStore::new();
+    bootstrap_with_genesis(&mut store, &genesis)
+        .expect("Invalid genesis")
+        .unwrap()
+        .expect_commit_success();
+
+    // Assert
+    let mut test_runner = TestRunner::new(true, &mut store);
+    assert_eq!(test_runner.get_current_epoch(), 5);
+    assert_eq!(
+        test_runner.get_validator_set(),
+        BTreeMap::from([(key1, dec!("100")), (key2, dec!("200"))])
+    );
+    let validator = test_runner.get_validator(key1).unwrap();
+    let account = ComponentAddress::virtual_account_from_public_key(&key1);
+    let resources = test_runner.get_component_resources(account);
+    assert_eq!(resources[&validator.stake_unit_resource], dec!("100"));
+}
+
+#[test]
+fn genesis_allocating_more_than_the_max_supply_should_be_rejected() {
+    // Arrange
+    let key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let mut genesis = GenesisBuilder::new();
+    genesis
+        .allocate_xrd_to_public_key(&key, dec!("600000000000"))
+        .validator(key, dec!("400000000001"));
+
+    // Act
+    let mut store = TypedInMemorySubstateStore::new();
+    let result = bootstrap_with_genesis(&mut store, &genesis);
+
+    // Assert
+    assert_eq!(result.err(), Some(GenesisError::XrdMaxSupplyExceeded));
+    assert_eq!(store.get_state_version(), 0);
+}
+
+#[test]
+fn genesis_with_an_invalid_package_should_fail_without_committing() {
+    // Arrange
+    let mut genesis = GenesisBuilder::new();
+    genesis.package(b"not wasm".to_vec(), Vec::new());
+
+    // Act
+    let mut store = TypedInMemorySubstateStore::new();
+    let result = bootstrap_with_genesis(&mut store, &genesis);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(GenesisError::TransactionFailed(..)) | Err(GenesisError::TransactionRejected(..))
+    ));
+    assert_eq!(store.get_state_version(), 0);
+}
@@ -1,6 +1,7 @@
 use radix_engine::engine::{CallFrameError, KernelError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::types::*;
+use radix_engine_constants::MAX_KV_STORE_SCAN_LIMIT;
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
@@ -384,3 +385,230 @@ fn multiple_reads_should_work() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn create_kv_store_with_entries(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    count: u32,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "KeyValueStoreTest",
+            "new_kv_store_with_entries",
+            args!(count),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0]
+}
+
+#[test]
+fn can_remove_entries() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let component_address = create_kv_store_with_entries(&mut test_runner, 3);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "contains_key", args!("1".to_owned()))
+        .call_method(component_address, "remove", args!("1".to_owned()))
+        .call_method(component_address, "contains_key", args!("1".to_owned()))
+        .call_method(component_address, "remove", args!("1".to_owned()))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert!(receipt.output::<bool>(1));
+    assert_eq!(receipt.output::<Option<String>>(2), Some("1".to_owned()));
+    assert!(!receipt.output::<bool>(3));
+    assert_eq!(receipt.output::<Option<String>>(4), None);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "contains_key", args!("1".to_owned()))
+        .call_method(component_address, "keys", args!(10u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    assert!(!receipt.output::<bool>(1));
+    assert_eq!(
+        receipt.output::<Vec<String>>(2),
+        vec!["0".to_owned(), "2".to_owned()]
+    );
+}
+
+#[test]
+fn cannot_remove_entries_owning_kv_stores() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "KeyValueStoreTest",
+            "new_kv_store_into_kv_store",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove_kv_store", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::StoredNodeRemoved(RENodeId::KeyValueStore(_)))
+        )
+    });
+}
+
+#[test]
+fn can_iterate_over_stored_entries_in_pages() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let component_address = create_kv_store_with_entries(&mut test_runner, 25);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "keys", args!(7u32))
+        .call_method(component_address, "entries", args!(7u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let keys: Vec<String> = receipt.output(1);
+    let entries: Vec<(String, String)> = receipt.output(2);
+    let expected: BTreeSet<String> = (0..25).map(|i: u32| i.to_string()).collect();
+    assert_eq!(keys.len(), 25);
+    assert_eq!(keys.iter().cloned().collect::<BTreeSet<String>>(), expected);
+    assert_eq!(
+        entries,
+        keys.iter()
+            .map(|key| (key.clone(), key.clone()))
+            .collect::<Vec<(String, String)>>()
+    );
+}
+
+#[test]
+fn iteration_reflects_updates_made_by_the_transaction() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let component_address = create_kv_store_with_entries(&mut test_runner, 10);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove", args!("3".to_owned()))
+        .call_method(
+            component_address,
+            "insert",
+            args!("new".to_owned(), "value".to_owned()),
+        )
+        .call_method(component_address, "keys", args!(4u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let keys: BTreeSet<String> = receipt.output::<Vec<String>>(3).into_iter().collect();
+    let mut expected: BTreeSet<String> = (0..10).map(|i: u32| i.to_string()).collect();
+    expected.remove("3");
+    expected.insert("new".to_owned());
+    assert_eq!(keys, expected);
+}
+
+#[test]
+fn can_iterate_over_in_memory_kv_store() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "KeyValueStoreTest",
+            "new_kv_store_then_iterate",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert_eq!(
+        receipt.output::<Vec<String>>(1),
+        vec!["a".to_owned(), "c".to_owned()]
+    );
+}
+
+#[test]
+fn cannot_scan_more_entries_than_the_limit() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let component_address = create_kv_store_with_entries(&mut test_runner, 1);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "keys",
+            args!(MAX_KV_STORE_SCAN_LIMIT + 1),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::KeyValueStoreScanLimitExceeded(_))
+        )
+    });
+}
+
+#[test]
+fn cannot_scan_with_a_limit_too_large_to_charge_for() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let component_address = create_kv_store_with_entries(&mut test_runner, 1);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "keys", args!(u32::MAX))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::KeyValueStoreScanLimitExceeded(_))
+        )
+    });
+}
@@ -1,5 +1,7 @@
-use radix_engine::engine::{AuthError, ModuleError, RuntimeError};
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::MetadataError;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::core::NetworkDefinition;
 use scrypto_unit::*;
@@ -33,7 +35,7 @@ fn cannot_set_package_metadata_with_no_owner() {
             scrypto_encode(&MetadataSetInvocation {
                 receiver: RENodeId::Global(GlobalAddress::Package(package_address)),
                 key: "name".to_string(),
-                value: "best package ever!".to_string(),
+                value: MetadataValue::String("best package ever!".to_string()),
             })
             .unwrap(),
         )
@@ -78,7 +80,7 @@ fn can_set_package_metadata_with_owner() {
             scrypto_encode(&MetadataSetInvocation {
                 receiver: RENodeId::Global(GlobalAddress::Package(package_address)),
                 key: "name".to_string(),
-                value: "best package ever!".to_string(),
+                value: MetadataValue::String("best package ever!".to_string()),
             })
             .unwrap(),
         )
@@ -91,7 +93,10 @@ fn can_set_package_metadata_with_owner() {
     // Assert
     receipt.expect_commit_success();
     let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
-    assert_eq!(metadata.get("name").unwrap(), "best package ever!");
+    assert_eq!(
+        metadata.get("name").unwrap(),
+        &MetadataValue::String("best package ever!".to_string())
+    );
 }
 
 #[test]
@@ -145,7 +150,7 @@ fn can_lock_package_metadata_with_owner() {
             scrypto_encode(&MetadataSetInvocation {
                 receiver: RENodeId::Global(GlobalAddress::Package(package_address)),
                 key: "name".to_string(),
-                value: "best package ever!".to_string(),
+                value: MetadataValue::String("best package ever!".to_string()),
             })
             .unwrap(),
         )
@@ -165,3 +170,113 @@ fn can_lock_package_metadata_with_owner() {
     let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
     assert!(metadata.get("name").is_none());
 }
+
+fn publish_package_and_set_metadata(
+    entries: Vec<(&str, MetadataValue)>,
+) -> (TransactionReceipt, HashMap<String, MetadataValue>) {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let (public_key, _, account) = test_runner.new_account(false);
+    let owner_badge_resource = test_runner.create_non_fungible_resource(account);
+    let owner_badge_addr = NonFungibleAddress::new(owner_badge_resource, NonFungibleId::U32(1));
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .publish_package_with_owner(code, HashMap::new(), owner_badge_addr)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let package_address = receipt.expect_commit().entity_changes.new_package_addresses[0];
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource);
+    for (key, value) in entries {
+        builder.call_native_method(
+            RENodeId::Global(GlobalAddress::Package(package_address)),
+            "set",
+            scrypto_encode(&MetadataSetInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Package(package_address)),
+                key: key.to_string(),
+                value,
+            })
+            .unwrap(),
+        );
+    }
+    let receipt = test_runner.execute_manifest(
+        builder.build(),
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
+    (receipt, metadata)
+}
+
+#[test]
+fn can_set_typed_package_metadata() {
+    // Act
+    let (receipt, metadata) = publish_package_and_set_metadata(vec![
+        (
+            "icon_url",
+            MetadataValue::Url("https://example.com/icon.png".to_string()),
+        ),
+        ("max_supply", MetadataValue::Decimal(dec!("100"))),
+        ("tags", MetadataValue::from(vec!["defi", "dex"])),
+    ]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        metadata.get("icon_url").unwrap(),
+        &MetadataValue::Url("https://example.com/icon.png".to_string())
+    );
+    assert_eq!(
+        metadata.get("max_supply").unwrap(),
+        &MetadataValue::Decimal(dec!("100"))
+    );
+    assert_eq!(
+        metadata.get("tags").unwrap(),
+        &MetadataValue::List(vec![
+            MetadataValue::String("defi".to_string()),
+            MetadataValue::String("dex".to_string())
+        ])
+    );
+}
+
+#[test]
+fn cannot_set_well_known_package_metadata_with_wrong_type() {
+    // Act
+    let (receipt, metadata) = publish_package_and_set_metadata(vec![(
+        "icon_url",
+        MetadataValue::String("https://example.com/icon.png".to_string()),
+    )]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                MetadataError::UnexpectedValueType { key, expected: MetadataValueType::Url }
+            )) if key == "icon_url"
+        )
+    });
+    assert!(!metadata.contains_key("icon_url"));
+}
+
+#[test]
+fn cannot_set_package_metadata_with_invalid_url() {
+    // Act
+    let (receipt, _) = publish_package_and_set_metadata(vec![(
+        "links",
+        MetadataValue::List(vec![MetadataValue::Url("example.com".to_string())]),
+    )]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                MetadataError::InvalidUrl(..)
+            ))
+        )
+    });
+}
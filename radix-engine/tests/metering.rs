@@ -192,19 +192,18 @@ fn test_basic_transfer() {
         + 0 /* blobs */
         + 2000 /* create_node */
         + 990 /* decode_manifest */
-        + 6600 /* drop_lock */
+        + 5000 /* drop_lock */
         + 2000 /* drop_node */
-        + 800  /* emit_event */
+        + 2800 /* emit_event */
         + 900 /* invoke */
-        + 8400 /* lock_substate */
+        + 6200 /* lock_substate */
         + 3000 /* read_owned_nodes */
-        + 28500 /* read_substate */
+        + 18000 /* read_substate */
         + 1000 /* run_native_function */
-        + 2200 /* run_native_method */
-        + 320727 /* run_wasm */
+        + 3700 /* run_native_method */
         + 330 /* verify_manifest */
         + 3750 /* verify_signatures */
-        + 17000, /* write_substate */
+        + 14000, /* write_substate */
         receipt.execution.fee_summary.cost_unit_consumed
     );
 }
@@ -1,5 +1,11 @@
+use radix_engine::engine::{
+    ApplicationError, AuthError, ModuleError, REActor, ResolvedMethod, RuntimeError,
+};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::ResourceManagerError;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
+use radix_engine_interface::api::types::NativeMethod;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
@@ -437,3 +443,204 @@ fn cant_burn_non_fungible_with_wrong_non_fungible_id_type() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+fn call_non_fungible_test_function(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    package: PackageAddress,
+    account: ComponentAddress,
+    function_name: &str,
+    args: Vec<u8>,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package, "NonFungibleTest", function_name, args)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn is_resource_manager_error(e: &RuntimeError, expected: ResourceManagerError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(error))
+            if *error == expected
+    )
+}
+
+#[test]
+fn can_burn_non_fungibles_from_vault() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "burn_non_fungibles_from_vault",
+        args!(true),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_burn_non_fungibles_from_vault_without_burn_auth() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "burn_non_fungibles_from_vault",
+        args!(false),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                actor: REActor::Method(
+                    ResolvedMethod::Native(NativeMethod::ResourceManager(
+                        ResourceManagerMethod::Burn
+                    )),
+                    ..
+                ),
+                ..
+            }))
+        )
+    });
+}
+
+#[test]
+fn can_update_mutable_non_fungible_field() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "update_non_fungible_field",
+        args!("available".to_string(), true),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_update_immutable_non_fungible_field() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "update_non_fungible_field_with_string",
+        args!("name".to_string(), "Updated".to_string()),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_resource_manager_error(
+            e,
+            ResourceManagerError::ImmutableNonFungibleField("name".to_string()),
+        )
+    });
+}
+
+#[test]
+fn cannot_update_unknown_non_fungible_field() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "update_non_fungible_field",
+        args!("price".to_string(), true),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_resource_manager_error(
+            e,
+            ResourceManagerError::NonFungibleFieldNotFound("price".to_string()),
+        )
+    });
+}
+
+#[test]
+fn cannot_update_non_fungible_field_with_value_of_wrong_type() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "update_non_fungible_field_with_string",
+        args!("available".to_string(), "yes".to_string()),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_resource_manager_error(e, ResourceManagerError::InvalidNonFungibleData)
+    });
+}
+
+#[test]
+fn cannot_mint_non_fungible_with_data_not_matching_schema() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    // Act
+    let receipt = call_non_fungible_test_function(
+        &mut test_runner,
+        package,
+        account,
+        "mint_non_fungible_with_mismatching_data",
+        args!(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_resource_manager_error(e, ResourceManagerError::InvalidNonFungibleData)
+    });
+}
@@ -159,6 +159,7 @@ fn test_basic_package_missing_export() {
                 output: Type::Unit,
                 export_name: "f".to_string(),
             }],
+            events: vec![],
         },
     );
 
@@ -1,6 +1,13 @@
-use radix_engine::ledger::TypedInMemorySubstateStore;
-use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
+use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::fee::CostedInvocation;
+use radix_engine::ledger::{HistoricalSubstateStore, TypedInMemorySubstateStore};
+use radix_engine::transaction::{
+    execute_preview_at_state_version, ExecutionConfig, FeeReserveConfig,
+};
 use radix_engine::types::*;
+use radix_engine::wasm::{
+    DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig,
+};
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use radix_engine_interface::rule;
@@ -27,6 +34,7 @@ fn test_transaction_preview_cost_estimate() {
         assume_all_signature_proofs: false,
         permit_invalid_header_epoch: false,
         permit_duplicate_intent_hash: false,
+        cost_breakdown: false,
     };
     let (notarized_transaction, preview_intent) = prepare_matching_test_tx_and_preview_intent(
         &test_runner,
@@ -52,6 +60,97 @@ fn test_transaction_preview_cost_estimate() {
         preview_receipt.execution.fee_summary.cost_unit_consumed,
         receipt.execution.fee_summary.cost_unit_consumed
     );
+    assert!(preview_receipt
+        .execution
+        .fee_summary
+        .cost_breakdown
+        .is_none());
+}
+
+#[test]
+fn test_transaction_preview_cost_breakdown() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new(&network)
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let preview_flags = PreviewFlags {
+        unlimited_loan: true,
+        assume_all_signature_proofs: false,
+        permit_invalid_header_epoch: false,
+        permit_duplicate_intent_hash: false,
+        cost_breakdown: true,
+    };
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+
+    // Act
+    let preview_result = test_runner
+        .execute_preview(preview_intent, &network)
+        .unwrap();
+
+    // Assert
+    preview_result.receipt.expect_commit_success();
+    let cost_unit_consumed = preview_result
+        .receipt
+        .execution
+        .fee_summary
+        .cost_unit_consumed;
+    let cost_breakdown = preview_result.cost_breakdown().unwrap();
+    assert_eq!(
+        cost_breakdown.transaction_cost_units
+            + cost_breakdown.instruction_cost_units.values().sum::<u32>(),
+        cost_unit_consumed
+    );
+    assert_eq!(
+        cost_breakdown
+            .instruction_cost_units
+            .keys()
+            .cloned()
+            .collect::<Vec<usize>>(),
+        vec![0, 1, 2]
+    );
+
+    let free = cost_breakdown
+        .invocations
+        .iter()
+        .find(|cost| {
+            cost.invocation
+                == CostedInvocation::Scrypto {
+                    package_address: FAUCET_PACKAGE,
+                    blueprint_name: "Faucet".to_string(),
+                    ident: "free".to_string(),
+                }
+        })
+        .unwrap();
+    assert_eq!(free.instruction_index, Some(1));
+    assert_eq!(free.depth, 0);
+    let nested_cost_units: u32 = cost_breakdown
+        .invocations
+        .iter()
+        .filter(|cost| cost.instruction_index == Some(1) && cost.depth > 0)
+        .map(|cost| cost.cost_units)
+        .sum();
+    assert!(nested_cost_units > 0);
+    assert_eq!(free.total_cost_units, free.cost_units + nested_cost_units);
+    assert!(free.total_cost_units <= cost_breakdown.instruction_cost_units[&1]);
+
+    assert!(
+        preview_result.recommended_cost_unit_limit as u64 >= cost_unit_consumed as u64 * 120 / 100
+    );
 }
 
 #[test]
@@ -72,6 +171,7 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
         assume_all_signature_proofs: true,
         permit_invalid_header_epoch: false,
         permit_duplicate_intent_hash: false,
+        cost_breakdown: false,
     };
 
     // Check method authorization (withdrawal) without a proof in the auth zone
@@ -99,6 +199,89 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().receipt.expect_commit_success();
 }
 
+#[test]
+fn test_transaction_preview_at_past_state_version() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap_and_history();
+    let network = NetworkDefinition::simulator();
+    let account = {
+        let mut test_runner = TestRunner::new(true, &mut substate_store);
+        test_runner.new_account_with_auth_rule(&rule!(allow_all))
+    };
+    let state_version = substate_store.get_state_version();
+    let preview_intent = {
+        let mut test_runner = TestRunner::new(true, &mut substate_store);
+        let (_, _, other_account) = test_runner.new_allocated_account();
+        let manifest = ManifestBuilder::new(&network)
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .withdraw_from_account(account, RADIX_TOKEN)
+            .call_method(
+                other_account,
+                "deposit_batch",
+                args!(Expression::entire_worktop()),
+            )
+            .build();
+        test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success();
+
+        let manifest = ManifestBuilder::new(&network)
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_method(account, "balance", args!(RADIX_TOKEN))
+            .build();
+        let preview_flags = PreviewFlags {
+            unlimited_loan: true,
+            assume_all_signature_proofs: false,
+            permit_invalid_header_epoch: false,
+            permit_duplicate_intent_hash: false,
+            cost_breakdown: false,
+        };
+        prepare_matching_test_tx_and_preview_intent(
+            &test_runner,
+            &network,
+            manifest,
+            &preview_flags,
+        )
+        .1
+    };
+    let scrypto_interpreter = ScryptoInterpreter {
+        wasm_metering_config: WasmMeteringConfig::new(
+            InstructionCostRules::tiered(1, 5, 10, 5000),
+            1024,
+        ),
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_instrumenter: WasmInstrumenter::default(),
+    };
+
+    // Act
+    let past_receipt = execute_preview_at_state_version(
+        &substate_store,
+        state_version,
+        &scrypto_interpreter,
+        &TestIntentHashManager::new(),
+        &network,
+        preview_intent.clone(),
+    )
+    .unwrap()
+    .receipt;
+    let current_receipt = execute_preview_at_state_version(
+        &substate_store,
+        substate_store.get_state_version(),
+        &scrypto_interpreter,
+        &TestIntentHashManager::new(),
+        &network,
+        preview_intent,
+    )
+    .unwrap()
+    .receipt;
+
+    // Assert
+    past_receipt.expect_commit_success();
+    current_receipt.expect_commit_success();
+    assert_eq!(past_receipt.output::<Decimal>(1), dec!("1000"));
+    assert_eq!(current_receipt.output::<Decimal>(1), Decimal::zero());
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &TestRunner<TypedInMemorySubstateStore>,
     network: &NetworkDefinition,
@@ -0,0 +1,244 @@
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::TransactionProcessorError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::{ManifestBuilder, SubintentBuilder, TransactionBuilder};
+use transaction::model::{SubintentHeader, TransactionHeader, TransactionManifest};
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
+
+struct Party {
+    private_key: EcdsaSecp256k1PrivateKey,
+    account: ComponentAddress,
+    resource_address: ResourceAddress,
+}
+
+fn new_party(test_runner: &mut TestRunner<TypedInMemorySubstateStore>) -> Party {
+    let (_, private_key, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+    Party {
+        private_key,
+        account,
+        resource_address,
+    }
+}
+
+fn execute_with_subintent(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    intent_signer: &EcdsaSecp256k1PrivateKey,
+    manifest: TransactionManifest,
+    subintent_signer: &EcdsaSecp256k1PrivateKey,
+    subintent_manifest: TransactionManifest,
+) -> TransactionReceipt {
+    let subintent = SubintentBuilder::new()
+        .header(SubintentHeader {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce: test_runner.next_transaction_nonce(),
+        })
+        .manifest(subintent_manifest)
+        .sign(subintent_signer)
+        .build();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: intent_signer.public_key().into(),
+            notary_as_signatory: true,
+            cost_unit_limit: 10_000_000,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .subintent(subintent)
+        .notarize(intent_signer)
+        .build();
+
+    let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+    let executable = validator
+        .validate(&transaction, &TestIntentHashManager::new())
+        .unwrap();
+    test_runner.execute_transaction(&executable)
+}
+
+#[test]
+fn subintent_can_swap_resources_with_the_intent_through_the_worktop() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let alice = new_party(&mut test_runner);
+    let bob = new_party(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(alice.account, 10.into(), alice.resource_address)
+        .yield_to_subintent(0)
+        .assert_worktop_contains_by_amount(20.into(), bob.resource_address)
+        .call_method(
+            alice.account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let subintent_manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .withdraw_from_account_by_amount(bob.account, 20.into(), bob.resource_address)
+        .take_from_worktop_by_amount(10.into(), alice.resource_address, |builder, bucket_id| {
+            builder.call_method(bob.account, "deposit", args!(Bucket(bucket_id)))
+        })
+        .build();
+    let receipt = execute_with_subintent(
+        &mut test_runner,
+        &alice.private_key,
+        manifest,
+        &bob.private_key,
+        subintent_manifest,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let alice_id = test_runner.deref_component(alice.account).unwrap().into();
+    let bob_id = test_runner.deref_component(bob.account).unwrap().into();
+    let balance_change = |component_id: ComponentId, resource_address: ResourceAddress| {
+        receipt
+            .expect_commit()
+            .resource_changes
+            .iter()
+            .filter(|r| r.component_id == component_id && r.resource_address == resource_address)
+            .fold(Decimal::zero(), |sum, r| sum + r.amount)
+    };
+    assert_eq!(
+        balance_change(alice_id, alice.resource_address),
+        dec!("-10")
+    );
+    assert_eq!(balance_change(alice_id, bob.resource_address), dec!("20"));
+    assert_eq!(balance_change(bob_id, alice.resource_address), dec!("10"));
+    assert_eq!(balance_change(bob_id, bob.resource_address), dec!("-20"));
+}
+
+#[test]
+fn subintent_cannot_use_the_proofs_of_the_intent_signers() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let alice = new_party(&mut test_runner);
+    let bob = new_party(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .yield_to_subintent(0)
+        .call_method(
+            bob.account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let subintent_manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .withdraw_from_account_by_amount(alice.account, 10.into(), alice.resource_address)
+        .build();
+    let receipt = execute_with_subintent(
+        &mut test_runner,
+        &alice.private_key,
+        manifest,
+        &bob.private_key,
+        subintent_manifest,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn intent_cannot_use_the_proofs_of_the_subintent_signers() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let alice = new_party(&mut test_runner);
+    let bob = new_party(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .yield_to_subintent(0)
+        .withdraw_from_account_by_amount(bob.account, 10.into(), bob.resource_address)
+        .call_method(
+            alice.account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let subintent_manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .create_proof_from_account(bob.account, bob.resource_address)
+        .build();
+    let receipt = execute_with_subintent(
+        &mut test_runner,
+        &alice.private_key,
+        manifest,
+        &bob.private_key,
+        subintent_manifest,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn subintent_must_hand_over_its_buckets_through_the_worktop() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let alice = new_party(&mut test_runner);
+    let bob = new_party(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .yield_to_subintent(0)
+        .call_method(
+            alice.account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let subintent_manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .withdraw_from_account_by_amount(bob.account, 10.into(), bob.resource_address)
+        .take_from_worktop(bob.resource_address, |builder, _| builder)
+        .build();
+    let receipt = execute_with_subintent(
+        &mut test_runner,
+        &alice.private_key,
+        manifest,
+        &bob.private_key,
+        subintent_manifest,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::SubintentBucketNotReturned(..)
+            ))
+        )
+    });
+}
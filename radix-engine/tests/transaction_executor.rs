@@ -1,7 +1,9 @@
 use radix_engine::engine::RejectionError;
 use radix_engine::engine::ScryptoInterpreter;
 use radix_engine::ledger::TypedInMemorySubstateStore;
-use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::{
+    execute_and_commit_transaction, execute_and_commit_transaction_with_intent_hashes,
+};
 use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
 use radix_engine::wasm::WasmInstrumenter;
@@ -10,12 +12,15 @@ use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
 use radix_engine_interface::core::NetworkDefinition;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
-use transaction::builder::TransactionBuilder;
+use transaction::builder::{SubintentBuilder, TransactionBuilder};
 use transaction::errors::{HeaderValidationError, TransactionValidationError};
-use transaction::model::{Executable, NotarizedTransaction, TransactionHeader};
+use transaction::model::{
+    Executable, NotarizedTransaction, SignedSubintent, SubintentHeader, TransactionHeader,
+};
 use transaction::signing::EcdsaSecp256k1PrivateKey;
 use transaction::validation::{
-    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+    IntentHashStore, NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator,
+    ValidationConfig,
 };
 
 #[test]
@@ -160,6 +165,120 @@ fn test_normal_transaction_flow() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn committed_intent_should_be_rejected_until_its_epoch_range_has_ended() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let scrypto_interpreter = ScryptoInterpreter {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_instrumenter: WasmInstrumenter::default(),
+        wasm_metering_config: WasmMeteringConfig::new(
+            InstructionCostRules::tiered(1, 5, 10, 5000),
+            1024,
+        ),
+    };
+    let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+    let transaction = create_notarized_transaction(TransactionParams {
+        cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+        start_epoch_inclusive: 0,
+        end_epoch_exclusive: 10,
+    });
+    let intent_hash = transaction.signed_intent.intent.hash().unwrap();
+    let executable = validator
+        .validate(&transaction, &substate_store)
+        .expect("Invalid transaction");
+    execute_and_commit_transaction_with_intent_hashes(
+        &mut substate_store,
+        &scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default(),
+        &executable,
+    )
+    .expect_commit_success();
+
+    // Act
+    let replay_before_expiry = validator.validate(&transaction, &substate_store).err();
+    TestRunner::new(true, &mut substate_store).set_current_epoch(10);
+    let next_transaction = create_notarized_transaction(TransactionParams {
+        cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+        start_epoch_inclusive: 10,
+        end_epoch_exclusive: 20,
+    });
+    let next_executable = validator
+        .validate(&next_transaction, &substate_store)
+        .expect("Invalid transaction");
+    execute_and_commit_transaction_with_intent_hashes(
+        &mut substate_store,
+        &scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default(),
+        &next_executable,
+    )
+    .expect_commit_success();
+    let replay_after_expiry = validator.validate(&transaction, &substate_store).err();
+
+    // Assert
+    assert_eq!(
+        replay_before_expiry,
+        Some(TransactionValidationError::IntentHashRejected)
+    );
+    assert_eq!(replay_after_expiry, None);
+    assert_eq!(substate_store.get_intent_hash_end_epoch(&intent_hash), None);
+    assert_eq!(
+        substate_store
+            .get_intent_hash_end_epoch(&next_transaction.signed_intent.intent.hash().unwrap()),
+        Some(20)
+    );
+}
+
+#[test]
+fn committed_subintent_should_be_rejected_in_another_transaction() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let scrypto_interpreter = ScryptoInterpreter {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_instrumenter: WasmInstrumenter::default(),
+        wasm_metering_config: WasmMeteringConfig::new(
+            InstructionCostRules::tiered(1, 5, 10, 5000),
+            1024,
+        ),
+    };
+    let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+    let subintent = SubintentBuilder::new()
+        .header(SubintentHeader {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 10,
+            nonce: 1,
+        })
+        .manifest(
+            ManifestBuilder::new(&NetworkDefinition::simulator())
+                .clear_auth_zone()
+                .build(),
+        )
+        .sign(&EcdsaSecp256k1PrivateKey::from_u64(4).unwrap())
+        .build();
+    let transaction = create_notarized_transaction_with_subintent(5, subintent.clone());
+    let executable = validator
+        .validate(&transaction, &substate_store)
+        .expect("Invalid transaction");
+    execute_and_commit_transaction_with_intent_hashes(
+        &mut substate_store,
+        &scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default(),
+        &executable,
+    )
+    .expect_commit_success();
+
+    // Act
+    let replay = create_notarized_transaction_with_subintent(6, subintent);
+    let result = validator.validate(&replay, &substate_store).err();
+
+    // Assert
+    assert_eq!(result, Some(TransactionValidationError::IntentHashRejected));
+}
+
 fn get_executable<'a>(
     transaction: &'a NotarizedTransaction,
 ) -> Result<Executable<'a>, TransactionValidationError> {
@@ -203,3 +322,32 @@ fn create_notarized_transaction(params: TransactionParams) -> NotarizedTransacti
         .notarize(&sk_notary)
         .build()
 }
+
+fn create_notarized_transaction_with_subintent(
+    nonce: u64,
+    subintent: SignedSubintent,
+) -> NotarizedTransaction {
+    let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+
+    TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 10,
+            nonce,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_as_signatory: false,
+            cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+            tip_percentage: 5,
+        })
+        .manifest(
+            ManifestBuilder::new(&NetworkDefinition::simulator())
+                .lock_fee(FAUCET_COMPONENT, 10.into())
+                .yield_to_subintent(0)
+                .build(),
+        )
+        .subintent(subintent)
+        .notarize(&sk_notary)
+        .build()
+}
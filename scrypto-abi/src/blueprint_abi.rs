@@ -23,6 +23,9 @@ pub struct Blueprint {
 pub struct BlueprintAbi {
     pub structure: Type,
     pub fns: Vec<Fn>,
+    /// The schemas of the events emitted by the blueprint.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub events: Vec<Type>,
 }
 
 impl BlueprintAbi {
@@ -38,6 +41,12 @@ impl BlueprintAbi {
     pub fn contains_fn(&self, fn_ident: &str) -> bool {
         self.get_fn_abi(fn_ident).is_some()
     }
+
+    pub fn get_event_abi(&self, type_name: &str) -> Option<&Type> {
+        self.events
+            .iter()
+            .find(|event| event.type_name() == Some(type_name))
+    }
 }
 
 /// Represents a method/function.
@@ -113,6 +113,16 @@ pub enum Type {
     Any,
 }
 
+impl Type {
+    /// Returns the name of a struct or enum type.
+    pub fn type_name(&self) -> Option<&str> {
+        match self {
+            Type::Struct { name, .. } | Type::Enum { name, .. } => Some(name.as_str()),
+            _ => None,
+        }
+    }
+}
+
 /// Represents the type info of an enum variant.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, TypeId, Decode, Encode)]
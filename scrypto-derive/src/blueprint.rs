@@ -89,6 +89,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     let output_abi = {
         let abi_ident = format_ident!("{}_abi", bp_ident);
         let abi_functions = generate_abi(bp_ident, bp_items)?;
+        let abi_events = extract_events(&bp_strut.attrs)?;
         quote! {
             #[no_mangle]
             pub extern "C" fn #abi_ident(input: *mut u8) -> *mut u8 {
@@ -99,9 +100,11 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
                 let fns: Vec<Fn> = vec![ #(#abi_functions),* ];
                 let structure: Type = #module_ident::#bp_ident::describe();
+                let events: Vec<Type> = vec![ #(<#abi_events>::describe()),* ];
                 let output = BlueprintAbi {
                     structure,
                     fns,
+                    events,
                 };
 
                 ::scrypto::buffer::scrypto_encode_to_buffer(&output).unwrap()
@@ -290,6 +293,20 @@ fn generate_dispatcher(
     Ok(functions)
 }
 
+// Returns the event types listed in the `#[events(..)]` attributes of the blueprint struct.
+fn extract_events(attrs: &[Attribute]) -> Result<Vec<Path>> {
+    let mut events = Vec::new();
+    for attr in attrs {
+        if attr.path.is_ident("events") {
+            let parsed = attr.parse_args_with(
+                syn::punctuated::Punctuated::<Path, Token![,]>::parse_terminated,
+            )?;
+            events.extend(parsed);
+        }
+    }
+    Ok(events)
+}
+
 // Parses function items of an `Impl` and returns ABI of functions.
 #[allow(dead_code)]
 fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Expr>> {
@@ -481,7 +498,7 @@ fn generate_stubs(
             fn blueprint_name(&self) -> String {
                 self.component.blueprint_name()
             }
-            fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+            fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataValue>>(&mut self, name: K, value: V) -> &mut Self {
                 self.component.metadata(name, value);
                 self
             }
@@ -527,7 +544,7 @@ fn generate_stubs(
             fn blueprint_name(&self) -> String {
                 self.component.blueprint_name()
             }
-            fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+            fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataValue>>(&mut self, name: K, value: V) -> &mut Self {
                 self.component.metadata(name, value);
                 self
             }
@@ -705,9 +722,11 @@ mod tests {
                         }
                     ];
                     let structure: Type = Test_impl::Test::describe();
+                    let events: Vec<Type> = vec![];
                     let output = BlueprintAbi {
                         structure,
                         fns,
+                        events,
                     };
                     ::scrypto::buffer::scrypto_encode_to_buffer(&output).unwrap()
                 }
@@ -726,7 +745,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataValue>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -776,7 +795,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataValue>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -846,9 +865,11 @@ mod tests {
                     use ::sbor::rust::vec::Vec;
                     let fns: Vec<Fn> = vec![];
                     let structure: Type = Test_impl::Test::describe();
+                    let events: Vec<Type> = vec![];
                     let output = BlueprintAbi {
                         structure,
                         fns,
+                        events,
                     };
                     ::scrypto::buffer::scrypto_encode_to_buffer(&output).unwrap()
                 }
@@ -867,7 +888,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataValue>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -910,7 +931,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataValue>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
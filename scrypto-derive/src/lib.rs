@@ -100,6 +100,11 @@ pub fn import(input: TokenStream) -> TokenStream {
 
 /// Derive code that describe a non-fungible data structure.
 ///
+/// Fields marked with `#[scrypto(mutable)]` make up the mutable part of the data, and the rest
+/// the immutable part. When a resource is built with `data_schema::<MyStruct>()`, the engine
+/// validates minted and updated data against the derived schema and only allows the mutable
+/// fields to be updated.
+///
 /// # Example
 ///
 /// ```ignore
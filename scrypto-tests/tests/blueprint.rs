@@ -62,6 +62,7 @@ fn test_simple_abi() {
     assert_json_eq(
         abi,
         json!({
+          "events": [],
           "fns": [
             {
               "export_name": "Simple_new",
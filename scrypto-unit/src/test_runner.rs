@@ -160,7 +160,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         )
     }
 
-    pub fn get_metadata(&mut self, address: GlobalAddress) -> HashMap<String, String> {
+    pub fn get_metadata(&mut self, address: GlobalAddress) -> HashMap<String, MetadataValue> {
         let node_id = RENodeId::Global(address);
         let global = self
             .execution_stores
@@ -548,13 +548,13 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 &mut store,
                 &mut self.scrypto_interpreter,
                 &FeeReserveConfig {
-                    cost_unit_price: DEFAULT_COST_UNIT_PRICE,
                     system_loan: DEFAULT_SYSTEM_LOAN,
                 },
                 &ExecutionConfig {
                     max_call_depth: DEFAULT_MAX_CALL_DEPTH,
                     trace: self.trace,
                     max_sys_call_trace_depth: 1,
+                    cost_breakdown: false,
                 },
                 &transaction.get_executable(initial_proofs),
             );
@@ -644,6 +644,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 Some(MintParams::Fungible {
                     amount: 5u32.into(),
                 }),
+                None,
             )
             .call_method(to, "deposit_batch", args!(Expression::entire_worktop()))
             .build();
@@ -737,6 +738,26 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         self.create_fungible_resource_and_deposit(access_rules, account)
     }
 
+    pub fn create_freezable_token(
+        &mut self,
+        account: ComponentAddress,
+    ) -> (ResourceAddress, ResourceAddress) {
+        let freeze_auth = self.create_non_fungible_resource(account);
+
+        let mut access_rules = HashMap::new();
+        access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Recall, (rule!(allow_all), LOCKED));
+        access_rules.insert(
+            ResourceMethodAuthKey::Freeze,
+            (rule!(require(freeze_auth)), LOCKED),
+        );
+
+        let token_address = self.create_fungible_resource_and_deposit(access_rules, account);
+
+        (token_address, freeze_auth)
+    }
+
     pub fn create_restricted_burn_token(
         &mut self,
         account: ComponentAddress,
@@ -797,6 +818,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::NonFungible { entries }),
+                None,
             )
             .call_method(
                 account,
@@ -828,6 +850,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount }),
+                None,
             )
             .call_method(
                 account,
@@ -860,6 +883,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount }),
+                None,
             )
             .call_method(
                 account,
@@ -936,6 +960,54 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         })
     }
 
+    pub fn next_epoch(&mut self) {
+        self.kernel_call(
+            vec![NonFungibleAddress::new(SYSTEM_TOKEN, NonFungibleId::U32(0))],
+            |kernel| {
+                kernel
+                    .invoke(EpochManagerNextEpochInvocation {
+                        receiver: EPOCH_MANAGER,
+                    })
+                    .unwrap()
+            },
+        );
+    }
+
+    pub fn get_validator_set(&mut self) -> ValidatorSet {
+        self.kernel_call(vec![], |kernel| {
+            kernel
+                .invoke(EpochManagerGetValidatorSetInvocation {
+                    receiver: EPOCH_MANAGER,
+                })
+                .unwrap()
+        })
+    }
+
+    pub fn get_validator(&mut self, key: EcdsaSecp256k1PublicKey) -> Option<ValidatorInfo> {
+        self.kernel_call(vec![], |kernel| {
+            kernel
+                .invoke(EpochManagerGetValidatorInvocation {
+                    receiver: EPOCH_MANAGER,
+                    key,
+                })
+                .unwrap()
+        })
+    }
+
+    pub fn set_current_time(&mut self, current_time_ms: i64) {
+        self.kernel_call(
+            vec![NonFungibleAddress::new(SYSTEM_TOKEN, NonFungibleId::U32(0))],
+            |kernel| {
+                kernel
+                    .invoke(ClockSetCurrentTimeInvocation {
+                        receiver: CLOCK,
+                        current_time_ms,
+                    })
+                    .unwrap()
+            },
+        );
+    }
+
     /// Performs a kernel call through a kernel with `is_system = true`.
     fn kernel_call<F, O>(&mut self, initial_proofs: Vec<NonFungibleAddress>, fun: F) -> O
     where
@@ -959,7 +1031,12 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             tx_hash,
             auth_zone_params,
             &blobs,
-            DEFAULT_MAX_CALL_DEPTH,
+            &ExecutionConfig {
+                max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+                trace: self.trace,
+                max_sys_call_trace_depth: 1,
+                cost_breakdown: false,
+            },
             track,
             &mut self.scrypto_interpreter,
             Vec::new(),
@@ -1050,6 +1127,7 @@ pub fn generate_single_function_abi(
                 output: output_type,
                 export_name: format!("{}_{}", blueprint_name, function_name),
             }],
+            events: vec![],
         },
     );
     blueprint_abis
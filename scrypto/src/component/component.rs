@@ -38,7 +38,7 @@ pub trait ComponentState<C: LocalComponent>: ScryptoEncode + ScryptoDecode {
 pub trait GlobalComponent {
     fn package_address(&self) -> PackageAddress;
     fn blueprint_name(&self) -> String;
-    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self;
+    fn metadata<K: AsRef<str>, V: Into<MetadataValue>>(&mut self, name: K, value: V) -> &mut Self;
     fn add_access_check(&mut self, access_rules: AccessRules) -> &mut Self;
     fn set_royalty_config(&mut self, royalty_config: RoyaltyConfig) -> &mut Self;
     fn claim_royalty(&self) -> Bucket;
@@ -50,7 +50,7 @@ pub trait GlobalComponent {
 pub trait LocalComponent {
     fn package_address(&self) -> PackageAddress;
     fn blueprint_name(&self) -> String;
-    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self;
+    fn metadata<K: AsRef<str>, V: Into<MetadataValue>>(&mut self, name: K, value: V) -> &mut Self;
     fn add_access_check(&mut self, access_rules: AccessRules) -> &mut Self;
     fn set_royalty_config(&mut self, royalty_config: RoyaltyConfig) -> &mut Self;
     fn globalize(self) -> ComponentAddress;
@@ -133,12 +133,16 @@ impl Component {
         self
     }
 
-    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+    pub fn metadata<K: AsRef<str>, V: Into<MetadataValue>>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> &mut Self {
         ScryptoEnv
             .invoke(MetadataSetInvocation {
                 receiver: RENodeId::Component(self.0),
                 key: name.as_ref().to_owned(),
-                value: value.as_ref().to_owned(),
+                value: value.into(),
             })
             .unwrap();
         self
@@ -197,12 +201,16 @@ impl GlobalComponentRef {
         scrypto_decode(&raw).unwrap()
     }
 
-    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+    pub fn metadata<K: AsRef<str>, V: Into<MetadataValue>>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> &mut Self {
         ScryptoEnv
             .invoke(MetadataSetInvocation {
                 receiver: RENodeId::Global(GlobalAddress::Component(self.0)),
                 key: name.as_ref().to_owned(),
-                value: value.as_ref().to_owned(),
+                value: value.into(),
             })
             .unwrap();
         self
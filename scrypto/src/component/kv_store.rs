@@ -95,6 +95,92 @@ impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> KeyValu
             .unwrap();
         env.sys_drop_lock(lock_handle).unwrap();
     }
+
+    /// Returns whether there's a value associated with the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.read_entry(scrypto_encode(key).unwrap()).0.is_some()
+    }
+
+    /// Removes the value associated with the given key, and returns it.
+    ///
+    /// The value must not own any vault or key value store, as these would be orphaned;
+    /// the engine rejects such removals.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let mut env = ScryptoEnv;
+        let offset =
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(scrypto_encode(key).unwrap()));
+        let lock_handle = env
+            .sys_lock_substate(RENodeId::KeyValueStore(self.id), offset, true)
+            .unwrap();
+        let raw_bytes = env.sys_read(lock_handle).unwrap();
+        let value: KeyValueStoreEntrySubstate = scrypto_decode(&raw_bytes).unwrap();
+
+        if value.0.is_some() {
+            env.sys_write(
+                lock_handle,
+                scrypto_encode(&KeyValueStoreEntrySubstate(None)).unwrap(),
+            )
+            .unwrap();
+        }
+        env.sys_drop_lock(lock_handle).unwrap();
+
+        value.0.map(|raw| scrypto_decode(&raw).unwrap())
+    }
+
+    /// Returns the keys of up to `limit` entries, starting after the given key, along with the
+    /// key to continue from if there may be more entries.
+    ///
+    /// Entries are visited in the order of their encoded keys. The number of entries scanned by
+    /// a single call is capped by the engine, and each of them is paid for.
+    pub fn keys(&self, start_after: Option<&K>, limit: u32) -> (Vec<K>, Option<K>) {
+        let mut env = ScryptoEnv;
+        let page = env
+            .sys_list_kv_store_keys(
+                RENodeId::KeyValueStore(self.id),
+                start_after.map(|key| scrypto_encode(key).unwrap()),
+                limit,
+            )
+            .unwrap();
+
+        (
+            page.keys
+                .iter()
+                .map(|raw| scrypto_decode(raw).unwrap())
+                .collect(),
+            page.next_start_after
+                .map(|raw| scrypto_decode(&raw).unwrap()),
+        )
+    }
+
+    /// Returns up to `limit` entries, starting after the given key, along with the key to
+    /// continue from if there may be more entries.
+    ///
+    /// See `keys` for the order and limits of the scan.
+    pub fn entries(&self, start_after: Option<&K>, limit: u32) -> (Vec<(K, V)>, Option<K>) {
+        let (keys, next_start_after) = self.keys(start_after, limit);
+        let entries = keys
+            .into_iter()
+            .filter_map(|key| {
+                self.read_entry(scrypto_encode(&key).unwrap())
+                    .0
+                    .map(|raw| (key, scrypto_decode(&raw).unwrap()))
+            })
+            .collect();
+
+        (entries, next_start_after)
+    }
+
+    fn read_entry(&self, key: Vec<u8>) -> KeyValueStoreEntrySubstate {
+        let mut env = ScryptoEnv;
+        let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key));
+        let lock_handle = env
+            .sys_lock_substate(RENodeId::KeyValueStore(self.id), offset, false)
+            .unwrap();
+        let raw_bytes = env.sys_read(lock_handle).unwrap();
+        env.sys_drop_lock(lock_handle).unwrap();
+
+        scrypto_decode(&raw_bytes).unwrap()
+    }
 }
 
 //========
@@ -1,6 +1,6 @@
 use radix_engine_interface::api::api::{EngineApi, Invokable, LoggerApi};
 use radix_engine_interface::api::types::{
-    Level, LockHandle, RENodeId, ScryptoActor, ScryptoRENode, SubstateOffset,
+    KeyValueStoreKeysPage, Level, LockHandle, RENodeId, ScryptoActor, ScryptoRENode, SubstateOffset,
 };
 use radix_engine_interface::crypto::Hash;
 use radix_engine_interface::data::ScryptoDecode;
@@ -107,6 +107,20 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         Ok(rtn)
     }
 
+    fn sys_list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        start_after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<KeyValueStoreKeysPage, EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::ListKeyValueStoreKeys(
+            node_id,
+            start_after,
+            limit,
+        ));
+        Ok(rtn)
+    }
+
     fn sys_get_actor(&mut self) -> Result<ScryptoActor, EngineApiError> {
         let rtn = call_engine(RadixEngineInput::GetActor());
         Ok(rtn)
@@ -121,6 +135,11 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         let rtn = call_engine(RadixEngineInput::GetTransactionHash());
         Ok(rtn)
     }
+
+    fn sys_emit_event(&mut self, type_name: String, event: Vec<u8>) -> Result<(), EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::EmitEvent(type_name, event));
+        Ok(rtn)
+    }
 }
 
 impl LoggerApi<EngineApiError> for ScryptoEnv {
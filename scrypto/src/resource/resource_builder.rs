@@ -38,6 +38,7 @@ impl ResourceBuilder {
 pub struct FungibleResourceBuilder {
     divisibility: u8,
     metadata: HashMap<String, String>,
+    max_supply: Option<Decimal>,
 }
 
 impl FungibleResourceBuilder {
@@ -45,6 +46,7 @@ impl FungibleResourceBuilder {
         Self {
             divisibility: DIVISIBILITY_MAXIMUM,
             metadata: HashMap::new(),
+            max_supply: None,
         }
     }
 
@@ -66,6 +68,14 @@ impl FungibleResourceBuilder {
         self
     }
 
+    /// Caps the total supply of the resource.
+    ///
+    /// The cap can't be changed once the resource is created, and minting beyond it fails.
+    pub fn max_supply<T: Into<Decimal>>(mut self, max_supply: T) -> Self {
+        self.max_supply = Some(max_supply.into());
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(
         self,
         method_auth: AccessRule,
@@ -76,6 +86,7 @@ impl FungibleResourceBuilder {
         FungibleResourceWithAuthBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
+            max_supply: self.max_supply,
             authorization,
         }
     }
@@ -90,6 +101,7 @@ impl FungibleResourceBuilder {
         FungibleResourceWithAuthBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
+            max_supply: self.max_supply,
             authorization,
         }
     }
@@ -104,6 +116,22 @@ impl FungibleResourceBuilder {
         FungibleResourceWithAuthBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            authorization,
+        }
+    }
+
+    pub fn freezable<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> FungibleResourceWithAuthBuilder {
+        let mut authorization = HashMap::new();
+        authorization.insert(Freeze, (method_auth, mutability.into()));
+        FungibleResourceWithAuthBuilder {
+            divisibility: self.divisibility,
+            metadata: self.metadata,
+            max_supply: self.max_supply,
             authorization,
         }
     }
@@ -118,6 +146,7 @@ impl FungibleResourceBuilder {
         FungibleResourceWithAuthBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
+            max_supply: self.max_supply,
             authorization,
         }
     }
@@ -132,6 +161,7 @@ impl FungibleResourceBuilder {
         FungibleResourceWithAuthBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
+            max_supply: self.max_supply,
             authorization,
         }
     }
@@ -146,6 +176,7 @@ impl FungibleResourceBuilder {
         FungibleResourceWithAuthBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
+            max_supply: self.max_supply,
             authorization,
         }
     }
@@ -170,6 +201,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 access_rules: authorization,
                 mint_params: Some(MintParams::fungible(amount)),
+                max_supply: self.max_supply,
+                non_fungible_data_schema: None,
             })
             .unwrap();
 
@@ -185,6 +218,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 access_rules: HashMap::new(),
                 mint_params: None,
+                max_supply: self.max_supply,
+                non_fungible_data_schema: None,
             })
             .unwrap();
 
@@ -204,6 +239,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: Some(MintParams::fungible(amount)),
+                max_supply: self.max_supply,
+                non_fungible_data_schema: None,
             })
             .unwrap();
 
@@ -219,6 +256,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: None,
+                max_supply: self.max_supply,
+                non_fungible_data_schema: None,
             })
             .unwrap();
 
@@ -236,6 +275,7 @@ impl FungibleResourceBuilder {
 pub struct FungibleResourceWithAuthBuilder {
     divisibility: u8,
     metadata: HashMap<String, String>,
+    max_supply: Option<Decimal>,
     authorization: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
 }
 
@@ -249,6 +289,14 @@ impl FungibleResourceWithAuthBuilder {
         self
     }
 
+    /// Caps the total supply of the resource.
+    ///
+    /// The cap can't be changed once the resource is created, and minting beyond it fails.
+    pub fn max_supply<T: Into<Decimal>>(mut self, max_supply: T) -> Self {
+        self.max_supply = Some(max_supply.into());
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(mut self, method_auth: AccessRule, mutability: R) -> Self {
         self.authorization
             .insert(Mint, (method_auth, mutability.into()));
@@ -271,6 +319,16 @@ impl FungibleResourceWithAuthBuilder {
         self
     }
 
+    pub fn freezable<R: Into<AccessRule>>(
+        mut self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self {
+        self.authorization
+            .insert(Freeze, (method_auth, mutability.into()));
+        self
+    }
+
     pub fn restrict_withdraw<R: Into<AccessRule>>(
         mut self,
         method_auth: AccessRule,
@@ -324,6 +382,8 @@ impl FungibleResourceWithAuthBuilder {
                 metadata: self.metadata,
                 access_rules: self.authorization,
                 mint_params,
+                max_supply: self.max_supply,
+                non_fungible_data_schema: None,
             })
             .unwrap()
     }
@@ -340,6 +400,8 @@ impl FungibleResourceWithAuthBuilder {
 pub struct NonFungibleResourceBuilder {
     metadata: HashMap<String, String>,
     id_type: NonFungibleIdType,
+    max_supply: Option<Decimal>,
+    non_fungible_data_schema: Option<NonFungibleDataSchema>,
 }
 
 impl NonFungibleResourceBuilder {
@@ -347,6 +409,8 @@ impl NonFungibleResourceBuilder {
         Self {
             metadata: HashMap::new(),
             id_type,
+            max_supply: None,
+            non_fungible_data_schema: None,
         }
     }
 
@@ -359,6 +423,22 @@ impl NonFungibleResourceBuilder {
         self
     }
 
+    /// Caps the total supply of the resource.
+    ///
+    /// The cap can't be changed once the resource is created, and minting beyond it fails.
+    pub fn max_supply<T: Into<Decimal>>(mut self, max_supply: T) -> Self {
+        self.max_supply = Some(max_supply.into());
+        self
+    }
+
+    /// Validates the data of minted and updated non-fungibles against the schema of `V`.
+    ///
+    /// Only the fields declared `#[scrypto(mutable)]` can be updated individually.
+    pub fn data_schema<V: NonFungibleData>(mut self) -> Self {
+        self.non_fungible_data_schema = Some(V::data_schema());
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(
         self,
         method_auth: AccessRule,
@@ -369,6 +449,8 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -383,6 +465,8 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -397,6 +481,24 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
+            authorization,
+        }
+    }
+
+    pub fn freezable<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> NonFungibleResourceWithAuthBuilder {
+        let mut authorization = HashMap::new();
+        authorization.insert(Freeze, (method_auth, mutability.into()));
+        NonFungibleResourceWithAuthBuilder {
+            id_type: self.id_type,
+            metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -411,6 +513,8 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -425,6 +529,8 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -439,6 +545,8 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -453,6 +561,8 @@ impl NonFungibleResourceBuilder {
         NonFungibleResourceWithAuthBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
+            max_supply: self.max_supply,
+            non_fungible_data_schema: self.non_fungible_data_schema,
             authorization,
         }
     }
@@ -499,6 +609,8 @@ impl NonFungibleResourceBuilder {
                 metadata: self.metadata,
                 access_rules: authorization,
                 mint_params,
+                max_supply: self.max_supply,
+                non_fungible_data_schema: self.non_fungible_data_schema,
             })
             .unwrap()
     }
@@ -525,6 +637,8 @@ impl NonFungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: Some(MintParams::NonFungible { entries: encoded }),
+                max_supply: self.max_supply,
+                non_fungible_data_schema: self.non_fungible_data_schema,
             })
             .unwrap();
 
@@ -540,6 +654,8 @@ impl NonFungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: None,
+                max_supply: self.max_supply,
+                non_fungible_data_schema: self.non_fungible_data_schema,
             })
             .unwrap();
 
@@ -557,6 +673,8 @@ impl NonFungibleResourceBuilder {
 pub struct NonFungibleResourceWithAuthBuilder {
     id_type: NonFungibleIdType,
     metadata: HashMap<String, String>,
+    max_supply: Option<Decimal>,
+    non_fungible_data_schema: Option<NonFungibleDataSchema>,
     authorization: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
 }
 
@@ -570,6 +688,22 @@ impl NonFungibleResourceWithAuthBuilder {
         self
     }
 
+    /// Caps the total supply of the resource.
+    ///
+    /// The cap can't be changed once the resource is created, and minting beyond it fails.
+    pub fn max_supply<T: Into<Decimal>>(mut self, max_supply: T) -> Self {
+        self.max_supply = Some(max_supply.into());
+        self
+    }
+
+    /// Validates the data of minted and updated non-fungibles against the schema of `V`.
+    ///
+    /// Only the fields declared `#[scrypto(mutable)]` can be updated individually.
+    pub fn data_schema<V: NonFungibleData>(mut self) -> Self {
+        self.non_fungible_data_schema = Some(V::data_schema());
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(mut self, method_auth: AccessRule, mutability: R) -> Self {
         self.authorization
             .insert(Mint, (method_auth, mutability.into()));
@@ -592,6 +726,16 @@ impl NonFungibleResourceWithAuthBuilder {
         self
     }
 
+    pub fn freezable<R: Into<AccessRule>>(
+        mut self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self {
+        self.authorization
+            .insert(Freeze, (method_auth, mutability.into()));
+        self
+    }
+
     pub fn restrict_withdraw<R: Into<AccessRule>>(
         mut self,
         method_auth: AccessRule,
@@ -665,6 +809,8 @@ impl NonFungibleResourceWithAuthBuilder {
                 metadata: self.metadata,
                 access_rules: self.authorization,
                 mint_params,
+                max_supply: self.max_supply,
+                non_fungible_data_schema: self.non_fungible_data_schema,
             })
             .unwrap()
     }
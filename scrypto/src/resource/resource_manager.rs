@@ -2,8 +2,9 @@ use radix_engine_interface::api::api::Invokable;
 use radix_engine_interface::api::types::{
     GlobalAddress, MetadataMethod, NativeFn, NativeMethod, RENodeId, ResourceManagerMethod,
 };
+use radix_engine_interface::data::{scrypto_encode, ScryptoEncode};
 use radix_engine_interface::math::Decimal;
-use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Recall, Withdraw};
+use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Freeze, Recall, Withdraw};
 use radix_engine_interface::model::*;
 
 use sbor::rust::collections::HashMap;
@@ -19,17 +20,19 @@ use crate::scrypto;
 pub struct ResourceManager(pub(crate) ResourceAddress);
 
 impl ResourceManager {
-    pub fn set_metadata(&mut self, key: String, value: String) {
+    /// Sets a metadata entry. Values of well-known keys must be of the type of the key, e.g. a
+    /// [`MetadataValue::Url`] for `icon_url`.
+    pub fn set_metadata<V: Into<MetadataValue>>(&mut self, key: String, value: V) {
         let mut env = ScryptoEnv;
         env.invoke(MetadataSetInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
             key,
-            value,
+            value: value.into(),
         })
         .unwrap()
     }
 
-    pub fn get_metadata(&mut self, key: String) -> Option<String> {
+    pub fn get_metadata(&mut self, key: String) -> Option<MetadataValue> {
         let mut env = ScryptoEnv;
         env.invoke(MetadataGetInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -94,6 +97,16 @@ impl ResourceManager {
         .unwrap()
     }
 
+    pub fn set_freezable(&mut self, access_rule: AccessRule) {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerUpdateVaultAuthInvocation {
+            receiver: self.0,
+            method: Freeze,
+            access_rule,
+        })
+        .unwrap()
+    }
+
     pub fn set_updateable_metadata(&self, access_rule: AccessRule) {
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
@@ -109,15 +122,18 @@ impl ResourceManager {
 
     pub fn set_updateable_non_fungible_data(&self, access_rule: AccessRule) {
         let mut env = ScryptoEnv;
-        env.invoke(AccessRulesSetMethodAccessRuleInvocation {
-            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
-            index: 0,
-            key: AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
-                ResourceManagerMethod::UpdateNonFungibleData,
-            ))),
-            rule: access_rule,
-        })
-        .unwrap();
+        for method in [
+            ResourceManagerMethod::UpdateNonFungibleData,
+            ResourceManagerMethod::UpdateNonFungibleDataField,
+        ] {
+            env.invoke(AccessRulesSetMethodAccessRuleInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+                index: 0,
+                key: AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(method))),
+                rule: access_rule.clone(),
+            })
+            .unwrap();
+        }
     }
 
     pub fn lock_mintable(&mut self) {
@@ -161,15 +177,18 @@ impl ResourceManager {
 
     pub fn lock_updateable_non_fungible_data(&mut self) {
         let mut env = ScryptoEnv;
-        env.invoke(AccessRulesSetMethodMutabilityInvocation {
-            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
-            index: 0,
-            key: AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
-                ResourceManagerMethod::UpdateNonFungibleData,
-            ))),
-            mutability: AccessRule::DenyAll,
-        })
-        .unwrap()
+        for method in [
+            ResourceManagerMethod::UpdateNonFungibleData,
+            ResourceManagerMethod::UpdateNonFungibleDataField,
+        ] {
+            env.invoke(AccessRulesSetMethodMutabilityInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+                index: 0,
+                key: AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(method))),
+                mutability: AccessRule::DenyAll,
+            })
+            .unwrap()
+        }
     }
 
     pub fn lock_withdrawable(&mut self) {
@@ -202,6 +221,16 @@ impl ResourceManager {
         .unwrap()
     }
 
+    pub fn lock_freezable(&mut self) {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerSetVaultAuthMutabilityInvocation {
+            receiver: self.0,
+            method: Freeze,
+            mutability: AccessRule::DenyAll,
+        })
+        .unwrap()
+    }
+
     fn mint_internal(&mut self, mint_params: MintParams) -> Bucket {
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerMintInvocation {
@@ -221,6 +250,22 @@ impl ResourceManager {
         .unwrap()
     }
 
+    fn update_non_fungible_data_field_internal(
+        &mut self,
+        id: NonFungibleId,
+        field: String,
+        data: Vec<u8>,
+    ) {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerUpdateNonFungibleDataFieldInvocation {
+            id,
+            field,
+            data,
+            receiver: self.0,
+        })
+        .unwrap()
+    }
+
     fn get_non_fungible_data_internal(&self, id: NonFungibleId) -> [Vec<u8>; 2] {
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerGetNonFungibleInvocation {
@@ -241,6 +286,11 @@ impl ResourceManager {
                 receiver: self.0,
             }
         }
+        pub fn max_supply(&self) -> Option<Decimal> {
+            ResourceManagerGetMaxSupplyInvocation {
+                receiver: self.0,
+            }
+        }
         pub fn non_fungible_exists(&self, id: &NonFungibleId) -> bool {
             ResourceManagerNonFungibleExistsInvocation {
                 receiver: self.0,
@@ -292,4 +342,22 @@ impl ResourceManager {
     ) {
         self.update_non_fungible_data_internal(id.clone(), new_data.mutable_data().unwrap())
     }
+
+    /// Updates a single mutable field of a non-fungible unit.
+    ///
+    /// # Panics
+    /// Panics if the resource has no data schema, the field is not a mutable one or the value
+    /// doesn't match the type of the field.
+    pub fn update_non_fungible_data_field<V: ScryptoEncode>(
+        &mut self,
+        id: &NonFungibleId,
+        field: &str,
+        value: V,
+    ) {
+        self.update_non_fungible_data_field_internal(
+            id.clone(),
+            field.to_string(),
+            scrypto_encode(&value).unwrap(),
+        )
+    }
 }
@@ -16,8 +16,10 @@ pub trait ScryptoVault {
     fn take_internal(&mut self, amount: Decimal) -> Bucket;
     fn lock_fee_internal(&mut self, amount: Decimal) -> ();
     fn lock_contingent_fee_internal(&mut self, amount: Decimal) -> ();
+    fn lock_sponsored_fee_internal(&mut self, amount: Decimal, sponsorship: FeeSponsorship) -> ();
     fn put(&mut self, bucket: Bucket) -> ();
     fn take_non_fungibles(&mut self, non_fungible_ids: &BTreeSet<NonFungibleId>) -> Bucket;
+    fn burn_non_fungibles(&mut self, non_fungible_ids: &BTreeSet<NonFungibleId>) -> ();
     fn resource_address(&self) -> ResourceAddress;
     fn non_fungible_ids(&self) -> BTreeSet<NonFungibleId>;
     fn create_proof(&self) -> Proof;
@@ -25,6 +27,7 @@ pub trait ScryptoVault {
     fn create_proof_by_ids(&self, ids: &BTreeSet<NonFungibleId>) -> Proof;
     fn lock_fee<A: Into<Decimal>>(&mut self, amount: A);
     fn lock_contingent_fee<A: Into<Decimal>>(&mut self, amount: A);
+    fn lock_sponsored_fee<A: Into<Decimal>>(&mut self, amount: A, sponsorship: FeeSponsorship);
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Bucket;
     fn take_all(&mut self) -> Bucket;
     fn take_non_fungible(&mut self, non_fungible_id: &NonFungibleId) -> Bucket;
@@ -80,6 +83,14 @@ impl ScryptoVault for Vault {
             }
         }
 
+        fn lock_sponsored_fee_internal(&mut self, amount: Decimal, sponsorship: FeeSponsorship) -> () {
+            VaultLockSponsoredFeeInvocation {
+                receiver: self.0,
+                amount,
+                sponsorship,
+            }
+        }
+
 
         fn put(&mut self, bucket: Bucket) -> () {
             VaultPutInvocation {
@@ -95,6 +106,13 @@ impl ScryptoVault for Vault {
             }
         }
 
+        fn burn_non_fungibles(&mut self, non_fungible_ids: &BTreeSet<NonFungibleId>) -> () {
+            VaultBurnNonFungiblesInvocation {
+                receiver: self.0,
+                non_fungible_ids: non_fungible_ids.clone(),
+            }
+        }
+
         fn resource_address(&self) -> ResourceAddress {
             VaultGetResourceAddressInvocation {
                 receiver: self.0,
@@ -137,6 +155,16 @@ impl ScryptoVault for Vault {
         self.lock_contingent_fee_internal(amount.into())
     }
 
+    /// Locks the specified amount as transaction fee, on the terms of the sponsorship.
+    ///
+    /// The transaction is rejected if it calls a method the sponsorship does not allow, if
+    /// the sponsorship has expired or if the vault locks more than its maximum contribution.
+    /// Once other payers have locked enough to cover the costs, it fails instead, and they pay
+    /// for the failure while the sponsor is refunded in full.
+    fn lock_sponsored_fee<A: Into<Decimal>>(&mut self, amount: A, sponsorship: FeeSponsorship) {
+        self.lock_sponsored_fee_internal(amount.into(), sponsorship)
+    }
+
     /// Takes some amount of resource from this vault into a bucket.
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Bucket {
         let bucket = self.take_internal(amount.into());
@@ -4,12 +4,13 @@ use radix_engine_interface::api::types::{
 };
 use radix_engine_interface::constants::EPOCH_MANAGER;
 use radix_engine_interface::crypto::*;
-use radix_engine_interface::data::{scrypto_decode, ScryptoDecode};
+use radix_engine_interface::data::{scrypto_decode, scrypto_encode, ScryptoDecode, ScryptoEncode};
 use radix_engine_interface::model::*;
 use sbor::rust::borrow::ToOwned;
 use sbor::rust::fmt::Debug;
 use sbor::rust::string::*;
 use sbor::rust::vec::Vec;
+use scrypto::abi::Describe;
 use scrypto::engine::scrypto_env::ScryptoEnv;
 
 /// The transaction runtime.
@@ -91,4 +92,18 @@ impl Runtime {
         let mut env = ScryptoEnv;
         env.sys_get_transaction_hash().unwrap()
     }
+
+    /// Emits an event, which is recorded in the transaction receipt.
+    ///
+    /// The event type must be a struct or enum listed in the `#[events(..)]` attribute of the
+    /// emitting blueprint.
+    pub fn emit_event<T: ScryptoEncode + Describe>(event: T) {
+        let type_name = T::describe()
+            .type_name()
+            .expect("Events must be structs or enums")
+            .to_owned();
+        let mut env = ScryptoEnv;
+        env.sys_emit_event(type_name, scrypto_encode(&event).unwrap())
+            .unwrap()
+    }
 }
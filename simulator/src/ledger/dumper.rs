@@ -79,6 +79,10 @@ pub fn dump_component<T: ReadableSubstateStore + QueryableSubstateStore, O: std:
         .map(|s| s.to_runtime().global().node_deref())
         .ok_or(DisplayError::ComponentNotFound)?;
 
+    if let RENodeId::Account(..) = component_id {
+        return dump_account(component_address, component_id, substate_store, output);
+    }
+
     let component_info: Option<ComponentInfoSubstate> = substate_store
         .get_substate(&SubstateId(
             component_id,
@@ -158,6 +162,48 @@ pub fn dump_component<T: ReadableSubstateStore + QueryableSubstateStore, O: std:
     }
 }
 
+/// Dump a native account into console.
+fn dump_account<T: ReadableSubstateStore, O: std::io::Write>(
+    component_address: ComponentAddress,
+    account_id: RENodeId,
+    substate_store: &T,
+    output: &mut O,
+) -> Result<(), DisplayError> {
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
+
+    let account: AccountSubstate = substate_store
+        .get_substate(&SubstateId(
+            account_id,
+            SubstateOffset::Account(AccountOffset::Account),
+        ))
+        .map(|s| s.substate)
+        .map(|s| s.to_runtime().into())
+        .ok_or(DisplayError::ComponentNotFound)?;
+
+    writeln!(
+        output,
+        "{}: {}",
+        "Component".green().bold(),
+        component_address.display(&bech32_encoder),
+    );
+    writeln!(
+        output,
+        "{}: {{ package_address: {}, blueprint_name: \"{}\" }}",
+        "Blueprint".green().bold(),
+        ACCOUNT_PACKAGE.display(&bech32_encoder),
+        ACCOUNT_BLUEPRINT
+    );
+    writeln!(
+        output,
+        "{}: {:?}",
+        "Deposit Policy".green().bold(),
+        account.deposit_policy
+    );
+
+    let vaults_found: HashSet<VaultId> = account.vaults.values().cloned().collect();
+    dump_resources(&vaults_found, substate_store, output)
+}
+
 fn dump_kv_store<T: ReadableSubstateStore + QueryableSubstateStore, O: std::io::Write>(
     component_address: ComponentAddress,
     kv_store_id: &KeyValueStoreId,
@@ -213,8 +259,8 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
             .map(|s| s.substate)
             .map(|s| s.into())
             .unwrap();
-        let amount = vault.0.amount();
-        let resource_address = vault.0.resource_address();
+        let amount = vault.resource.amount();
+        let resource_address = vault.resource.resource_address();
         let global: Option<GlobalAddressSubstate> = substate_store
             .get_substate(&SubstateId(
                 RENodeId::Global(GlobalAddress::Resource(resource_address)),
@@ -245,26 +291,27 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
         let metadata = metadata.ok_or(DisplayError::ResourceManagerNotFound)?;
         writeln!(
             output,
-            "{} {{ amount: {}, resource address: {}{}{} }}",
+            "{} {{ amount: {}, resource address: {}{}{} }}{}",
             list_item_prefix(last),
             amount,
             resource_address.display(&bech32_encoder),
             metadata
                 .metadata
                 .get("name")
-                .map(|name| format!(", name: \"{}\"", name))
+                .map(|name| format!(", name: \"{}\"", name.display(&bech32_encoder)))
                 .unwrap_or(String::new()),
             metadata
                 .metadata
                 .get("symbol")
-                .map(|symbol| format!(", symbol: \"{}\"", symbol))
+                .map(|symbol| format!(", symbol: \"{}\"", symbol.display(&bech32_encoder)))
                 .unwrap_or(String::new()),
+            if vault.frozen { " (frozen)" } else { "" },
         );
         if matches!(
             resource_manager.resource_type,
             ResourceType::NonFungible { .. }
         ) {
-            let ids = vault.0.ids();
+            let ids = vault.resource.ids();
             for (inner_last, id) in ids.iter().identify_last() {
                 let non_fungible: NonFungibleSubstate = substate_store
                     .get_substate(&SubstateId(
@@ -298,6 +345,17 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
     Ok(())
 }
 
+/// Names the kind of non-string metadata values, which are rendered alike otherwise.
+fn metadata_value_kind(value: &MetadataValue) -> Option<&'static str> {
+    match value {
+        MetadataValue::String(..) => None,
+        MetadataValue::Url(..) => Some("Url"),
+        MetadataValue::Decimal(..) => Some("Decimal"),
+        MetadataValue::Address(..) => Some("Address"),
+        MetadataValue::List(..) => Some("List"),
+    }
+}
+
 /// Dump a resource into console.
 pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
     resource_address: ResourceAddress,
@@ -344,13 +402,17 @@ pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
         "Metadata".green().bold(),
         metadata.metadata.len()
     );
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
     for (last, e) in metadata.metadata.iter().identify_last() {
         writeln!(
             output,
-            "{} {}: {}",
+            "{} {}: {}{}",
             list_item_prefix(last),
             e.0.green().bold(),
-            e.1
+            e.1.display(&bech32_encoder),
+            metadata_value_kind(e.1)
+                .map(|kind| format!(" ({})", kind))
+                .unwrap_or_default()
         );
     }
     writeln!(
@@ -359,5 +421,8 @@ pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
         "Total Supply".green().bold(),
         resource_manager.total_supply
     );
+    if let Some(max_supply) = resource_manager.max_supply {
+        writeln!(output, "{}: {}", "Max Supply".green().bold(), max_supply);
+    }
     Ok(())
 }
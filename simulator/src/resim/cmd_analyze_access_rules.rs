@@ -0,0 +1,193 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::ledger::*;
+use radix_engine::model::*;
+use radix_engine::types::*;
+use radix_engine_interface::api::types::RENodeId;
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use utils::ContextualDisplay;
+
+use crate::ledger::*;
+use crate::resim::*;
+use crate::utils::*;
+
+/// Analyze which badges can reach the methods of a component or package, and report access
+/// rules which can't work as intended
+#[derive(Parser, Debug)]
+pub struct AnalyzeAccessRules {
+    /// The address of a component or package
+    address: String,
+}
+
+impl AnalyzeAccessRules {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        let bech32_decoder = Bech32Decoder::new(&NetworkDefinition::simulator());
+        let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
+
+        let analysis = if let Ok(component_address) =
+            bech32_decoder.validate_and_decode_component_address(&self.address)
+        {
+            let component_id = deref_global(&ledger, GlobalAddress::Component(component_address))
+                .ok_or(Error::LedgerDumpError(DisplayError::ComponentNotFound))?;
+            let abi = radix_engine::model::export_abi_by_component(&ledger, component_address)
+                .map_err(Error::AbiExportError)?;
+            let substate = access_rules_chain(&ledger, component_id)
+                .ok_or(Error::LedgerDumpError(DisplayError::ComponentNotFound))?;
+
+            if let RENodeId::Account(..) = component_id {
+                analyze_account_access_rules(&substate.access_rules_chain, &abi)
+            } else {
+                analyze_blueprint_access_rules(&substate.access_rules_chain, &abi)
+            }
+        } else if let Ok(package_address) =
+            bech32_decoder.validate_and_decode_package_address(&self.address)
+        {
+            let package_id = deref_global(&ledger, GlobalAddress::Package(package_address))
+                .ok_or(Error::LedgerDumpError(DisplayError::PackageNotFound))?;
+            let substate = access_rules_chain(&ledger, package_id)
+                .ok_or(Error::LedgerDumpError(DisplayError::PackageNotFound))?;
+            let package_info: PackageInfoSubstate = ledger
+                .get_substate(&SubstateId(
+                    package_id,
+                    SubstateOffset::Package(PackageOffset::Info),
+                ))
+                .map(|s| s.substate.to_runtime().into())
+                .ok_or(Error::LedgerDumpError(DisplayError::PackageNotFound))?;
+
+            analyze_package_access_rules(&substate.access_rules_chain, &package_info.blueprint_abis)
+        } else {
+            return Err(Error::InvalidId(self.address.clone()));
+        };
+
+        writeln!(out, "{}", "Methods".green().bold()).map_err(Error::IOError)?;
+        for (last, method) in analysis.methods.iter().identify_last() {
+            let access = if method.is_unreachable() {
+                "unreachable".to_string()
+            } else if method.is_public() {
+                "public".to_string()
+            } else {
+                let badges = method.badges();
+                if badges.is_empty() {
+                    "protected".to_string()
+                } else {
+                    badges
+                        .iter()
+                        .map(|badge| format_badge(badge, &bech32_encoder))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                }
+            };
+            writeln!(
+                out,
+                "{} {}: {}{}",
+                list_item_prefix(last),
+                format_method_key(&method.key),
+                access,
+                if method.falls_back_to_default() {
+                    " (default rule)"
+                } else {
+                    ""
+                }
+            )
+            .map_err(Error::IOError)?;
+        }
+
+        writeln!(
+            out,
+            "{}: {}",
+            "Issues".green().bold(),
+            analysis.issues.len()
+        )
+        .map_err(Error::IOError)?;
+        for (last, issue) in analysis.issues.iter().identify_last() {
+            let description = match issue {
+                AccessRulesIssue::MissingGroup { layer, key, group } => format!(
+                    "{} (layer {}): group \"{}\" has no rule, the default rule applies",
+                    format_method_key(key),
+                    layer,
+                    group
+                ),
+                AccessRulesIssue::UnknownMethod { layer, key } => format!(
+                    "{} (layer {}): rule for a method the blueprint does not have",
+                    format_key(key),
+                    layer
+                ),
+                AccessRulesIssue::InvalidSchemaPath { layer, key, path } => format!(
+                    "{} (layer {}): \"{}\" is not a field of the component state",
+                    format_method_key(key),
+                    layer,
+                    path
+                ),
+                AccessRulesIssue::SchemaPathTypeMismatch {
+                    layer,
+                    key,
+                    path,
+                    expected,
+                    actual,
+                } => format!(
+                    "{} (layer {}): \"{}\" is a {:?} but a {:?} is expected",
+                    format_method_key(key),
+                    layer,
+                    path,
+                    actual,
+                    expected
+                ),
+            };
+            writeln!(out, "{} {}", list_item_prefix(last), description.red())
+                .map_err(Error::IOError)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn deref_global<T: ReadableSubstateStore>(
+    substate_store: &T,
+    global_address: GlobalAddress,
+) -> Option<RENodeId> {
+    substate_store
+        .get_substate(&SubstateId(
+            RENodeId::Global(global_address),
+            SubstateOffset::Global(GlobalOffset::Global),
+        ))
+        .map(|s| s.substate.to_runtime().global().node_deref())
+}
+
+fn access_rules_chain<T: ReadableSubstateStore>(
+    substate_store: &T,
+    node_id: RENodeId,
+) -> Option<AccessRulesChainSubstate> {
+    substate_store
+        .get_substate(&SubstateId(
+            node_id,
+            SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+        ))
+        .map(|s| s.substate.to_runtime().into())
+}
+
+fn format_key(key: &AccessRuleKey) -> String {
+    match key {
+        AccessRuleKey::ScryptoMethod(ident) => ident.clone(),
+        AccessRuleKey::Native(native_fn) => format!("{:?}", native_fn),
+    }
+}
+
+fn format_method_key(key: &MethodKey) -> String {
+    match &key.blueprint_name {
+        Some(blueprint_name) => format!("{}::{}", blueprint_name, format_key(&key.rule_key)),
+        None => format_key(&key.rule_key),
+    }
+}
+
+fn format_badge(badge: &SoftResourceOrNonFungible, bech32_encoder: &Bech32Encoder) -> String {
+    match badge {
+        SoftResourceOrNonFungible::StaticNonFungible(non_fungible_address) => {
+            non_fungible_address.display(bech32_encoder).to_string()
+        }
+        SoftResourceOrNonFungible::StaticResource(resource_address) => {
+            resource_address.display(bech32_encoder).to_string()
+        }
+        SoftResourceOrNonFungible::Dynamic(path) => format!("<state: {}>", path),
+    }
+}
@@ -0,0 +1,40 @@
+use clap::Parser;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_stores::ledger_export::export_ledger;
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use crate::resim::*;
+
+/// Export the ledger state to a file
+#[derive(Parser, Debug)]
+pub struct ExportLedger {
+    /// The path of the file to write to
+    path: PathBuf,
+}
+
+impl ExportLedger {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+        let file =
+            File::create(&self.path).map_err(|err| Error::IOErrorAtPath(err, self.path.clone()))?;
+        let header = export_ledger(
+            &ledger,
+            &NetworkDefinition::simulator(),
+            &mut BufWriter::new(file),
+        )
+        .map_err(Error::LedgerExportError)?;
+
+        writeln!(
+            out,
+            "Exported {} substates to {}.",
+            header.substate_count,
+            self.path.display()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use clap::Parser;
+use radix_engine::ledger::HistoricalSubstateStore;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_stores::ledger_export::{import_ledger, verify_ledger_export};
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use std::fs::File;
+use std::io::{BufReader, Seek, SeekFrom};
+use std::path::PathBuf;
+
+use crate::resim::*;
+
+/// Replace the ledger state with one exported by `export-ledger`, clearing the current ledger once the export is verified
+#[derive(Parser, Debug)]
+pub struct ImportLedger {
+    /// The path of the file to read from
+    path: PathBuf,
+}
+
+impl ImportLedger {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let file =
+            File::open(&self.path).map_err(|err| Error::IOErrorAtPath(err, self.path.clone()))?;
+        let mut reader = BufReader::new(file);
+
+        // The export is verified before the current ledger is cleared
+        let network = NetworkDefinition::simulator();
+        verify_ledger_export(&mut reader, &network).map_err(Error::LedgerExportError)?;
+        reader.seek(SeekFrom::Start(0)).map_err(Error::IOError)?;
+
+        // The imported ledger is kept in the mode of the ledger it replaces
+        let keep_history = RadixEngineDB::new(get_data_dir()?).keeps_history();
+        clear_ledger()?;
+        let mut ledger = if keep_history {
+            RadixEngineDB::with_history(get_data_dir()?)
+        } else {
+            RadixEngineDB::new(get_data_dir()?)
+        };
+        let header =
+            import_ledger(reader, &network, &mut ledger).map_err(Error::LedgerExportError)?;
+
+        writeln!(
+            out,
+            "Imported {} substates and {} intent hashes from {}.",
+            header.substate_count,
+            header.intent_hash_count,
+            self.path.display()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
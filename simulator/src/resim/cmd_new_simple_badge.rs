@@ -102,6 +102,8 @@ impl NewSimpleBadge {
                             ),
                         )]),
                     }),
+                    max_supply: None,
+                    non_fungible_data_schema: None,
                 })
                 .unwrap(),
             })
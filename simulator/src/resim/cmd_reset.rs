@@ -1,17 +1,114 @@
 use clap::Parser;
+use radix_engine::ledger::{
+    bootstrap_with_genesis, GenesisBuilder, IterableSubstateStore, SubstateRange,
+    TypedInMemorySubstateStore, WriteableSubstateStore,
+};
+use radix_engine::types::*;
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
 use std::fs::remove_dir_all;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use crate::resim::*;
 
 /// Reset this simulator
 #[derive(Parser, Debug)]
-pub struct Reset {}
+pub struct Reset {
+    /// The path to a genesis config file (JSON) to bootstrap the ledger with
+    #[clap(long)]
+    genesis: Option<PathBuf>,
+}
+
+/// The genesis config file, where amounts are decimal strings and accounts are addresses.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct GenesisConfig {
+    pub xrd_metadata: HashMap<String, String>,
+    pub xrd_allocations: Vec<GenesisXrdAllocation>,
+    /// Paths to `.wasm` files, with their `.abi` files alongside, relative to the config file
+    pub packages: Vec<PathBuf>,
+    pub initial_epoch: Option<u64>,
+    pub initial_time_ms: Option<i64>,
+    pub validators: Vec<GenesisValidator>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GenesisXrdAllocation {
+    pub account: String,
+    pub amount: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GenesisValidator {
+    pub public_key: EcdsaSecp256k1PublicKey,
+    pub stake: String,
+}
+
+impl GenesisConfig {
+    pub fn to_genesis_builder(&self, base_dir: &Path) -> Result<GenesisBuilder, Error> {
+        let mut genesis = GenesisBuilder::new();
+        for (name, value) in &self.xrd_metadata {
+            genesis.xrd_metadata(name, value);
+        }
+        for allocation in &self.xrd_allocations {
+            let account = SimulatorComponentAddress::from_str(&allocation.account)
+                .map_err(Error::AddressError)?;
+            let amount = Decimal::from_str(&allocation.amount).map_err(Error::ParseDecimalError)?;
+            genesis.allocate_xrd(account.0, amount);
+        }
+        for path in &self.packages {
+            let code_path = base_dir.join(path);
+            let abi_path = code_path.with_extension("abi");
+            let code = fs::read(&code_path).map_err(|err| Error::IOErrorAtPath(err, code_path))?;
+            let abi = fs::read(&abi_path).map_err(|err| Error::IOErrorAtPath(err, abi_path))?;
+            genesis.package(code, abi);
+        }
+        if let Some(epoch) = self.initial_epoch {
+            genesis.initial_epoch(epoch);
+        }
+        if let Some(time_ms) = self.initial_time_ms {
+            genesis.initial_time_ms(time_ms);
+        }
+        for validator in &self.validators {
+            let stake = Decimal::from_str(&validator.stake).map_err(Error::ParseDecimalError)?;
+            genesis.validator(validator.public_key, stake);
+        }
+        Ok(genesis)
+    }
+}
 
 impl Reset {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        // The genesis is run against an in-memory store before the current ledger is cleared,
+        // so that an invalid config, including invalid package code, leaves it untouched
+        let genesis_substates = match &self.genesis {
+            Some(path) => {
+                let config: GenesisConfig = serde_json::from_slice(
+                    &fs::read(path).map_err(|err| Error::IOErrorAtPath(err, path.clone()))?,
+                )
+                .map_err(Error::JSONError)?;
+                let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+                let genesis = config.to_genesis_builder(base_dir)?;
+                let mut genesis_store = TypedInMemorySubstateStore::new();
+                bootstrap_with_genesis(&mut genesis_store, &genesis)
+                    .map_err(Error::GenesisError)?;
+                Some(genesis_store.list_all_substates(&SubstateRange::All))
+            }
+            None => None,
+        };
+
         let dir = get_data_dir()?;
         remove_dir_all(dir).map_err(Error::IOError)?;
         writeln!(out, "Data directory cleared.").map_err(Error::IOError)?;
+
+        if let Some(substates) = genesis_substates {
+            let mut substate_store = RadixEngineDB::new(get_data_dir()?);
+            substate_store.commit_batch(substates);
+            writeln!(out, "Ledger bootstrapped from genesis config.").map_err(Error::IOError)?;
+        }
         Ok(())
     }
 }
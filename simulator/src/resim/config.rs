@@ -31,6 +31,23 @@ pub fn get_data_dir() -> Result<PathBuf, Error> {
     Ok(path)
 }
 
+/// Removes the ledger from the data directory, keeping the simulator configs.
+pub fn clear_ledger() -> Result<(), Error> {
+    let configs_path = get_configs_path()?;
+    for entry in fs::read_dir(get_data_dir()?).map_err(Error::IOError)? {
+        let path = entry.map_err(Error::IOError)?.path();
+        if path == configs_path {
+            continue;
+        }
+        if path.is_dir() {
+            fs::remove_dir_all(path).map_err(Error::IOError)?;
+        } else {
+            fs::remove_file(path).map_err(Error::IOError)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn get_configs_path() -> Result<PathBuf, Error> {
     let mut path = get_data_dir()?;
     path.push("config");
@@ -2,10 +2,13 @@ use std::io;
 use std::path::PathBuf;
 
 use radix_engine::engine::*;
+use radix_engine::ledger::GenesisError;
 use radix_engine::model::{ExportError, ExtractAbiError};
 use radix_engine::types::{AddressError, ParseNonFungibleAddressError};
 use radix_engine::wasm::PrepareError;
 use radix_engine_interface::core::ParseNetworkError;
+use radix_engine_interface::math::ParseDecimalError;
+use radix_engine_stores::ledger_export::LedgerExportError;
 use sbor::*;
 use transaction::errors::*;
 
@@ -49,6 +52,10 @@ pub enum Error {
 
     LedgerDumpError(DisplayError),
 
+    LedgerExportError(LedgerExportError),
+
+    GenesisError(GenesisError),
+
     CompileError(transaction::manifest::CompileError),
 
     DecompileError(transaction::manifest::DecompileError),
@@ -65,5 +72,7 @@ pub enum Error {
 
     ParseNetworkError(ParseNetworkError),
 
+    ParseDecimalError(ParseDecimalError),
+
     OwnerBadgeNotSpecified,
 }
@@ -1,8 +1,11 @@
 mod addressing;
+mod cmd_analyze_access_rules;
 mod cmd_call_function;
 mod cmd_call_method;
 mod cmd_export_abi;
+mod cmd_export_ledger;
 mod cmd_generate_key_pair;
+mod cmd_import_ledger;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
@@ -23,10 +26,13 @@ mod config;
 mod error;
 
 pub use addressing::*;
+pub use cmd_analyze_access_rules::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
 pub use cmd_export_abi::*;
+pub use cmd_export_ledger::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_import_ledger::*;
 pub use cmd_mint::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
@@ -53,7 +59,7 @@ pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
 use clap::{Parser, Subcommand};
 use radix_engine::engine::ScryptoInterpreter;
 use radix_engine::model::*;
-use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::execute_and_commit_transaction_with_intent_hashes;
 use radix_engine::transaction::TransactionOutcome;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::transaction::TransactionResult;
@@ -70,11 +76,13 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 use transaction::builder::ManifestBuilder;
+use transaction::errors::TransactionValidationError;
 use transaction::manifest::decompile;
 use transaction::model::AuthModule;
 use transaction::model::TestTransaction;
 use transaction::model::TransactionManifest;
 use transaction::signing::EcdsaSecp256k1PrivateKey;
+use transaction::validation::IntentHashManager;
 use utils::ContextualDisplay;
 
 /// Build fast, reward everyone, and scale without friction
@@ -93,10 +101,13 @@ impl ResimCli {
 
 #[derive(Subcommand, Debug)]
 pub enum Command {
+    AnalyzeAccessRules(AnalyzeAccessRules),
     CallFunction(CallFunction),
     CallMethod(CallMethod),
     ExportAbi(ExportAbi),
+    ExportLedger(ExportLedger),
     GenerateKeyPair(GenerateKeyPair),
+    ImportLedger(ImportLedger),
     Mint(crate::resim::cmd_mint::Mint),
     NewAccount(NewAccount),
     NewSimpleBadge(NewSimpleBadge),
@@ -121,10 +132,13 @@ pub fn run() -> Result<(), Error> {
     let mut out = std::io::stdout();
 
     match cli.command {
+        Command::AnalyzeAccessRules(cmd) => cmd.run(&mut out),
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
         Command::ExportAbi(cmd) => cmd.run(&mut out),
+        Command::ExportLedger(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
+        Command::ImportLedger(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
         Command::NewSimpleBadge(cmd) => cmd.run(&mut out),
@@ -179,7 +193,7 @@ pub fn handle_manifest<O: std::io::Write>(
         None => {
             let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?);
 
-            let mut scrypto_interpreter = ScryptoInterpreter {
+            let scrypto_interpreter = ScryptoInterpreter {
                 wasm_engine: DefaultWasmEngine::default(),
                 wasm_instrumenter: WasmInstrumenter::default(),
                 wasm_metering_config: WasmMeteringConfig::new(
@@ -198,15 +212,22 @@ pub fn handle_manifest<O: std::io::Write>(
             }
             let nonce = get_nonce()?;
             let transaction = TestTransaction::new(manifest, nonce, DEFAULT_COST_UNIT_LIMIT);
+            let intent_hash = transaction.intent_hash();
+            if !substate_store.allows(&intent_hash) {
+                return Err(Error::TransactionValidationError(
+                    TransactionValidationError::IntentHashRejected,
+                ));
+            }
 
-            let receipt = execute_and_commit_transaction(
+            let receipt = execute_and_commit_transaction_with_intent_hashes(
                 &mut substate_store,
-                &mut scrypto_interpreter,
+                &scrypto_interpreter,
                 &FeeReserveConfig::default(),
                 &ExecutionConfig {
                     max_call_depth: DEFAULT_MAX_CALL_DEPTH,
                     trace,
                     max_sys_call_trace_depth: 1,
+                    cost_breakdown: false,
                 },
                 &transaction.get_executable(initial_proofs),
             );
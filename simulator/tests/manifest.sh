@@ -13,7 +13,7 @@ export account=`$resim new-account | awk '/Account component address:/ {print $N
 export owner_badge=`$resim new-simple-badge --name 'OwnerBadge' | awk '/NFAddress:/ {print $NF}'`
 export package=`$resim publish ../examples/hello-world --owner-badge $owner_badge | awk '/Package:/ {print $NF}'`
 
-export xrd=resource_sim1qzkcyv5dwq3r6kawy6pxpvcythx8rh8ntum6ws62p95sqjjpwr
+export xrd=resource_sim1qz0ef2v6ud8ykdzgqdeg86pf4jcyh9zx5jc3572yl63q0cd72r
 
 output=`$resim run ./tests/m1.rtm | awk '/Component:|Resource:/ {print $NF}'`
 export component=`echo $output | cut -d " " -f1`
@@ -48,6 +48,10 @@ $resim show $account
 $resim show $account2
 $resim show $token_address
 
+# Test - analyze access rules
+$resim analyze-access-rules $component
+$resim analyze-access-rules $package
+
 # Test - output manifest
 mkdir -p target
 $resim new-badge-fixed 1 --name 'MintBadge' --manifest ./target/temp.rtm
@@ -98,4 +102,9 @@ non_fungible_id=`echo "$non_fungible_create_receipt" | awk '/NFID:/ {print $NF}'
 token_address=`$resim new-token-mutable $non_fungible | awk '/Resource:/ {print $NF}'`
 
 # Test - mint and transfer (Mintable that requires a `NonFungibleAddress`)
-$resim mint 777 $token_address --proofs "$non_fungible_id,$non_fungible_resource"
\ No newline at end of file
+$resim mint 777 $token_address --proofs "$non_fungible_id,$non_fungible_resource"
+
+# Test - export and re-import the ledger
+$resim export-ledger ./target/ledger.export
+$resim import-ledger ./target/ledger.export
+$resim show $account
@@ -23,8 +23,7 @@ test_crates_features \
 
 echo "Testing scrypto packages..."
 test_packages \
-    "assets/blueprints/account \
-    assets/blueprints/faucet \
+    "assets/blueprints/faucet \
     examples/hello-world \
     examples/no-std"
 
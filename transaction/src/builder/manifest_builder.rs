@@ -124,6 +124,7 @@ impl ManifestBuilder {
                 self.id_validator.move_resources(&scrypt_value).unwrap();
             }
             Instruction::PublishPackageWithOwner { .. } => {}
+            Instruction::YieldToSubintent { .. } => {}
         }
 
         self.instructions.push(inst);
@@ -318,6 +319,7 @@ impl ManifestBuilder {
         metadata: HashMap<String, String>,
         access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, R)>,
         mint_params: Option<MintParams>,
+        max_supply: Option<Decimal>,
     ) -> &mut Self {
         let input = ResourceManagerCreateInvocation {
             resource_type,
@@ -327,6 +329,8 @@ impl ManifestBuilder {
                 .map(|(k, v)| (k, (v.0, v.1.into())))
                 .collect(),
             mint_params,
+            max_supply,
+            non_fungible_data_schema: None,
         };
 
         self.add_instruction(Instruction::CallNativeFunction {
@@ -547,6 +551,12 @@ impl ManifestBuilder {
         .0
     }
 
+    /// Runs the manifest of the sub-intent at the given index.
+    pub fn yield_to_subintent(&mut self, subintent_index: u32) -> &mut Self {
+        self.add_instruction(Instruction::YieldToSubintent { subintent_index })
+            .0
+    }
+
     /// Builds a transaction manifest.
     /// TODO: consider using self
     pub fn build(&self) -> TransactionManifest {
@@ -581,6 +591,8 @@ impl ManifestBuilder {
                 metadata,
                 access_rules: resource_auth,
                 mint_params,
+                max_supply: None,
+                non_fungible_data_schema: None,
             })
             .unwrap(),
         })
@@ -611,6 +623,8 @@ impl ManifestBuilder {
                 mint_params: Option::Some(MintParams::Fungible {
                     amount: initial_supply.into(),
                 }),
+                max_supply: None,
+                non_fungible_data_schema: None,
             })
             .unwrap(),
         })
@@ -643,6 +657,8 @@ impl ManifestBuilder {
                 metadata,
                 access_rules: resource_auth,
                 mint_params,
+                max_supply: None,
+                non_fungible_data_schema: None,
             })
             .unwrap(),
         })
@@ -673,6 +689,8 @@ impl ManifestBuilder {
                 mint_params: Option::Some(MintParams::Fungible {
                     amount: initial_supply.into(),
                 }),
+                max_supply: None,
+                non_fungible_data_schema: None,
             })
             .unwrap(),
         })
@@ -832,6 +850,22 @@ impl ManifestBuilder {
         .0
     }
 
+    pub fn lock_sponsored_fee(
+        &mut self,
+        account: ComponentAddress,
+        amount: Decimal,
+        sponsorship: FeeSponsorship,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "lock_sponsored_fee".to_string(),
+            },
+            args: args!(amount, sponsorship),
+        })
+        .0
+    }
+
     pub fn lock_contingent_fee(&mut self, account: ComponentAddress, amount: Decimal) -> &mut Self {
         self.add_instruction(Instruction::CallMethod {
             method_ident: ScryptoMethodIdent {
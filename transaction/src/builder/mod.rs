@@ -1,5 +1,7 @@
 mod manifest_builder;
+mod subintent_builder;
 mod transaction_builder;
 
 pub use manifest_builder::ManifestBuilder;
+pub use subintent_builder::SubintentBuilder;
 pub use transaction_builder::TransactionBuilder;
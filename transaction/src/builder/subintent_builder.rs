@@ -0,0 +1,63 @@
+use radix_engine_interface::crypto::SignatureWithPublicKey;
+use radix_engine_interface::data::scrypto_encode;
+
+use crate::{model::*, signing::Signer};
+
+pub struct SubintentBuilder {
+    manifest: Option<TransactionManifest>,
+    header: Option<SubintentHeader>,
+    subintent_signatures: Vec<SignatureWithPublicKey>,
+}
+
+impl Default for SubintentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SubintentBuilder {
+    pub fn new() -> Self {
+        Self {
+            manifest: None,
+            header: None,
+            subintent_signatures: vec![],
+        }
+    }
+
+    pub fn manifest(mut self, manifest: TransactionManifest) -> Self {
+        self.manifest = Some(manifest);
+        self
+    }
+
+    pub fn header(mut self, header: SubintentHeader) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
+        let subintent = self.subintent();
+        let subintent_payload = scrypto_encode(&subintent).unwrap();
+        self.subintent_signatures
+            .push(signer.sign(&subintent_payload));
+        self
+    }
+
+    pub fn signer_signatures(mut self, sigs: Vec<SignatureWithPublicKey>) -> Self {
+        self.subintent_signatures.extend(sigs);
+        self
+    }
+
+    pub fn build(&self) -> SignedSubintent {
+        SignedSubintent {
+            subintent: self.subintent(),
+            subintent_signatures: self.subintent_signatures.clone(),
+        }
+    }
+
+    fn subintent(&self) -> Subintent {
+        Subintent {
+            manifest: self.manifest.clone().expect("Manifest not specified"),
+            header: self.header.clone().expect("Header not specified"),
+        }
+    }
+}
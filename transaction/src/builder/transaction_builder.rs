@@ -6,6 +6,7 @@ use crate::{model::*, signing::Signer};
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifest>,
     header: Option<TransactionHeader>,
+    subintents: Vec<SignedSubintent>,
     intent_signatures: Vec<SignatureWithPublicKey>,
     notary_signature: Option<Signature>,
 }
@@ -15,6 +16,7 @@ impl TransactionBuilder {
         Self {
             manifest: None,
             header: None,
+            subintents: vec![],
             intent_signatures: vec![],
             notary_signature: None,
         }
@@ -30,6 +32,12 @@ impl TransactionBuilder {
         self
     }
 
+    /// Adds a sub-intent, which the manifest yields to by its index in the order of addition.
+    pub fn subintent(mut self, subintent: SignedSubintent) -> Self {
+        self.subintents.push(subintent);
+        self
+    }
+
     pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
         let intent = self.transaction_intent();
         let intent_payload = scrypto_encode(&intent).unwrap();
@@ -65,6 +73,7 @@ impl TransactionBuilder {
         TransactionIntent {
             manifest: self.manifest.clone().expect("Manifest not specified"),
             header: self.header.clone().expect("Header not specified"),
+            subintents: self.subintents.clone(),
         }
     }
 
@@ -16,10 +16,26 @@ pub enum HeaderValidationError {
     InvalidTipBps,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubintentValidationError {
+    InvalidNetwork(u32),
+    InvalidEpochRange(u32),
+    EpochRangeTooLarge(u32),
+    /// A yield to an index past the last sub-intent
+    SubintentNotFound(u32),
+    SubintentYieldedMoreThanOnce(u32),
+    SubintentNeverYielded(u32),
+    /// A yield from the manifest of a sub-intent, which may only be yielded to
+    YieldFromSubintent(u32),
+    /// A sub-intent which already appears earlier in the transaction, or as its intent
+    DuplicateSubintent(u32),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SignatureValidationError {
     TooManySignatures,
     InvalidIntentSignature,
+    InvalidSubintentSignature(u32),
     InvalidNotarySignature,
     DuplicateSigner,
     SerializationError(EncodeError),
@@ -59,6 +75,7 @@ pub enum TransactionValidationError {
     DeserializationError(DecodeError),
     IntentHashRejected,
     HeaderValidationError(HeaderValidationError),
+    SubintentValidationError(SubintentValidationError),
     SignatureValidationError(SignatureValidationError),
     IdValidationError(IdValidationError),
     CallDataValidationError(CallDataValidationError),
@@ -109,11 +109,16 @@ pub enum Instruction {
         owner_badge: Value,
     },
 
+    YieldToSubintent {
+        subintent_index: Value,
+    },
+
     CreateResource {
         resource_type: Value,
         metadata: Value,
         access_rules: Value,
         mint_params: Value,
+        max_supply: Value,
     },
 
     BurnBucket {
@@ -152,6 +157,8 @@ pub enum RENode {
     Package(Value),
     EpochManager(Value),
     Clock(Value),
+    Account(Value),
+    AccessController(Value),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -172,7 +172,9 @@ mod tests {
                         access_rules: HashMap::new(),
                         mint_params: Some(MintParams::Fungible {
                             amount: "1.0".into()
-                        })
+                        }),
+                        max_supply: None,
+                        non_fungible_data_schema: None,
                     }).unwrap(),
                 },
                 Instruction::CallMethod {
@@ -354,6 +354,9 @@ pub fn decompile_instruction<F: fmt::Write>(
                     .display(context.for_value_display()),
             )?;
         }
+        Instruction::YieldToSubintent { subintent_index } => {
+            write!(f, "YIELD_TO_SUBINTENT {}u32;", subintent_index)?;
+        }
     }
     Ok(())
 }
@@ -405,9 +408,16 @@ pub fn decompile_call_native_function<F: fmt::Write>(
             }
         }
         ("ResourceManager", "create") => {
-            if let Ok(input) = scrypto_decode::<ResourceManagerCreateInvocation>(&args) {
+            // Non-fungible data schemas can only be expressed with the generic representation
+            if let Ok(
+                input @ ResourceManagerCreateInvocation {
+                    non_fungible_data_schema: None,
+                    ..
+                },
+            ) = scrypto_decode::<ResourceManagerCreateInvocation>(&args)
+            {
                 f.write_str(&format!(
-                    "CREATE_RESOURCE {} {} {} {};",
+                    "CREATE_RESOURCE {} {} {} {} {};",
                     IndexedScryptoValue::from_typed(&input.resource_type)
                         .display(context.for_value_display()),
                     IndexedScryptoValue::from_typed(&input.metadata)
@@ -416,6 +426,8 @@ pub fn decompile_call_native_function<F: fmt::Write>(
                         .display(context.for_value_display()),
                     IndexedScryptoValue::from_typed(&input.mint_params)
                         .display(context.for_value_display()),
+                    IndexedScryptoValue::from_typed(&input.max_supply)
+                        .display(context.for_value_display()),
                 ))?;
                 return Ok(());
             }
@@ -548,6 +560,8 @@ fn format_node_id(node_id: &RENodeId, context: &mut DecompilationContext) -> Str
         RENodeId::Component(id) => format!("Component(\"{}\")", format_id(id)),
         RENodeId::EpochManager(id) => format!("EpochManager(\"{}\")", format_id(id)),
         RENodeId::Clock(id) => format!("Clock(\"{}\")", format_id(id)),
+        RENodeId::Account(id) => format!("Account(\"{}\")", format_id(id)),
+        RENodeId::AccessController(id) => format!("AccessController(\"{}\")", format_id(id)),
         RENodeId::Vault(id) => format!("Vault(\"{}\")", format_id(id)),
         RENodeId::ResourceManager(id) => format!("ResourceManager(\"{}\")", format_id(id)),
         RENodeId::Package(id) => format!("Package(\"{}\")", format_id(id)),
@@ -652,7 +666,7 @@ POP_FROM_AUTH_ZONE Proof("proof3");
 DROP_PROOF Proof("proof3");
 RETURN_TO_WORKTOP Bucket("bucket2");
 TAKE_FROM_WORKTOP_BY_IDS Array<NonFungibleId>(NonFungibleId(Bytes("031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f"))) ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") Bucket("bucket3");
-CREATE_RESOURCE Enum("Fungible", 0u8) Array<Tuple>() Array<Tuple>() Some(Enum("Fungible", Decimal("1")));
+CREATE_RESOURCE Enum("Fungible", 0u8) Array<Tuple>() Array<Tuple>() Some(Enum("Fungible", Decimal("1"))) None;
 CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "deposit_batch" Expression("ENTIRE_WORKTOP");
 DROP_ALL_PROOFS;
 CALL_METHOD ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum") "complicated_method" Decimal("1") PreciseDecimal("2");
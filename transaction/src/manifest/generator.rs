@@ -448,11 +448,15 @@ pub fn generate_instruction(
             abi: generate_blob(abi, blobs)?,
             owner_badge: generate_non_fungible_address(owner_badge, bech32_decoder)?,
         },
+        ast::Instruction::YieldToSubintent { subintent_index } => Instruction::YieldToSubintent {
+            subintent_index: generate_u32(subintent_index)?,
+        },
         ast::Instruction::CreateResource {
             resource_type,
             metadata,
             access_rules,
             mint_params,
+            max_supply,
         } => {
             // Generates call data
             let mut args = Vec::new();
@@ -461,6 +465,7 @@ pub fn generate_instruction(
                 generate_value(metadata, None, resolver, bech32_decoder, blobs)?,
                 generate_value(access_rules, None, resolver, bech32_decoder, blobs)?,
                 generate_value(mint_params, None, resolver, bech32_decoder, blobs)?,
+                generate_value(max_supply, None, resolver, bech32_decoder, blobs)?,
             ] {
                 let validated_arg = IndexedScryptoValue::from_value(arg)
                     .expect("Failed to convert value into IndexedScryptoValue");
@@ -469,6 +474,8 @@ pub fn generate_instruction(
                     .map_err(GeneratorError::IdValidationError)?;
                 args.push(validated_arg.dom);
             }
+            // Non-fungible data schemas can't be expressed in the manifest syntax
+            args.push(IndexedScryptoValue::from_typed(&Option::<NonFungibleDataSchema>::None).dom);
             let args = args_from_value_vec!(args);
 
             // Check if call data matches ABI
@@ -552,6 +559,13 @@ fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     }
 }
 
+fn generate_u32(value: &ast::Value) -> Result<u32, GeneratorError> {
+    match value {
+        ast::Value::U32(n) => Ok(*n),
+        v => invalid_type!(v, ast::Type::U32),
+    }
+}
+
 fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     match value {
         ast::Value::Decimal(inner) => match &**inner {
@@ -770,6 +784,10 @@ fn generate_re_node_id(
             Ok(RENodeId::EpochManager(generate_node_id(node_id)?))
         }
         ast::RENode::Clock(node_id) => Ok(RENodeId::Clock(generate_node_id(node_id)?)),
+        ast::RENode::Account(node_id) => Ok(RENodeId::Account(generate_node_id(node_id)?)),
+        ast::RENode::AccessController(node_id) => {
+            Ok(RENodeId::AccessController(generate_node_id(node_id)?))
+        }
         ast::RENode::Vault(node_id) => Ok(RENodeId::Vault(generate_node_id(node_id)?)),
         ast::RENode::ResourceManager(node_id) => {
             Ok(RENodeId::ResourceManager(generate_node_id(node_id)?))
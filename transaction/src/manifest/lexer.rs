@@ -79,6 +79,8 @@ pub enum TokenKind {
     ResourceManager,
     Package,
     Clock,
+    Account,
+    AccessController,
 
     /* Other interpreted */
     Expression,
@@ -126,6 +128,7 @@ pub enum TokenKind {
     CallNativeFunction,
     CallNativeMethod,
     PublishPackageWithOwner,
+    YieldToSubintent,
     CreateResource,
     BurnBucket,
     MintFungible,
@@ -427,6 +430,8 @@ impl Lexer {
             "ResourceManager" => Ok(TokenKind::ResourceManager),
             "Package" => Ok(TokenKind::Package),
             "Clock" => Ok(TokenKind::Clock),
+            "Account" => Ok(TokenKind::Account),
+            "AccessController" => Ok(TokenKind::AccessController),
 
             "Expression" => Ok(TokenKind::Expression),
             "Blob" => Ok(TokenKind::Blob),
@@ -465,6 +470,7 @@ impl Lexer {
             "CALL_NATIVE_FUNCTION" => Ok(TokenKind::CallNativeFunction),
             "CALL_NATIVE_METHOD" => Ok(TokenKind::CallNativeMethod),
             "PUBLISH_PACKAGE_WITH_OWNER" => Ok(TokenKind::PublishPackageWithOwner),
+            "YIELD_TO_SUBINTENT" => Ok(TokenKind::YieldToSubintent),
             "CREATE_RESOURCE" => Ok(TokenKind::CreateResource),
             "BURN_BUCKET" => Ok(TokenKind::BurnBucket),
             "MINT_FUNGIBLE" => Ok(TokenKind::MintFungible),
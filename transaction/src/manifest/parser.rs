@@ -186,11 +186,15 @@ impl Parser {
                 abi: self.parse_value()?,
                 owner_badge: self.parse_value()?,
             },
+            TokenKind::YieldToSubintent => Instruction::YieldToSubintent {
+                subintent_index: self.parse_value()?,
+            },
             TokenKind::CreateResource => Instruction::CreateResource {
                 resource_type: self.parse_value()?,
                 metadata: self.parse_value()?,
                 access_rules: self.parse_value()?,
                 mint_params: self.parse_value()?,
+                max_supply: self.parse_value()?,
             },
             TokenKind::BurnBucket => Instruction::BurnBucket {
                 bucket: self.parse_value()?,
@@ -230,7 +234,9 @@ impl Parser {
             | TokenKind::EpochManager
             | TokenKind::Vault
             | TokenKind::ResourceManager
-            | TokenKind::Package => Ok(Receiver::Ref(self.parse_re_node()?)),
+            | TokenKind::Package
+            | TokenKind::Account
+            | TokenKind::AccessController => Ok(Receiver::Ref(self.parse_re_node()?)),
             _ => Err(ParserError::UnexpectedToken(token)),
         }
     }
@@ -250,6 +256,8 @@ impl Parser {
             TokenKind::Vault => Ok(RENode::Vault(self.parse_values_one()?)),
             TokenKind::ResourceManager => Ok(RENode::ResourceManager(self.parse_values_one()?)),
             TokenKind::Package => Ok(RENode::Package(self.parse_values_one()?)),
+            TokenKind::Account => Ok(RENode::Account(self.parse_values_one()?)),
+            TokenKind::AccessController => Ok(RENode::AccessController(self.parse_values_one()?)),
             _ => Err(ParserError::UnexpectedToken(token)),
         }
     }
@@ -701,6 +709,12 @@ mod tests {
             }
         );
         parse_instruction_ok!(r#"DROP_ALL_PROOFS;"#, Instruction::DropAllProofs);
+        parse_instruction_ok!(
+            r#"YIELD_TO_SUBINTENT 1u32;"#,
+            Instruction::YieldToSubintent {
+                subintent_index: Value::U32(1),
+            }
+        );
         parse_instruction_ok!(
             r#"CALL_FUNCTION  PackageAddress("01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c")  "Airdrop"  "new"  500u32;"#,
             Instruction::CallFunction {
@@ -746,7 +760,7 @@ mod tests {
     #[test]
     fn test_create_resource() {
         parse_instruction_ok!(
-            r#"CREATE_RESOURCE Enum("Fungible", 0u8) Array<Tuple>() Array<Tuple>() Enum("Some", Enum("Fungible", Decimal("1.0")));"#,
+            r#"CREATE_RESOURCE Enum("Fungible", 0u8) Array<Tuple>() Array<Tuple>() Enum("Some", Enum("Fungible", Decimal("1.0"))) Enum("Some", Decimal("10.0"));"#,
             Instruction::CreateResource {
                 resource_type: Value::Enum("Fungible".to_string(), vec![Value::U8(0)]),
                 metadata: Value::Array(Type::Tuple, vec![]),
@@ -758,6 +772,10 @@ mod tests {
                         vec![Value::Decimal(Value::String("1.0".into()).into())]
                     )]
                 ),
+                max_supply: Value::Enum(
+                    "Some".to_string(),
+                    vec![Value::Decimal(Value::String("10.0".into()).into())]
+                ),
             }
         );
     }
@@ -1,6 +1,7 @@
 use radix_engine_interface::crypto::{hash, Hash};
 use radix_engine_interface::model::*;
 use radix_engine_interface::scrypto;
+use sbor::rust::borrow::Cow;
 use sbor::rust::collections::{BTreeSet, HashMap};
 use sbor::rust::vec::Vec;
 use sbor::{Decode, Encode, TypeId};
@@ -38,6 +39,16 @@ pub struct Executable<'a> {
     instructions: &'a [Instruction],
     blobs: HashMap<Hash, &'a [u8]>,
     context: ExecutionContext,
+    subintents: Vec<ExecutableSubintent<'a>>,
+}
+
+/// Represents a validated sub-intent, which is run when the transaction manifest yields to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ExecutableSubintent<'a> {
+    pub instructions: Cow<'a, [Instruction]>,
+    /// The proofs of the sub-intent signers, which are only visible to its own instructions
+    pub auth_zone_params: AuthZoneParams,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -53,8 +64,11 @@ pub struct RuntimeValidationRequest {
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum RuntimeValidation {
-    /// To ensure we don't commit a duplicate intent hash
-    IntentHashUniqueness { intent_hash: Hash },
+    /// To ensure we don't commit a duplicate intent hash, until the intent's epoch range has ended
+    IntentHashUniqueness {
+        intent_hash: Hash,
+        end_epoch_exclusive: u64,
+    },
     /// For preview - still do the look-ups to give equivalent cost unit spend, but ignore the result
     WithinEpochRange {
         start_epoch_inclusive: u64,
@@ -89,9 +103,25 @@ impl<'a> Executable<'a> {
             instructions,
             blobs,
             context,
+            subintents: Vec::new(),
         }
     }
 
+    /// Adds the sub-intent at the next index. Its blobs are shared with the whole transaction.
+    pub fn add_subintent(
+        &mut self,
+        instructions: &'a [Instruction],
+        blobs: &'a [Vec<u8>],
+        auth_zone_params: AuthZoneParams,
+    ) {
+        self.blobs
+            .extend(blobs.iter().map(|b| (hash(b), b.as_slice())));
+        self.subintents.push(ExecutableSubintent {
+            instructions: Cow::Borrowed(instructions),
+            auth_zone_params,
+        });
+    }
+
     pub fn transaction_hash(&self) -> &Hash {
         &self.context.transaction_hash
     }
@@ -115,4 +145,23 @@ impl<'a> Executable<'a> {
     pub fn runtime_validations(&self) -> &[RuntimeValidationRequest] {
         &self.context.runtime_validations
     }
+
+    /// The hashes of the intents committed by this transaction, with the epoch they expire at
+    pub fn intent_hashes(&self) -> Vec<(Hash, u64)> {
+        self.context
+            .runtime_validations
+            .iter()
+            .filter_map(|request| match request.validation {
+                RuntimeValidation::IntentHashUniqueness {
+                    intent_hash,
+                    end_epoch_exclusive,
+                } => Some((intent_hash, end_epoch_exclusive)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn subintents(&self) -> &[ExecutableSubintent<'a>] {
+        &self.subintents
+    }
 }
@@ -122,4 +122,9 @@ pub enum Instruction {
         abi: Blob,
         owner_badge: NonFungibleAddress,
     },
+
+    /// Runs the manifest of a sub-intent of the transaction to completion.
+    ///
+    /// The sub-intent shares the worktop, but not the buckets, proofs or auth zone.
+    YieldToSubintent { subintent_index: u32 },
 }
@@ -24,11 +24,39 @@ pub struct TransactionHeader {
     pub tip_percentage: u8,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct SubintentHeader {
+    pub network_id: u8,
+    pub start_epoch_inclusive: u64,
+    pub end_epoch_exclusive: u64,
+    pub nonce: u64,
+}
+
+/// A manifest fragment of one party of a transaction, which is signed independently of the
+/// transaction it is composed into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct Subintent {
+    pub header: SubintentHeader,
+    pub manifest: TransactionManifest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct SignedSubintent {
+    pub subintent: Subintent,
+    pub subintent_signatures: Vec<SignatureWithPublicKey>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct TransactionIntent {
     pub header: TransactionHeader,
     pub manifest: TransactionManifest,
+    /// The sub-intents the manifest yields to, by index
+    pub subintents: Vec<SignedSubintent>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -80,6 +108,7 @@ impl TransactionIntent {
         Ok(Self {
             header,
             manifest: compile(manifest, &network, blobs)?,
+            subintents: Vec::new(),
         })
     }
 
@@ -96,6 +125,50 @@ impl TransactionIntent {
     }
 }
 
+impl Subintent {
+    pub fn new(
+        network: &NetworkDefinition,
+        header: SubintentHeader,
+        manifest: &str,
+        blobs: Vec<Vec<u8>>,
+    ) -> Result<Self, IntentCreationError> {
+        if network.id != header.network_id {
+            return Err(IntentCreationError::ConfigErr(
+                IntentConfigError::MismatchedNetwork {
+                    expected: network.id,
+                    actual: header.network_id,
+                },
+            ));
+        }
+        Ok(Self {
+            header,
+            manifest: compile(manifest, &network, blobs)?,
+        })
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode(slice)
+    }
+
+    pub fn hash(&self) -> Result<Hash, EncodeError> {
+        Ok(hash(self.to_bytes()?))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(self)
+    }
+}
+
+impl SignedSubintent {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode(slice)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(self)
+    }
+}
+
 impl SignedTransactionIntent {
     pub fn from_slice(slice: &[u8]) -> Result<Self, DecodeError> {
         scrypto_decode(slice)
@@ -171,18 +244,18 @@ mod tests {
         };
 
         assert_eq!(
-            "9cda7159f23b34d561ef8e4cb5d8b39285a0a31f184519e6f785948fd606e567",
+            "dd2c961e5c98bc6232ecd1b94f02d96011c500b66361a9407c9946dee9f8bfaf",
             transaction.signed_intent.intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "b89323e4e7ade97cd220b149e89976bd4c0626c1c1b387b373ea260190c2a2cd",
+            "b256b4521363f8626e6a04cde3a82b505a0a01920e2abbe8772c31d3a588a6de",
             transaction.signed_intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "fe0afee4fad4b6a033eb7c11254bfd7202fcc210eda08d718ee4443c71034553",
+            "8439bd0c92ba36b5e614353e1b7dd96138ee21e7c01e1f1118a89dee63f98c53",
             transaction.hash().unwrap().to_string()
         );
-        assert_eq!("5c2102210221022109070107f20a00000000000000000a64000000000000000a0500000000000000110e4563647361536563703235366b3101b102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f00070521022011010d436c656172417574685a6f6e65002020002011020e4563647361536563703235366b3101b20183dca0e64adce008b3d3f40db9135b53e2f01b9fe41177b8be68a1bfde4deebb7fa17743cca82405f4707d791bb6aa68a7734f34ebff46674f1b18159162dcd60e4563647361536563703235366b3101b20142d83a8ef50e4a0f08db5c524799823f977779c43bdfa0adb5a4b2116b375ef80ec7f5a70e82f6553b91d3103c72e802c057db07b0cfa098714f6ac739cb995a110e4563647361536563703235366b3101b20144828e8764cd9734fd1e5b96430cb58c8ed1ec1f89e8c9a35e2ba1c484ae4b9a54009875f296a6b08f2930e140dda0f6e1b82745e99d745fe183b82ccc45372c", hex::encode(scrypto_encode(&transaction).unwrap()));
+        assert_eq!("5c2102210221032109070107f20a00000000000000000a64000000000000000a0500000000000000110e4563647361536563703235366b3101b102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f00070521022011010d436c656172417574685a6f6e65002020002021002011020e4563647361536563703235366b3101b2014e3c9a4ad4086bf98866efec5142f701906d48ae380154324c6dad86097647e70c3c32591c59b616101b2633e7b0cc6154fb8ddc59e848c8297f8e3b863e434e0e4563647361536563703235366b3101b2013ff3dc0a0ec85fa38b94195a583494a465f9e1983e459c6c20340c095b43e86a5c5a903c2f19deaf0bb5406a905d6ccb7bad820dfe6f2c3e15e0176048b11eda110e4563647361536563703235366b3101b20167a879eb81b5a83360c62d774086023c07b1cdae0a7d6db2cbef4f5268d3d40a1cd295723ffcda7c232700ebd4465c7c63ca3b99974553a4c5fe5d2afc5e077a", hex::encode(scrypto_encode(&transaction).unwrap()));
     }
 
     #[test]
@@ -227,17 +300,17 @@ mod tests {
         };
 
         assert_eq!(
-            "1933fc0545cc2ca8bf57a6fdba3a383bd6028ccc992cc3cae1505e57cdefc009",
+            "a497d46917746031f05e731aa1b0a104d0bf77a7808c3a19c6cde3d364ac071c",
             transaction.signed_intent.intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "bbd6fb37e2aba741a584cb992275369d9d6e85fd32977c6250e0576d3dc0f4cc",
+            "1235c8891eaa8e154f3fc6e69de66490a308f152f21af2d880a253d992d6ccee",
             transaction.signed_intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "25a1a372864e1ec9dd7126fe3c9f389d9521adb9c53476390af0b1eb414e6a20",
+            "a1646493c97bcf2bdf6f9ebdf0aefb934f103672cf0240ae33b6cacfc6d5fe7a",
             transaction.hash().unwrap().to_string()
         );
-        assert_eq!("5c2102210221022109070107f20a00000000000000000a64000000000000000a0500000000000000110c45646473614564323535313901b3f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f00070521022011010d436c656172417574685a6f6e65002020002011020c45646473614564323535313902b34cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29b492b6e108ec83c86015eec245ed44ba438c61cb4ec4396583d44e9f9e045cc95e703bae15bc343b58fa8b4e3ea44c30c2836050120c2404a9b34f26041fdd6e000c45646473614564323535313902b37422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe2674b4a28834022531011c128b43841ef86a27af1c9e56351291fa3ef65f1bc8b3ad74e8d2cc0f4f9b58bccd0cebe0f83ee5553e03bfb62b7f19094ece1a1c18e36000110c45646473614564323535313901b4d90ad73e09dd9537251a949c09da8371b772555ff51026395c48afb97b7be7aeb349f4e4026fca0cd84260032ccb8f6b161fcf8ddbcc9331f24e83573d2d7805", hex::encode(scrypto_encode(&transaction).unwrap()));
+        assert_eq!("5c2102210221032109070107f20a00000000000000000a64000000000000000a0500000000000000110c45646473614564323535313901b3f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f00070521022011010d436c656172417574685a6f6e65002020002021002011020c45646473614564323535313902b34cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29b4ae07802f8d7a8d9254935bd78dd7f7f96463b6ea823e90eefeda5d8d89ddb0de23f09b193b4e94b0b018212c1204a3aac2655de9905ef9578d58ea3a7de9f70f0c45646473614564323535313902b37422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe2674b400a4a4f88fdb0ade5949ff7d49a57484620df0248e934297a64293be0cfef3b70828498f5020e02763311737fcfe12df76b807854a6477c11501a2856de93d0f110c45646473614564323535313901b44ed0f3855c425dfe1305347cc8915bf867bd804c7a14b28ca571bd363cbe0ec8a74a9f355d02d9980536603e918ca01f4da7b5e10a58248d780ded56cef78009", hex::encode(scrypto_encode(&transaction).unwrap()));
     }
 }
@@ -11,6 +11,8 @@ pub struct PreviewFlags {
     pub assume_all_signature_proofs: bool,
     pub permit_duplicate_intent_hash: bool,
     pub permit_invalid_header_epoch: bool,
+    /// Whether to break the costs down by instruction and invocation
+    pub cost_breakdown: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,6 +1,6 @@
 use radix_engine_constants::TRANSACTION_VERSION_V1;
 use radix_engine_interface::core::NetworkDefinition;
-use radix_engine_interface::crypto::{EcdsaSecp256k1PublicKey, EcdsaSecp256k1Signature};
+use radix_engine_interface::crypto::{EcdsaSecp256k1PublicKey, EcdsaSecp256k1Signature, Hash};
 use radix_engine_interface::model::*;
 
 use sbor::rust::vec::Vec;
@@ -34,6 +34,14 @@ impl TestTransaction {
         Self { transaction }
     }
 
+    pub fn intent_hash(&self) -> Hash {
+        self.transaction.signed_intent.intent.hash().unwrap()
+    }
+
+    pub fn header(&self) -> &TransactionHeader {
+        &self.transaction.signed_intent.intent.header
+    }
+
     pub fn get_executable<'a>(&'a self, initial_proofs: Vec<NonFungibleAddress>) -> Executable<'a> {
         let transaction_hash = self.transaction.hash().unwrap();
         let intent = &self.transaction.signed_intent.intent;
@@ -51,7 +59,11 @@ impl TestTransaction {
                     cost_unit_limit: intent.header.cost_unit_limit,
                     tip_percentage: intent.header.tip_percentage,
                 },
-                runtime_validations: vec![],
+                runtime_validations: vec![RuntimeValidation::IntentHashUniqueness {
+                    intent_hash: self.intent_hash(),
+                    end_epoch_exclusive: intent.header.end_epoch_exclusive,
+                }
+                .enforced()],
             },
         )
     }
@@ -69,7 +69,7 @@ mod tests {
     #[test]
     fn test_non_fungible_address_codec() {
         let expected_id_hex = "031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f";
-        let expected_address_hex = "00b91737ee8a4de59d49dad40de5560e5754466ac84cf5432ea95d5c200721031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f";
+        let expected_address_hex = "007595a1c09bc044384fba809bc7bc5c3b53f192b27ed537a7a4c95c200721031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f";
         let private_key = EcdsaSecp256k1PrivateKey::from_bytes(&[1u8; 32]).unwrap();
         let public_key = private_key.public_key();
         let auth_address = NonFungibleAddress::new(
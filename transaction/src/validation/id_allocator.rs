@@ -70,6 +70,17 @@ impl IdAllocator {
         Ok(ComponentAddress::Account(hash(data).lower_26_bytes()))
     }
 
+    pub fn new_access_controller_address(
+        &mut self,
+        transaction_hash: Hash,
+    ) -> Result<ComponentAddress, IdAllocationError> {
+        let mut data = transaction_hash.to_vec();
+        data.extend(self.next()?.to_le_bytes());
+        Ok(ComponentAddress::AccessController(
+            hash(data).lower_26_bytes(),
+        ))
+    }
+
     /// Creates a new component address.
     pub fn new_component_address(
         &mut self,
@@ -1,10 +1,34 @@
 use radix_engine_interface::crypto::Hash;
 use sbor::rust::collections::HashMap;
+use sbor::rust::vec::Vec;
 
 pub trait IntentHashManager {
     fn allows(&self, hash: &Hash) -> bool;
 }
 
+/// A persistent record of the intent hashes of committed transactions.
+///
+/// Each hash is kept until the epoch range of its intent has ended, after which the intent can
+/// no longer be committed and its hash can be pruned.
+pub trait IntentHashStore {
+    /// Returns the `end_epoch_exclusive` of the committed intent with the given hash.
+    fn get_intent_hash_end_epoch(&self, intent_hash: &Hash) -> Option<u64>;
+
+    fn put_intent_hash(&mut self, intent_hash: Hash, end_epoch_exclusive: u64);
+
+    /// Removes the intent hashes with an `end_epoch_exclusive` at or before the given epoch.
+    fn remove_expired_intent_hashes(&mut self, current_epoch: u64);
+
+    /// Returns all intent hashes with their `end_epoch_exclusive`, ordered by the latter.
+    fn list_intent_hashes(&self) -> Vec<(Hash, u64)>;
+}
+
+impl<S: IntentHashStore> IntentHashManager for S {
+    fn allows(&self, hash: &Hash) -> bool {
+        self.get_intent_hash_end_epoch(hash).is_none()
+    }
+}
+
 pub enum HashStatus {
     Commited,
     Cancelled,
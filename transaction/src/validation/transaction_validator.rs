@@ -79,12 +79,39 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
         let signer_keys = self
             .validate_signatures(&transaction)
             .map_err(TransactionValidationError::SignatureValidationError)?;
+        let mut subintent_signer_keys = Vec::new();
+        for (index, signed_subintent) in intent.subintents.iter().enumerate() {
+            subintent_signer_keys.push(
+                self.validate_subintent_signatures(index as u32, signed_subintent)
+                    .map_err(TransactionValidationError::SignatureValidationError)?,
+            );
+        }
 
         let transaction_hash = transaction.hash()?;
 
         let header = &intent.header;
 
-        Ok(Executable::new(
+        let mut runtime_validations = vec![
+            RuntimeValidation::IntentHashUniqueness {
+                intent_hash,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            }
+            .enforced(),
+            RuntimeValidation::WithinEpochRange {
+                start_epoch_inclusive: header.start_epoch_inclusive,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            }
+            .enforced(),
+        ];
+        for signed_subintent in &intent.subintents {
+            runtime_validations.extend(Self::subintent_runtime_validations(
+                &signed_subintent.subintent,
+                false,
+                false,
+            )?);
+        }
+
+        let mut executable = Executable::new(
             &intent.manifest.instructions,
             &intent.manifest.blobs,
             ExecutionContext {
@@ -97,16 +124,22 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
                     cost_unit_limit: header.cost_unit_limit,
                     tip_percentage: header.tip_percentage,
                 },
-                runtime_validations: vec![
-                    RuntimeValidation::IntentHashUniqueness { intent_hash }.enforced(),
-                    RuntimeValidation::WithinEpochRange {
-                        start_epoch_inclusive: header.start_epoch_inclusive,
-                        end_epoch_exclusive: header.end_epoch_exclusive,
-                    }
-                    .enforced(),
-                ],
+                runtime_validations,
             },
-        ))
+        );
+        for (signed_subintent, signer_keys) in intent.subintents.iter().zip(subintent_signer_keys) {
+            let manifest = &signed_subintent.subintent.manifest;
+            executable.add_subintent(
+                &manifest.instructions,
+                &manifest.blobs,
+                AuthZoneParams {
+                    initial_proofs: AuthModule::pk_non_fungibles(&signer_keys),
+                    virtualizable_proofs_resource_addresses: BTreeSet::new(),
+                },
+            );
+        }
+
+        Ok(executable)
     }
 }
 
@@ -146,27 +179,85 @@ impl NotarizedTransactionValidator {
             }
         };
 
-        Ok(Executable::new(
+        // The sub-intents are signed as part of the intent, so their signatures are verified
+        let mut subintent_signer_keys = Vec::new();
+        for (index, signed_subintent) in intent.subintents.iter().enumerate() {
+            subintent_signer_keys.push(
+                self.validate_subintent_signatures(index as u32, signed_subintent)
+                    .map_err(TransactionValidationError::SignatureValidationError)?,
+            );
+        }
+
+        let mut runtime_validations = vec![
+            RuntimeValidation::IntentHashUniqueness {
+                intent_hash,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            }
+            .with_skipped_assertion_if(flags.permit_duplicate_intent_hash),
+            RuntimeValidation::WithinEpochRange {
+                start_epoch_inclusive: header.start_epoch_inclusive,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            }
+            .with_skipped_assertion_if(flags.permit_invalid_header_epoch),
+        ];
+        for signed_subintent in &intent.subintents {
+            runtime_validations.extend(Self::subintent_runtime_validations(
+                &signed_subintent.subintent,
+                flags.permit_duplicate_intent_hash,
+                flags.permit_invalid_header_epoch,
+            )?);
+        }
+
+        let mut executable = Executable::new(
             &manifest.instructions,
             &manifest.blobs,
             ExecutionContext {
                 transaction_hash,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
-                    virtualizable_proofs_resource_addresses,
+                    virtualizable_proofs_resource_addresses:
+                        virtualizable_proofs_resource_addresses.clone(),
                 },
                 fee_payment,
-                runtime_validations: vec![
-                    RuntimeValidation::IntentHashUniqueness { intent_hash }
-                        .with_skipped_assertion_if(flags.permit_duplicate_intent_hash),
-                    RuntimeValidation::WithinEpochRange {
-                        start_epoch_inclusive: header.start_epoch_inclusive,
-                        end_epoch_exclusive: header.end_epoch_exclusive,
-                    }
-                    .with_skipped_assertion_if(flags.permit_invalid_header_epoch),
-                ],
+                runtime_validations,
             },
-        ))
+        );
+        for (signed_subintent, signer_keys) in intent.subintents.iter().zip(subintent_signer_keys) {
+            let manifest = &signed_subintent.subintent.manifest;
+            executable.add_subintent(
+                &manifest.instructions,
+                &manifest.blobs,
+                AuthZoneParams {
+                    initial_proofs: AuthModule::pk_non_fungibles(&signer_keys),
+                    virtualizable_proofs_resource_addresses:
+                        virtualizable_proofs_resource_addresses.clone(),
+                },
+            );
+        }
+
+        Ok(executable)
+    }
+
+    fn subintent_runtime_validations(
+        subintent: &Subintent,
+        permit_duplicate_intent_hash: bool,
+        permit_invalid_header_epoch: bool,
+    ) -> Result<Vec<RuntimeValidationRequest>, TransactionValidationError> {
+        let header = &subintent.header;
+        Ok(vec![
+            // Sub-intents can be composed into any transaction, so their hashes are committed
+            // alongside the intent hash, for the validator to reject replays
+            RuntimeValidation::IntentHashUniqueness {
+                intent_hash: subintent.hash()?,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            }
+            .with_skipped_assertion_if(permit_duplicate_intent_hash),
+            RuntimeValidation::WithinEpochRange {
+                start_epoch_inclusive: header.start_epoch_inclusive,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            }
+            .with_skipped_assertion_if(permit_invalid_header_epoch),
+        ])
     }
 
     pub fn validate_intent<I: IntentHashManager>(
@@ -186,9 +277,74 @@ impl NotarizedTransactionValidator {
 
         Self::validate_manifest(&intent.manifest)?;
 
+        // Each sub-intent hash is committed alongside the intent hash, so a duplicate would
+        // let the same sub-intent run twice
+        let mut intent_hashes = HashSet::new();
+        intent_hashes.insert(*intent_hash);
+        for (index, signed_subintent) in intent.subintents.iter().enumerate() {
+            let subintent = &signed_subintent.subintent;
+            let subintent_hash = subintent.hash()?;
+            if !intent_hash_manager.allows(&subintent_hash) {
+                return Err(TransactionValidationError::IntentHashRejected);
+            }
+            if !intent_hashes.insert(subintent_hash) {
+                return Err(TransactionValidationError::SubintentValidationError(
+                    SubintentValidationError::DuplicateSubintent(index as u32),
+                ));
+            }
+
+            self.validate_subintent_header(index as u32, &subintent.header)
+                .map_err(TransactionValidationError::SubintentValidationError)?;
+
+            Self::validate_manifest(&subintent.manifest)?;
+        }
+
+        Self::validate_subintent_yields(intent)
+            .map_err(TransactionValidationError::SubintentValidationError)?;
+
         return Ok(());
     }
 
+    /// Checks that the intent manifest yields to each of its sub-intents exactly once, and that
+    /// the sub-intent manifests don't yield any further.
+    pub fn validate_subintent_yields(
+        intent: &TransactionIntent,
+    ) -> Result<(), SubintentValidationError> {
+        let mut yielded = BTreeSet::new();
+        for inst in &intent.manifest.instructions {
+            if let Instruction::YieldToSubintent { subintent_index } = inst {
+                if *subintent_index as usize >= intent.subintents.len() {
+                    return Err(SubintentValidationError::SubintentNotFound(
+                        *subintent_index,
+                    ));
+                }
+                if !yielded.insert(*subintent_index) {
+                    return Err(SubintentValidationError::SubintentYieldedMoreThanOnce(
+                        *subintent_index,
+                    ));
+                }
+            }
+        }
+
+        for (index, signed_subintent) in intent.subintents.iter().enumerate() {
+            let index = index as u32;
+            if !yielded.contains(&index) {
+                return Err(SubintentValidationError::SubintentNeverYielded(index));
+            }
+            if signed_subintent
+                .subintent
+                .manifest
+                .instructions
+                .iter()
+                .any(|inst| matches!(inst, Instruction::YieldToSubintent { .. }))
+            {
+                return Err(SubintentValidationError::YieldFromSubintent(index));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn validate_manifest(
         manifest: &TransactionManifest,
     ) -> Result<(), TransactionValidationError> {
@@ -274,6 +430,7 @@ impl NotarizedTransactionValidator {
                         .map_err(TransactionValidationError::CallDataValidationError)?;
                 }
                 Instruction::PublishPackageWithOwner { .. } => {}
+                Instruction::YieldToSubintent { .. } => {}
             }
         }
 
@@ -318,6 +475,61 @@ impl NotarizedTransactionValidator {
         Ok(())
     }
 
+    pub fn validate_subintent_header(
+        &self,
+        subintent_index: u32,
+        header: &SubintentHeader,
+    ) -> Result<(), SubintentValidationError> {
+        // network
+        if header.network_id != self.config.network_id {
+            return Err(SubintentValidationError::InvalidNetwork(subintent_index));
+        }
+
+        // epoch
+        if header.end_epoch_exclusive <= header.start_epoch_inclusive {
+            return Err(SubintentValidationError::InvalidEpochRange(subintent_index));
+        }
+        if header.end_epoch_exclusive - header.start_epoch_inclusive > self.config.max_epoch_range {
+            return Err(SubintentValidationError::EpochRangeTooLarge(
+                subintent_index,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the signatures of a sub-intent, which only cover the sub-intent itself, so that
+    /// it can be composed into a transaction by another party.
+    pub fn validate_subintent_signatures(
+        &self,
+        subintent_index: u32,
+        signed_subintent: &SignedSubintent,
+    ) -> Result<Vec<PublicKey>, SignatureValidationError> {
+        if signed_subintent.subintent_signatures.len() > MAX_NUMBER_OF_INTENT_SIGNATURES {
+            return Err(SignatureValidationError::TooManySignatures);
+        }
+
+        let mut signers = HashSet::new();
+        let subintent_payload = signed_subintent.subintent.to_bytes()?;
+        for sig in &signed_subintent.subintent_signatures {
+            let public_key = recover(&subintent_payload, sig).ok_or(
+                SignatureValidationError::InvalidSubintentSignature(subintent_index),
+            )?;
+
+            if !verify(&subintent_payload, &public_key, &sig.signature()) {
+                return Err(SignatureValidationError::InvalidSubintentSignature(
+                    subintent_index,
+                ));
+            }
+
+            if !signers.insert(public_key) {
+                return Err(SignatureValidationError::DuplicateSigner);
+            }
+        }
+
+        Ok(signers.into_iter().collect())
+    }
+
     pub fn validate_signatures(
         &self,
         transaction: &NotarizedTransaction,
@@ -387,7 +599,8 @@ mod tests {
 
     use super::*;
     use crate::{
-        builder::ManifestBuilder, builder::TransactionBuilder, signing::EcdsaSecp256k1PrivateKey,
+        builder::ManifestBuilder, builder::SubintentBuilder, builder::TransactionBuilder,
+        signing::EcdsaSecp256k1PrivateKey, signing::EddsaEd25519PrivateKey,
     };
 
     macro_rules! assert_invalid_tx {
@@ -450,6 +663,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_subintent_yields() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let yield_manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .yield_to_subintent(0)
+            .build();
+
+        for (yields, subintents, expected) in [
+            (
+                vec![1],
+                vec![create_subintent(1, 5)],
+                SubintentValidationError::SubintentNotFound(1),
+            ),
+            (
+                vec![0, 0],
+                vec![create_subintent(1, 5)],
+                SubintentValidationError::SubintentYieldedMoreThanOnce(0),
+            ),
+            (
+                vec![0],
+                vec![create_subintent(1, 5), create_subintent(2, 6)],
+                SubintentValidationError::SubintentNeverYielded(1),
+            ),
+            (
+                vec![0],
+                vec![SubintentBuilder::new()
+                    .header(subintent_header(5))
+                    .manifest(yield_manifest.clone())
+                    .sign(&EddsaEd25519PrivateKey::from_u64(1).unwrap())
+                    .build()],
+                SubintentValidationError::YieldFromSubintent(0),
+            ),
+        ] {
+            assert_eq!(
+                Err(TransactionValidationError::SubintentValidationError(
+                    expected
+                )),
+                validator.validate(
+                    &create_transaction_with_subintents(yields, subintents),
+                    &TestIntentHashManager::new(),
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_duplicate_subintents() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+
+        assert_eq!(
+            Err(TransactionValidationError::SubintentValidationError(
+                SubintentValidationError::DuplicateSubintent(1)
+            )),
+            validator.validate(
+                &create_transaction_with_subintents(
+                    vec![0, 1],
+                    vec![create_subintent(1, 5), create_subintent(1, 5)]
+                ),
+                &TestIntentHashManager::new(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_subintent_signatures() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+
+        // The signature of a sub-intent doesn't cover any other sub-intent
+        let mut tampered = create_subintent(1, 5);
+        tampered.subintent.header.nonce = 6;
+        assert_eq!(
+            Err(TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::InvalidSubintentSignature(1)
+            )),
+            validator.validate(
+                &create_transaction_with_subintents(
+                    vec![0, 1],
+                    vec![create_subintent(2, 5), tampered]
+                ),
+                &TestIntentHashManager::new(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_valid_subintents() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+
+        let tx = create_transaction_with_subintents(
+            vec![1, 0],
+            vec![create_subintent(1, 5), create_subintent(2, 6)],
+        );
+        let executable = validator
+            .validate(&tx, &TestIntentHashManager::new())
+            .unwrap();
+
+        assert_eq!(executable.subintents().len(), 2);
+        for subintent in executable.subintents() {
+            assert_eq!(subintent.auth_zone_params.initial_proofs.len(), 1);
+        }
+    }
+
     #[test]
     fn test_valid_preview() {
         let mut intent_hash_manager: TestIntentHashManager = TestIntentHashManager::new();
@@ -467,6 +782,7 @@ mod tests {
                 assume_all_signature_proofs: false,
                 permit_invalid_header_epoch: false,
                 permit_duplicate_intent_hash: false,
+                cost_breakdown: false,
             },
         };
 
@@ -510,4 +826,55 @@ mod tests {
 
         builder.build()
     }
+
+    fn subintent_header(nonce: u64) -> SubintentHeader {
+        SubintentHeader {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce,
+        }
+    }
+
+    fn create_subintent(signer: u64, nonce: u64) -> SignedSubintent {
+        SubintentBuilder::new()
+            .header(subintent_header(nonce))
+            .manifest(
+                ManifestBuilder::new(&NetworkDefinition::simulator())
+                    .clear_auth_zone()
+                    .build(),
+            )
+            .sign(&EddsaEd25519PrivateKey::from_u64(signer).unwrap())
+            .build()
+    }
+
+    fn create_transaction_with_subintents(
+        yields: Vec<u32>,
+        subintents: Vec<SignedSubintent>,
+    ) -> NotarizedTransaction {
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+
+        let mut manifest_builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        for subintent_index in yields {
+            manifest_builder.yield_to_subintent(subintent_index);
+        }
+
+        let mut builder = TransactionBuilder::new()
+            .header(TransactionHeader {
+                version: 1,
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: 0,
+                end_epoch_exclusive: 100,
+                nonce: 5,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_as_signatory: false,
+                cost_unit_limit: 1_000_000,
+                tip_percentage: 5,
+            })
+            .manifest(manifest_builder.build());
+        for subintent in subintents {
+            builder = builder.subintent(subintent);
+        }
+        builder.notarize(&sk_notary).build()
+    }
 }